@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use crate::RollbackDsu;
+
+/// Offline dynamic connectivity.
+///
+/// Records a sequence of edge insertions, edge removals and queries on an undirected graph, after which all of the
+/// queries are answered at once. Each query is answered by a callback which can inspect the connectivity of the graph
+/// at the time of the query through a [`RollbackDsu`].
+///
+/// Each edge is alive during an interval of queries. The intervals are stored in a segment tree over the queries, which
+/// is traversed depth-first while merging the edges of each node on the way down and undoing them on the way up.
+///
+/// # Examples
+/// ```
+/// # use comlib_common::DynamicConnectivity;
+/// let mut graph = DynamicConnectivity::new(3);
+/// let mut queries = Vec::new();
+/// graph.add_edge(0, 1);
+/// graph.add_edge(1, 2);
+/// queries.push((graph.query(), 0, 2));
+/// graph.remove_edge(0, 1);
+/// queries.push((graph.query(), 0, 2));
+/// let answers = graph.solve(|query, dsu| dsu.same(queries[query].1, queries[query].2));
+/// assert_eq!(answers, vec![true, false]);
+/// ```
+///
+/// # Time complexity
+/// Recording takes `O(1)` expected time per operation. Solving takes `O(m log q log n)` time, where m is the number of
+/// edges and q the number of queries, in addition to the time taken by the callback.
+#[derive(Debug, Clone)]
+pub struct DynamicConnectivity {
+    n: usize,
+    /// The number of queries recorded so far.
+    queries: usize,
+    /// The query index at which each currently alive edge was added, keyed by its normalized endpoints.
+    alive: HashMap<(usize, usize), Vec<usize>>,
+    /// The edges together with the ranges of queries during which they are alive.
+    intervals: Vec<(usize, usize, usize, usize)>,
+}
+
+impl DynamicConnectivity {
+    /// Constructs an empty graph with `n` vertices.
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            queries: 0,
+            alive: HashMap::new(),
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Adds an edge between the given vertices.
+    ///
+    /// Parallel edges are allowed, and each of them must be removed separately.
+    ///
+    /// # Panics
+    /// Panics if either of the vertices is out of bounds.
+    pub fn add_edge(&mut self, a: usize, b: usize) {
+        assert!(a < self.n && b < self.n, "vertex out of bounds");
+        self.alive
+            .entry((a.min(b), a.max(b)))
+            .or_default()
+            .push(self.queries);
+    }
+
+    /// Removes an edge between the given vertices.
+    ///
+    /// # Panics
+    /// Panics if there is no such edge.
+    pub fn remove_edge(&mut self, a: usize, b: usize) {
+        let key = (a.min(b), a.max(b));
+        let start = self
+            .alive
+            .get_mut(&key)
+            .and_then(Vec::pop)
+            .expect("no edge to remove");
+        if self.alive[&key].is_empty() {
+            self.alive.remove(&key);
+        }
+        self.intervals.push((key.0, key.1, start, self.queries));
+    }
+
+    /// Records a query on the current graph.
+    ///
+    /// Returns the index of the query, which is passed to the callback of [`solve`](Self::solve).
+    pub fn query(&mut self) -> usize {
+        self.queries += 1;
+        self.queries - 1
+    }
+
+    /// Answers all of the queries.
+    ///
+    /// The callback is called once for each query in increasing order, with the index of the query and the state of
+    /// the graph at the time of the query. The results of the callback are returned in the same order.
+    pub fn solve<T, F>(self, answer: F) -> Vec<T>
+    where
+        F: FnMut(usize, &RollbackDsu) -> T,
+    {
+        let q = self.queries;
+        let mut intervals = self.intervals;
+        for ((a, b), starts) in self.alive {
+            intervals.extend(starts.into_iter().map(|start| (a, b, start, q)));
+        }
+
+        // The edges alive during all queries of each node of the segment tree. The number of leaves is rounded up to a
+        // power of two so that the depth-first traversal visits the queries in order.
+        let size = q.next_power_of_two();
+        let mut tree = vec![Vec::new(); 2 * size];
+        for (a, b, start, end) in intervals {
+            let (mut start, mut end) = (start + size, end + size);
+            while start < end {
+                if start & 1 == 1 {
+                    tree[start].push((a, b));
+                    start += 1;
+                }
+                if end & 1 == 1 {
+                    end -= 1;
+                    tree[end].push((a, b));
+                }
+                start >>= 1;
+                end >>= 1;
+            }
+        }
+
+        let mut solver = Solver {
+            tree,
+            size,
+            queries: q,
+            dsu: RollbackDsu::new(self.n),
+            answer,
+            answers: Vec::with_capacity(q),
+        };
+        if q > 0 {
+            solver.visit(1);
+        }
+        solver.answers
+    }
+}
+
+/// State of the traversal of the segment tree.
+struct Solver<T, F> {
+    tree: Vec<Vec<(usize, usize)>>,
+    /// The number of leaves.
+    size: usize,
+    queries: usize,
+    dsu: RollbackDsu,
+    answer: F,
+    answers: Vec<T>,
+}
+
+impl<T, F> Solver<T, F>
+where
+    F: FnMut(usize, &RollbackDsu) -> T,
+{
+    /// Answers the queries in the subtree of the given node.
+    fn visit(&mut self, node: usize) {
+        let snapshot = self.dsu.snapshot();
+        for &(a, b) in &self.tree[node] {
+            self.dsu.union(a, b);
+        }
+        if node >= self.size {
+            let query = node - self.size;
+            if query < self.queries {
+                self.answers.push((self.answer)(query, &self.dsu));
+            }
+        } else {
+            self.visit(2 * node);
+            self.visit(2 * node + 1);
+        }
+        self.dsu.rollback(snapshot);
+    }
+}
@@ -0,0 +1,120 @@
+/// Disjoint set union, also known as union-find.
+///
+/// Maintains a partition of the elements `0..n` into disjoint sets, supporting merging two sets and checking whether
+/// two elements belong to the same set. Uses union by size and path compression.
+///
+/// # Examples
+/// ```
+/// # use comlib_common::Dsu;
+/// let mut dsu = Dsu::new(5);
+/// assert!(dsu.union(0, 1));
+/// assert!(dsu.union(3, 4));
+/// assert!(dsu.union(1, 4));
+/// assert!(!dsu.union(0, 3));
+/// assert!(dsu.same(0, 4));
+/// assert_eq!(dsu.size(3), 4);
+/// assert_eq!(dsu.groups(), vec![vec![0, 1, 3, 4], vec![2]]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(α(n))` amortized time, where α is the inverse Ackermann function, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct Dsu {
+    /// The parent of each element, or the element itself for the representatives.
+    parent: Vec<usize>,
+    /// The size of the set of each representative.
+    size: Vec<usize>,
+    /// The number of sets.
+    count: usize,
+}
+
+impl Dsu {
+    /// Constructs a partition of `n` elements, each in its own set.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Checks whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Finds the representative of the set containing the given element.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut x = x;
+        // Path halving: point every other element on the path to its grandparent
+        while self.parent[x] != x {
+            let grandparent = self.parent[self.parent[x]];
+            self.parent[x] = grandparent;
+            x = grandparent;
+        }
+        x
+    }
+
+    /// Merges the sets containing the given elements.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    /// Checks whether the given elements are in the same set.
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing the given element.
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Returns the sets of the partition.
+    ///
+    /// The elements of each set are in increasing order, and the sets are ordered by their smallest elements.
+    ///
+    /// # Time complexity
+    /// Takes `O(n α(n))` time.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        // Index of the group of each representative
+        let mut index = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for x in 0..n {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::with_capacity(self.size[root]));
+            }
+            groups[index[root]].push(x);
+        }
+        groups
+    }
+}
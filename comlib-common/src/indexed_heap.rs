@@ -0,0 +1,188 @@
+/// Binary min-heap of elements `0..n` keyed by priorities which can be decreased.
+///
+/// Unlike [`BinaryHeap`](std::collections::BinaryHeap), the heap knows the position of each element, so the priority
+/// of an element already in the heap can be updated in place. This makes it suitable for Dijkstra's and Prim's
+/// algorithms without storing outdated entries. To pop the maximum instead, wrap the priorities in
+/// [`Reverse`](std::cmp::Reverse).
+///
+/// # Examples
+/// ```
+/// # use comlib_common::IndexedHeap;
+/// let mut heap = IndexedHeap::new(4);
+/// heap.push_or_decrease(2, 10);
+/// heap.push_or_decrease(0, 7);
+/// heap.push_or_decrease(2, 5);
+/// assert!(!heap.push_or_decrease(0, 8));
+/// assert_eq!(heap.pop(), Some((2, 5)));
+/// assert_eq!(heap.pop(), Some((0, 7)));
+/// assert_eq!(heap.pop(), None);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` time, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<T> {
+    /// The elements in heap order.
+    heap: Vec<usize>,
+    /// The position of each element in `heap`, or `usize::MAX` if it is not in the heap.
+    position: Vec<usize>,
+    /// The priority of each element in the heap.
+    priority: Vec<Option<T>>,
+}
+
+impl<T: Ord> IndexedHeap<T> {
+    /// Constructs an empty heap for the elements `0..n`.
+    ///
+    /// # Time complexity
+    /// Takes `O(n)` time.
+    pub fn new(n: usize) -> Self {
+        Self {
+            heap: Vec::new(),
+            position: vec![usize::MAX; n],
+            priority: (0..n).map(|_| None).collect(),
+        }
+    }
+
+    /// Returns the number of elements in the heap.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Checks whether the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Checks whether the element is in the heap.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn contains(&self, element: usize) -> bool {
+        self.position[element] != usize::MAX
+    }
+
+    /// Returns the priority of the element, or `None` if it is not in the heap.
+    pub fn priority(&self, element: usize) -> Option<&T> {
+        self.priority[element].as_ref()
+    }
+
+    /// Returns the element with the smallest priority together with the priority, or `None` if the heap is empty.
+    ///
+    /// # Time complexity
+    /// Takes `O(1)` time.
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        let &element = self.heap.first()?;
+        Some((element, self.priority[element].as_ref().unwrap()))
+    }
+
+    /// Inserts the element with the given priority, or decreases its priority if it is already in the heap.
+    ///
+    /// Returns `false`, leaving the heap unchanged, if the element already has a priority at most the given one.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn push_or_decrease(&mut self, element: usize, priority: T) -> bool {
+        match &self.priority[element] {
+            Some(current) if *current <= priority => return false,
+            Some(_) => {}
+            None => {
+                self.position[element] = self.heap.len();
+                self.heap.push(element);
+            }
+        }
+        self.priority[element] = Some(priority);
+        self.sift_up(self.position[element]);
+        true
+    }
+
+    /// Sets the priority of the element, inserting it if it is not in the heap.
+    ///
+    /// Returns the previous priority of the element, or `None` if it was not in the heap.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn set(&mut self, element: usize, priority: T) -> Option<T> {
+        let previous = self.remove(element);
+        self.push_or_decrease(element, priority);
+        previous
+    }
+
+    /// Removes the element with the smallest priority and returns it together with the priority, or `None` if the
+    /// heap is empty.
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        let &element = self.heap.first()?;
+        self.remove(element).map(|priority| (element, priority))
+    }
+
+    /// Removes the element from the heap, returning its priority, or `None` if it was not in the heap.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn remove(&mut self, element: usize) -> Option<T> {
+        let priority = self.priority[element].take()?;
+        let position = std::mem::replace(&mut self.position[element], usize::MAX);
+        let last = self.heap.pop().unwrap();
+        if last != element {
+            // Move the last element into the hole, after which it may need to move either up or down
+            self.heap[position] = last;
+            self.position[last] = position;
+            self.sift_down(position);
+            self.sift_up(self.position[last]);
+        }
+        Some(priority)
+    }
+
+    /// Removes all elements from the heap.
+    ///
+    /// # Time complexity
+    /// Takes `O(k)` time, where k is the number of elements in the heap.
+    pub fn clear(&mut self) {
+        for element in self.heap.drain(..) {
+            self.position[element] = usize::MAX;
+            self.priority[element] = None;
+        }
+    }
+
+    /// Checks whether the element at the first position should be above the element at the second position.
+    fn less(&self, a: usize, b: usize) -> bool {
+        self.priority[self.heap[a]] < self.priority[self.heap[b]]
+    }
+
+    /// Swaps the elements at the given positions.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position[self.heap[a]] = a;
+        self.position[self.heap[b]] = b;
+    }
+
+    /// Moves the element at the given position up until its parent has a smaller priority.
+    fn sift_up(&mut self, position: usize) {
+        let mut position = position;
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if !self.less(position, parent) {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    /// Moves the element at the given position down until its children have larger priorities.
+    fn sift_down(&mut self, position: usize) {
+        let mut position = position;
+        loop {
+            let mut smallest = position;
+            for child in [2 * position + 1, 2 * position + 2] {
+                if child < self.heap.len() && self.less(child, smallest) {
+                    smallest = child;
+                }
+            }
+            if smallest == position {
+                break;
+            }
+            self.swap(position, smallest);
+            position = smallest;
+        }
+    }
+}
@@ -3,11 +3,26 @@
 //!
 //! ## Content
 //! - [Map optimized for small number of key-value pairs](MiniMap)
+//! - [Disjoint set union](Dsu)
+//!   - [with rollback](RollbackDsu)
+//! - [Offline dynamic connectivity](DynamicConnectivity)
+//! - [Sorted multiset with order statistics](OrderedMultiset)
+//! - [Priority queue with decrease-key](IndexedHeap)
 //!
 //! ## Still missing
 //! Whatever seems useful for implementing Comlib.
 
 #![warn(missing_docs)]
 
+mod connectivity;
+pub use connectivity::DynamicConnectivity;
+mod dsu;
+pub use dsu::Dsu;
+mod indexed_heap;
+pub use indexed_heap::IndexedHeap;
 mod minimap;
 pub use minimap::MiniMap;
+mod multiset;
+pub use multiset::{MultisetIter, OrderedMultiset};
+mod rollback_dsu;
+pub use rollback_dsu::RollbackDsu;
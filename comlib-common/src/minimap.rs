@@ -158,7 +158,7 @@ where
 
     /// Ensures a value is in the entry by inserting the default if empty, and returns a mutable reference to the value
     /// in the entry.
-    pub fn or_default<F>(self) -> &'a mut V
+    pub fn or_default(self) -> &'a mut V
     where
         V: Default,
     {
@@ -0,0 +1,353 @@
+use std::iter::FromIterator;
+
+/// Sorted multiset supporting order statistics.
+///
+/// Implemented as a [treap](https://en.wikipedia.org/wiki/Treap) in which equal values share a single node. In addition
+/// to the usual set operations, the multiset can find the k-th smallest value and count the values smaller than a given
+/// value.
+///
+/// # Examples
+/// ```
+/// # use comlib_common::OrderedMultiset;
+/// let mut set: OrderedMultiset<_> = vec![5, 1, 3, 3, 8].into_iter().collect();
+/// assert_eq!(set.kth(2), Some(&3));
+/// assert_eq!(set.count_less(&5), 3);
+/// assert!(set.remove_one(&3));
+/// assert_eq!(set.count(&3), 1);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![&1, &3, &5, &8]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` expected time, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct OrderedMultiset<T> {
+    /// The nodes of the treap. Nodes in `free` are unused.
+    nodes: Vec<Node<T>>,
+    /// Indices of unused nodes.
+    free: Vec<usize>,
+    root: Option<usize>,
+    /// State of the generator of priorities.
+    seed: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    /// The number of copies of the value.
+    count: usize,
+    /// The total number of values in the subtree.
+    size: usize,
+    priority: u64,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+impl<T: Ord> OrderedMultiset<T> {
+    /// Constructs an empty multiset.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            seed: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    /// Returns the number of values in the multiset, counting duplicates.
+    ///
+    /// # Time complexity
+    /// Takes `O(1)` time.
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    /// Checks whether the multiset is empty.
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Adds a copy of the value to the multiset.
+    pub fn insert(&mut self, value: T) {
+        let (less, rest) = self.split(self.root, &value, false);
+        let (equal, greater) = self.split(rest, &value, true);
+        let equal = match equal {
+            Some(node) => {
+                self.nodes[node].count += 1;
+                self.update(node);
+                node
+            }
+            None => self.allocate(value),
+        };
+        let left = self.merge(less, Some(equal));
+        self.root = self.merge(left, greater);
+    }
+
+    /// Removes one copy of the value from the multiset.
+    ///
+    /// Returns `false` if the value was not in the multiset.
+    pub fn remove_one(&mut self, value: &T) -> bool {
+        self.remove(value, 1) > 0
+    }
+
+    /// Removes all copies of the value from the multiset.
+    ///
+    /// Returns the number of copies removed.
+    pub fn remove_all(&mut self, value: &T) -> usize {
+        self.remove(value, usize::MAX)
+    }
+
+    /// Returns the number of copies of the value in the multiset.
+    pub fn count(&self, value: &T) -> usize {
+        self.find(value).map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// Checks whether the multiset contains the value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Returns the number of values strictly smaller than the given value.
+    pub fn count_less(&self, value: &T) -> usize {
+        let mut result = 0;
+        let mut current = self.root;
+        while let Some(node) = current {
+            let node = &self.nodes[node];
+            if node.value < *value {
+                result += self.size(node.left) + node.count;
+                current = node.right;
+            } else {
+                current = node.left;
+            }
+        }
+        result
+    }
+
+    /// Returns the number of values smaller than or equal to the given value.
+    pub fn count_less_or_equal(&self, value: &T) -> usize {
+        self.count_less(value) + self.count(value)
+    }
+
+    /// Returns the k-th smallest value, counting from zero, or `None` if there are at most `k` values.
+    pub fn kth(&self, k: usize) -> Option<&T> {
+        let mut k = k;
+        let mut current = self.root;
+        while let Some(node) = current {
+            let node = &self.nodes[node];
+            let left = self.size(node.left);
+            if k < left {
+                current = node.left;
+            } else if k < left + node.count {
+                return Some(&node.value);
+            } else {
+                k -= left + node.count;
+                current = node.right;
+            }
+        }
+        None
+    }
+
+    /// Returns the smallest value, or `None` if the multiset is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.kth(0)
+    }
+
+    /// Returns the largest value, or `None` if the multiset is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.len().checked_sub(1).and_then(|k| self.kth(k))
+    }
+
+    /// Returns an iterator over the values in increasing order, including duplicates.
+    ///
+    /// # Time complexity
+    /// Iterating over the whole multiset takes `O(n)` time.
+    pub fn iter(&self) -> MultisetIter<'_, T> {
+        let mut iter = MultisetIter {
+            set: self,
+            stack: Vec::new(),
+            current: None,
+        };
+        iter.descend(self.root);
+        iter
+    }
+
+    /// Removes at most `limit` copies of the value, returning the number of copies removed.
+    fn remove(&mut self, value: &T, limit: usize) -> usize {
+        let (less, rest) = self.split(self.root, value, false);
+        let (equal, greater) = self.split(rest, value, true);
+        let mut removed = 0;
+        let mut equal = equal;
+        if let Some(node) = equal {
+            removed = self.nodes[node].count.min(limit);
+            self.nodes[node].count -= removed;
+            if self.nodes[node].count == 0 {
+                self.free.push(node);
+                equal = None;
+            } else {
+                self.update(node);
+            }
+        }
+        let left = self.merge(less, equal);
+        self.root = self.merge(left, greater);
+        removed
+    }
+
+    /// Finds the node containing the value.
+    fn find(&self, value: &T) -> Option<usize> {
+        let mut current = self.root;
+        while let Some(node) = current {
+            let n = &self.nodes[node];
+            if *value < n.value {
+                current = n.left;
+            } else if n.value < *value {
+                current = n.right;
+            } else {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    /// Creates a new node containing a single copy of the value.
+    fn allocate(&mut self, value: T) -> usize {
+        // Xorshift
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let node = Node {
+            value,
+            count: 1,
+            size: 1,
+            priority: self.seed,
+            left: None,
+            right: None,
+        };
+        if let Some(index) = self.free.pop() {
+            self.nodes[index] = node;
+            index
+        } else {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        }
+    }
+
+    /// Splits the tree into the values smaller than the given value and the rest. If `inclusive` is set, values equal
+    /// to the given value go to the first part.
+    fn split(
+        &mut self,
+        tree: Option<usize>,
+        value: &T,
+        inclusive: bool,
+    ) -> (Option<usize>, Option<usize>) {
+        let node = match tree {
+            Some(node) => node,
+            None => return (None, None),
+        };
+        let goes_left = if inclusive {
+            self.nodes[node].value <= *value
+        } else {
+            self.nodes[node].value < *value
+        };
+        if goes_left {
+            let (left, right) = self.split(self.nodes[node].right, value, inclusive);
+            self.nodes[node].right = left;
+            self.update(node);
+            (Some(node), right)
+        } else {
+            let (left, right) = self.split(self.nodes[node].left, value, inclusive);
+            self.nodes[node].left = right;
+            self.update(node);
+            (left, Some(node))
+        }
+    }
+
+    /// Concatenates two trees, assuming that all values of the first are smaller than those of the second.
+    fn merge(&mut self, left: Option<usize>, right: Option<usize>) -> Option<usize> {
+        match (left, right) {
+            (None, tree) | (tree, None) => tree,
+            (Some(l), Some(r)) => {
+                if self.nodes[l].priority > self.nodes[r].priority {
+                    self.nodes[l].right = self.merge(self.nodes[l].right, right);
+                    self.update(l);
+                    left
+                } else {
+                    self.nodes[r].left = self.merge(left, self.nodes[r].left);
+                    self.update(r);
+                    right
+                }
+            }
+        }
+    }
+
+    /// Recomputes the size of the subtree of the node.
+    fn update(&mut self, node: usize) {
+        let n = &self.nodes[node];
+        self.nodes[node].size = n.count + self.size(n.left) + self.size(n.right);
+    }
+
+    fn size(&self, tree: Option<usize>) -> usize {
+        tree.map_or(0, |node| self.nodes[node].size)
+    }
+}
+
+impl<T: Ord> Default for OrderedMultiset<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Extend<T> for OrderedMultiset<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for OrderedMultiset<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Iterator over the values of an [`OrderedMultiset`] in increasing order.
+///
+/// Created by [`OrderedMultiset::iter`].
+#[derive(Debug)]
+pub struct MultisetIter<'a, T> {
+    set: &'a OrderedMultiset<T>,
+    /// The nodes whose left subtrees are being visited.
+    stack: Vec<usize>,
+    /// The node being visited and the number of its copies left.
+    current: Option<(usize, usize)>,
+}
+
+impl<T> MultisetIter<'_, T> {
+    /// Pushes the leftmost path of the tree onto the stack.
+    fn descend(&mut self, tree: Option<usize>) {
+        let mut tree = tree;
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = self.set.nodes[node].left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for MultisetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((node, left)) = self.current {
+            if left > 0 {
+                self.current = Some((node, left - 1));
+                return Some(&self.set.nodes[node].value);
+            }
+        }
+        let node = self.stack.pop()?;
+        let n = &self.set.nodes[node];
+        self.descend(n.right);
+        self.current = Some((node, n.count - 1));
+        Some(&n.value)
+    }
+}
@@ -0,0 +1,147 @@
+/// Disjoint set union supporting undoing merges.
+///
+/// Like [`Dsu`](crate::Dsu), but merges can be undone in the reverse order they were made. Because of this, paths are
+/// not compressed and only union by rank is used. This is the building block of
+/// [`DynamicConnectivity`](crate::DynamicConnectivity).
+///
+/// # Examples
+/// ```
+/// # use comlib_common::RollbackDsu;
+/// let mut dsu = RollbackDsu::new(4);
+/// dsu.union(0, 1);
+/// let snapshot = dsu.snapshot();
+/// dsu.union(1, 2);
+/// dsu.union(2, 3);
+/// assert!(dsu.same(0, 3));
+/// dsu.rollback(snapshot);
+/// assert!(dsu.same(0, 1));
+/// assert!(!dsu.same(0, 2));
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` time, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct RollbackDsu {
+    /// The parent of each element, or the element itself for the representatives.
+    parent: Vec<usize>,
+    /// The rank of each representative.
+    rank: Vec<u8>,
+    /// The size of the set of each representative.
+    size: Vec<usize>,
+    /// The merges made, as the absorbed representative and whether the rank of the other one increased.
+    history: Vec<(usize, bool)>,
+    /// The number of sets.
+    count: usize,
+}
+
+impl RollbackDsu {
+    /// Constructs a partition of `n` elements, each in its own set.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+            history: Vec::new(),
+            count: n,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Checks whether there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Finds the representative of the set containing the given element.
+    ///
+    /// # Panics
+    /// Panics if the element is out of bounds.
+    pub fn find(&self, x: usize) -> usize {
+        let mut x = x;
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merges the sets containing the given elements.
+    ///
+    /// Returns `false` if they were already in the same set, in which case nothing is recorded to be undone.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let increased = self.rank[a] == self.rank[b];
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        if increased {
+            self.rank[a] += 1;
+        }
+        self.history.push((b, increased));
+        self.count -= 1;
+        true
+    }
+
+    /// Checks whether the given elements are in the same set.
+    pub fn same(&self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing the given element.
+    pub fn size(&self, x: usize) -> usize {
+        self.size[self.find(x)]
+    }
+
+    /// Returns the number of merges made so far, which can be passed to [`rollback`](Self::rollback).
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Undoes the latest merge.
+    ///
+    /// Returns `false` if there were no merges to undo.
+    ///
+    /// # Time complexity
+    /// Takes `O(1)` time.
+    pub fn undo(&mut self) -> bool {
+        let (b, increased) = match self.history.pop() {
+            Some(merge) => merge,
+            None => return false,
+        };
+        let a = self.parent[b];
+        self.parent[b] = b;
+        self.size[a] -= self.size[b];
+        if increased {
+            self.rank[a] -= 1;
+        }
+        self.count += 1;
+        true
+    }
+
+    /// Undoes merges until the given number of merges remain.
+    ///
+    /// # Panics
+    /// Panics if fewer merges have been made.
+    ///
+    /// # Time complexity
+    /// Takes `O(1)` time per undone merge.
+    pub fn rollback(&mut self, snapshot: usize) {
+        assert!(snapshot <= self.history.len(), "snapshot is in the future");
+        while self.history.len() > snapshot {
+            self.undo();
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use comlib_common::{Dsu, DynamicConnectivity, RollbackDsu};
+use comlib_testing::lcg;
+
+#[test]
+fn test_rollback_dsu() {
+    let mut dsu = RollbackDsu::new(5);
+    assert!(dsu.union(0, 1));
+    assert!(dsu.union(2, 3));
+    let snapshot = dsu.snapshot();
+    assert!(dsu.union(1, 3));
+    assert!(!dsu.union(0, 2));
+    assert_eq!(dsu.size(0), 4);
+    assert_eq!(dsu.count(), 2);
+    assert!(dsu.undo());
+    assert_eq!(dsu.size(0), 2);
+    assert!(!dsu.same(1, 2));
+    assert_eq!(dsu.count(), 3);
+    assert!(dsu.union(4, 0));
+    dsu.rollback(snapshot);
+    assert!(!dsu.same(4, 0));
+    dsu.rollback(0);
+    assert_eq!(dsu.count(), 5);
+    assert!(!dsu.undo());
+}
+
+#[test]
+fn test_dynamic_connectivity_random() {
+    let mut random = lcg(1);
+
+    for n in 1..8 {
+        let mut graph = DynamicConnectivity::new(n);
+        let mut edges = Vec::new();
+        let mut expected = Vec::new();
+        let mut queries = Vec::new();
+        for _ in 0..60 {
+            match random(3) {
+                0 => {
+                    let (a, b) = (random(n as u64) as usize, random(n as u64) as usize);
+                    graph.add_edge(a, b);
+                    edges.push((a, b));
+                }
+                1 if !edges.is_empty() => {
+                    let (a, b) = edges.swap_remove(random(edges.len() as u64) as usize);
+                    graph.remove_edge(b, a);
+                }
+                _ => {
+                    let (a, b) = (random(n as u64) as usize, random(n as u64) as usize);
+                    let mut dsu = Dsu::new(n);
+                    for &(x, y) in &edges {
+                        dsu.union(x, y);
+                    }
+                    assert_eq!(graph.query(), queries.len());
+                    queries.push((a, b));
+                    expected.push((dsu.same(a, b), dsu.count()));
+                }
+            }
+        }
+        let answers = graph.solve(|query, dsu| {
+            let (a, b) = queries[query];
+            (dsu.same(a, b), dsu.count())
+        });
+        assert_eq!(answers, expected);
+    }
+}
+
+#[test]
+fn test_dynamic_connectivity_without_queries() {
+    let mut graph = DynamicConnectivity::new(2);
+    graph.add_edge(0, 1);
+    assert!(graph.solve(|_, dsu| dsu.count()).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "no edge to remove")]
+fn test_dynamic_connectivity_missing_edge() {
+    let mut graph = DynamicConnectivity::new(3);
+    graph.add_edge(0, 1);
+    graph.remove_edge(1, 2);
+}
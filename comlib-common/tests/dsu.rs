@@ -0,0 +1,56 @@
+use comlib_common::Dsu;
+use comlib_testing::lcg;
+
+#[test]
+fn test_dsu() {
+    let mut dsu = Dsu::new(6);
+    assert_eq!(dsu.len(), 6);
+    assert_eq!(dsu.count(), 6);
+    assert!(dsu.union(2, 5));
+    assert!(dsu.union(5, 0));
+    assert!(!dsu.union(0, 2));
+    assert_eq!(dsu.count(), 4);
+    assert_eq!(dsu.size(2), 3);
+    assert_eq!(dsu.size(1), 1);
+    assert!(dsu.same(0, 2));
+    assert!(!dsu.same(1, 2));
+    assert_eq!(dsu.find(0), dsu.find(5));
+    assert_eq!(dsu.groups(), vec![vec![0, 2, 5], vec![1], vec![3], vec![4]]);
+
+    let mut empty = Dsu::new(0);
+    assert!(empty.is_empty());
+    assert!(empty.groups().is_empty());
+}
+
+#[test]
+fn test_dsu_random() {
+    let mut random = lcg(1);
+
+    let n = 50;
+    let mut dsu = Dsu::new(n);
+    // Label of the set of each element
+    let mut label: Vec<usize> = (0..n).collect();
+    for _ in 0..200 {
+        let a = random(n as u64) as usize;
+        let b = random(n as u64) as usize;
+        let merged = label[a] != label[b];
+        assert_eq!(dsu.union(a, b), merged);
+        let old = label[b];
+        let new = label[a];
+        label
+            .iter_mut()
+            .filter(|l| **l == old)
+            .for_each(|l| *l = new);
+
+        let c = random(n as u64) as usize;
+        assert_eq!(
+            dsu.size(c),
+            label.iter().filter(|&&l| l == label[c]).count()
+        );
+        assert_eq!(dsu.same(a, c), label[a] == label[c]);
+    }
+    let mut distinct = label.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert_eq!(dsu.count(), distinct.len());
+}
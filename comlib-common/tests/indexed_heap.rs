@@ -0,0 +1,73 @@
+use comlib_common::IndexedHeap;
+use comlib_testing::lcg;
+
+#[test]
+fn test_indexed_heap() {
+    let mut heap = IndexedHeap::new(5);
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+    assert!(heap.push_or_decrease(3, 8));
+    assert!(heap.push_or_decrease(1, 4));
+    assert!(heap.push_or_decrease(4, 6));
+    assert!(!heap.push_or_decrease(1, 4));
+    assert!(heap.push_or_decrease(3, 2));
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some((3, &2)));
+    assert_eq!(heap.priority(4), Some(&6));
+    assert_eq!(heap.priority(0), None);
+
+    assert_eq!(heap.set(3, 9), Some(2));
+    assert_eq!(heap.remove(1), Some(4));
+    assert_eq!(heap.remove(1), None);
+    assert!(!heap.contains(1));
+    assert_eq!(heap.pop(), Some((4, 6)));
+    assert_eq!(heap.pop(), Some((3, 9)));
+    assert_eq!(heap.pop(), None);
+
+    heap.push_or_decrease(0, 1);
+    heap.clear();
+    assert!(heap.is_empty());
+    assert!(!heap.contains(0));
+}
+
+#[test]
+fn test_indexed_heap_random() {
+    let mut random = lcg(1);
+
+    let n = 30;
+    let mut heap = IndexedHeap::new(n);
+    let mut priorities: Vec<Option<u64>> = vec![None; n];
+    for _ in 0..3000 {
+        let element = random(n as u64) as usize;
+        match random(4) {
+            0 => {
+                let priority = random(100);
+                let changed = priorities[element].is_none_or(|p| priority < p);
+                assert_eq!(heap.push_or_decrease(element, priority), changed);
+                if changed {
+                    priorities[element] = Some(priority);
+                }
+            }
+            1 => {
+                let priority = random(100);
+                assert_eq!(heap.set(element, priority), priorities[element]);
+                priorities[element] = Some(priority);
+            }
+            2 => {
+                assert_eq!(heap.remove(element), priorities[element].take());
+            }
+            _ => {
+                let expected = (0..n)
+                    .filter_map(|i| priorities[i].map(|p| (p, i)))
+                    .min()
+                    .map(|(p, _)| p);
+                let popped = heap.pop();
+                assert_eq!(popped.map(|(_, p)| p), expected);
+                if let Some((i, _)) = popped {
+                    priorities[i] = None;
+                }
+            }
+        }
+        assert_eq!(heap.len(), priorities.iter().flatten().count());
+    }
+}
@@ -0,0 +1,68 @@
+use comlib_common::OrderedMultiset;
+use comlib_testing::lcg;
+
+#[test]
+fn test_ordered_multiset() {
+    let mut set = OrderedMultiset::new();
+    assert!(set.is_empty());
+    assert_eq!(set.kth(0), None);
+    assert_eq!(set.first(), None);
+    assert_eq!(set.last(), None);
+    assert!(!set.remove_one(&1));
+
+    set.extend(vec![4, 2, 4, 7, 4, 1]);
+    assert_eq!(set.len(), 6);
+    assert_eq!(set.count(&4), 3);
+    assert_eq!(set.count_less(&4), 2);
+    assert_eq!(set.count_less_or_equal(&4), 5);
+    assert_eq!(set.count_less(&100), 6);
+    assert_eq!(set.kth(4), Some(&4));
+    assert_eq!(set.kth(5), Some(&7));
+    assert_eq!(set.kth(6), None);
+    assert_eq!(set.first(), Some(&1));
+    assert_eq!(set.last(), Some(&7));
+
+    assert!(set.remove_one(&4));
+    assert_eq!(set.remove_all(&4), 2);
+    assert!(!set.contains(&4));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 2, 7]);
+}
+
+#[test]
+fn test_ordered_multiset_random() {
+    let mut random = lcg(1);
+
+    let mut set = OrderedMultiset::new();
+    let mut sorted: Vec<u64> = Vec::new();
+    for _ in 0..2000 {
+        let value = random(30);
+        match random(3) {
+            0 | 1 => {
+                set.insert(value);
+                let position = sorted.partition_point(|&x| x < value);
+                sorted.insert(position, value);
+            }
+            _ => {
+                let position = sorted.iter().position(|&x| x == value);
+                assert_eq!(set.remove_one(&value), position.is_some());
+                if let Some(position) = position {
+                    sorted.remove(position);
+                }
+            }
+        }
+
+        assert_eq!(set.len(), sorted.len());
+        let query = random(32);
+        assert_eq!(
+            set.count_less(&query),
+            sorted.iter().filter(|&&x| x < query).count()
+        );
+        assert_eq!(
+            set.count(&query),
+            sorted.iter().filter(|&&x| x == query).count()
+        );
+        let k = random(sorted.len() as u64 + 2) as usize;
+        assert_eq!(set.kth(k), sorted.get(k));
+    }
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), sorted);
+}
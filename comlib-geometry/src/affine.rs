@@ -0,0 +1,153 @@
+use crate::{Point, Polygon, Segment, ValidCoordinate};
+use comlib_math::{Float, Numeric};
+
+/// Affine transformation of the plane.
+///
+/// The transformation is represented by a 3×3 matrix acting on homogeneous coordinates:
+/// ```text
+/// | a b c |
+/// | d e f |
+/// | 0 0 1 |
+/// ```
+/// It maps the point `(x, y)` to `(ax + by + c, dx + ey + f)`. Only the first two rows are stored, as the last one is
+/// always the same.
+///
+/// With integral coordinates the transformations are exact, so for example rotations are only available by multiples of
+/// 90 degrees.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{Affine, Point};
+/// # use comlib_math::Quot;
+/// // Rotate by 90 degrees around (1, 1) and then double the size
+/// let transform = Affine::translation((-1, -1))
+///     .then(Affine::rotation_90(1))
+///     .then(Affine::translation((1, 1)))
+///     .then(Affine::scaling(Quot::from(2), Quot::from(2)));
+/// assert_eq!(transform.apply((3, 1)), Point::from((2, 6)));
+/// assert_eq!(transform.inverse().unwrap().apply((2, 6)), Point::from((3, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine<T: ValidCoordinate>([[T::Coordinate; 3]; 2]);
+
+impl<T: ValidCoordinate> Affine<T> {
+    /// Constructs the transformation from the first two rows of its matrix.
+    pub fn new(matrix: [[T::Coordinate; 3]; 2]) -> Self {
+        Self(matrix)
+    }
+
+    /// Returns the first two rows of the matrix of the transformation.
+    pub fn matrix(self) -> [[T::Coordinate; 3]; 2] {
+        self.0
+    }
+
+    /// Constructs the identity transformation.
+    pub fn identity() -> Self {
+        Self::scaling(T::Coordinate::one(), T::Coordinate::one())
+    }
+
+    /// Constructs the transformation moving every point by the given offset.
+    pub fn translation<P: Into<Point<T>>>(offset: P) -> Self {
+        let offset = offset.into();
+        let (zero, one) = (T::Coordinate::zero(), T::Coordinate::one());
+        Self([[one, zero, offset.x()], [zero, one, offset.y()]])
+    }
+
+    /// Constructs the transformation scaling the x- and y-coordinates by the given factors.
+    ///
+    /// Negative factors reflect the points across the axes.
+    pub fn scaling(x: T::Coordinate, y: T::Coordinate) -> Self {
+        let zero = T::Coordinate::zero();
+        Self([[x, zero, zero], [zero, y, zero]])
+    }
+
+    /// Constructs the counter-clockwise rotation around the origin by `times` multiples of 90 degrees.
+    ///
+    /// Negative values of `times` rotate clockwise.
+    pub fn rotation_90(times: i32) -> Self {
+        let (zero, one) = (T::Coordinate::zero(), T::Coordinate::one());
+        let (sin, cos) = match times.rem_euclid(4) {
+            0 => (zero, one),
+            1 => (one, zero),
+            2 => (zero, -one),
+            _ => (-one, zero),
+        };
+        Self::rotation_by(sin, cos)
+    }
+
+    /// Constructs the counter-clockwise rotation around the origin by the given angle in radians.
+    pub fn rotation(angle: T::Coordinate) -> Self
+    where
+        T::Coordinate: Float,
+    {
+        let (sin, cos) = angle.get_sin_cos();
+        Self::rotation_by(sin, cos)
+    }
+
+    /// Constructs the rotation around the origin with the given sine and cosine.
+    fn rotation_by(sin: T::Coordinate, cos: T::Coordinate) -> Self {
+        let zero = T::Coordinate::zero();
+        Self([[cos, -sin, zero], [sin, cos, zero]])
+    }
+
+    /// Composes the transformations such that `self` is applied first and `other` after it.
+    pub fn then(self, other: Self) -> Self {
+        let [[a, b, c], [d, e, f]] = other.0;
+        let [[g, h, i], [j, k, l]] = self.0;
+        Self([
+            [a * g + b * j, a * h + b * k, a * i + b * l + c],
+            [d * g + e * j, d * h + e * k, d * i + e * l + f],
+        ])
+    }
+
+    /// Computes the determinant of the transformation.
+    ///
+    /// The determinant is the factor by which the transformation scales areas. It is negative if the transformation
+    /// changes the orientation.
+    pub fn determinant(self) -> T::Coordinate {
+        let [[a, b, _], [d, e, _]] = self.0;
+        a * e - b * d
+    }
+
+    /// Computes the inverse transformation.
+    ///
+    /// Returns `None` if the transformation is not invertible, i.e. its determinant is zero.
+    pub fn inverse(self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        let [[a, b, c], [d, e, f]] = self.0;
+        let (a, b, d, e) = (e / det, -b / det, -d / det, a / det);
+        Some(Self([[a, b, -(a * c + b * f)], [d, e, -(d * c + e * f)]]))
+    }
+
+    /// Applies the transformation to the point.
+    pub fn apply<P: Into<Point<T>>>(self, p: P) -> Point<T> {
+        let p = p.into();
+        let (x, y) = (p.x(), p.y());
+        let [[a, b, c], [d, e, f]] = self.0;
+        Point::from_coordinates(a * x + b * y + c, d * x + e * y + f)
+    }
+
+    /// Applies the transformation to the segment.
+    ///
+    /// Returns `None` if the segment collapses into a single point.
+    pub fn apply_segment(self, segment: Segment<T>) -> Option<Segment<T>> {
+        Segment::between(self.apply(segment.0), self.apply(segment.1))
+    }
+
+    /// Applies the transformation to the polygon.
+    ///
+    /// The order of the vertices is kept, so the orientation of the polygon changes if the determinant of the
+    /// transformation is negative.
+    pub fn apply_polygon(self, polygon: &Polygon<T>) -> Polygon<T> {
+        Polygon::from(polygon.points().map(|p| self.apply(p)).collect::<Vec<_>>())
+    }
+}
+
+impl<T: ValidCoordinate> Default for Affine<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Ordering, Point, ValidCoordinate};
+use comlib_math::{Sign, Signed};
+use std::cmp::Ordering as CmpOrdering;
+
+/// Sorts the points by their polar angle around the pivot.
+///
+/// The angles are measured counter-clockwise starting from the direction of the positive x-axis, which is included.
+/// Points equal to the pivot come first. The sort is stable, so points in the same direction from the pivot keep their
+/// relative order.
+///
+/// The comparisons are exact: they only use the signs of the coordinates and of cross products.
+///
+/// # Time complexity
+/// Takes O(n log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{sort_by_angle_around, Point};
+/// let mut points: Vec<Point<i64>> = vec![(0, -1), (-1, 0), (1, 1), (1, 0), (0, 1), (2, 2)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// sort_by_angle_around(Point::from((0, 0)), &mut points);
+/// let expected: Vec<Point<i64>> = vec![(1, 0), (1, 1), (2, 2), (0, 1), (-1, 0), (0, -1)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// assert_eq!(points, expected);
+/// ```
+pub fn sort_by_angle_around<T: ValidCoordinate>(pivot: Point<T>, points: &mut [Point<T>]) {
+    points.sort_by(|&a, &b| compare_angles(pivot, a, b));
+}
+
+/// Compares the polar angles of the points around the pivot.
+///
+/// See [`sort_by_angle_around`] for the order used.
+pub fn compare_angles<T: ValidCoordinate>(
+    pivot: Point<T>,
+    a: Point<T>,
+    b: Point<T>,
+) -> CmpOrdering {
+    half(pivot, a).cmp(&half(pivot, b)).then_with(|| {
+        if a == pivot || b == pivot {
+            return CmpOrdering::Equal;
+        }
+        match Point::ordering([pivot, a, b]) {
+            Ordering::Counterclockwise => CmpOrdering::Less,
+            Ordering::Collinear => CmpOrdering::Equal,
+            Ordering::Clockwise => CmpOrdering::Greater,
+        }
+    })
+}
+
+/// Classifies the direction of the point from the pivot.
+///
+/// Returns 0 for the pivot itself, 1 for the half-plane of angles in `[0, π)` and 2 for angles in `[π, 2π)`.
+fn half<T: ValidCoordinate>(pivot: Point<T>, p: Point<T>) -> u8 {
+    let dx = (p.x() - pivot.x()).get_sign();
+    let dy = (p.y() - pivot.y()).get_sign();
+    match (dy, dx) {
+        (Sign::Neutral, Sign::Neutral) => 0,
+        (Sign::Positive, _) | (Sign::Neutral, Sign::Positive) => 1,
+        _ => 2,
+    }
+}
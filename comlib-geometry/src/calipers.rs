@@ -0,0 +1,301 @@
+use crate::{Ordering, Point, Polygon, ValidCoordinate};
+use comlib_math::{Float, Numeric};
+
+/// Rotating calipers algorithms on convex polygons.
+///
+/// All of these methods require the polygon to be convex and its vertices to be in counter-clockwise order, as
+/// returned by [`convex_hull`](crate::convex_hull). Collinear vertices are allowed.
+impl<T: ValidCoordinate> Polygon<T> {
+    /// Finds a pair of vertices of the convex polygon with maximum distance, i.e. the diameter of the polygon.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{convex_hull, Point};
+    /// let hull = convex_hull(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((3, 0)),
+    ///     Point::from((4, 2)),
+    ///     Point::from((0, 1)),
+    ///     Point::from((1, 1)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(hull.diameter(), (Point::from((0, 0)), Point::from((4, 2))));
+    /// ```
+    pub fn diameter(&self) -> (Point<T>, Point<T>) {
+        let points = strictly_convex_vertices(self);
+        let sq_dist = |a: Point<T>, b: Point<T>| {
+            let dx = b.x() - a.x();
+            let dy = b.y() - a.y();
+            dx * dx + dy * dy
+        };
+
+        if points.len() < 3 {
+            // All vertices are collinear, so the vertex furthest from any vertex is an endpoint of the diameter
+            let furthest_from = |a: Point<T>| {
+                let b = points
+                    .iter()
+                    .copied()
+                    .max_by(|&p, &q| {
+                        sq_dist(a, p)
+                            .partial_cmp(&sq_dist(a, q))
+                            .expect("coordinates to be comparable")
+                    })
+                    .unwrap();
+                (a, b)
+            };
+            return furthest_from(furthest_from(points[0]).1);
+        }
+
+        let mut best = (points[0], points[0]);
+        let mut best_dist = T::Coordinate::zero();
+        let mut update = |a: Point<T>, b: Point<T>| {
+            let dist = sq_dist(a, b);
+            if dist > best_dist {
+                best = (a, b);
+                best_dist = dist;
+            }
+        };
+        let n = points.len();
+        calipers(&points, |i, [far, _, _]| {
+            let next = (far + 1) % n;
+            update(points[i], points[far]);
+            update(points[(i + 1) % n], points[far]);
+            update(points[i], points[next]);
+            update(points[(i + 1) % n], points[next]);
+        });
+        best
+    }
+
+    /// Computes the squared minimum width of the convex polygon.
+    ///
+    /// The minimum width is the minimum distance between two parallel lines enclosing the polygon.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    pub fn sq_width(&self) -> T::Coordinate {
+        let points = strictly_convex_vertices(self);
+        if points.len() < 3 {
+            return T::Coordinate::zero();
+        }
+
+        let mut best = None;
+        calipers(&points, |i, [far, _, _]| {
+            let (dx, dy) = edge_direction(&points, i);
+            let height = cross(dx, dy, points[i], points[far]);
+            let width = height * height / (dx * dx + dy * dy);
+            if best.is_none_or(|best| width < best) {
+                best = Some(width);
+            }
+        });
+        best.unwrap()
+    }
+
+    /// Computes the minimum width of the convex polygon.
+    ///
+    /// See [`sq_width`](Self::sq_width) for details.
+    pub fn width(&self) -> T::Coordinate
+    where
+        T::Coordinate: Float,
+    {
+        self.sq_width().get_sqrt()
+    }
+
+    /// Finds the minimum-area rectangle enclosing the convex polygon.
+    ///
+    /// The rectangle is returned in counter-clockwise order. It is degenerate if the polygon has no area.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{convex_hull, Point};
+    /// # use comlib_math::Quot;
+    /// let hull = convex_hull(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 2)),
+    ///     Point::from((1, 3)),
+    ///     Point::from((-1, 1)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(hull.min_area_rectangle().area(), Quot::from(4));
+    /// ```
+    pub fn min_area_rectangle(&self) -> Polygon<T> {
+        self.min_rectangle_by(|length, height, sq_len| length * height / sq_len)
+    }
+
+    /// Finds the minimum-perimeter rectangle enclosing the convex polygon.
+    ///
+    /// The rectangle is returned in counter-clockwise order. It is degenerate if the polygon has no area.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    pub fn min_perimeter_rectangle(&self) -> Polygon<T> {
+        // The perimeter is proportional to the square root of this value
+        self.min_rectangle_by(|length, height, sq_len| {
+            (length + height) * (length + height) / sq_len
+        })
+    }
+
+    /// Finds the enclosing rectangle minimizing the given cost.
+    ///
+    /// The optimal rectangle has one side collinear with an edge of the polygon. The cost function gets the length and
+    /// the height of the rectangle along the edge, both scaled by the length of the edge, and the squared length of the
+    /// edge.
+    fn min_rectangle_by<F>(&self, cost: F) -> Polygon<T>
+    where
+        F: Fn(T::Coordinate, T::Coordinate, T::Coordinate) -> T::Coordinate,
+    {
+        let points = strictly_convex_vertices(self);
+        if points.len() < 3 {
+            let (a, b) = self.diameter();
+            return Polygon::from(vec![a, b, b, a]);
+        }
+
+        let mut best: Option<(T::Coordinate, Polygon<T>)> = None;
+        calipers(&points, |i, [far, front, back]| {
+            let (dx, dy) = edge_direction(&points, i);
+            let sq_len = dx * dx + dy * dy;
+            let origin = points[i];
+            let front = dot(dx, dy, origin, points[front]);
+            let back = dot(dx, dy, origin, points[back]);
+            let height = cross(dx, dy, origin, points[far]);
+
+            let value = cost(front - back, height, sq_len);
+            if best.as_ref().is_none_or(|(best, _)| value < *best) {
+                // Maps coordinates along the edge and its normal back to the plane
+                let corner = |t: T::Coordinate, s: T::Coordinate| {
+                    let x = origin.x() + (dx * t - dy * s) / sq_len;
+                    let y = origin.y() + (dy * t + dx * s) / sq_len;
+                    let ([x, y], z) = T::from_coordinates([x, y]);
+                    Point::new(x, y, z)
+                };
+                let rectangle = vec![
+                    corner(back, T::Coordinate::zero()),
+                    corner(front, T::Coordinate::zero()),
+                    corner(front, height),
+                    corner(back, height),
+                ];
+                best = Some((value, Polygon::from(rectangle)));
+            }
+        });
+        best.unwrap().1
+    }
+}
+
+/// Returns the vertices of the convex polygon without repeated or collinear vertices.
+///
+/// # Panics
+/// Panics if the polygon is empty.
+fn strictly_convex_vertices<T: ValidCoordinate>(polygon: &Polygon<T>) -> Vec<Point<T>> {
+    let mut points: Vec<_> = polygon.points().collect();
+    assert!(!points.is_empty(), "polygon cannot be empty");
+    points.dedup();
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    let n = points.len();
+    let strict: Vec<_> = (0..n)
+        .filter(|&i| {
+            Point::ordering([points[(i + n - 1) % n], points[i], points[(i + 1) % n]])
+                != Ordering::Collinear
+        })
+        .map(|i| points[i])
+        .collect();
+    if strict.is_empty() {
+        points
+    } else {
+        strict
+    }
+}
+
+/// Returns the direction of the edge starting at the `i`th vertex.
+fn edge_direction<T: ValidCoordinate>(
+    points: &[Point<T>],
+    i: usize,
+) -> (T::Coordinate, T::Coordinate) {
+    let a = points[i];
+    let b = points[(i + 1) % points.len()];
+    (b.x() - a.x(), b.y() - a.y())
+}
+
+/// Computes the cross product of `(dx, dy)` and `p - origin`.
+fn cross<T: ValidCoordinate>(
+    dx: T::Coordinate,
+    dy: T::Coordinate,
+    origin: Point<T>,
+    p: Point<T>,
+) -> T::Coordinate {
+    dx * (p.y() - origin.y()) - dy * (p.x() - origin.x())
+}
+
+/// Computes the dot product of `(dx, dy)` and `p - origin`.
+fn dot<T: ValidCoordinate>(
+    dx: T::Coordinate,
+    dy: T::Coordinate,
+    origin: Point<T>,
+    p: Point<T>,
+) -> T::Coordinate {
+    dx * (p.x() - origin.x()) + dy * (p.y() - origin.y())
+}
+
+/// Runs rotating calipers over a strictly convex polygon in counter-clockwise order.
+///
+/// For each edge `i` calls `f` with the index of the vertex furthest from the edge, and the indices of the vertices
+/// furthest forward and backward along the direction of the edge.
+fn calipers<T, F>(points: &[Point<T>], mut f: F)
+where
+    T: ValidCoordinate,
+    F: FnMut(usize, [usize; 3]),
+{
+    let n = points.len();
+    debug_assert!(n >= 3);
+
+    // The measures which the pointers maximize for the given edge
+    let measures = |i: usize, j: usize| {
+        let (dx, dy) = edge_direction(points, i);
+        let origin = points[i];
+        [
+            cross(dx, dy, origin, points[j]),
+            dot(dx, dy, origin, points[j]),
+            -dot(dx, dy, origin, points[j]),
+        ]
+    };
+
+    // Initialize the pointers by a linear scan
+    let mut pointers = [0; 3];
+    for j in 1..n {
+        let current = measures(0, j);
+        for k in 0..3 {
+            if current[k] > measures(0, pointers[k])[k] {
+                pointers[k] = j;
+            }
+        }
+    }
+
+    for i in 0..n {
+        // All of the pointers rotate in the same direction as the edges
+        for (k, pointer) in pointers.iter_mut().enumerate() {
+            while measures(i, (*pointer + 1) % n)[k] > measures(i, *pointer)[k] {
+                *pointer = (*pointer + 1) % n;
+            }
+        }
+        f(i, pointers);
+    }
+}
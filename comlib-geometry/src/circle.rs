@@ -0,0 +1,351 @@
+use crate::{Containment, Line, Point, ValidCoordinate};
+use comlib_math::{Float, Numeric, Sign, Signed};
+
+/// Circle defined by its center and squared radius.
+///
+/// The squared radius is stored instead of the radius so that circles passing through points with exact coordinates
+/// can be represented exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle<T: ValidCoordinate> {
+    center: Point<T>,
+    sq_radius: T::Coordinate,
+}
+
+impl<T: ValidCoordinate> Circle<T> {
+    /// Constructs the circle with the given center and radius.
+    ///
+    /// # Panics
+    /// Panics if the radius is negative.
+    pub fn from_center_and_radius<P: Into<Point<T>>>(center: P, radius: T::Coordinate) -> Self {
+        assert!(
+            radius.get_sign() != Sign::Negative,
+            "radius must not be negative"
+        );
+        Self::from_center_and_sq_radius(center, radius * radius)
+    }
+
+    /// Constructs the circle with the given center and squared radius.
+    ///
+    /// # Panics
+    /// Panics if the squared radius is negative.
+    pub fn from_center_and_sq_radius<P: Into<Point<T>>>(
+        center: P,
+        sq_radius: T::Coordinate,
+    ) -> Self {
+        assert!(
+            sq_radius.get_sign() != Sign::Negative,
+            "squared radius must not be negative"
+        );
+        Self {
+            center: center.into(),
+            sq_radius,
+        }
+    }
+
+    /// Constructs the circle passing through the given three points.
+    ///
+    /// Returns `None` if the points are collinear.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, Point};
+    /// # use comlib_math::Quot;
+    /// let circle = Circle::through_points((0, 0), (2, 0), (0, 2)).unwrap();
+    /// assert_eq!(circle.center(), Point::from((1, 1)));
+    /// assert_eq!(circle.sq_radius(), Quot::from(2));
+    ///
+    /// assert_eq!(Circle::through_points((0, 0), (1, 1), (2, 2)), None);
+    /// ```
+    pub fn through_points<P1, P2, P3>(p1: P1, p2: P2, p3: P3) -> Option<Self>
+    where
+        P1: Into<Point<T>>,
+        P2: Into<Point<T>>,
+        P3: Into<Point<T>>,
+    {
+        let p1 = p1.into();
+        let p2 = p2.into();
+        let p3 = p3.into();
+
+        // Solve the center relative to the first point
+        let (ax, ay) = (p2.x() - p1.x(), p2.y() - p1.y());
+        let (bx, by) = (p3.x() - p1.x(), p3.y() - p1.y());
+        let d = (ax * by - ay * bx) * T::Coordinate::from_int(2);
+        if d.is_zero() {
+            return None;
+        }
+        let a2 = ax * ax + ay * ay;
+        let b2 = bx * bx + by * by;
+        let ux = (by * a2 - ay * b2) / d;
+        let uy = (ax * b2 - bx * a2) / d;
+
+        Some(Self {
+            center: Point::from_coordinates(p1.x() + ux, p1.y() + uy),
+            sq_radius: ux * ux + uy * uy,
+        })
+    }
+
+    /// Returns the center of the circle.
+    pub fn center(self) -> Point<T> {
+        self.center
+    }
+
+    /// Returns the squared radius of the circle.
+    pub fn sq_radius(self) -> T::Coordinate {
+        self.sq_radius
+    }
+
+    /// Returns the radius of the circle.
+    pub fn radius(self) -> T::Coordinate
+    where
+        T::Coordinate: Float,
+    {
+        self.sq_radius.get_sqrt()
+    }
+
+    /// Checks whether the given point lies inside the circle, on it, or outside of it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, Containment};
+    /// # use comlib_math::Quot;
+    /// let circle = Circle::from_center_and_radius((0, 0), Quot::from(5));
+    /// assert_eq!(circle.contains((1, 2)), Containment::Inside);
+    /// assert_eq!(circle.contains((3, 4)), Containment::OnBoundary);
+    /// assert_eq!(circle.contains((4, 4)), Containment::Outside);
+    /// ```
+    pub fn contains<P: Into<Point<T>>>(self, p: P) -> Containment {
+        let p = p.into();
+        let dx = p.x() - self.center.x();
+        let dy = p.y() - self.center.y();
+        match T::difference_sign(dx * dx + dy * dy, self.sq_radius) {
+            Sign::Negative => Containment::Inside,
+            Sign::Neutral => Containment::OnBoundary,
+            Sign::Positive => Containment::Outside,
+        }
+    }
+
+    /// Computes the intersection of the circle and a line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, CircleIntersection, Line, Point};
+    /// # use comlib_math::Quot;
+    /// let circle = Circle::from_center_and_radius((0, 0), Quot::from(1));
+    /// assert_eq!(
+    ///     circle.intersect_line(Line::spanned_by((-1, 1), (1, 1))),
+    ///     CircleIntersection::Tangent(Point::from((0, 1))),
+    /// );
+    /// match circle.intersect_line(Line::spanned_by((0, 0), (1, 1))) {
+    ///     CircleIntersection::Secant([(x1, y1), (x2, y2)]) => {
+    ///         assert!((x1 + x2).abs() < 1e-9 && (y1 + y2).abs() < 1e-9);
+    ///         assert!((x1 * x1 + y1 * y1 - 1.0).abs() < 1e-9);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn intersect_line(self, line: Line<T>) -> CircleIntersection<T> {
+        let foot = line.closest_point_to(self.center);
+        let dx = foot.x() - self.center.x();
+        let dy = foot.y() - self.center.y();
+        let sq_dist = dx * dx + dy * dy;
+        match T::difference_sign(self.sq_radius, sq_dist) {
+            Sign::Negative => CircleIntersection::None,
+            Sign::Neutral => CircleIntersection::Tangent(foot),
+            Sign::Positive => {
+                // Move along the direction of the line from the foot of the perpendicular
+                let (dx, dy) = (line.b.as_f64(), -line.a.as_f64());
+                let sq_half_chord = self.sq_radius - sq_dist;
+                let scale = (sq_half_chord.as_f64() / (dx * dx + dy * dy)).sqrt();
+                let (x, y) = foot.to_f64_pair();
+                CircleIntersection::Secant([
+                    (x - dx * scale, y - dy * scale),
+                    (x + dx * scale, y + dy * scale),
+                ])
+            }
+        }
+    }
+
+    /// Computes the intersection of two circles.
+    ///
+    /// A circle with zero radius is treated as the single point at its center. It touches another circle passing through
+    /// that point, so two zero-radius circles at the same point meet at a [`Tangent`](CircleIntersection::Tangent)
+    /// instead of being [`Coincident`](CircleIntersection::Coincident).
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, CircleIntersection, Point};
+    /// # use comlib_math::Quot;
+    /// let c1 = Circle::from_center_and_radius((0, 0), Quot::from(1));
+    /// let c2 = Circle::from_center_and_radius((3, 0), Quot::from(2));
+    /// assert_eq!(c1.intersect(c2), CircleIntersection::Tangent(Point::from((1, 0))));
+    /// assert_eq!(c1.intersect(c1), CircleIntersection::Coincident);
+    /// ```
+    pub fn intersect(self, other: Self) -> CircleIntersection<T> {
+        match self.radical_line(other) {
+            Some(line) => self.intersect_line(line),
+            None if T::difference_sign(self.sq_radius, other.sq_radius) != Sign::Neutral => {
+                CircleIntersection::None
+            }
+            None if T::difference_sign(self.sq_radius, T::Coordinate::zero()) == Sign::Neutral => {
+                CircleIntersection::Tangent(self.center)
+            }
+            None => CircleIntersection::Coincident,
+        }
+    }
+
+    /// Constructs the radical line of two circles.
+    ///
+    /// The radical line consists of the points which have equal power with respect to both circles. If the circles
+    /// intersect, the intersection points lie on the radical line.
+    ///
+    /// Returns `None` if the circles are concentric.
+    pub fn radical_line(self, other: Self) -> Option<Line<T>> {
+        if self.center == other.center {
+            return None;
+        }
+
+        let two = T::Coordinate::from_int(2);
+        let power = |circle: Self| {
+            let center = circle.center;
+            center.x() * center.x() + center.y() * center.y() - circle.sq_radius
+        };
+        let a = (other.center.x() - self.center.x()) * two;
+        let b = (other.center.y() - self.center.y()) * two;
+        let c = power(self) - power(other);
+        let ([a, b, c], _) = T::from_coordinates([a, b, c]);
+        Some(Line { a, b, c }.normalized())
+    }
+
+    /// Constructs the lines through the given point which are tangent to the circle.
+    ///
+    /// Returns [`Tangents::None`] if the point lies inside the circle, and [`Tangents::Touching`] if it lies on the
+    /// circle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, Tangents};
+    /// let circle = Circle::from_center_and_radius((0.0, 0.0), 1.0);
+    /// match circle.tangents_from((2.0, 0.0)) {
+    ///     Tangents::Two([l1, l2]) => {
+    ///         assert!(l1.contains((2.0, 0.0)) && l2.contains((2.0, 0.0)));
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(matches!(circle.tangents_from((1.0, 0.0)), Tangents::Touching(_)));
+    /// assert!(matches!(circle.tangents_from((0.5, 0.0)), Tangents::None));
+    /// ```
+    pub fn tangents_from<P: Into<Point<T>>>(self, p: P) -> Tangents<T>
+    where
+        T::Coordinate: Float,
+    {
+        let point = Self::from_center_and_sq_radius(p, T::Coordinate::zero());
+        self.tangents_with(point, T::Coordinate::one())
+    }
+
+    /// Constructs the lines which are tangent to both circles.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Circle, Tangents};
+    /// let c1 = Circle::from_center_and_radius((0.0, 0.0), 1.0);
+    /// let c2 = Circle::from_center_and_radius((2.0, 0.0), 1.0);
+    /// let tangents = c1.common_tangents(c2);
+    /// assert!(matches!(tangents.outer, Tangents::Two(_)));
+    /// assert!(matches!(tangents.inner, Tangents::Touching(_)));
+    /// ```
+    pub fn common_tangents(self, other: Self) -> CommonTangents<T>
+    where
+        T::Coordinate: Float,
+    {
+        if self.center == other.center {
+            let outer = if T::difference_sign(self.sq_radius, other.sq_radius) == Sign::Neutral {
+                Tangents::Infinite
+            } else {
+                Tangents::None
+            };
+            return CommonTangents {
+                outer,
+                inner: Tangents::None,
+            };
+        }
+
+        CommonTangents {
+            outer: self.tangents_with(other, T::Coordinate::one()),
+            inner: self.tangents_with(other, -T::Coordinate::one()),
+        }
+    }
+
+    /// Constructs the tangents common to both circles, treating the other circle as having radius `sign * r`.
+    ///
+    /// Outer tangents are obtained with positive sign and inner tangents with negative sign. The centers must be
+    /// distinct.
+    fn tangents_with(self, other: Self, sign: T::Coordinate) -> Tangents<T>
+    where
+        T::Coordinate: Float,
+    {
+        let r1 = self.radius();
+        let r2 = other.radius() * sign;
+        let dx = other.center.x() - self.center.x();
+        let dy = other.center.y() - self.center.y();
+        let dr = r1 - r2;
+        let d2 = dx * dx + dy * dy;
+
+        // The unit normal of a tangent line, pointing from the center of this circle towards the tangent point
+        let tangent = |h: T::Coordinate| {
+            let nx = (dx * dr - dy * h) / d2;
+            let ny = (dy * dr + dx * h) / d2;
+            let c = -(nx * self.center.x() + ny * self.center.y() + r1);
+            let ([a, b, c], _) = T::from_coordinates([nx, ny, c]);
+            Line { a, b, c }.normalized()
+        };
+
+        match T::difference_sign(d2, dr * dr) {
+            Sign::Negative => Tangents::None,
+            Sign::Neutral => Tangents::Touching(tangent(T::Coordinate::zero())),
+            Sign::Positive => {
+                let h = (d2 - dr * dr).get_sqrt();
+                Tangents::Two([tangent(-h), tangent(h)])
+            }
+        }
+    }
+}
+
+/// Tangent lines of a circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tangents<T: ValidCoordinate> {
+    /// There are no tangent lines.
+    None,
+    /// There is a single tangent line, at which the shapes touch each other.
+    Touching(Line<T>),
+    /// There are two distinct tangent lines.
+    Two([Line<T>; 2]),
+    /// Every tangent line of the circle is a tangent, i.e. the circles are the same.
+    Infinite,
+}
+
+/// Common tangent lines of two circles.
+///
+/// This struct is created by [`Circle::common_tangents`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CommonTangents<T: ValidCoordinate> {
+    /// Tangents which don't separate the circles.
+    pub outer: Tangents<T>,
+    /// Tangents which separate the circles.
+    pub inner: Tangents<T>,
+}
+
+/// Intersection of a circle with a line or another circle.
+///
+/// Tangent points can be represented exactly, but crossing points generally have irrational coordinates, so they are
+/// given as `f64` pairs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleIntersection<T: ValidCoordinate> {
+    /// There are no common points.
+    None,
+    /// The shapes touch at a single point.
+    Tangent(Point<T>),
+    /// The shapes cross at two points.
+    Secant([(f64, f64); 2]),
+    /// The circles are the same circle with a positive radius.
+    Coincident,
+}
@@ -0,0 +1,144 @@
+use crate::{Containment, Ordering, Point, Polygon, ValidCoordinate};
+use comlib_math::Sign;
+
+/// Logarithmic-time queries on convex polygons.
+///
+/// All of these methods require the polygon to be convex, its vertices to be in counter-clockwise order, and to have no
+/// repeated or collinear vertices, as returned by [`convex_hull_in_place`](crate::convex_hull_in_place) with
+/// [`CollinearPoints::Remove`](crate::CollinearPoints::Remove). The results are unspecified for other polygons.
+impl<T: ValidCoordinate> Polygon<T> {
+    /// Finds a vertex of the convex polygon furthest in the given direction.
+    ///
+    /// The direction is given as a vector. If several vertices are equally far, any one of them is returned.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{convex_hull, Point};
+    /// # use comlib_math::Quot;
+    /// let hull = convex_hull(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((4, 0)),
+    ///     Point::from((5, 3)),
+    ///     Point::from((1, 4)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(hull.extreme_vertex((1, 0)), Point::from((5, 3)));
+    /// assert_eq!(hull.extreme_vertex((-1, 1)), Point::from((1, 4)));
+    /// assert_eq!(hull.extreme_vertex((0, -1)).y(), Quot::from(0));
+    /// ```
+    pub fn extreme_vertex<P: Into<Point<T>>>(&self, direction: P) -> Point<T> {
+        let direction = direction.into();
+        let (dx, dy) = (direction.x(), direction.y());
+        let points = &self.0;
+        let i = extreme_by(points.len(), |i, j| {
+            let (a, b) = (points[i], points[j]);
+            T::difference_sign(dx * a.x() + dy * a.y(), dx * b.x() + dy * b.y())
+        });
+        points[i]
+    }
+
+    /// Finds the vertices of the convex polygon touched by the two tangent lines through the given point.
+    ///
+    /// Returns `None` if the point is not outside of the polygon. Otherwise returns the vertices `(a, b)` such that the
+    /// whole polygon lies on the left side of the ray from the point through `a`, and on the right side of the ray
+    /// through `b`. If a tangent line passes through an edge of the polygon, either endpoint of the edge may be
+    /// returned.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{convex_hull, Point};
+    /// let hull = convex_hull(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 0)),
+    ///     Point::from((2, 2)),
+    ///     Point::from((0, 2)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(
+    ///     hull.tangents_from((4, 1)),
+    ///     Some((Point::from((2, 2)), Point::from((2, 0))))
+    /// );
+    /// assert_eq!(hull.tangents_from((1, 1)), None);
+    /// ```
+    pub fn tangents_from<P: Into<Point<T>>>(&self, p: P) -> Option<(Point<T>, Point<T>)> {
+        let p = p.into();
+        if self.contains_convex(p) != Containment::Outside {
+            return None;
+        }
+        let points = &self.0;
+        // Seen from an outside point, the vertices span an angle of less than 180 degrees, so they can be compared by
+        // their angles
+        let cmp = |i: usize, j: usize| match Point::ordering([p, points[j], points[i]]) {
+            Ordering::Counterclockwise => Sign::Positive,
+            Ordering::Collinear => Sign::Neutral,
+            Ordering::Clockwise => Sign::Negative,
+        };
+        let a = extreme_by(points.len(), |i, j| -cmp(i, j));
+        let b = extreme_by(points.len(), cmp);
+        Some((points[a], points[b]))
+    }
+}
+
+/// Finds the maximum of a cyclic sequence of `n` values which first increase and then decrease.
+///
+/// The values are given by the comparison function `cmp(i, j)`, returning the sign of `value(i) - value(j)`. The
+/// sequence may have a plateau of two equal values at both its maximum and its minimum, but not elsewhere. Returns the
+/// index of some maximal value.
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub(crate) fn extreme_by<F>(n: usize, cmp: F) -> usize
+where
+    F: Fn(usize, usize) -> Sign,
+{
+    assert!(n > 0, "polygon cannot be empty");
+    if n < 3 {
+        return (1..n).fold(0, |best, i| {
+            if cmp(i, best) == Sign::Positive {
+                i
+            } else {
+                best
+            }
+        });
+    }
+
+    // Direction of the edge from the `i`th value to the next one
+    let step = |i: usize| cmp((i + 1) % n, i);
+    // Start from a value on a slope, so that it's known on which side of the maximum it is
+    let start = if step(0) != Sign::Neutral { 0 } else { 1 };
+    let index = |k: usize| (start + k) % n;
+    // Find the first value which is not on the increasing run leading to the maximum
+    let before_max = |k: usize| {
+        let i = index(k);
+        if step(start) == Sign::Positive {
+            // The increasing run starts from `start`, and after the maximum the values stay below the start
+            step(i) == Sign::Positive && cmp(i, start) != Sign::Negative
+        } else {
+            // The values first decrease below the start, and the increasing run ends above the start
+            cmp(i, start) == Sign::Negative || step(i) == Sign::Positive
+        }
+    };
+
+    let (mut low, mut high) = (1, n);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if before_max(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    index(low)
+}
@@ -0,0 +1,241 @@
+use crate::{convex_hull, convex_hull_3d, Ordering, Point, Point3, Triangle};
+use comlib_math::{Numeric, Quot};
+use std::collections::HashMap;
+
+/// Delaunay triangulation of a set of points, and the Voronoi diagram dual to it.
+///
+/// The Delaunay triangulation is a triangulation such that no point lies strictly inside the circumcircle of any of the
+/// triangles. If four or more points lie on a common circle, the triangulation is not unique and any valid one is
+/// returned.
+///
+/// The triangulation is computed by lifting the points onto the paraboloid `z = x² + y²` and taking the lower part of
+/// their [3D convex hull](convex_hull_3d). Hence the points must have integral coordinates with `x² + y²` at most `1e12`,
+/// which holds for coordinates of absolute value at most `7e5`.
+///
+/// The [Voronoi diagram] divides the plane into cells consisting of the points closest to each site. Its vertices are
+/// the circumcenters of the Delaunay triangles, and its edges separate sites adjacent in the triangulation.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{Delaunay, Point};
+/// let points: Vec<Point<i64>> = vec![(0, 0), (4, 0), (0, 4), (4, 4), (1, 2)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// let delaunay = Delaunay::new(points);
+/// assert_eq!(delaunay.triangles().len(), 4);
+/// assert_eq!(delaunay.points()[delaunay.nearest_site((3, 1))], Point::from((4, 0)));
+/// ```
+///
+/// [Voronoi diagram]: https://en.wikipedia.org/wiki/Voronoi_diagram
+#[derive(Debug, Clone)]
+pub struct Delaunay {
+    points: Vec<Point<i64>>,
+    triangles: Vec<[usize; 3]>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// Edge of a Voronoi diagram.
+///
+/// The edge lies on the perpendicular bisector of its two sites. Its endpoints are Voronoi vertices, given as indices to
+/// [`Delaunay::triangles`]. A missing endpoint means that the edge extends to infinity in that direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VoronoiEdge {
+    /// The sites separated by the edge, as indices to [`Delaunay::points`]. The first site is on the left side of the
+    /// edge when traversing from the first endpoint to the second.
+    pub sites: [usize; 2],
+    /// The endpoints of the edge.
+    pub vertices: [Option<usize>; 2],
+}
+
+impl Delaunay {
+    /// Computes the Delaunay triangulation of the given points.
+    ///
+    /// Duplicate points are removed, and the remaining points are sorted. The indices used by the triangulation refer to
+    /// the resulting [`points`](Self::points).
+    ///
+    /// # Panics
+    /// Panics if some of the points don't have integral coordinates, or if `x² + y²` exceeds `1e12` for some point.
+    ///
+    /// # Time complexity
+    /// Takes O(n²) time in the worst case.
+    pub fn new(mut points: Vec<Point<i64>>) -> Self {
+        points.sort();
+        points.dedup();
+
+        let lifted = points
+            .iter()
+            .map(|p| {
+                let (x, y) = (integral(p.x()), integral(p.y()));
+                let z = x
+                    .checked_mul(x)
+                    .zip(y.checked_mul(y))
+                    .and_then(|(xx, yy)| xx.checked_add(yy))
+                    .filter(|&z| z <= 1_000_000_000_000)
+                    .expect("points must satisfy x² + y² <= 1e12");
+                Point3::new(x, y, z)
+            })
+            .collect();
+        let triangles: Vec<_> = match convex_hull_3d(lifted) {
+            // Faces of the lower hull are clockwise when viewed from above
+            Some(hull) => hull
+                .faces()
+                .iter()
+                .filter(|&&[a, b, c]| {
+                    Point::ordering([points[a], points[b], points[c]]) == Ordering::Clockwise
+                })
+                .map(|&[a, b, c]| [a, c, b])
+                .collect(),
+            // The lifted points are coplanar if the points are collinear or they all lie on a common circle. In the
+            // latter case any triangulation of their convex hull is a Delaunay triangulation.
+            None => match convex_hull(points.clone()) {
+                Some(hull) if !hull.area().is_zero() => {
+                    let hull: Vec<_> = hull
+                        .points()
+                        .map(|p| points.binary_search(&p).unwrap())
+                        .collect();
+                    (1..hull.len() - 1)
+                        .map(|i| [hull[0], hull[i], hull[i + 1]])
+                        .collect()
+                }
+                _ => Vec::new(),
+            },
+        };
+
+        let mut edges: Vec<_> = if triangles.is_empty() {
+            // All of the points are collinear, so consecutive points are adjacent
+            (1..points.len()).map(|i| (i - 1, i)).collect()
+        } else {
+            triangles
+                .iter()
+                .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+                .map(|(a, b)| (a.min(b), a.max(b)))
+                .collect()
+        };
+        edges.sort_unstable();
+        edges.dedup();
+        // Pushing the edges in sorted order keeps the lists of neighbors sorted
+        let mut neighbors = vec![Vec::new(); points.len()];
+        for (a, b) in edges {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+
+        Self {
+            points,
+            triangles,
+            neighbors,
+        }
+    }
+
+    /// Returns the sites of the triangulation in sorted order.
+    pub fn points(&self) -> &[Point<i64>] {
+        &self.points
+    }
+
+    /// Returns the triangles of the triangulation as indices to [`points`](Self::points).
+    ///
+    /// The vertices of each triangle are in counter-clockwise order. There are no triangles if all of the points are
+    /// collinear.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Returns the sites adjacent to the given site in the triangulation, in increasing order.
+    pub fn neighbors(&self, site: usize) -> &[usize] {
+        &self.neighbors[site]
+    }
+
+    /// Returns the edges of the triangulation as pairs of indices to [`points`](Self::points).
+    ///
+    /// The first index of each pair is smaller than the second one. The Euclidean minimum spanning tree is a subset of
+    /// these edges.
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        (0..self.points.len())
+            .flat_map(|a| {
+                self.neighbors[a]
+                    .iter()
+                    .filter(move |&&b| a < b)
+                    .map(move |&b| (a, b))
+            })
+            .collect()
+    }
+
+    /// Computes the vertices of the Voronoi diagram.
+    ///
+    /// The `i`th vertex is the circumcenter of the `i`th triangle. Vertices are repeated if more than three sites lie on
+    /// a common circle.
+    pub fn voronoi_vertices(&self) -> Vec<Point<i64>> {
+        self.triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                Triangle::new(self.points[a], self.points[b], self.points[c])
+                    .circumcenter()
+                    .expect("Delaunay triangles to be non-degenerate")
+            })
+            .collect()
+    }
+
+    /// Computes the edges of the Voronoi diagram.
+    ///
+    /// There is an edge between each pair of adjacent sites. The edges between sites on a common circle have zero length.
+    pub fn voronoi_edges(&self) -> Vec<VoronoiEdge> {
+        // Maps each directed edge to the triangle on its left side
+        let mut left = HashMap::new();
+        for (i, &[a, b, c]) in self.triangles.iter().enumerate() {
+            left.insert((a, b), i);
+            left.insert((b, c), i);
+            left.insert((c, a), i);
+        }
+        self.edges()
+            .into_iter()
+            .map(|(a, b)| VoronoiEdge {
+                sites: [a, b],
+                // Moving from the triangle on the right side of a -> b to the one on the left keeps a on the left
+                vertices: [left.get(&(b, a)).copied(), left.get(&(a, b)).copied()],
+            })
+            .collect()
+    }
+
+    /// Finds the site closest to the given point.
+    ///
+    /// Ties are broken arbitrarily.
+    ///
+    /// # Panics
+    /// Panics if there are no sites.
+    ///
+    /// # Time complexity
+    /// Walks along the triangulation, taking O(n) time in the worst case.
+    pub fn nearest_site<P: Into<Point<i64>>>(&self, p: P) -> usize {
+        assert!(!self.points.is_empty(), "there must be at least one site");
+        let p = p.into();
+        let sq_dist = |i: usize| {
+            let dx = self.points[i].x() - p.x();
+            let dy = self.points[i].y() - p.y();
+            dx * dx + dy * dy
+        };
+
+        // Some neighbor of a site is always closer to the point unless the site is the closest one
+        let mut current = 0;
+        let mut current_dist = sq_dist(current);
+        loop {
+            let closer = self.neighbors[current]
+                .iter()
+                .map(|&i| (i, sq_dist(i)))
+                .find(|&(_, dist)| dist < current_dist);
+            match closer {
+                Some((i, dist)) => {
+                    current = i;
+                    current_dist = dist;
+                }
+                None => return current,
+            }
+        }
+    }
+}
+
+/// Converts the coordinate into an integer.
+fn integral(coordinate: Quot<i64>) -> i64 {
+    assert_eq!(coordinate.denominator(), 1, "coordinates must be integral");
+    coordinate.numerator()
+}
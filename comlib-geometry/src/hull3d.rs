@@ -0,0 +1,181 @@
+use comlib_math::Quot;
+use std::collections::HashSet;
+
+/// Point in 3D space with integral coordinates.
+///
+/// Computations on the points use `i128` for intermediate values, so coordinates up to `1e12` in absolute value are
+/// handled exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    /// The x-coordinate.
+    pub x: i64,
+    /// The y-coordinate.
+    pub y: i64,
+    /// The z-coordinate.
+    pub z: i64,
+}
+
+impl Point3 {
+    /// Constructs a new point.
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the vector from `self` to `other`.
+    fn to(self, other: Self) -> [i128; 3] {
+        [
+            other.x as i128 - self.x as i128,
+            other.y as i128 - self.y as i128,
+            other.z as i128 - self.z as i128,
+        ]
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+/// Convex polyhedron defined by its vertices and triangular faces.
+///
+/// The vertices of each face are in counter-clockwise order when viewed from outside of the polyhedron.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyhedron {
+    points: Vec<Point3>,
+    faces: Vec<[usize; 3]>,
+}
+
+impl Polyhedron {
+    /// Returns the points the polyhedron was constructed from.
+    ///
+    /// Not all of the points are necessarily vertices of the polyhedron.
+    pub fn points(&self) -> &[Point3] {
+        &self.points
+    }
+
+    /// Returns the faces of the polyhedron as indices to [`points`](Self::points).
+    ///
+    /// Faces which are not triangles are split into triangles.
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    /// Computes the surface area of the polyhedron.
+    pub fn surface_area(&self) -> f64 {
+        self.faces
+            .iter()
+            .map(|&face| {
+                let normal = self.normal(face);
+                let sq_len: f64 = normal.iter().map(|&v| (v as f64) * (v as f64)).sum();
+                sq_len.sqrt() / 2.0
+            })
+            .sum()
+    }
+
+    /// Computes the volume of the polyhedron.
+    pub fn volume(&self) -> Quot<i128> {
+        // Sum the volumes of the tetrahedra spanned by the faces and the first vertex. The tetrahedra lie inside the
+        // polyhedron, so the sum is at most six times the volume of the bounding cube, 6 * (2e12)^3 < 2^127.
+        let origin = self.points[self.faces[0][0]];
+        let volume = self
+            .faces
+            .iter()
+            .map(|&face| dot(self.normal(face), origin.to(self.points[face[0]])))
+            .try_fold(0i128, i128::checked_add)
+            .expect("volume to fit in i128");
+        Quot::new(volume, 6).unwrap()
+    }
+
+    /// Computes the outward normal of the face, scaled by twice the area of the face.
+    fn normal(&self, [a, b, c]: [usize; 3]) -> [i128; 3] {
+        let a = self.points[a];
+        cross(a.to(self.points[b]), a.to(self.points[c]))
+    }
+
+    /// Checks whether the point lies strictly in front of the face.
+    fn sees(&self, face: [usize; 3], p: Point3) -> bool {
+        dot(self.normal(face), self.points[face[0]].to(p)) > 0
+    }
+}
+
+fn cross([ax, ay, az]: [i128; 3], [bx, by, bz]: [i128; 3]) -> [i128; 3] {
+    [ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx]
+}
+
+fn dot(a: [i128; 3], b: [i128; 3]) -> i128 {
+    a.iter().zip(&b).map(|(a, b)| a * b).sum()
+}
+
+/// Computes the convex hull of the given set of points in 3D space.
+///
+/// Returns `None` if all of the points are coplanar, otherwise returns the convex hull. Points lying on the faces of the
+/// hull, including vertices of coplanar faces, may or may not be vertices of the hull.
+///
+/// # Time complexity
+/// Takes O(n²) time in the worst case.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{convex_hull_3d, Point3};
+/// # use comlib_math::Quot;
+/// let points: Vec<Point3> = vec![(0, 0, 0), (4, 0, 0), (0, 4, 0), (0, 0, 4), (1, 1, 1), (0, 0, 1)]
+///     .into_iter()
+///     .map(Point3::from)
+///     .collect();
+/// let hull = convex_hull_3d(points).unwrap();
+/// assert_eq!(hull.faces().len(), 4);
+/// assert_eq!(hull.volume(), Quot::new(32, 3).unwrap());
+/// ```
+pub fn convex_hull_3d(points: Vec<Point3>) -> Option<Polyhedron> {
+    let initial = initial_tetrahedron(&points)?;
+    let [a, b, c, d] = initial;
+    let mut hull = Polyhedron {
+        points,
+        faces: vec![[a, b, c], [a, c, d], [a, d, b], [b, d, c]],
+    };
+    // Make the faces point outwards
+    if hull.sees(hull.faces[0], hull.points[d]) {
+        for face in &mut hull.faces {
+            face.swap(1, 2);
+        }
+    }
+
+    for i in 0..hull.points.len() {
+        if initial.contains(&i) {
+            continue;
+        }
+        let p = hull.points[i];
+        let (visible, mut faces): (Vec<[usize; 3]>, Vec<_>) =
+            hull.faces.iter().partition(|&&face| hull.sees(face, p));
+        if visible.is_empty() {
+            continue;
+        }
+
+        // The horizon consists of the edges of the visible faces which aren't shared by two visible faces
+        let edges: HashSet<_> = visible
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect();
+        faces.extend(
+            edges
+                .iter()
+                .filter(|&&(a, b)| !edges.contains(&(b, a)))
+                .map(|&(a, b)| [a, b, i]),
+        );
+        hull.faces = faces;
+    }
+
+    Some(hull)
+}
+
+/// Finds indices of four points which are not coplanar.
+fn initial_tetrahedron(points: &[Point3]) -> Option<[usize; 4]> {
+    let a = 0;
+    let b = (0..points.len()).find(|&i| points[i] != points[a])?;
+    let ab = points[a].to(points[b]);
+    let c = (0..points.len()).find(|&i| cross(ab, points[a].to(points[i])) != [0; 3])?;
+    let normal = cross(ab, points[a].to(points[c]));
+    let d = (0..points.len()).find(|&i| dot(normal, points[a].to(points[i])) != 0)?;
+    Some([a, b, c, d])
+}
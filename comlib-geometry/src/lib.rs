@@ -2,14 +2,30 @@
 //!
 //! This crate provides geometric primitives and algorithms that work on them.
 //! The main primitive types are [`Point`] and [`Line`].
-//! For convenience the crate also provides [`Segment`] and [`Polygon`] types.
+//! For convenience the crate also provides [`Segment`], [`Triangle`], [`Polygon`] and [`Circle`] types, and [`Affine`]
+//! transformations of them.
 //!
 //! Currently only the following algorithms have been implemented:
-//! - [Convex hull](convex_hull)
+//! - [Convex hull](convex_hull), also [in place](convex_hull_in_place) and [in 3D](convex_hull_3d)
+//! - [Point in polygon](Polygon::contains)
+//! - [Convex polygon intersection](convex_intersection)
+//! - [Segment intersections](segment_intersections)
+//! - [Orthogonal range counting](RectangleCounter)
+//! - [Angular sort](sort_by_angle_around)
+//! - [Delaunay triangulation and Voronoi diagram](Delaunay)
+//! - [Point location](PointLocator) among non-crossing segments
+//! - Rotating calipers: [diameter](Polygon::diameter), [width](Polygon::sq_width) and
+//!   [enclosing rectangles](Polygon::min_area_rectangle)
+//! - [Extreme vertex](Polygon::extreme_vertex) and [tangents](Polygon::tangents_from) of a convex polygon in
+//!   logarithmic time
 #![warn(missing_docs)]
 
-use comlib_math::{gcd, Quot, Signed};
-use std::num::NonZeroI64;
+use comlib_math::{gcd, Eps, Integer, Quot, Sign, Signed};
+use polygon::compare_points;
+use std::{
+    cmp,
+    num::{NonZeroI128, NonZeroI64},
+};
 
 mod primitive;
 pub use primitive::{Line, LineIntersection, Ordering, Point, Segment, SegmentIntersection};
@@ -17,145 +33,289 @@ pub use primitive::{Line, LineIntersection, Ordering, Point, Segment, SegmentInt
 mod unit;
 pub use unit::Unit;
 
+mod calipers;
 mod polygon;
-pub use polygon::{Polygon, PolygonSegmentIter};
+pub use polygon::{
+    convex_intersection, intersection_area, Containment, Polygon, PolygonSegmentIter,
+};
 
+mod convex;
+
+mod circle;
+pub use circle::{Circle, CircleIntersection, CommonTangents, Tangents};
+
+mod triangle;
+pub use triangle::Triangle;
+
+mod sweep;
+pub use sweep::{any_segment_intersection, segment_intersections};
+
+mod range_count;
+pub use range_count::RectangleCounter;
+
+mod angle;
+pub use angle::{compare_angles, sort_by_angle_around};
+
+mod affine;
+pub use affine::Affine;
+
+mod hull3d;
+pub use hull3d::{convex_hull_3d, Point3, Polyhedron};
+
+mod delaunay;
+pub use delaunay::{Delaunay, VoronoiEdge};
+
+mod locate;
+pub use locate::PointLocator;
+
+/// Types which can be used as coordinates of geometric primitives.
+///
+/// Points are stored in [homogeneous coordinates](https://en.wikipedia.org/wiki/Homogeneous_coordinates) `(x, y, z)`
+/// representing the point `(x/z, y/z)`. Integral types use the divisor `z` to represent rational points exactly, while
+/// floating-point types use [`Unit`] as the divisor.
 pub trait ValidCoordinate: Sized + Signed + PartialEq {
+    /// Type of the divisor `z` of the homogeneous coordinates.
     type Divisor: Into<Self> + Copy;
+    /// Type of the actual coordinates `x/z` and `y/z`.
     type Coordinate: Signed;
 
+    /// Divides the values by `last` and normalizes the result.
+    ///
+    /// Returns `None` if `last` is zero.
     fn try_normalize<const N: usize>(
         values: [Self; N],
         last: Self,
     ) -> Option<([Self; N], Self::Divisor)>;
 
+    /// Scales the values to a canonical representation, keeping their ratios.
     fn normalize<const N: usize>(values: [Self; N]) -> [Self; N];
 
+    /// Scales the values and the divisor to a canonical representation, keeping their ratios.
     fn normalize_with_divisor<const N: usize>(
         values: ([Self; N], Self::Divisor),
     ) -> ([Self; N], Self::Divisor);
 
+    /// Converts actual coordinates into homogeneous values sharing a common divisor.
     fn from_coordinates<const N: usize>(
         coordinates: [Self::Coordinate; N],
     ) -> ([Self; N], Self::Divisor);
 
+    /// Converts homogeneous values into actual coordinates.
     fn to_coordinates<const N: usize>(values: ([Self; N], Self::Divisor)) -> [Self::Coordinate; N];
-}
 
-impl ValidCoordinate for i64 {
-    type Divisor = NonZeroI64;
-    type Coordinate = Quot<i64>;
+    /// Computes `numerator / denominator` as an actual coordinate.
+    ///
+    /// Returns `None` if `denominator` is zero.
+    fn try_divide(numerator: Self, denominator: Self) -> Option<Self::Coordinate>;
 
-    fn try_normalize<const N: usize>(
-        values: [i64; N],
-        last: i64,
-    ) -> Option<([i64; N], NonZeroI64)> {
-        Some(Self::normalize_with_divisor((
-            values,
-            NonZeroI64::new(last)?,
-        )))
+    /// Determines the sign of `a - b`, as used by geometric predicates such as [`Point::ordering`].
+    ///
+    /// Exact types compare the values exactly. Floating-point types consider values within their default [`Eps`] equal.
+    fn difference_sign(a: Self::Coordinate, b: Self::Coordinate) -> Sign {
+        (a - b).get_sign()
     }
 
-    fn normalize<const N: usize>(values: [i64; N]) -> [i64; N] {
-        let mut div = values[0];
-        let mut values = values;
-        for i in 1..N {
-            div = gcd(div, values[i]);
-        }
-        for i in 0..N {
-            values[i] /= div;
-        }
-        values
+    /// Determines the orientation of three points given as homogeneous values, as used by [`Point::ordering`].
+    ///
+    /// Returns the sign of the cross product of `p1 - p0` and `p2 - p0`.
+    fn orientation(points: [([Self; 2], Self::Divisor); 3]) -> Sign {
+        let [p0, p1, p2] = points.map(Self::to_coordinates);
+        let dx1 = p1[0] - p0[0];
+        let dy1 = p1[1] - p0[1];
+        let dx2 = p2[0] - p0[0];
+        let dy2 = p2[1] - p0[1];
+        Self::difference_sign(dx1 * dy2, dx2 * dy1)
     }
+}
 
-    fn normalize_with_divisor<const N: usize>(
-        values: ([i64; N], NonZeroI64),
-    ) -> ([i64; N], NonZeroI64) {
-        let mut div = values.1.get();
-        let mut values = values;
-        for i in 0..N {
-            div = gcd(div, values.0[i]);
-        }
-        for i in 0..N {
-            values.0[i] /= div;
-        }
-        (values.0, unsafe {
-            NonZeroI64::new_unchecked(values.1.get() / div)
-        })
-    }
+/// Implements [`ValidCoordinate`] for an integral type.
+///
+/// Integral coordinates represent rational points exactly. Orientation is computed exactly using the wide integer type
+/// of the coordinate type for intermediate values, and panics if even those overflow.
+macro_rules! impl_integer_coordinate {
+    ($t:ident, $nonzero:ident) => {
+        impl ValidCoordinate for $t {
+            type Divisor = $nonzero;
+            type Coordinate = Quot<$t>;
+
+            fn try_normalize<const N: usize>(
+                values: [$t; N],
+                last: $t,
+            ) -> Option<([$t; N], $nonzero)> {
+                Some(Self::normalize_with_divisor((values, $nonzero::new(last)?)))
+            }
 
-    fn from_coordinates<const N: usize>(coordinates: [Quot<i64>; N]) -> ([Self; N], Self::Divisor) {
-        let mut div = coordinates[0].denominator();
-        for i in 1..N {
-            div = (div.checked_mul(coordinates[i].denominator()).unwrap())
-                / gcd(div, coordinates[i].denominator());
-        }
-        let mut values = [0; N];
-        for i in 0..N {
-            values[i] = coordinates[i].numerator() * (div / coordinates[i].denominator());
-        }
-        (values, unsafe { NonZeroI64::new_unchecked(div) })
-    }
+            fn normalize<const N: usize>(mut values: [$t; N]) -> [$t; N] {
+                let mut div = values.iter().fold(0, |div, &value| gcd(div, value));
+                if div == 0 {
+                    return values;
+                }
+                // Make the first non-zero value positive
+                if values.iter().find(|&&value| value != 0).unwrap() / div < 0 {
+                    div = -div;
+                }
+                for value in &mut values {
+                    *value /= div;
+                }
+                values
+            }
 
-    fn to_coordinates<const N: usize>(values: ([Self; N], NonZeroI64)) -> [Quot<i64>; N] {
-        let mut coordinates = [Quot::zero(); N];
-        for i in 0..N {
-            coordinates[i] = Quot::new_nonzero(values.0[i], values.1);
+            fn normalize_with_divisor<const N: usize>(
+                values: ([$t; N], $nonzero),
+            ) -> ([$t; N], $nonzero) {
+                let (mut values, divisor) = values;
+                let mut div = values
+                    .iter()
+                    .fold(divisor.get(), |div, &value| gcd(div, value));
+                // Make the divisor positive
+                if divisor.get() / div < 0 {
+                    div = -div;
+                }
+                for value in &mut values {
+                    *value /= div;
+                }
+                (values, unsafe {
+                    $nonzero::new_unchecked(divisor.get() / div)
+                })
+            }
+
+            fn from_coordinates<const N: usize>(
+                coordinates: [Quot<$t>; N],
+            ) -> ([Self; N], Self::Divisor) {
+                let mut div = coordinates[0].denominator();
+                for coordinate in &coordinates[1..] {
+                    div = (div.checked_mul(coordinate.denominator()).unwrap())
+                        / gcd(div, coordinate.denominator());
+                }
+                let mut values = [0 as $t; N];
+                for (value, coordinate) in values.iter_mut().zip(&coordinates) {
+                    *value = coordinate.numerator() * (div / coordinate.denominator());
+                }
+                (values, unsafe { $nonzero::new_unchecked(div) })
+            }
+
+            fn to_coordinates<const N: usize>(values: ([Self; N], $nonzero)) -> [Quot<$t>; N] {
+                let mut coordinates = [Quot::zero(); N];
+                for (coordinate, &value) in coordinates.iter_mut().zip(&values.0) {
+                    *coordinate = Quot::new_nonzero(value, values.1);
+                }
+                coordinates
+            }
+
+            fn try_divide(numerator: $t, denominator: $t) -> Option<Quot<$t>> {
+                Quot::new(numerator, denominator)
+            }
+
+            fn orientation(points: [([$t; 2], $nonzero); 3]) -> Sign {
+                let [p0, p1, p2] =
+                    points.map(|([x, y], z)| [x.widen(), y.widen(), z.get().widen()]);
+                // Differences of the points scaled by the product of their divisors
+                let delta =
+                    |a: [<$t as Integer>::Wide; 3], b: [<$t as Integer>::Wide; 3], i: usize| {
+                        b[i].checked_mul(a[2])
+                            .zip(a[i].checked_mul(b[2]))
+                            .and_then(|(p, q)| p.checked_sub(q))
+                            .expect("difference of coordinates to fit in the wide type")
+                    };
+                // The divisors are positive, so the scaling doesn't affect the sign
+                let cross = delta(p0, p1, 0)
+                    .checked_mul(delta(p0, p2, 1))
+                    .zip(delta(p0, p2, 0).checked_mul(delta(p0, p1, 1)))
+                    .and_then(|(a, b)| a.checked_sub(b))
+                    .expect("cross product to fit in the wide type");
+                cross.get_sign()
+            }
         }
-        coordinates
-    }
+    };
 }
 
-impl ValidCoordinate for f32 {
-    type Divisor = Unit;
-    type Coordinate = f32;
+impl_integer_coordinate!(i64, NonZeroI64);
+impl_integer_coordinate!(i128, NonZeroI128);
 
-    fn try_normalize<const N: usize>(mut values: [f32; N], last: f32) -> Option<([f32; N], Unit)> {
-        let normalizer = 1.0 / last;
-        for i in 0..N {
-            values[i] *= normalizer;
-        }
-        if IntoIterator::into_iter(values).any(f32::is_nan) {
-            None
-        } else {
-            Some((values, Unit))
-        }
-    }
+/// Implements [`ValidCoordinate`] for a floating-point type.
+///
+/// Floating-point coordinates are stored as is with [`Unit`] as the divisor. Geometric predicates treat values within
+/// the default [`Eps`] of the type as equal, so nearly collinear points are considered collinear. The tolerance is
+/// relative to the magnitude of the compared values, and hence works also for large coordinates.
+macro_rules! impl_float_coordinate {
+    ($t:ty) => {
+        impl ValidCoordinate for $t {
+            type Divisor = Unit;
+            type Coordinate = $t;
+
+            fn try_normalize<const N: usize>(
+                mut values: [$t; N],
+                last: $t,
+            ) -> Option<([$t; N], Unit)> {
+                let normalizer = 1.0 / last;
+                for value in &mut values {
+                    *value *= normalizer;
+                }
+                if IntoIterator::into_iter(values).any(<$t>::is_nan) {
+                    None
+                } else {
+                    Some((values, Unit))
+                }
+            }
 
-    fn normalize<const N: usize>(mut values: [f32; N]) -> [f32; N] {
-        // Normalize the scale such that the first non-zero variable is 1
-        for i in 0..N {
-            if values[i] > 1e-6 {
-                let multiplier = 1.0 / values[i];
-                for j in 0..N {
-                    values[j] *= multiplier;
+            fn normalize<const N: usize>(mut values: [$t; N]) -> [$t; N] {
+                // Normalize the scale such that the first non-zero variable is 1
+                if let Some(&first) = values
+                    .iter()
+                    .find(|&&value| !Eps::<$t>::DEFAULT.is_zero(value))
+                {
+                    let multiplier = 1.0 / first;
+                    for value in &mut values {
+                        *value *= multiplier;
+                    }
                 }
-                return values;
+
+                values
             }
-        }
 
-        values
-    }
+            fn normalize_with_divisor<const N: usize>(values: ([$t; N], Unit)) -> ([$t; N], Unit) {
+                values
+            }
 
-    fn normalize_with_divisor<const N: usize>(values: ([f32; N], Unit)) -> ([f32; N], Unit) {
-        values
-    }
+            fn from_coordinates<const N: usize>(
+                coordinates: [$t; N],
+            ) -> ([Self; N], Self::Divisor) {
+                (coordinates, Unit)
+            }
 
-    fn from_coordinates<const N: usize>(coordinates: [f32; N]) -> ([Self; N], Self::Divisor) {
-        (coordinates, Unit)
-    }
+            fn to_coordinates<const N: usize>(values: ([Self; N], Unit)) -> [$t; N] {
+                values.0
+            }
 
-    fn to_coordinates<const N: usize>(values: ([Self; N], Unit)) -> [f32; N] {
-        values.0
-    }
+            fn try_divide(numerator: $t, denominator: $t) -> Option<$t> {
+                if denominator == 0.0 {
+                    None
+                } else {
+                    Some(numerator / denominator)
+                }
+            }
+
+            fn difference_sign(a: $t, b: $t) -> Sign {
+                match Eps::<$t>::DEFAULT.approx_cmp(a, b) {
+                    cmp::Ordering::Less => Sign::Negative,
+                    cmp::Ordering::Equal => Sign::Neutral,
+                    cmp::Ordering::Greater => Sign::Positive,
+                }
+            }
+        }
+    };
 }
 
+impl_float_coordinate!(f32);
+impl_float_coordinate!(f64);
+
 /// Computes the convex hull of the given set of points.
 ///
 /// Return `None` if all points are equal, otherwise returns the convex hull. The convex hull is returned in
-/// counter-clockwise order.
+/// counter-clockwise order, starting from the lexicographically smallest point.
 ///
-/// The hull contains all points that are on the edge of the hull.
+/// The hull contains all points that are on the edge of the hull, but duplicate points are removed. See
+/// [`convex_hull_in_place`] for computing the hull without collinear points.
 ///
 /// # Convex hull
 /// A convex hull of a set of points is the minimum-area convex polygon containing all of the points. It is also the
@@ -164,141 +324,155 @@ impl ValidCoordinate for f32 {
 /// The intuitive way to think about the convex hull is to think about nails on a board (i.e. the set of the points) and
 /// the shape a tight rubber band stretched around the nails would form (i.e. the convex hull).
 pub fn convex_hull<T: ValidCoordinate>(mut points: Vec<Point<T>>) -> Option<Polygon<T>> {
+    convex_hull_in_place(&mut points, CollinearPoints::Keep);
     if points.len() <= 1 {
-        return None;
+        None
+    } else {
+        Some(points.into())
     }
+}
 
-    // Sort the points in the order of increasing x-coordinate
-    points.sort_by(|a, b| {
-        a.x()
-            .partial_cmp(&b.x())
-            .expect("coordinates to be comparable")
-            .then(
-                a.y()
-                    .partial_cmp(&b.y())
-                    .expect("coordinates to be comparable"),
-            )
-    });
-
-    let mut points = points.into_iter();
-    let mut points = points.by_ref();
+/// Whether to keep points lying on the edges of a convex hull.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollinearPoints {
+    /// Keep all points on the boundary of the hull.
+    Keep,
+    /// Keep only the corners of the hull.
+    Remove,
+}
 
-    // Initialize both hull halves
-    let mut lower_hull = vec![points.next().unwrap()];
-    for point in &mut points {
-        if point != lower_hull[0] {
-            lower_hull.push(point);
-            break;
-        }
+/// Computes the convex hull of the given set of points, replacing the points with the vertices of the hull.
+///
+/// The vertices of the hull are in counter-clockwise order, starting from the lexicographically smallest point.
+/// Duplicate points are removed, and the `collinear` policy decides whether points lying on the edges of the hull are
+/// kept. If all of the points are collinear, the resulting hull is degenerate: it goes from one end of the line to the
+/// other and back. If all of the points are equal, a single point remains.
+///
+/// The hull is computed within the vector without allocating. Only a degenerate hull keeping collinear points grows it.
+///
+/// # Time complexity
+/// Takes O(n log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{convex_hull_in_place, CollinearPoints, Point};
+/// let square: Vec<Point<i64>> = vec![(0, 0), (1, 0), (2, 0), (2, 2), (1, 1), (0, 2), (0, 0)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+///
+/// let mut hull = square.clone();
+/// convex_hull_in_place(&mut hull, CollinearPoints::Keep);
+/// let expected: Vec<Point<i64>> = vec![(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// assert_eq!(hull, expected);
+///
+/// let mut hull = square;
+/// convex_hull_in_place(&mut hull, CollinearPoints::Remove);
+/// let expected: Vec<Point<i64>> = vec![(0, 0), (2, 0), (2, 2), (0, 2)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// assert_eq!(hull, expected);
+/// ```
+pub fn convex_hull_in_place<T: ValidCoordinate>(
+    points: &mut Vec<Point<T>>,
+    collinear: CollinearPoints,
+) {
+    // Sort the points in the order of increasing x-coordinate
+    points.sort_unstable_by(|&a, &b| compare_points(a, b));
+    points.dedup();
+    if points.len() <= 1 {
+        return;
     }
 
-    // Check that all points are not the same
-    if lower_hull.len() == 1 {
-        return None;
+    // The lower hull goes from the first point to the last one and the upper hull back. Points strictly above the line
+    // between them can only be on the upper hull, and points strictly below it only on the lower hull.
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let side = |point| Point::ordering([first, last, point]);
+    let has_above = points
+        .iter()
+        .any(|&point| side(point) == Ordering::Counterclockwise);
+    let has_below = points
+        .iter()
+        .any(|&point| side(point) == Ordering::Clockwise);
+    if !has_above && !has_below {
+        // All points are collinear, so the hull goes from one end of the line to the other and back
+        let len = points.len();
+        match collinear {
+            CollinearPoints::Keep => {
+                for i in (1..len - 1).rev() {
+                    let point = points[i];
+                    points.push(point);
+                }
+            }
+            CollinearPoints::Remove => {
+                points.swap(1, len - 1);
+                points.truncate(2);
+            }
+        }
+        return;
     }
 
-    let mut upper_hull = lower_hull.clone();
-
-    // Do one sweep over the point cloud and compute both parts of the hull
-    for point in points {
-        while lower_hull.len() >= 2
-            && Point::ordering([
-                lower_hull[lower_hull.len() - 2],
-                lower_hull[lower_hull.len() - 1],
-                point,
-            ]) == Ordering::Clockwise
-        {
-            lower_hull.pop();
+    // Points on the line between the ends belong to the upper hull only when it is an edge of the hull
+    let is_upper = |point| match side(point) {
+        Ordering::Counterclockwise => true,
+        Ordering::Collinear => !has_above && point != first && point != last,
+        Ordering::Clockwise => false,
+    };
+    // Order the candidates for the lower hull from left to right, followed by those for the upper hull from right to
+    // left
+    points.sort_unstable_by(|&a, &b| {
+        is_upper(a).cmp(&is_upper(b)).then_with(|| {
+            if is_upper(a) {
+                compare_points(b, a)
+            } else {
+                compare_points(a, b)
+            }
+        })
+    });
+    let upper_start = points.partition_point(|&point| !is_upper(point));
+
+    // Both halves of the hull turn only counter-clockwise
+    let is_removed = |hull: &[Point<T>], point: Point<T>| match Point::ordering([
+        hull[hull.len() - 2],
+        hull[hull.len() - 1],
+        point,
+    ]) {
+        Ordering::Counterclockwise => false,
+        Ordering::Collinear => collinear == CollinearPoints::Remove,
+        Ordering::Clockwise => true,
+    };
+
+    // Sweep over the candidates, compacting the hull into the beginning of the points. The hull never gets longer than
+    // the number of points processed, so it never overwrites a point yet to be processed.
+    let mut len = 0;
+    for i in 0..upper_start {
+        let point = points[i];
+        while len >= 2 && is_removed(&points[..len], point) {
+            len -= 1;
         }
-        lower_hull.push(point);
-
-        while upper_hull.len() >= 2
-            && Point::ordering([
-                upper_hull[upper_hull.len() - 2],
-                upper_hull[upper_hull.len() - 1],
-                point,
-            ]) == Ordering::Counterclockwise
-        {
-            upper_hull.pop();
+        points[len] = point;
+        len += 1;
+    }
+    let lower_len = len;
+    for i in upper_start..points.len() {
+        let point = points[i];
+        while len > lower_len && is_removed(&points[..len], point) {
+            len -= 1;
         }
-        upper_hull.push(point);
+        points[len] = point;
+        len += 1;
     }
-
-    // Make sure that both hulls start and end at the same point
-    debug_assert_eq!(lower_hull.first(), upper_hull.first());
-    debug_assert_eq!(lower_hull.last(), upper_hull.last());
-    debug_assert_ne!(lower_hull.first(), lower_hull.last());
-
-    // Reverse the order of the upper hull and append it to the lower
-    upper_hull.reverse();
-    lower_hull.extend_from_slice(&upper_hull[1..upper_hull.len() - 1]);
-
-    Some(lower_hull.into())
+    // The hull closes back at the first point
+    while len > lower_len && is_removed(&points[..len], first) {
+        len -= 1;
+    }
+    points.truncate(len);
 }
 
-// /// Represents the set of points (x, y) which satisfy ax^2 + ay^2 + bxz + cyz + dz^2 = 0.
-// #[derive(Debug, Clone, Copy)]
-// pub struct Circle<T:ValidCoordinate> {
-//     a: T,
-//     b: T,
-//     c: T,
-//     d: T,
-// }
-
-// impl<T:ValidCoordinate>  Circle<T> {
-//     pub fn from_center_and_radius<P: Into<Point<T>>, R: Into<Quot<T>>>(center: P, radius: R) -> Self {
-//         let center = center.into();
-//         let radius = radius.into();
-
-//         let b = center.x() * -2;
-//         let c = center.y() * -2;
-//         let d = center.x() * center.x() + center.y() * center.y() - radius * radius;
-//         let a = lcm(lcm(b.denominator(), c.denominator()), d.denominator());
-//         let b = (b * a).numerator();
-//         let c = (c * a).numerator();
-//         let d = (d * a).numerator();
-//         assert_ne!(a, 0, "The circle can't be degenerate");
-
-//         Self { a, b, c, d }
-//     }
-
-//     // TODO: Better API, probably one which returns Inside/AtEdge/Outside
-//     pub fn contains<P: Into<Point<T>>>(self, p: P) -> bool {
-//         let p = p.into();
-//         let x = p.x;
-//         let y = p.y;
-//         let z = p.z.get();
-//         self.a * (x * x + y * y) + self.b * x * z + self.c * y * z + self.d * z * z == 0
-//     }
-
-//     /// Constructs the line on which the intersections of the given circles reside.
-//     pub fn intersection_line(self, other: Self) -> Option<Line<T>> {
-//         let a = self.b * other.a - self.a * other.b;
-//         let b = self.c * other.a - self.a * other.c;
-//         let c = self.d * other.a - self.a * other.d;
-//         println!("{} {} {}", a, b, c);
-
-//         Some(Line { a, b, c }.normalized())
-//     }
-
-//     /// Computes the squared radius of the circle.
-//     pub fn radius2(self) -> Quot<T> {
-//         let c = self.center();
-//         c.x() * c.x() + c.y() * c.y() - Quot::new(self.d, self.a).unwrap()
-//     }
-
-//     pub fn center(self) -> Point<T> {
-//         let z = -2 * self.a;
-//         let x = self.b;
-//         let y = self.c;
-//         Point {
-//             x,
-//             y,
-//             z: unsafe { NonZeroI64::new_unchecked(z) },
-//         }
-//     }
-// }
-
 // macro_rules! impl_vec {
 //     (impl<$t:ident> math for $v:ident, $dim:tt) => {
 //         impl_vec!(@IMPL: impl<$t> Add [add, +] for $v, $dim);
@@ -492,40 +666,6 @@ pub fn convex_hull<T: ValidCoordinate>(mut points: Vec<Point<T>>) -> Option<Poly
 // impl_zero_one!(f32);
 // impl_zero_one!(f64);
 
-// pub trait AlmostEq {
-//     fn almost_eq(&self, rhs: &Self) -> bool;
-// }
-
-// macro_rules! impl_almost_eq {
-//     (exact $t:ty) => {
-//         impl AlmostEq for $t {
-//             fn almost_eq(&self, rhs: &Self) -> bool {
-//                 self == rhs
-//             }
-//         }
-//     };
-//     (epsilon $t:ty, $eps:expr) => {
-//         impl AlmostEq for $t {
-//             fn almost_eq(&self, rhs: &Self) -> bool {
-//                 (self - rhs).abs() < $eps
-//             }
-//         }
-//     };
-// }
-// impl_almost_eq!(exact i8);
-// impl_almost_eq!(exact i16);
-// impl_almost_eq!(exact i32);
-// impl_almost_eq!(exact i64);
-// impl_almost_eq!(exact i128);
-// impl_almost_eq!(exact isize);
-// impl_almost_eq!(exact u8);
-// impl_almost_eq!(exact u16);
-// impl_almost_eq!(exact u32);
-// impl_almost_eq!(exact u64);
-// impl_almost_eq!(exact u128);
-// impl_almost_eq!(exact usize);
-// impl_almost_eq!(epsilon f32, 1e-5);
-// impl_almost_eq!(epsilon f64, 1e-8);
 // pub trait WithY<T> {
 //     fn with_y(self, y: T) -> Vec2<T>;
 // }
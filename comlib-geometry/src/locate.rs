@@ -0,0 +1,317 @@
+use crate::polygon::compare_points;
+use crate::{Ordering, Point, Segment, ValidCoordinate};
+use comlib_math::Numeric;
+use std::cmp::Ordering as CmpOrdering;
+use std::rc::Rc;
+
+/// Point location among non-crossing segments.
+///
+/// Answers which segment lies directly below or above a query point, i.e. which segment is hit first by a vertical
+/// ray shot from the point. For a planar subdivision, such as a triangulation, this identifies the face containing the
+/// point, as each face lies directly above some of its edges.
+///
+/// The plane is divided into vertical slabs at the endpoints of the segments. The segments crossing each slab are
+/// stored in a persistent balanced tree ordered from bottom to top, and consecutive slabs share most of their trees.
+///
+/// The segments may touch each other only at their endpoints. The coordinates should be exact, as the structure relies
+/// on exact comparisons.
+///
+/// # Time complexity
+/// Construction takes O(n log n) time and space, and each query takes O(log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{PointLocator, Segment};
+/// let locator = PointLocator::new(vec![
+///     Segment::between((0, 0), (4, 0)).unwrap(),
+///     Segment::between((0, 0), (2, 3)).unwrap(),
+///     Segment::between((2, 3), (4, 0)).unwrap(),
+///     Segment::between((1, 4), (3, 4)).unwrap(),
+/// ]);
+/// assert_eq!(locator.segment_below((2, 1)), Some(0));
+/// assert_eq!(locator.segment_above((2, 1)), Some(2));
+/// assert_eq!(locator.segment_below((1, 3)), Some(1));
+/// assert_eq!(locator.segment_above((1, 3)), Some(3));
+/// assert_eq!(locator.segment_above((2, 5)), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PointLocator<T: ValidCoordinate> {
+    segments: Vec<Segment<T>>,
+    /// The endpoints of the segments ordered from left to right.
+    ends: Vec<(Point<T>, Point<T>)>,
+    /// The distinct x-coordinates of the endpoints in increasing order.
+    xs: Vec<T::Coordinate>,
+    /// The trees of the slabs between consecutive x-coordinates.
+    slabs: Vec<Tree>,
+    /// The vertical segments ordered by their endpoints.
+    vertical: Vec<usize>,
+}
+
+type Tree = Option<Rc<Node>>;
+
+/// Node of a persistent treap storing segments by their index.
+#[derive(Debug)]
+struct Node {
+    segment: usize,
+    priority: u64,
+    left: Tree,
+    right: Tree,
+}
+
+impl<T: ValidCoordinate> PointLocator<T> {
+    /// Constructs the point location structure over the given segments.
+    pub fn new(segments: Vec<Segment<T>>) -> Self {
+        let ends: Vec<_> = segments
+            .iter()
+            .map(|s| match compare_points(s.0, s.1) {
+                CmpOrdering::Greater => (s.1, s.0),
+                _ => (s.0, s.1),
+            })
+            .collect();
+        let mut xs: Vec<_> = ends.iter().flat_map(|&(a, b)| [a.x(), b.x()]).collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).expect("coordinates to be comparable"));
+        xs.dedup();
+
+        let mut vertical: Vec<_> = (0..segments.len())
+            .filter(|&i| ends[i].0.x() == ends[i].1.x())
+            .collect();
+        vertical.sort_by(|&i, &j| compare_points(ends[i].0, ends[j].0));
+
+        let mut locator = Self {
+            segments,
+            ends,
+            xs,
+            slabs: Vec::new(),
+            vertical,
+        };
+        locator.build_slabs();
+        locator
+    }
+
+    /// Returns the segments the structure was constructed from.
+    pub fn segments(&self) -> &[Segment<T>] {
+        &self.segments
+    }
+
+    /// Finds the segment directly below the given point.
+    ///
+    /// Returns the index of the segment which a ray shot downwards from the point hits first, or `None` if there is no
+    /// such segment. Segments containing the point are hit immediately. If several segments are hit at the same point,
+    /// any one of them is returned.
+    pub fn segment_below<P: Into<Point<T>>>(&self, p: P) -> Option<usize> {
+        self.find(p.into(), false)
+    }
+
+    /// Finds the segment directly above the given point.
+    ///
+    /// See [`segment_below`](Self::segment_below) for details.
+    pub fn segment_above<P: Into<Point<T>>>(&self, p: P) -> Option<usize> {
+        self.find(p.into(), true)
+    }
+
+    /// Finds the first segment hit by a vertical ray from the point.
+    fn find(&self, p: Point<T>, upwards: bool) -> Option<usize> {
+        let x = p.x();
+        let slab = self.xs.partition_point(|&v| v < x);
+        if slab == self.xs.len() || (slab == 0 && self.xs[0] != x) {
+            return None;
+        }
+
+        // The point is either strictly inside the slab to the left of `slab`, or on the boundary between two slabs
+        let mut candidates = Vec::with_capacity(3);
+        if slab > 0 {
+            candidates.extend(self.find_in_slab(slab - 1, p, upwards));
+        }
+        if self.xs[slab] == x {
+            if slab < self.slabs.len() {
+                candidates.extend(self.find_in_slab(slab, p, upwards));
+            }
+            candidates.extend(self.find_vertical(p, upwards));
+        }
+
+        // Choose the candidate hit closest to the point
+        let hit = |i: usize| {
+            let (a, b) = self.ends[i];
+            let y = if a.x() == b.x() {
+                let (low, high) = (a.y(), b.y());
+                if p.y() < low {
+                    low
+                } else if p.y() > high {
+                    high
+                } else {
+                    p.y()
+                }
+            } else {
+                a.y() + (b.y() - a.y()) * (x - a.x()) / (b.x() - a.x())
+            };
+            if upwards {
+                -y
+            } else {
+                y
+            }
+        };
+        candidates.into_iter().max_by(|&i, &j| {
+            hit(i)
+                .partial_cmp(&hit(j))
+                .expect("coordinates to be comparable")
+        })
+    }
+
+    /// Finds the first segment of the slab hit by a vertical ray from the point.
+    fn find_in_slab(&self, slab: usize, p: Point<T>, upwards: bool) -> Option<usize> {
+        // The segments which the ray doesn't hit form a suffix of the slab when going downwards, and a prefix when
+        // going upwards
+        let excluded = if upwards {
+            Ordering::Counterclockwise
+        } else {
+            Ordering::Clockwise
+        };
+        let mut node = &self.slabs[slab];
+        let mut found = None;
+        while let Some(current) = node {
+            let (a, b) = self.ends[current.segment];
+            let hit = Point::ordering([a, b, p]) != excluded;
+            if hit {
+                found = Some(current.segment);
+            }
+            node = if hit != upwards {
+                &current.right
+            } else {
+                &current.left
+            };
+        }
+        found
+    }
+
+    /// Finds the first vertical segment hit by a vertical ray from the point.
+    fn find_vertical(&self, p: Point<T>, upwards: bool) -> Option<usize> {
+        // The vertical segments on the same line are disjoint, so they are ordered both by their bottoms and tops
+        let on_line = |i: usize| self.ends[i].0.x().partial_cmp(&p.x());
+        let start = self
+            .vertical
+            .partition_point(|&i| on_line(i) == Some(CmpOrdering::Less));
+        let end = self
+            .vertical
+            .partition_point(|&i| on_line(i) != Some(CmpOrdering::Greater));
+        let line = &self.vertical[start..end];
+        if upwards {
+            let idx = line.partition_point(|&i| self.ends[i].1.y() < p.y());
+            line.get(idx).copied()
+        } else {
+            let idx = line.partition_point(|&i| self.ends[i].0.y() <= p.y());
+            idx.checked_sub(1).map(|idx| line[idx])
+        }
+    }
+
+    /// Sweeps over the slabs from left to right, building the trees of the slabs.
+    fn build_slabs(&mut self) {
+        let mut starting = vec![Vec::new(); self.xs.len()];
+        let mut ending = vec![Vec::new(); self.xs.len()];
+        let position = |x: T::Coordinate| self.xs.partition_point(|&v| v < x);
+        for (i, &(a, b)) in self.ends.iter().enumerate() {
+            if a.x() != b.x() {
+                starting[position(a.x())].push(i);
+                ending[position(b.x())].push(i);
+            }
+        }
+
+        let mut root = None;
+        for slab in 0..self.xs.len().saturating_sub(1) {
+            if slab > 0 {
+                let x = self.slab_middle(slab - 1);
+                for &i in &ending[slab] {
+                    let (left, right) = split(&root, &|j| self.compare_at(j, i, x).is_lt());
+                    let (_, right) = split(&right, &|j| self.compare_at(j, i, x).is_le());
+                    root = merge(left, right);
+                }
+            }
+            let x = self.slab_middle(slab);
+            for &i in &starting[slab] {
+                let (left, right) = split(&root, &|j| self.compare_at(j, i, x).is_lt());
+                let node = Rc::new(Node {
+                    segment: i,
+                    priority: priority(i),
+                    left: None,
+                    right: None,
+                });
+                root = merge(merge(left, Some(node)), right);
+            }
+            self.slabs.push(root.clone());
+        }
+    }
+
+    /// Returns the x-coordinate in the middle of the slab.
+    fn slab_middle(&self, slab: usize) -> T::Coordinate {
+        (self.xs[slab] + self.xs[slab + 1]) / T::Coordinate::from_int(2)
+    }
+
+    /// Compares the heights of two non-vertical segments at the given x-coordinate.
+    fn compare_at(&self, i: usize, j: usize, x: T::Coordinate) -> CmpOrdering {
+        let y = |i: usize| {
+            let (a, b) = self.ends[i];
+            a.y() + (b.y() - a.y()) * (x - a.x()) / (b.x() - a.x())
+        };
+        y(i).partial_cmp(&y(j))
+            .expect("coordinates to be comparable")
+    }
+}
+
+/// Computes a pseudorandom priority for the segment.
+fn priority(segment: usize) -> u64 {
+    // SplitMix64
+    let mut z = (segment as u64).wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// Splits the tree into the nodes satisfying the predicate and the rest, without modifying the original tree.
+///
+/// The predicate must hold for a prefix of the nodes.
+fn split<F: Fn(usize) -> bool>(tree: &Tree, goes_left: &F) -> (Tree, Tree) {
+    match tree {
+        None => (None, None),
+        Some(node) => {
+            if goes_left(node.segment) {
+                let (middle, right) = split(&node.right, goes_left);
+                let node = Node {
+                    right: middle,
+                    left: node.left.clone(),
+                    ..**node
+                };
+                (Some(Rc::new(node)), right)
+            } else {
+                let (left, middle) = split(&node.left, goes_left);
+                let node = Node {
+                    left: middle,
+                    right: node.right.clone(),
+                    ..**node
+                };
+                (left, Some(Rc::new(node)))
+            }
+        }
+    }
+}
+
+/// Concatenates two trees, without modifying the original trees.
+fn merge(left: Tree, right: Tree) -> Tree {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(left), Some(right)) => {
+            let node = if left.priority > right.priority {
+                Node {
+                    right: merge(left.right.clone(), Some(right)),
+                    left: left.left.clone(),
+                    ..*left
+                }
+            } else {
+                Node {
+                    left: merge(Some(left), right.left.clone()),
+                    right: right.right.clone(),
+                    ..*right
+                }
+            };
+            Some(Rc::new(node))
+        }
+    }
+}
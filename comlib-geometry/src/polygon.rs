@@ -1,13 +1,66 @@
-use crate::{Point, Segment, ValidCoordinate};
-use comlib_math::Numeric;
+use crate::{Line, Ordering, Point, Segment, ValidCoordinate};
+use comlib_math::{Numeric, Sign, Signed};
+use std::collections::BTreeSet;
+use std::ops::Index;
 
-pub struct Polygon<T: ValidCoordinate>(Vec<Point<T>>);
+/// Location of a point relative to a shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// The point lies strictly inside the shape.
+    Inside,
+    /// The point lies on the boundary of the shape.
+    OnBoundary,
+    /// The point lies strictly outside the shape.
+    Outside,
+}
+
+/// Polygon defined by its vertices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon<T: ValidCoordinate>(pub(crate) Vec<Point<T>>);
 
 impl<T: ValidCoordinate> Polygon<T> {
-    pub fn points(&self) -> impl Iterator<Item = Point<T>> + '_ {
+    /// Returns the number of vertices of the polygon.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks whether the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the vertices of the polygon.
+    pub fn points(&self) -> impl DoubleEndedIterator<Item = Point<T>> + ExactSizeIterator + '_ {
         self.0.iter().copied()
     }
 
+    /// Consumes the polygon, returning its vertices.
+    pub fn into_points(self) -> Vec<Point<T>> {
+        self.0
+    }
+
+    /// Returns the vertex at the given index, wrapping around the polygon.
+    ///
+    /// Negative indices count backwards from the end, so `-1` refers to the last vertex.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let polygon = Polygon::from(vec![Point::from((0, 0)), Point::from((1, 0)), Point::from((0, 1))]);
+    /// assert_eq!(polygon.cyclic(4), Point::from((1, 0)));
+    /// assert_eq!(polygon.cyclic(-1), Point::from((0, 1)));
+    /// ```
+    pub fn cyclic(&self, index: isize) -> Point<T> {
+        assert!(!self.0.is_empty(), "polygon cannot be empty");
+        self.0[index.rem_euclid(self.0.len() as isize) as usize]
+    }
+
+    /// Iterates over the edges of the polygon.
+    ///
+    /// The first edge goes from the last vertex to the first one.
     pub fn segments(&self) -> PolygonSegmentIter<'_, T> {
         PolygonSegmentIter(*self.0.last().expect("polygon cannot be empty"), &self.0)
     }
@@ -22,6 +75,642 @@ impl<T: ValidCoordinate> Polygon<T> {
         }
         area / T::Coordinate::from_int(2)
     }
+
+    /// Computes the centroid, i.e. the center of mass, of the polygon.
+    ///
+    /// Returns `None` if the polygon has no area. The centroid is exact for integral coordinates.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let polygon = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((4, 0)),
+    ///     Point::from((4, 1)),
+    ///     Point::from((1, 1)),
+    ///     Point::from((1, 3)),
+    ///     Point::from((0, 3)),
+    /// ]);
+    /// assert_eq!(polygon.centroid(), Point::try_new(3, 2, 2));
+    /// ```
+    pub fn centroid(&self) -> Option<Point<T>> {
+        let (mut area, mut x, mut y) = (
+            T::Coordinate::zero(),
+            T::Coordinate::zero(),
+            T::Coordinate::zero(),
+        );
+        let mut previous = *self.0.last().expect("polygon cannot be empty");
+        for &p in &self.0 {
+            let cross = previous.x() * p.y() - p.x() * previous.y();
+            area += cross;
+            x += (previous.x() + p.x()) * cross;
+            y += (previous.y() + p.y()) * cross;
+            previous = p;
+        }
+        if area.is_zero() {
+            return None;
+        }
+        let divisor = area * T::Coordinate::from_int(3);
+        Some(Point::from_coordinates(x / divisor, y / divisor))
+    }
+
+    /// Computes the perimeter of the polygon.
+    pub fn perimeter(&self) -> f64 {
+        let mut previous = self
+            .0
+            .last()
+            .expect("polygon cannot be empty")
+            .to_f64_pair();
+        let mut perimeter = 0.0;
+        for p in &self.0 {
+            let p = p.to_f64_pair();
+            perimeter += (p.0 - previous.0).hypot(p.1 - previous.1);
+            previous = p;
+        }
+        perimeter
+    }
+
+    /// Finds the vertex of the polygon furthest from the given point.
+    ///
+    /// The furthest point of a polygon from any point is always one of its vertices. If several vertices are equally far,
+    /// the first one of them is returned.
+    ///
+    /// # Panics
+    /// Panics if the polygon is empty.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let polygon = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((4, 0)),
+    ///     Point::from((1, 1)),
+    ///     Point::from((0, 3)),
+    /// ]);
+    /// assert_eq!(polygon.farthest_point_from((1, 2)), Point::from((4, 0)));
+    /// ```
+    pub fn farthest_point_from<P: Into<Point<T>>>(&self, p: P) -> Point<T> {
+        let p = p.into();
+        let sq_dist = |q: Point<T>| {
+            let dx = q.x() - p.x();
+            let dy = q.y() - p.y();
+            dx * dx + dy * dy
+        };
+        let mut points = self.points();
+        let first = points.next().expect("polygon cannot be empty");
+        points
+            .fold((first, sq_dist(first)), |(best, best_dist), q| {
+                let dist = sq_dist(q);
+                if dist > best_dist {
+                    (q, dist)
+                } else {
+                    (best, best_dist)
+                }
+            })
+            .0
+    }
+
+    /// Computes the orientation of the polygon.
+    ///
+    /// Returns [`Ordering::Counterclockwise`] if the signed area of the polygon is positive, [`Ordering::Clockwise`] if
+    /// it is negative, and [`Ordering::Collinear`] if the polygon has no area.
+    pub fn orientation(&self) -> Ordering {
+        match self.area().get_sign() {
+            Sign::Positive => Ordering::Counterclockwise,
+            Sign::Neutral => Ordering::Collinear,
+            Sign::Negative => Ordering::Clockwise,
+        }
+    }
+
+    /// Reverses the order of the vertices if the polygon is defined in clockwise order.
+    ///
+    /// The first vertex of the polygon is kept in place.
+    pub fn make_ccw(&mut self) {
+        if self.orientation() == Ordering::Clockwise {
+            self.0[1..].reverse();
+        }
+    }
+
+    /// Rotates the vertices of the polygon so that the lexicographically smallest vertex is the first one.
+    ///
+    /// Together with [`make_ccw`](Self::make_ccw) this brings polygons to a canonical form, so that equal polygons can
+    /// be compared with `==`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let mut p1 = Polygon::from(vec![Point::from((1, 1)), Point::from((0, 1)), Point::from((0, 0))]);
+    /// let mut p2 = Polygon::from(vec![Point::from((0, 1)), Point::from((1, 1)), Point::from((0, 0))]);
+    /// p1.make_ccw();
+    /// p1.normalize_start();
+    /// p2.make_ccw();
+    /// p2.normalize_start();
+    /// assert_eq!(p1, p2);
+    /// ```
+    pub fn normalize_start(&mut self) {
+        let start = (0..self.0.len())
+            .min_by(|&i, &j| compare_points(self.0[i], self.0[j]))
+            .unwrap_or(0);
+        self.0.rotate_left(start);
+    }
+
+    /// Checks whether the polygon is convex.
+    ///
+    /// The polygon may be defined in either orientation, and it may contain collinear vertices. Polygons with less
+    /// than three vertices, repeated consecutive vertices, or edges doubling back on themselves are not considered
+    /// convex.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let square = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 0)),
+    ///     Point::from((2, 2)),
+    ///     Point::from((0, 2)),
+    /// ]);
+    /// assert!(square.is_convex());
+    /// let arrow = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 1)),
+    ///     Point::from((0, 2)),
+    ///     Point::from((1, 1)),
+    /// ]);
+    /// assert!(!arrow.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let points = &self.0;
+        let n = points.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut turn = None;
+        // Signs of the x-direction of the edges. The edge direction of a convex polygon turns around exactly once, so
+        // the sign changes exactly twice. This rules out polygons which wind around several times, such as
+        // pentagrams.
+        let mut first_sign = None;
+        let mut last_sign = None;
+        let mut sign_changes = 0;
+        for i in 0..n {
+            let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+            if a == b {
+                return false;
+            }
+
+            match Point::ordering([a, b, c]) {
+                Ordering::Collinear => {
+                    // Collinear vertices are fine as long as the path continues in the same direction
+                    let dot = (b.x() - a.x()) * (c.x() - b.x()) + (b.y() - a.y()) * (c.y() - b.y());
+                    if dot.get_sign() != Sign::Positive {
+                        return false;
+                    }
+                }
+                ordering => {
+                    if turn.replace(ordering).is_some_and(|turn| turn != ordering) {
+                        return false;
+                    }
+                }
+            }
+
+            let sign = (b.x() - a.x()).get_sign();
+            if sign != Sign::Neutral {
+                if last_sign.is_some_and(|last_sign| last_sign != sign) {
+                    sign_changes += 1;
+                }
+                first_sign.get_or_insert(sign);
+                last_sign = Some(sign);
+            }
+        }
+        if first_sign != last_sign {
+            sign_changes += 1;
+        }
+
+        turn.is_some() && sign_changes <= 2
+    }
+
+    /// Checks whether the polygon is simple, i.e. whether its edges intersect only at the shared endpoints of
+    /// consecutive edges.
+    ///
+    /// Polygons with less than three vertices or repeated vertices are not considered simple.
+    ///
+    /// # Time complexity
+    /// Takes O(n log n) time. This uses the [Shamos–Hoey] sweep line algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Polygon};
+    /// let arrow = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 1)),
+    ///     Point::from((0, 2)),
+    ///     Point::from((1, 1)),
+    /// ]);
+    /// assert!(arrow.is_simple());
+    /// let bowtie = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((2, 2)),
+    ///     Point::from((2, 0)),
+    ///     Point::from((0, 2)),
+    /// ]);
+    /// assert!(!bowtie.is_simple());
+    /// ```
+    ///
+    /// [Shamos–Hoey]: https://en.wikipedia.org/wiki/Multiple_line_segment_intersection#Shamos%E2%80%93Hoey_algorithm
+    pub fn is_simple(&self) -> bool {
+        let points = &self.0;
+        let n = points.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut edges = Vec::with_capacity(n);
+        for index in 0..n {
+            let (a, b) = (points[index], points[(index + 1) % n]);
+            let edge = match compare_points(a, b) {
+                std::cmp::Ordering::Less => SweepEdge {
+                    left: a,
+                    right: b,
+                    index,
+                },
+                std::cmp::Ordering::Greater => SweepEdge {
+                    left: b,
+                    right: a,
+                    index,
+                },
+                std::cmp::Ordering::Equal => return false,
+            };
+            edges.push(edge);
+        }
+
+        // Edges are inserted before any edges are removed at the same point so that edges touching at a point are
+        // checked against each other.
+        let mut events = Vec::with_capacity(2 * n);
+        for edge in &edges {
+            events.push((edge.left, false, edge.index));
+            events.push((edge.right, true, edge.index));
+        }
+        events.sort_by(|a, b| compare_points(a.0, b.0).then(a.1.cmp(&b.1)));
+
+        let intersect = |e1: &SweepEdge<T>, e2: &SweepEdge<T>| {
+            let (i, j) = (e1.index, e2.index);
+            if (i + 1) % n == j || (j + 1) % n == i {
+                // Consecutive edges share an endpoint, so they intersect elsewhere only if they overlap
+                let (i, j) = if (i + 1) % n == j { (i, j) } else { (j, i) };
+                let (a, b, c) = (points[i], points[j], points[(j + 1) % n]);
+                let dot = (b.x() - a.x()) * (c.x() - b.x()) + (b.y() - a.y()) * (c.y() - b.y());
+                Point::ordering([a, b, c]) == Ordering::Collinear
+                    && dot.get_sign() == Sign::Negative
+            } else {
+                segments_intersect(e1.left, e1.right, e2.left, e2.right)
+            }
+        };
+
+        let mut status = BTreeSet::new();
+        for (_, is_removal, index) in events {
+            let edge = edges[index];
+            if !is_removal {
+                let below = status.range(..edge).next_back();
+                let above = status.range(edge..).next();
+                if below.is_some_and(|below| intersect(below, &edge))
+                    || above.is_some_and(|above| intersect(above, &edge))
+                {
+                    return false;
+                }
+                status.insert(edge);
+            } else {
+                status.remove(&edge);
+                let below = status.range(..edge).next_back();
+                let above = status.range(edge..).next();
+                if let (Some(below), Some(above)) = (below, above) {
+                    if intersect(below, above) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Checks whether the given point lies inside the polygon, on its boundary, or outside of it.
+    ///
+    /// The polygon must be simple, but it may be defined in either orientation.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time. Use [`contains_convex`](Self::contains_convex) for faster queries on convex polygons.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Containment, Point, Polygon};
+    /// let polygon = Polygon::from(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((4, 0)),
+    ///     Point::from((4, 4)),
+    ///     Point::from((2, 1)),
+    ///     Point::from((0, 4)),
+    /// ]);
+    /// assert_eq!(polygon.contains((1, 1)), Containment::Inside);
+    /// assert_eq!(polygon.contains((2, 1)), Containment::OnBoundary);
+    /// assert_eq!(polygon.contains((2, 3)), Containment::Outside);
+    /// ```
+    pub fn contains<P: Into<Point<T>>>(&self, p: P) -> Containment {
+        let p = p.into();
+        let mut inside = false;
+        for Segment(a, b) in self.segments() {
+            let ordering = Point::ordering([a, b, p]);
+            if ordering == Ordering::Collinear && Segment(a, b).contains(p) {
+                return Containment::OnBoundary;
+            }
+
+            // Cast a ray from the point towards positive x and count the edges it crosses. Edges are treated as
+            // half-open in y so that vertices on the ray are counted exactly once.
+            let (ay, by, py) = (a.y(), b.y(), p.y());
+            if (ay > py) != (by > py) {
+                let crosses_right = if by > ay {
+                    ordering == Ordering::Counterclockwise
+                } else {
+                    ordering == Ordering::Clockwise
+                };
+                if crosses_right {
+                    inside = !inside;
+                }
+            }
+        }
+
+        if inside {
+            Containment::Inside
+        } else {
+            Containment::Outside
+        }
+    }
+
+    /// Checks whether the given point lies inside the convex polygon, on its boundary, or outside of it.
+    ///
+    /// The polygon must be convex and its vertices must be in counter-clockwise order, as returned by
+    /// [`convex_hull`](crate::convex_hull). Collinear vertices are allowed. The result is unspecified for other
+    /// polygons.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{convex_hull, Containment, Point};
+    /// let hull = convex_hull(vec![
+    ///     Point::from((0, 0)),
+    ///     Point::from((4, 0)),
+    ///     Point::from((1, 1)),
+    ///     Point::from((4, 4)),
+    ///     Point::from((0, 4)),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(hull.contains_convex((1, 1)), Containment::Inside);
+    /// assert_eq!(hull.contains_convex((4, 2)), Containment::OnBoundary);
+    /// assert_eq!(hull.contains_convex((5, 2)), Containment::Outside);
+    /// ```
+    pub fn contains_convex<P: Into<Point<T>>>(&self, p: P) -> Containment {
+        let p = p.into();
+        let on_boundary_if = |cond: bool| {
+            if cond {
+                Containment::OnBoundary
+            } else {
+                Containment::Outside
+            }
+        };
+
+        let (&first, rest) = match self.0.split_first() {
+            Some(split) => split,
+            None => return Containment::Outside,
+        };
+        match rest {
+            [] => return on_boundary_if(p == first),
+            [second] => return on_boundary_if(Segment(first, *second).contains(p)),
+            _ => {}
+        }
+        if p == first {
+            return Containment::OnBoundary;
+        }
+
+        let second = rest[0];
+        let last = rest[rest.len() - 1];
+        let first_ordering = Point::ordering([first, second, p]);
+        let last_ordering = Point::ordering([first, last, p]);
+        if first_ordering == Ordering::Clockwise || last_ordering == Ordering::Counterclockwise {
+            return Containment::Outside;
+        }
+
+        // The rays from the first vertex to the other vertices are sorted by angle, so we can binary search for the
+        // wedge containing the point.
+        if first_ordering == Ordering::Collinear {
+            // The point is on the ray through the first edge. There may be several collinear vertices on that ray, so
+            // find the furthest one.
+            let idx =
+                rest.partition_point(|&q| Point::ordering([first, q, p]) != Ordering::Clockwise);
+            return on_boundary_if(Segment(first, rest[idx - 1]).contains(p));
+        }
+        let idx =
+            rest.partition_point(|&q| Point::ordering([first, q, p]) == Ordering::Counterclockwise);
+        if last_ordering == Ordering::Collinear {
+            // The point is on the ray through the last edge, and `rest[idx]` is the furthest vertex on that ray.
+            return on_boundary_if(Segment(first, rest[idx]).contains(p));
+        }
+
+        match Point::ordering([rest[idx - 1], rest[idx], p]) {
+            Ordering::Counterclockwise => Containment::Inside,
+            Ordering::Collinear => Containment::OnBoundary,
+            Ordering::Clockwise => Containment::Outside,
+        }
+    }
+}
+
+/// Computes the intersection of two convex polygons.
+///
+/// Both polygons must be convex and their vertices must be in counter-clockwise order, as returned by
+/// [`convex_hull`](crate::convex_hull). The intersection is returned in counter-clockwise order.
+///
+/// Returns `None` if the intersection has no area, i.e. if the polygons don't overlap or only touch each other.
+///
+/// # Time complexity
+/// Takes O(nm) time, where n and m are the numbers of vertices of the polygons. This uses the
+/// [Sutherland–Hodgman] algorithm.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{convex_intersection, Point, Polygon};
+/// # use comlib_math::Quot;
+/// let square = |x, y| {
+///     Polygon::from(vec![
+///         Point::from((x, y)),
+///         Point::from((x + 2, y)),
+///         Point::from((x + 2, y + 2)),
+///         Point::from((x, y + 2)),
+///     ])
+/// };
+/// let intersection = convex_intersection(&square(0, 0), &square(1, 1)).unwrap();
+/// assert_eq!(intersection.area(), Quot::from(1));
+/// assert!(convex_intersection(&square(0, 0), &square(2, 0)).is_none());
+/// ```
+///
+/// [Sutherland–Hodgman]: https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm
+pub fn convex_intersection<T: ValidCoordinate>(
+    a: &Polygon<T>,
+    b: &Polygon<T>,
+) -> Option<Polygon<T>> {
+    let mut output = a.0.clone();
+    let mut previous_clip = *b.0.last()?;
+    for &clip in &b.0 {
+        let (c, d) = (previous_clip, clip);
+        previous_clip = clip;
+        if c == d || output.is_empty() {
+            continue;
+        }
+
+        // Keep the part of the polygon on the left side of the directed line c -> d
+        let input = std::mem::take(&mut output);
+        let mut p = *input.last().unwrap();
+        let mut p_ordering = Point::ordering([c, d, p]);
+        for &q in &input {
+            let q_ordering = Point::ordering([c, d, q]);
+            let crosses = matches!(
+                (p_ordering, q_ordering),
+                (Ordering::Clockwise, Ordering::Counterclockwise)
+                    | (Ordering::Counterclockwise, Ordering::Clockwise)
+            );
+            if crosses {
+                let crossing = Line::spanned_by(c, d)
+                    .intersect(Line::spanned_by(p, q))
+                    .unwrap_point();
+                output.push(crossing);
+            }
+            if q_ordering != Ordering::Clockwise {
+                output.push(q);
+            }
+            p = q;
+            p_ordering = q_ordering;
+        }
+    }
+
+    output.dedup();
+    while output.len() > 1 && output.first() == output.last() {
+        output.pop();
+    }
+    let intersection = Polygon(output);
+    if intersection.0.len() >= 3 && !intersection.area().is_zero() {
+        Some(intersection)
+    } else {
+        None
+    }
+}
+
+/// Computes the area of the intersection of two convex polygons.
+///
+/// Both polygons must be convex and their vertices must be in counter-clockwise order. For integral coordinates the
+/// area is computed exactly.
+///
+/// See [`convex_intersection`] for details.
+pub fn intersection_area<T: ValidCoordinate>(a: &Polygon<T>, b: &Polygon<T>) -> T::Coordinate {
+    convex_intersection(a, b).map_or(T::Coordinate::zero(), |intersection| intersection.area())
+}
+
+/// Compares points lexicographically by their coordinates.
+pub(crate) fn compare_points<T: ValidCoordinate>(a: Point<T>, b: Point<T>) -> std::cmp::Ordering {
+    a.x()
+        .partial_cmp(&b.x())
+        .expect("coordinates to be comparable")
+        .then(
+            a.y()
+                .partial_cmp(&b.y())
+                .expect("coordinates to be comparable"),
+        )
+}
+
+/// Checks whether the closed segments `a-b` and `c-d` have a common point.
+fn segments_intersect<T: ValidCoordinate>(
+    a: Point<T>,
+    b: Point<T>,
+    c: Point<T>,
+    d: Point<T>,
+) -> bool {
+    let o1 = Point::ordering([a, b, c]);
+    let o2 = Point::ordering([a, b, d]);
+    let o3 = Point::ordering([c, d, a]);
+    let o4 = Point::ordering([c, d, b]);
+    if o1 == Ordering::Collinear && o2 == Ordering::Collinear {
+        let (ab, cd) = (Segment(a, b), Segment(c, d));
+        ab.contains(c) || ab.contains(d) || cd.contains(a) || cd.contains(b)
+    } else {
+        (o1 == Ordering::Collinear || o1 != o2) && (o3 == Ordering::Collinear || o3 != o4)
+    }
+}
+
+/// Edge of a polygon in the sweep line status of [`Polygon::is_simple`].
+///
+/// The edges are ordered from bottom to top at the current position of the sweep line. The ordering is only
+/// meaningful for non-intersecting edges which are both intersected by the sweep line.
+#[derive(Clone, Copy)]
+struct SweepEdge<T: ValidCoordinate> {
+    left: Point<T>,
+    right: Point<T>,
+    index: usize,
+}
+
+impl<T: ValidCoordinate> PartialEq for SweepEdge<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T: ValidCoordinate> Eq for SweepEdge<T> {}
+
+impl<T: ValidCoordinate> PartialOrd for SweepEdge<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ValidCoordinate> Ord for SweepEdge<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.index == other.index {
+            return std::cmp::Ordering::Equal;
+        }
+
+        // Compare the edges at the left endpoint of the one that starts later
+        let (first, second, flip) =
+            if compare_points(self.left, other.left) != std::cmp::Ordering::Greater {
+                (self, other, false)
+            } else {
+                (other, self, true)
+            };
+        let mut ordering = Point::ordering([first.left, first.right, second.left]);
+        if ordering == Ordering::Collinear {
+            ordering = Point::ordering([first.left, first.right, second.right]);
+        }
+        let result = match ordering {
+            Ordering::Counterclockwise => std::cmp::Ordering::Less,
+            Ordering::Clockwise => std::cmp::Ordering::Greater,
+            Ordering::Collinear => first.index.cmp(&second.index),
+        };
+        if flip {
+            result.reverse()
+        } else {
+            result
+        }
+    }
+}
+
+impl<T: ValidCoordinate> Index<usize> for Polygon<T> {
+    type Output = Point<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
 }
 
 impl<T: ValidCoordinate> From<Vec<Point<T>>> for Polygon<T> {
@@ -30,6 +719,9 @@ impl<T: ValidCoordinate> From<Vec<Point<T>>> for Polygon<T> {
     }
 }
 
+/// Iterator over the edges of a [`Polygon`].
+///
+/// Use [`Polygon::segments`] to construct.
 pub struct PolygonSegmentIter<'a, T: ValidCoordinate>(Point<T>, &'a [Point<T>]);
 
 impl<'a, T: ValidCoordinate> Iterator for PolygonSegmentIter<'a, T> {
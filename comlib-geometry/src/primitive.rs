@@ -1,28 +1,64 @@
-use crate::ValidCoordinate;
-use comlib_math::{Float, Numeric, Sign, Signed};
-use std::fmt;
-
-#[derive(Clone, Copy, Eq, PartialOrd, Ord, Hash)]
+use crate::{Unit, ValidCoordinate};
+use comlib_math::{Float, Numeric, Sign};
+use std::{fmt, hash, num::NonZeroI128};
+
+/// Point in 2D plane represented by homogeneous coordinates.
+///
+/// The homogeneous coordinates `(x, y, z)` represent the point `(x/z, y/z)`. Points which are equal compare equal
+/// regardless of their representation, and they hash to the same value.
+///
+/// Points are ordered lexicographically by their actual coordinates.
+///
+/// Pairs of [`i64`] and [`f32`] coordinates convert into points. The other coordinate types have no such conversion, as
+/// it would make untyped literals ambiguous. Construct their points with [`Point::new`], or convert existing points with
+/// [`Point::to_i128`] and [`Point::to_f64`].
+#[derive(Clone, Copy, Eq)]
 pub struct Point<T: ValidCoordinate> {
+    /// Homogeneous x-coordinate.
     pub x: T,
+    /// Homogeneous y-coordinate.
     pub y: T,
+    /// Divisor of the homogeneous coordinates.
     pub z: T::Divisor,
 }
 
 impl<T: ValidCoordinate> Point<T> {
+    /// Constructs a new point from homogeneous coordinates.
     pub fn new(x: T, y: T, z: T::Divisor) -> Self {
         Self { x, y, z }.normalized()
     }
 
+    /// Constructs a new point from homogeneous coordinates.
+    ///
+    /// Returns `None` if `z` is zero.
+    ///
+    /// Both [`i64`] and [`i128`] are valid coordinate types, so integer literals need a type when nothing else determines
+    /// it:
+    /// ```
+    /// # use comlib_geometry::Point;
+    /// assert_eq!(Point::<i64>::try_new(2, 6, 2), Some(Point::from((1, 3))));
+    /// assert_eq!(Point::try_new(2, 6, 0i64), None);
+    /// ```
     pub fn try_new(x: T, y: T, z: T) -> Option<Self> {
         let ([x, y], z) = T::try_normalize([x, y], z)?;
         Some(Self { x, y, z })
     }
 
+    /// Constructs a point from its actual coordinates.
+    pub(crate) fn from_coordinates(x: T::Coordinate, y: T::Coordinate) -> Self {
+        let ([x, y], z) = T::from_coordinates([x, y]);
+        Self::new(x, y, z)
+    }
+
+    /// Normalizes the representation of the point.
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
 
+    /// Returns the point in normalized representation.
+    ///
+    /// For integral coordinates the normalized representation has a positive divisor and no common factor is shared
+    /// by all of the homogeneous coordinates.
     pub fn normalized(self) -> Self {
         let normalized = T::normalize_with_divisor(([self.x, self.y], self.z));
         Self {
@@ -32,41 +68,67 @@ impl<T: ValidCoordinate> Point<T> {
         }
     }
 
+    /// Converts the point into a pair of `f32` coordinates.
     pub fn to_f32_pair(self) -> (f32, f32) {
         (self.x().as_f32(), self.y().as_f32())
     }
 
+    /// Converts the point into a pair of `f64` coordinates.
     pub fn to_f64_pair(self) -> (f64, f64) {
         (self.x().as_f64(), self.y().as_f64())
     }
 
+    /// Converts the point into a point with `f32` coordinates.
     pub fn to_f32(self) -> Point<f32> {
         let (x, y) = self.to_f32_pair();
         Point::from((x, y))
     }
 
-    // pub fn to_f64(self) -> Point<f64> {
-    //     let (x, y) = self.to_f64_pair();
-    //     Point::from((x, y))
-    // }
+    /// Converts the point into a point with `f64` coordinates.
+    pub fn to_f64(self) -> Point<f64> {
+        let (x, y) = self.to_f64_pair();
+        Point::new(x, y, Unit)
+    }
 
+    /// Gets the x-coordinate of the point.
     pub fn x(self) -> T::Coordinate {
         T::to_coordinates(([self.x], self.z))[0]
     }
 
+    /// Gets the y-coordinate of the point.
     pub fn y(self) -> T::Coordinate {
         T::to_coordinates(([self.y], self.z))[0]
     }
 
+    /// Computes the mirror image of the point with respect to the given center point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Point;
+    /// assert_eq!(Point::from((1, 2)).reflect_across((2, 0)), Point::from((3, -2)));
+    /// ```
+    pub fn reflect_across<P: Into<Self>>(self, center: P) -> Self {
+        let center = center.into();
+        let two = T::Coordinate::from_int(2);
+        Self::from_coordinates(center.x() * two - self.x(), center.y() * two - self.y())
+    }
+
+    /// Computes the orientation of the given three points.
+    ///
+    /// The orientation tells which way the path `p0 -> p1 -> p2` turns at `p1`.
+    ///
+    /// For integral coordinate types the result is exact: the cross product is computed using the wide integer type of
+    /// the coordinate type, such as `i128` for `i64`. Points with integral `i64` coordinates of absolute value below
+    /// `2^62` are always handled, as are rational points whose scaled differences fit in the wide type. For
+    /// floating-point types, values within the default [`Eps`](comlib_math::Eps) are considered equal.
+    ///
+    /// # Panics
+    /// Panics if the intermediate values overflow even the wide integer type. The result is never silently wrong.
     pub fn ordering<P: Into<Self>>(points: [P; 3]) -> Ordering {
         let [p0, p1, p2] = points;
         let points = [p0.into(), p1.into(), p2.into()];
         // Compute the sign of the signed area of the parallelogram spanned by the points
-        let dx1 = points[1].x() - points[0].x();
-        let dy1 = points[1].y() - points[0].y();
-        let dx2 = points[2].x() - points[0].x();
-        let dy2 = points[2].y() - points[0].y();
-        match (dx1 * dy2 - dx2 * dy1).get_sign() {
+        match T::orientation(points.map(|p| ([p.x, p.y], p.z))) {
             Sign::Negative => Ordering::Clockwise,
             Sign::Neutral => Ordering::Collinear,
             Sign::Positive => Ordering::Counterclockwise,
@@ -74,10 +136,14 @@ impl<T: ValidCoordinate> Point<T> {
     }
 }
 
+/// Orientation of three points.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Ordering {
+    /// The points turn counter-clockwise, i.e. to the left.
     Counterclockwise,
+    /// The points lie on the same line.
     Collinear,
+    /// The points turn clockwise, i.e. to the right.
     Clockwise,
 }
 
@@ -88,6 +154,13 @@ pub enum Ordering {
 //         Self { x, y, z }
 //     }
 // }
+impl Point<i64> {
+    /// Converts the point into a point with `i128` coordinates.
+    pub fn to_i128(self) -> Point<i128> {
+        Point::new(self.x.into(), self.y.into(), NonZeroI128::from(self.z))
+    }
+}
+
 impl From<(i64, i64)> for Point<i64> {
     fn from((x, y): (i64, i64)) -> Self {
         let ([x, y], z) = i64::from_coordinates([x.into(), y.into()]);
@@ -97,7 +170,7 @@ impl From<(i64, i64)> for Point<i64> {
 
 impl From<(f32, f32)> for Point<f32> {
     fn from((x, y): (f32, f32)) -> Self {
-        let ([x, y], z) = f32::from_coordinates([x.into(), y.into()]);
+        let ([x, y], z) = f32::from_coordinates([x, y]);
         Self { x, y, z }
     }
 }
@@ -121,15 +194,57 @@ impl<T: ValidCoordinate> PartialEq for Point<T> {
     }
 }
 
+impl<T: ValidCoordinate> hash::Hash for Point<T>
+where
+    T: hash::Hash,
+    T::Divisor: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // Equal points have the same normalized representation
+        let normalized = self.normalized();
+        normalized.x.hash(state);
+        normalized.y.hash(state);
+        normalized.z.hash(state);
+    }
+}
+
+impl<T: ValidCoordinate> PartialOrd for Point<T>
+where
+    T::Coordinate: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match self.x().partial_cmp(&other.x())? {
+            std::cmp::Ordering::Equal => self.y().partial_cmp(&other.y()),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<T: ValidCoordinate> Ord for Point<T>
+where
+    Self: Eq,
+    T::Coordinate: Ord,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.x().cmp(&other.x()).then(self.y().cmp(&other.y()))
+    }
+}
+
 /// Represents the set of points (x, y) which satisfy ax + by + c = 0.
-#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
+///
+/// Lines are equal if their coefficients are proportional to each other. Equal lines hash to the same value.
+#[derive(Debug, Clone, Copy, Eq)]
 pub struct Line<T: ValidCoordinate> {
+    /// Coefficient of x.
     pub a: T,
+    /// Coefficient of y.
     pub b: T,
+    /// Constant term.
     pub c: T,
 }
 
 impl<T: ValidCoordinate> Line<T> {
+    /// Constructs the line passing through the given two points.
     pub fn spanned_by<P1: Into<Point<T>>, P2: Into<Point<T>>>(p1: P1, p2: P2) -> Self {
         let p1 = p1.into();
         let p2 = p2.into();
@@ -143,20 +258,109 @@ impl<T: ValidCoordinate> Line<T> {
         Self { a, b, c }.normalized()
     }
 
+    /// Normalizes the representation of the line.
     pub fn normalize(&mut self) {
         *self = self.normalized();
     }
 
+    /// Returns the line in normalized representation.
+    ///
+    /// For integral coefficients the first non-zero coefficient of the normalized representation is positive and the
+    /// coefficients share no common factor.
     pub fn normalized(self) -> Self {
         let [a, b, c] = T::normalize([self.a, self.b, self.c]);
         Self { a, b, c }
     }
 
+    /// Constructs the line parallel to this one passing through the given point.
+    pub fn parallel_through<P: Into<Point<T>>>(self, p: P) -> Self {
+        let p = p.into();
+        let p_z = p.z.into();
+        Self {
+            a: self.a * p_z,
+            b: self.b * p_z,
+            c: -(self.a * p.x + self.b * p.y),
+        }
+        .normalized()
+    }
+
+    /// Constructs the line perpendicular to this one passing through the given point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Line;
+    /// let line = Line::spanned_by((0, 0), (1, 2));
+    /// assert_eq!(line.perpendicular_through((1, 2)), Line::spanned_by((1, 2), (3, 1)));
+    /// ```
+    pub fn perpendicular_through<P: Into<Point<T>>>(self, p: P) -> Self {
+        let p = p.into();
+        let p_z = p.z.into();
+        Self {
+            a: self.b * p_z,
+            b: -self.a * p_z,
+            c: self.a * p.y - self.b * p.x,
+        }
+        .normalized()
+    }
+
+    /// Computes the slope of the line.
+    ///
+    /// Returns `None` if the line is vertical.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Line;
+    /// # use comlib_math::Quot;
+    /// assert_eq!(Line::spanned_by((1, 1), (3, 2)).slope(), Some(Quot::new(1, 2).unwrap()));
+    /// assert_eq!(Line::spanned_by((1, 1), (1, 2)).slope(), None);
+    /// ```
+    pub fn slope(self) -> Option<T::Coordinate> {
+        T::try_divide(-self.a, self.b)
+    }
+
+    /// Computes the x-coordinate of the point where the line crosses the x-axis.
+    ///
+    /// Returns `None` if the line is horizontal.
+    pub fn x_intercept(self) -> Option<T::Coordinate> {
+        T::try_divide(-self.c, self.a)
+    }
+
+    /// Computes the y-coordinate of the point where the line crosses the y-axis.
+    ///
+    /// Returns `None` if the line is vertical.
+    pub fn y_intercept(self) -> Option<T::Coordinate> {
+        T::try_divide(-self.c, self.b)
+    }
+
+    /// Computes the y-coordinate of the point on the line with the given x-coordinate.
+    ///
+    /// Returns `None` if the line is vertical.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Line;
+    /// # use comlib_math::Quot;
+    /// let line = Line::spanned_by((1, 1), (3, 2));
+    /// assert_eq!(line.y_at(Quot::from(4)), Some(Quot::new(5, 2).unwrap()));
+    /// ```
+    pub fn y_at(self, x: T::Coordinate) -> Option<T::Coordinate> {
+        Some(-(T::try_divide(self.a, self.b)? * x + T::try_divide(self.c, self.b)?))
+    }
+
+    /// Computes the x-coordinate of the point on the line with the given y-coordinate.
+    ///
+    /// Returns `None` if the line is horizontal.
+    pub fn x_at(self, y: T::Coordinate) -> Option<T::Coordinate> {
+        Some(-(T::try_divide(self.b, self.a)? * y + T::try_divide(self.c, self.a)?))
+    }
+
+    /// Checks whether the given point lies on the line.
     pub fn contains<P: Into<Point<T>>>(self, p: P) -> bool {
         let p = p.into();
         (self.a * p.x + self.b * p.y + self.c * p.z.into()).is_zero()
     }
 
+    /// Computes the intersection of two lines.
     pub fn intersect(self, other: Self) -> LineIntersection<T> {
         let (x, y, z) = (
             other.c * self.b - self.c * other.b,
@@ -172,32 +376,101 @@ impl<T: ValidCoordinate> Line<T> {
         }
     }
 
+    /// Computes the point on the line closest to the given point.
+    ///
+    /// This is the same as [`project`](Self::project).
     pub fn closest_point_to<P: Into<Point<T>>>(self, p: P) -> Point<T> {
+        self.project(p)
+    }
+
+    /// Computes the orthogonal projection of the given point onto the line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Line, Point};
+    /// let line = Line::spanned_by((0, 0), (2, 1));
+    /// assert_eq!(line.project((0, 5)), Point::from((2, 1)));
+    /// ```
+    pub fn project<P: Into<Point<T>>>(self, p: P) -> Point<T> {
         let p = p.into();
-        let x = self.b * (self.b * p.x - self.a * p.y) - self.a * self.c;
-        let y = self.a * (self.a * p.y - self.b * p.x) - self.b * self.c;
-        let z = (self.a * self.a + self.b * self.b) * p.z.into();
+        let p_z = p.z.into();
+        let x = self.b * (self.b * p.x - self.a * p.y) - self.a * self.c * p_z;
+        let y = self.a * (self.a * p.y - self.b * p.x) - self.b * self.c * p_z;
+        let z = (self.a * self.a + self.b * self.b) * p_z;
 
         let ([x, y], z) = T::try_normalize([x, y], z).expect("z should be normalizable");
 
         Point { x, y, z }
     }
+
+    /// Computes the mirror image of the given point with respect to the line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Line, Point};
+    /// let line = Line::spanned_by((0, 0), (1, 1));
+    /// assert_eq!(line.reflect((3, 1)), Point::from((1, 3)));
+    /// ```
+    pub fn reflect<P: Into<Point<T>>>(self, p: P) -> Point<T> {
+        let p = p.into();
+        p.reflect_across(self.project(p))
+    }
 }
 
 impl<T: ValidCoordinate> PartialEq for Line<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.a * other.c == other.a * self.c && self.b * other.c == other.b * self.c
+        // The coefficients are proportional if all of the 2x2 minors vanish
+        self.a * other.b == other.a * self.b
+            && self.a * other.c == other.a * self.c
+            && self.b * other.c == other.b * self.c
+    }
+}
+
+impl<T: ValidCoordinate> hash::Hash for Line<T>
+where
+    T: hash::Hash,
+{
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        // Equal lines have the same normalized representation
+        let normalized = self.normalized();
+        normalized.a.hash(state);
+        normalized.b.hash(state);
+        normalized.c.hash(state);
+    }
+}
+
+impl<T: ValidCoordinate> PartialOrd for Line<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let lhs = self.normalized();
+        let rhs = other.normalized();
+        (lhs.a, lhs.b, lhs.c).partial_cmp(&(rhs.a, rhs.b, rhs.c))
+    }
+}
+
+impl<T: ValidCoordinate + Ord> Ord for Line<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let lhs = self.normalized();
+        let rhs = other.normalized();
+        (lhs.a, lhs.b, lhs.c).cmp(&(rhs.a, rhs.b, rhs.c))
     }
 }
 
+/// Intersection of two lines.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineIntersection<T: ValidCoordinate> {
+    /// The lines are parallel and don't intersect.
     None,
+    /// The lines intersect at a single point.
     Point(Point<T>),
+    /// The lines are the same line.
     Line(Line<T>),
 }
 
 impl<T: ValidCoordinate> LineIntersection<T> {
+    /// Asserts that the lines don't intersect.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not `None`.
     pub fn unwrap_none(self) {
         match self {
             Self::None => {}
@@ -206,6 +479,10 @@ impl<T: ValidCoordinate> LineIntersection<T> {
         }
     }
 
+    /// Returns the intersection point.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not a point.
     pub fn unwrap_point(self) -> Point<T> {
         match self {
             Self::None => panic!("expected Point but was None"),
@@ -214,6 +491,10 @@ impl<T: ValidCoordinate> LineIntersection<T> {
         }
     }
 
+    /// Returns the intersection line.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not a line.
     pub fn unwrap_line(self) -> Line<T> {
         match self {
             Self::None => panic!("expected Line but was None"),
@@ -223,12 +504,17 @@ impl<T: ValidCoordinate> LineIntersection<T> {
     }
 }
 
+/// Line segment between two points.
+///
+/// Segments are equal if they have the same endpoints, regardless of their direction.
 // TODO: Eq, Ord
 #[derive(Debug, Clone, Copy)]
-// TODO: Decide whether they should be pub or not
-pub struct Segment<T: ValidCoordinate>(pub Point<T>, pub Point<T>);
+pub struct Segment<T: ValidCoordinate>(pub(crate) Point<T>, pub(crate) Point<T>);
 
 impl<T: ValidCoordinate> Segment<T> {
+    /// Constructs the segment between the given points.
+    ///
+    /// Returns `None` if the points are equal.
     pub fn between<P1: Into<Point<T>>, P2: Into<Point<T>>>(p1: P1, p2: P2) -> Option<Self> {
         let p1 = p1.into();
         let p2 = p2.into();
@@ -239,10 +525,59 @@ impl<T: ValidCoordinate> Segment<T> {
         }
     }
 
+    /// Returns the start point of the segment.
+    pub fn start(self) -> Point<T> {
+        self.0
+    }
+
+    /// Returns the end point of the segment.
+    pub fn end(self) -> Point<T> {
+        self.1
+    }
+
+    /// Computes the midpoint of the segment.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Segment};
+    /// let segment = Segment::between((0, 1), (3, 2)).unwrap();
+    /// assert_eq!(segment.midpoint(), Point::try_new(3, 3, 2).unwrap());
+    /// ```
+    pub fn midpoint(self) -> Point<T> {
+        let two = T::Coordinate::from_int(2);
+        Point::from_coordinates(
+            (self.0.x() + self.1.x()) / two,
+            (self.0.y() + self.1.y()) / two,
+        )
+    }
+
+    /// Checks whether the given point lies on the segment, including its endpoints.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Segment};
+    /// let segment = Segment::between((0, 0), (4, 2)).unwrap();
+    /// assert!(segment.contains((2, 1)));
+    /// assert!(segment.contains((4, 2)));
+    /// assert!(!segment.contains((6, 3)));
+    /// assert!(segment.contains(Point::try_new(2, 1, 4).unwrap()));
+    /// ```
+    pub fn contains<P: Into<Point<T>>>(self, p: P) -> bool {
+        let p = p.into();
+        let between = |a: T::Coordinate, b: T::Coordinate, x: T::Coordinate| {
+            (a <= x && x <= b) || (b <= x && x <= a)
+        };
+        Point::ordering([self.0, self.1, p]) == Ordering::Collinear
+            && between(self.0.x(), self.1.x(), p.x())
+            && between(self.0.y(), self.1.y(), p.y())
+    }
+
+    /// Returns the line on which the segment lies.
     pub fn to_line(self) -> Line<T> {
         Line::spanned_by(self.0, self.1)
     }
 
+    /// Computes the intersection of two segments.
     pub fn intersect(self, other: Self) -> SegmentIntersection<T> {
         fn order_points_by<T: ValidCoordinate, R: PartialOrd, F: Fn(Point<T>) -> R>(
             p1: Point<T>,
@@ -336,12 +671,59 @@ impl<T: ValidCoordinate> Segment<T> {
         }
     }
 
+    /// Computes the intersection of two segments as parameters along both of them.
+    ///
+    /// Returns `(t, u)` such that the intersection point is `self.0 + t (self.1 - self.0)` and
+    /// `other.0 + u (other.1 - other.0)`, where both `t` and `u` are in `[0, 1]`. If the segments overlap, the point of
+    /// the overlap closest to `self.0` is used, i.e. `t` is the first parameter at which `self` touches `other`. Returns
+    /// `None` if the segments don't intersect.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Segment;
+    /// # use comlib_math::Quot;
+    /// let s1 = Segment::between((0, 0), (4, 0)).unwrap();
+    /// let s2 = Segment::between((1, -1), (1, 2)).unwrap();
+    /// assert_eq!(
+    ///     s1.intersect_param(s2),
+    ///     Some((Quot::new(1, 4).unwrap(), Quot::new(1, 3).unwrap()))
+    /// );
+    /// let s3 = Segment::between((6, 0), (2, 0)).unwrap();
+    /// assert_eq!(
+    ///     s1.intersect_param(s3),
+    ///     Some((Quot::new(1, 2).unwrap(), Quot::from(1)))
+    /// );
+    /// ```
+    pub fn intersect_param(self, other: Self) -> Option<(T::Coordinate, T::Coordinate)> {
+        let p = match self.intersect(other) {
+            SegmentIntersection::None => return None,
+            SegmentIntersection::Point(p) => p,
+            SegmentIntersection::Segment(Segment(p, q)) => {
+                if self.parameter_of(p) < self.parameter_of(q) {
+                    p
+                } else {
+                    q
+                }
+            }
+        };
+        Some((self.parameter_of(p), other.parameter_of(p)))
+    }
+
+    /// Computes the parameter `t` such that the given point on the segment is `self.0 + t (self.1 - self.0)`.
+    fn parameter_of(self, p: Point<T>) -> T::Coordinate {
+        let dx = self.1.x() - self.0.x();
+        let dy = self.1.y() - self.0.y();
+        ((p.x() - self.0.x()) * dx + (p.y() - self.0.y()) * dy) / self.sq_len()
+    }
+
+    /// Computes the squared length of the segment.
     pub fn sq_len(self) -> T::Coordinate {
         let dx = self.1.x() - self.0.x();
         let dy = self.1.y() - self.0.y();
         dx * dx + dy * dy
     }
 
+    /// Computes the length of the segment.
     pub fn len(self) -> T::Coordinate
     where
         T::Coordinate: Float,
@@ -356,14 +738,22 @@ impl<T: ValidCoordinate> PartialEq for Segment<T> {
     }
 }
 
+/// Intersection of two segments.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SegmentIntersection<T: ValidCoordinate> {
+    /// The segments don't intersect.
     None,
+    /// The segments intersect at a single point.
     Point(Point<T>),
+    /// The segments overlap on a segment.
     Segment(Segment<T>),
 }
 
 impl<T: ValidCoordinate> SegmentIntersection<T> {
+    /// Asserts that the segments don't intersect.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not `None`.
     pub fn unwrap_none(self) {
         match self {
             Self::None => {}
@@ -372,6 +762,10 @@ impl<T: ValidCoordinate> SegmentIntersection<T> {
         }
     }
 
+    /// Returns the intersection point.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not a point.
     pub fn unwrap_point(self) -> Point<T> {
         match self {
             Self::None => panic!("expected Point but was None"),
@@ -380,6 +774,10 @@ impl<T: ValidCoordinate> SegmentIntersection<T> {
         }
     }
 
+    /// Returns the intersection segment.
+    ///
+    /// # Panics
+    /// Panics if the intersection is not a segment.
     pub fn unwrap_line(self) -> Segment<T> {
         match self {
             Self::None => panic!("expected Segment but was None"),
@@ -0,0 +1,118 @@
+use crate::{Point, ValidCoordinate};
+use std::cmp::Ordering;
+
+/// Static structure counting the points inside axis-aligned rectangles.
+///
+/// The structure is a merge sort tree: a segment tree over the points sorted by their x-coordinates, where each node
+/// stores the sorted y-coordinates of the points in its range.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{Point, RectangleCounter};
+/// # use comlib_math::Quot;
+/// let points: Vec<Point<i64>> = vec![(0, 0), (1, 2), (2, 1), (3, 3), (1, 1)]
+///     .into_iter()
+///     .map(Point::from)
+///     .collect();
+/// let counter = RectangleCounter::new(&points);
+/// let q = |x: i64| Quot::from(x);
+/// assert_eq!(counter.count(q(1), q(2), q(1), q(2)), 3);
+/// assert_eq!(counter.count(q(0), q(3), q(0), q(3)), 5);
+/// assert_eq!(counter.count(q(4), q(5), q(0), q(3)), 0);
+/// ```
+pub struct RectangleCounter<T: ValidCoordinate> {
+    /// The x-coordinates of the points in sorted order.
+    xs: Vec<T::Coordinate>,
+    /// Sorted y-coordinates in each node of the segment tree. Node `i` has children `2i` and `2i + 1`, and the leaves
+    /// start at `xs.len()`.
+    tree: Vec<Vec<T::Coordinate>>,
+}
+
+impl<T: ValidCoordinate> RectangleCounter<T> {
+    /// Builds the structure over the given points.
+    ///
+    /// # Time complexity
+    /// Takes O(n log n) time and space.
+    pub fn new(points: &[Point<T>]) -> Self {
+        let mut coordinates: Vec<_> = points.iter().map(|p| (p.x(), p.y())).collect();
+        coordinates.sort_by(|a, b| compare(a.0, b.0));
+
+        let n = coordinates.len();
+        let mut tree = vec![Vec::new(); 2 * n];
+        for (i, &(_, y)) in coordinates.iter().enumerate() {
+            tree[n + i].push(y);
+        }
+        for i in (1..n).rev() {
+            let (left, right) = (&tree[2 * i], &tree[2 * i + 1]);
+            let mut merged = Vec::with_capacity(left.len() + right.len());
+            let (mut l, mut r) = (0, 0);
+            while l < left.len() && r < right.len() {
+                if compare(left[l], right[r]) != Ordering::Greater {
+                    merged.push(left[l]);
+                    l += 1;
+                } else {
+                    merged.push(right[r]);
+                    r += 1;
+                }
+            }
+            merged.extend_from_slice(&left[l..]);
+            merged.extend_from_slice(&right[r..]);
+            tree[i] = merged;
+        }
+
+        Self {
+            xs: coordinates.into_iter().map(|(x, _)| x).collect(),
+            tree,
+        }
+    }
+
+    /// Returns the number of points in the structure.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Checks whether the structure contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Counts the points in the rectangle `[x1, x2] × [y1, y2]`, boundary included.
+    ///
+    /// # Time complexity
+    /// Takes O(log² n) time.
+    pub fn count(
+        &self,
+        x1: T::Coordinate,
+        x2: T::Coordinate,
+        y1: T::Coordinate,
+        y2: T::Coordinate,
+    ) -> usize {
+        let n = self.xs.len();
+        let mut l = self.xs.partition_point(|&x| x < x1) + n;
+        let mut r = self.xs.partition_point(|&x| x <= x2) + n;
+
+        let count_in = |ys: &[T::Coordinate]| {
+            let lo = ys.partition_point(|&y| y < y1);
+            let hi = ys.partition_point(|&y| y <= y2);
+            hi.saturating_sub(lo)
+        };
+        let mut count = 0;
+        while l < r {
+            if l % 2 == 1 {
+                count += count_in(&self.tree[l]);
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                count += count_in(&self.tree[r]);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        count
+    }
+}
+
+fn compare<C: PartialOrd>(a: C, b: C) -> Ordering {
+    a.partial_cmp(&b).expect("coordinates to be comparable")
+}
@@ -0,0 +1,321 @@
+use crate::polygon::compare_points;
+use crate::{Point, Segment, SegmentIntersection, ValidCoordinate};
+use comlib_math::Numeric;
+use std::cell::Cell;
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+/// Finds all points at which at least two of the given segments intersect.
+///
+/// Returns the intersection points in lexicographic order, each together with the sorted indices of all segments
+/// passing through it. If segments overlap, only the endpoints and crossing points within the overlap are reported.
+///
+/// The coordinates should be exact, as the algorithm relies on exact comparisons.
+///
+/// # Time complexity
+/// Takes O((n + k) log n) time, where k is the total size of the output. This uses the [Bentley–Ottmann] algorithm.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{segment_intersections, Point, Segment};
+/// let segments = [
+///     Segment::between((0, 0), (4, 4)).unwrap(),
+///     Segment::between((0, 4), (4, 0)).unwrap(),
+///     Segment::between((2, 0), (2, 1)).unwrap(),
+///     Segment::between((1, 1), (0, 2)).unwrap(),
+/// ];
+/// assert_eq!(
+///     segment_intersections(&segments),
+///     vec![(Point::from((1, 1)), vec![0, 3]), (Point::from((2, 2)), vec![0, 1])],
+/// );
+/// ```
+///
+/// [Bentley–Ottmann]: https://en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
+pub fn segment_intersections<T: ValidCoordinate>(
+    segments: &[Segment<T>],
+) -> Vec<(Point<T>, Vec<usize>)> {
+    let mut intersections = Vec::new();
+    sweep(segments, |p, indices| {
+        intersections.push((p, indices));
+        true
+    });
+    intersections
+}
+
+/// Finds a pair of intersecting segments.
+///
+/// Returns the indices of two segments which share a common point, or `None` if all of the segments are disjoint.
+///
+/// The coordinates should be exact, as the algorithm relies on exact comparisons.
+///
+/// # Time complexity
+/// Takes O(n log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_geometry::{any_segment_intersection, Segment};
+/// let mut segments = vec![
+///     Segment::between((0, 0), (4, 4)).unwrap(),
+///     Segment::between((0, 4), (1, 3)).unwrap(),
+/// ];
+/// assert_eq!(any_segment_intersection(&segments), None);
+/// segments.push(Segment::between((1, 3), (2, 4)).unwrap());
+/// assert_eq!(any_segment_intersection(&segments), Some((1, 2)));
+/// ```
+pub fn any_segment_intersection<T: ValidCoordinate>(
+    segments: &[Segment<T>],
+) -> Option<(usize, usize)> {
+    let mut result = None;
+    sweep(segments, |_, indices| {
+        result = Some((indices[0], indices[1]));
+        false
+    });
+    result
+}
+
+/// Which side of the current event point is used to order the segments passing through it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Before,
+    After,
+}
+
+/// State of the sweep shared by all keys in the sweep line status.
+struct SweepState<T: ValidCoordinate> {
+    /// The segments with their endpoints in lexicographic order.
+    segments: Vec<(Point<T>, Point<T>)>,
+    /// The current event point.
+    point: Cell<Point<T>>,
+    side: Cell<Side>,
+}
+
+impl<T: ValidCoordinate> SweepState<T> {
+    /// Computes the y-coordinate of the segment at the x-coordinate of the current event point.
+    ///
+    /// Vertical segments are considered to be at the event point.
+    fn y_at_point(&self, index: usize) -> T::Coordinate {
+        let (a, b) = self.segments[index];
+        let p = self.point.get();
+        let dx = b.x() - a.x();
+        if dx.is_zero() {
+            p.y()
+        } else {
+            a.y() + (b.y() - a.y()) * (p.x() - a.x()) / dx
+        }
+    }
+
+    /// Compares two segments passing through the sweep line.
+    fn compare(&self, i: usize, j: usize) -> CmpOrdering {
+        if i == j {
+            return CmpOrdering::Equal;
+        }
+
+        let ordering = self
+            .y_at_point(i)
+            .partial_cmp(&self.y_at_point(j))
+            .expect("coordinates to be comparable");
+        if ordering != CmpOrdering::Equal {
+            return ordering;
+        }
+
+        // The segments meet at the sweep line, so compare their slopes. Right after the event point steeper segments
+        // are higher, and right before it they are lower.
+        let (a1, b1) = self.segments[i];
+        let (a2, b2) = self.segments[j];
+        let slope = (b1.y() - a1.y()) * (b2.x() - a2.x()) - (b2.y() - a2.y()) * (b1.x() - a1.x());
+        let slope = slope
+            .partial_cmp(&T::Coordinate::zero())
+            .expect("coordinates to be comparable");
+        let slope = match self.side.get() {
+            Side::Before => slope.reverse(),
+            Side::After => slope,
+        };
+        slope.then(i.cmp(&j))
+    }
+}
+
+/// Key in the sweep line status.
+///
+/// Besides segments, the keys can be used to find the segments passing through the current event point: the
+/// [`Below`](SweepKeyKind::Below) key is less than all of them and the [`Above`](SweepKeyKind::Above) key is greater.
+struct SweepKey<T: ValidCoordinate> {
+    state: Rc<SweepState<T>>,
+    kind: SweepKeyKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SweepKeyKind {
+    Below,
+    Segment(usize),
+    Above,
+}
+
+impl<T: ValidCoordinate> SweepKey<T> {
+    fn new(state: &Rc<SweepState<T>>, kind: SweepKeyKind) -> Self {
+        Self {
+            state: Rc::clone(state),
+            kind,
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self.kind {
+            SweepKeyKind::Segment(index) => index,
+            _ => unreachable!("only segments are stored in the status"),
+        }
+    }
+}
+
+impl<T: ValidCoordinate> PartialEq for SweepKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl<T: ValidCoordinate> Eq for SweepKey<T> {}
+
+impl<T: ValidCoordinate> PartialOrd for SweepKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ValidCoordinate> Ord for SweepKey<T> {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        use SweepKeyKind::*;
+        let state = &self.state;
+        let compare_to_point = |index: usize| {
+            state
+                .y_at_point(index)
+                .partial_cmp(&state.point.get().y())
+                .expect("coordinates to be comparable")
+        };
+        match (self.kind, other.kind) {
+            (Segment(i), Segment(j)) => state.compare(i, j),
+            (Segment(i), Below) => compare_to_point(i).then(CmpOrdering::Greater),
+            (Segment(i), Above) => compare_to_point(i).then(CmpOrdering::Less),
+            (Below, Segment(_)) | (Above, Segment(_)) => other.cmp(self).reverse(),
+            (Below, Below) | (Above, Above) => CmpOrdering::Equal,
+            (Below, Above) => CmpOrdering::Less,
+            (Above, Below) => CmpOrdering::Greater,
+        }
+    }
+}
+
+/// Point in the event queue, ordered lexicographically.
+struct EventPoint<T: ValidCoordinate>(Point<T>);
+
+impl<T: ValidCoordinate> PartialEq for EventPoint<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl<T: ValidCoordinate> Eq for EventPoint<T> {}
+
+impl<T: ValidCoordinate> PartialOrd for EventPoint<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: ValidCoordinate> Ord for EventPoint<T> {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        compare_points(self.0, other.0)
+    }
+}
+
+/// Runs the Bentley–Ottmann sweep over the segments.
+///
+/// Calls `report` for each point through which at least two segments pass, with the sorted indices of those segments.
+/// The sweep stops if `report` returns `false`.
+fn sweep<T, F>(segments: &[Segment<T>], mut report: F)
+where
+    T: ValidCoordinate,
+    F: FnMut(Point<T>, Vec<usize>) -> bool,
+{
+    if segments.is_empty() {
+        return;
+    }
+
+    let ordered: Vec<_> = segments
+        .iter()
+        .map(|&Segment(a, b)| {
+            if compare_points(a, b) == CmpOrdering::Greater {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+        .collect();
+
+    // Each event stores the segments starting at it
+    let mut events: BTreeMap<EventPoint<T>, Vec<usize>> = BTreeMap::new();
+    for (index, &(a, b)) in ordered.iter().enumerate() {
+        events.entry(EventPoint(a)).or_default().push(index);
+        events.entry(EventPoint(b)).or_default();
+    }
+
+    let state = Rc::new(SweepState {
+        point: Cell::new(ordered[0].0),
+        side: Cell::new(Side::Before),
+        segments: ordered,
+    });
+    let key = |kind| SweepKey::new(&state, kind);
+    let below = key(SweepKeyKind::Below);
+    let above = key(SweepKeyKind::Above);
+
+    // The order of the keys depends on the shared sweep state, but it is kept consistent with the order of the set
+    #[allow(clippy::mutable_key_type)]
+    let mut status = BTreeSet::new();
+    while let Some((EventPoint(p), starting)) = events.pop_first() {
+        state.point.set(p);
+        state.side.set(Side::Before);
+
+        // Find the segments passing through the event point
+        let through: Vec<usize> = status.range(&below..&above).map(SweepKey::index).collect();
+        let (ending, containing): (Vec<usize>, Vec<usize>) = through
+            .iter()
+            .partition(|&&index| state.segments[index].1 == p);
+
+        if starting.len() + through.len() > 1 {
+            let mut indices: Vec<usize> = starting.iter().chain(&through).copied().collect();
+            indices.sort_unstable();
+            if !report(p, indices) {
+                return;
+            }
+        }
+
+        // Reorder the segments passing through the event point to the order after it
+        for &index in ending.iter().chain(&containing) {
+            status.remove(&key(SweepKeyKind::Segment(index)));
+        }
+        state.side.set(Side::After);
+        for &index in starting.iter().chain(&containing) {
+            status.insert(key(SweepKeyKind::Segment(index)));
+        }
+
+        let mut find_event = |lower: Option<&SweepKey<T>>, upper: Option<&SweepKey<T>>| {
+            if let (Some(lower), Some(upper)) = (lower, upper) {
+                let (a1, b1) = state.segments[lower.index()];
+                let (a2, b2) = state.segments[upper.index()];
+                if let SegmentIntersection::Point(q) = Segment(a1, b1).intersect(Segment(a2, b2)) {
+                    if compare_points(q, p) == CmpOrdering::Greater {
+                        events.entry(EventPoint(q)).or_default();
+                    }
+                }
+            }
+        };
+        let lower_neighbor = status.range(..&below).next_back();
+        let upper_neighbor = status.range(&above..).next();
+        let mut new = status.range(&below..&above);
+        match (new.next(), new.next_back()) {
+            (None, _) => find_event(lower_neighbor, upper_neighbor),
+            (Some(lowest), highest) => {
+                find_event(lower_neighbor, Some(lowest));
+                find_event(Some(highest.unwrap_or(lowest)), upper_neighbor);
+            }
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{Circle, Point, Segment, ValidCoordinate};
+use comlib_math::{Float, Numeric, Signed};
+
+/// Triangle defined by its vertices.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle<T: ValidCoordinate>(
+    /// The first vertex of the triangle.
+    pub Point<T>,
+    /// The second vertex of the triangle.
+    pub Point<T>,
+    /// The third vertex of the triangle.
+    pub Point<T>,
+);
+
+impl<T: ValidCoordinate> Triangle<T> {
+    /// Constructs the triangle with the given vertices.
+    pub fn new<P1, P2, P3>(p1: P1, p2: P2, p3: P3) -> Self
+    where
+        P1: Into<Point<T>>,
+        P2: Into<Point<T>>,
+        P3: Into<Point<T>>,
+    {
+        Self(p1.into(), p2.into(), p3.into())
+    }
+
+    /// Computes the signed area of the triangle.
+    ///
+    /// The sign of the area is positive if the vertices are in counter-clockwise order, and negative otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::Triangle;
+    /// # use comlib_math::Quot;
+    /// assert_eq!(Triangle::new((0, 0), (3, 0), (0, 1)).area(), Quot::new(3, 2).unwrap());
+    /// assert_eq!(Triangle::new((0, 0), (0, 1), (3, 0)).area(), Quot::new(-3, 2).unwrap());
+    /// ```
+    pub fn area(self) -> T::Coordinate {
+        let Self(a, b, c) = self;
+        let cross = (b.x() - a.x()) * (c.y() - a.y()) - (c.x() - a.x()) * (b.y() - a.y());
+        cross / T::Coordinate::from_int(2)
+    }
+
+    /// Checks whether the vertices of the triangle are collinear.
+    pub fn is_degenerate(self) -> bool {
+        self.area().is_zero()
+    }
+
+    /// Computes the circumcircle of the triangle, i.e. the circle passing through all of its vertices.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn circumcircle(self) -> Option<Circle<T>> {
+        Circle::through_points(self.0, self.1, self.2)
+    }
+
+    /// Computes the circumcenter of the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_geometry::{Point, Triangle};
+    /// let triangle = Triangle::new((0, 0), (1, 0), (0, 1));
+    /// assert_eq!(triangle.circumcenter(), Point::try_new(1, 1, 2));
+    /// ```
+    pub fn circumcenter(self) -> Option<Point<T>> {
+        Some(self.circumcircle()?.center())
+    }
+
+    /// Computes the squared circumradius of the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn sq_circumradius(self) -> Option<T::Coordinate> {
+        Some(self.circumcircle()?.sq_radius())
+    }
+
+    /// Computes the perimeter of the triangle.
+    pub fn perimeter(self) -> T::Coordinate
+    where
+        T::Coordinate: Float,
+    {
+        let [a, b, c] = self.side_lengths();
+        a + b + c
+    }
+
+    /// Computes the incircle of the triangle, i.e. the largest circle inside the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn incircle(self) -> Option<Circle<T>>
+    where
+        T::Coordinate: Float,
+    {
+        let area = self.area().get_abs();
+        if area.is_zero() {
+            return None;
+        }
+
+        // The incenter is the average of the vertices weighted by the lengths of the opposite sides
+        let [a, b, c] = self.side_lengths();
+        let perimeter = a + b + c;
+        let x = (self.0.x() * a + self.1.x() * b + self.2.x() * c) / perimeter;
+        let y = (self.0.y() * a + self.1.y() * b + self.2.y() * c) / perimeter;
+        let radius = area * T::Coordinate::from_int(2) / perimeter;
+        Some(Circle::from_center_and_radius(
+            Point::from_coordinates(x, y),
+            radius,
+        ))
+    }
+
+    /// Computes the incenter of the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn incenter(self) -> Option<Point<T>>
+    where
+        T::Coordinate: Float,
+    {
+        Some(self.incircle()?.center())
+    }
+
+    /// Computes the inradius of the triangle.
+    ///
+    /// Returns `None` if the triangle is degenerate.
+    pub fn inradius(self) -> Option<T::Coordinate>
+    where
+        T::Coordinate: Float,
+    {
+        Some(self.incircle()?.radius())
+    }
+
+    /// Computes the lengths of the sides opposite to each vertex.
+    fn side_lengths(self) -> [T::Coordinate; 3]
+    where
+        T::Coordinate: Float,
+    {
+        let len = |p: Point<T>, q: Point<T>| {
+            Segment::between(p, q).map_or(T::Coordinate::zero(), |segment| segment.len())
+        };
+        [
+            len(self.1, self.2),
+            len(self.2, self.0),
+            len(self.0, self.1),
+        ]
+    }
+}
@@ -1,11 +1,20 @@
 use comlib_math::Numeric;
 use std::{fmt, ops};
 
+/// Divisor of homogeneous coordinates of floating-point types.
+///
+/// Floating-point coordinates don't need a divisor, so this type always represents the value 1.
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct Unit;
 
-impl Into<f32> for Unit {
-    fn into(self) -> f32 {
+impl From<Unit> for f32 {
+    fn from(_: Unit) -> f32 {
+        1.
+    }
+}
+
+impl From<Unit> for f64 {
+    fn from(_: Unit) -> f64 {
         1.
     }
 }
@@ -98,3 +107,11 @@ impl ops::Mul<f32> for Unit {
         rhs
     }
 }
+
+impl ops::Mul<f64> for Unit {
+    type Output = f64;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        rhs
+    }
+}
@@ -0,0 +1,88 @@
+use comlib_geometry::{Affine, Point, Polygon, Segment, Unit};
+use comlib_math::Quot;
+use std::f64::consts::PI;
+
+#[test]
+fn test_rotation_90() {
+    let p = Point::from((3, 1));
+    let rotated: Vec<_> = (-1..=4)
+        .map(|times| Affine::rotation_90(times).apply(p))
+        .collect();
+    let expected: Vec<Point<i64>> = vec![(1, -3), (3, 1), (-1, 3), (-3, -1), (1, -3), (3, 1)]
+        .into_iter()
+        .map(Point::from)
+        .collect();
+    assert_eq!(rotated, expected);
+}
+
+#[test]
+fn test_rotation() {
+    let p = |x: f64, y: f64| Point::new(x, y, Unit);
+    let transform = Affine::rotation(PI / 6.0).then(Affine::rotation(PI / 3.0));
+    let (x, y) = transform.apply(p(2.0, 1.0)).to_f64_pair();
+    assert!((x + 1.0).abs() < 1e-9 && (y - 2.0).abs() < 1e-9);
+
+    let (x, y) = Affine::rotation(-PI / 4.0).apply(p(1.0, 1.0)).to_f64_pair();
+    assert!((x - 2f64.sqrt()).abs() < 1e-9 && y.abs() < 1e-9);
+}
+
+#[test]
+fn test_composition_and_inverse() {
+    let q = |x: i64| Quot::from(x);
+    let transforms = [
+        Affine::identity(),
+        Affine::translation((2, -3)),
+        Affine::scaling(q(2), Quot::new(1, 3).unwrap()),
+        Affine::scaling(q(-1), q(1)),
+        Affine::rotation_90(1),
+        Affine::new([[q(1), q(2), q(3)], [q(0), q(1), q(-1)]]),
+    ];
+    let points: Vec<Point<i64>> = vec![(0, 0), (1, 2), (-3, 5), (7, -4)]
+        .into_iter()
+        .map(Point::from)
+        .collect();
+    for &a in &transforms {
+        let inverse = a.inverse().unwrap();
+        assert_eq!(a.then(inverse), Affine::identity());
+        for &b in &transforms {
+            for &p in &points {
+                assert_eq!(a.then(b).apply(p), b.apply(a.apply(p)));
+            }
+        }
+    }
+
+    let singular = Affine::<i64>::new([[q(1), q(2), q(0)], [q(2), q(4), q(1)]]);
+    assert_eq!(singular.determinant(), q(0));
+    assert_eq!(singular.inverse(), None);
+}
+
+#[test]
+fn test_apply_segment_and_polygon() {
+    let transform =
+        Affine::translation((1, 1)).then(Affine::scaling(Quot::from(-2), Quot::from(1)));
+    let segment = Segment::between((0, 0), (1, 2)).unwrap();
+    let transformed = transform.apply_segment(segment).unwrap();
+    assert_eq!(transformed.start(), Point::from((-2, 1)));
+    assert_eq!(transformed.end(), Point::from((-4, 3)));
+
+    let collapse = Affine::scaling(Quot::from(0), Quot::from(0));
+    assert!(collapse.apply_segment(segment).is_none());
+
+    let square = Polygon::from(vec![
+        Point::from((0, 0)),
+        Point::from((1, 0)),
+        Point::from((1, 1)),
+        Point::from((0, 1)),
+    ]);
+    let transformed = transform.apply_polygon(&square);
+    assert_eq!(transformed.area(), square.area() * transform.determinant());
+    assert_eq!(
+        transformed.points().collect::<Vec<_>>(),
+        vec![
+            Point::from((-2, 1)),
+            Point::from((-4, 1)),
+            Point::from((-4, 2)),
+            Point::from((-2, 2)),
+        ]
+    );
+}
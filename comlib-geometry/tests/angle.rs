@@ -0,0 +1,78 @@
+use comlib_geometry::{compare_angles, sort_by_angle_around, Point};
+use std::cmp::Ordering;
+
+fn points(coordinates: &[(i64, i64)]) -> Vec<Point<i64>> {
+    coordinates.iter().map(|&p| Point::from(p)).collect()
+}
+
+#[test]
+fn test_sort_by_angle_around() {
+    let mut ps = points(&[
+        (1, -1),
+        (3, 3),
+        (-2, 0),
+        (2, 0),
+        (0, 0),
+        (1, 1),
+        (-1, -5),
+        (0, 1),
+        (-1, 1),
+        (0, -1),
+        (1, 0),
+    ]);
+    sort_by_angle_around(Point::from((0, 0)), &mut ps);
+    assert_eq!(
+        ps,
+        points(&[
+            (0, 0),
+            (2, 0),
+            (1, 0),
+            (3, 3),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-2, 0),
+            (-1, -5),
+            (0, -1),
+            (1, -1),
+        ])
+    );
+}
+
+#[test]
+fn test_sort_around_other_pivot() {
+    let pivot = Point::try_new(1, 1, 2).unwrap();
+    let mut ps = points(&[(0, 0), (1, 0), (1, 1), (0, 1)]);
+    sort_by_angle_around(pivot, &mut ps);
+    assert_eq!(ps, points(&[(1, 1), (0, 1), (0, 0), (1, 0)]));
+}
+
+#[test]
+fn test_compare_angles_consistent() {
+    let pivot = Point::from((0, 0));
+    let ps: Vec<Point<i64>> = (-3..=3)
+        .flat_map(|x| (-3..=3).map(move |y| Point::from((x, y))))
+        .collect();
+    let angle = |p: Point<i64>| {
+        let (x, y) = p.to_f64_pair();
+        let angle = y.atan2(x);
+        if angle < 0.0 {
+            angle + 2.0 * std::f64::consts::PI
+        } else {
+            angle
+        }
+    };
+    for &a in &ps {
+        for &b in &ps {
+            if a == pivot || b == pivot {
+                continue;
+            }
+            let expected = if (angle(a) - angle(b)).abs() < 1e-12 {
+                Ordering::Equal
+            } else {
+                angle(a).partial_cmp(&angle(b)).unwrap()
+            };
+            assert_eq!(compare_angles(pivot, a, b), expected, "{:?} {:?}", a, b);
+        }
+    }
+}
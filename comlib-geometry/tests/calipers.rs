@@ -0,0 +1,202 @@
+use comlib_geometry::{convex_hull, Point, Polygon, Segment};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+fn random_hulls() -> impl Iterator<Item = (Vec<Point<i64>>, Polygon<i64>)> {
+    let mut next = lcg(987654321);
+    (0..3000).filter_map(move |_| {
+        let n = 1 + next(10) as usize;
+        let range = 2 + next(8);
+        let points: Vec<Point<i64>> = (0..n)
+            .map(|_| Point::from((next(range) as i64, next(range) as i64)))
+            .collect();
+        let hull = convex_hull(points.clone())?;
+        Some((points, hull))
+    })
+}
+
+fn sq_dist(a: Point<i64>, b: Point<i64>) -> Quot<i64> {
+    if a == b {
+        Quot::from(0)
+    } else {
+        Segment::between(a, b).unwrap().sq_len()
+    }
+}
+
+/// Computes the minimum over the edges of the maximum squared distance from the edge line.
+fn brute_force_sq_width(hull: &Polygon<i64>) -> Quot<i64> {
+    let mut best: Option<Quot<i64>> = None;
+    for segment in hull.segments() {
+        let (a, b) = (segment.start(), segment.end());
+        let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+        let height = hull
+            .points()
+            .map(|p| {
+                let cross = dx * (p.y() - a.y()) - dy * (p.x() - a.x());
+                cross * cross / (dx * dx + dy * dy)
+            })
+            .max()
+            .unwrap();
+        if best.is_none_or(|best| height < best) {
+            best = Some(height);
+        }
+    }
+    best.unwrap()
+}
+
+/// Computes the minimum area of a rectangle with a side collinear with an edge of the hull.
+fn brute_force_min_area(hull: &Polygon<i64>) -> Quot<i64> {
+    let mut best: Option<Quot<i64>> = None;
+    for segment in hull.segments() {
+        let (a, b) = (segment.start(), segment.end());
+        let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+        let dots: Vec<_> = hull
+            .points()
+            .map(|p| dx * (p.x() - a.x()) + dy * (p.y() - a.y()))
+            .collect();
+        let height = hull
+            .points()
+            .map(|p| dx * (p.y() - a.y()) - dy * (p.x() - a.x()))
+            .max()
+            .unwrap();
+        let length = *dots.iter().max().unwrap() - *dots.iter().min().unwrap();
+        let area = length * height / (dx * dx + dy * dy);
+        if best.is_none_or(|best| area < best) {
+            best = Some(area);
+        }
+    }
+    best.unwrap()
+}
+
+#[test]
+fn test_diameter() {
+    for (points, hull) in random_hulls() {
+        let expected = points
+            .iter()
+            .flat_map(|&a| points.iter().map(move |&b| sq_dist(a, b)))
+            .max()
+            .unwrap();
+        let (a, b) = hull.diameter();
+        assert_eq!(sq_dist(a, b), expected, "{:?}", points);
+    }
+}
+
+#[test]
+fn test_diameter_degenerate() {
+    let polygon = Polygon::from(vec![Point::from((1, 1))]);
+    assert_eq!(
+        polygon.diameter(),
+        (Point::from((1, 1)), Point::from((1, 1)))
+    );
+    let hull = convex_hull(vec![
+        Point::from((1, 1)),
+        Point::from((0, 0)),
+        Point::from((3, 3)),
+        Point::from((2, 2)),
+    ])
+    .unwrap();
+    let (a, b) = hull.diameter();
+    assert_eq!(sq_dist(a, b), Quot::from(18));
+}
+
+#[test]
+fn test_repeated_points() {
+    let hull = convex_hull(
+        [(0, 0), (0, 0), (2, 0), (2, 0), (0, 0), (0, 1)]
+            .iter()
+            .map(|&p| Point::from(p))
+            .collect(),
+    )
+    .unwrap();
+    let (a, b) = hull.diameter();
+    assert_eq!(sq_dist(a, b), Quot::from(5));
+    assert_eq!(hull.sq_width(), Quot::new(4, 5).unwrap());
+    assert_eq!(hull.min_area_rectangle().area(), Quot::from(2));
+}
+
+#[test]
+fn test_width() {
+    for (points, hull) in random_hulls() {
+        let expected = if hull.area() == Quot::from(0) {
+            Quot::from(0)
+        } else {
+            brute_force_sq_width(&hull)
+        };
+        assert_eq!(hull.sq_width(), expected, "{:?}", points);
+    }
+
+    let square = Polygon::from(vec![
+        Point::from((0.0, 0.0)),
+        Point::from((2.0, 0.0)),
+        Point::from((2.0, 2.0)),
+        Point::from((0.0, 2.0)),
+    ]);
+    assert!((square.width() - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_min_area_rectangle() {
+    for (points, hull) in random_hulls() {
+        if hull.area() == Quot::from(0) {
+            continue;
+        }
+        let rectangle = hull.min_area_rectangle();
+        assert_eq!(
+            rectangle.area(),
+            brute_force_min_area(&hull),
+            "{:?}",
+            points
+        );
+        assert!(rectangle.is_convex());
+        for p in &points {
+            assert_ne!(
+                rectangle.contains(*p),
+                comlib_geometry::Containment::Outside,
+                "{:?}",
+                points
+            );
+        }
+    }
+}
+
+#[test]
+fn test_min_perimeter_rectangle() {
+    let hull = convex_hull(vec![
+        Point::from((0, 0)),
+        Point::from((4, 4)),
+        Point::from((3, 5)),
+        Point::from((-1, 1)),
+    ])
+    .unwrap();
+    let mut rectangle = hull.min_perimeter_rectangle();
+    rectangle.normalize_start();
+    assert_eq!(
+        rectangle,
+        Polygon::from(vec![
+            Point::from((-1, 1)),
+            Point::from((0, 0)),
+            Point::from((4, 4)),
+            Point::from((3, 5)),
+        ])
+    );
+
+    let hull = convex_hull(vec![
+        Point::from((0, 0)),
+        Point::from((10, 0)),
+        Point::from((10, 1)),
+        Point::from((1, 10)),
+        Point::from((0, 10)),
+    ])
+    .unwrap();
+    let mut rectangle = hull.min_perimeter_rectangle();
+    rectangle.normalize_start();
+    assert_eq!(
+        rectangle,
+        Polygon::from(vec![
+            Point::from((0, 0)),
+            Point::from((10, 0)),
+            Point::from((10, 10)),
+            Point::from((0, 10)),
+        ])
+    );
+}
@@ -0,0 +1,268 @@
+use comlib_geometry::{Circle, CircleIntersection, Containment, Line, Point, Tangents, Unit};
+use comlib_math::{Numeric, Quot};
+
+#[test]
+fn test_from_center_and_radius() {
+    let circle = Circle::from_center_and_radius((1, 2), Quot::new(3, 2).unwrap());
+    assert_eq!(circle.center(), Point::from((1, 2)));
+    assert_eq!(circle.sq_radius(), Quot::new(9, 4).unwrap());
+
+    let circle = Circle::from_center_and_sq_radius((1, 2), Quot::from(2));
+    assert_eq!(circle.contains((2, 3)), Containment::OnBoundary);
+    assert_eq!(circle.contains((1, 3)), Containment::Inside);
+    assert_eq!(circle.contains((3, 2)), Containment::Outside);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_radius() {
+    Circle::from_center_and_radius((0, 0), Quot::from(-1));
+}
+
+#[test]
+fn test_through_points() {
+    let points = [(0, 0), (4, 2), (-3, 5), (7, -1), (2, 9), (-6, -4)];
+    for &p1 in &points {
+        for &p2 in &points {
+            for &p3 in &points {
+                let circle = match Circle::through_points(p1, p2, p3) {
+                    Some(circle) => circle,
+                    None => continue,
+                };
+                for &p in &[p1, p2, p3] {
+                    assert_eq!(circle.contains(p), Containment::OnBoundary);
+                }
+            }
+        }
+    }
+
+    // The center is not an integral point
+    let circle = Circle::through_points((0, 0), (1, 0), (0, 1)).unwrap();
+    assert_eq!(circle.center(), Point::try_new(1, 1, 2).unwrap());
+    assert_eq!(circle.sq_radius(), Quot::new(1, 2).unwrap());
+    assert_eq!(circle.contains((1, 1)), Containment::OnBoundary);
+
+    assert_eq!(Circle::through_points((0, 0), (0, 0), (1, 1)), None);
+    assert_eq!(Circle::through_points((0, 0), (1, 2), (2, 4)), None);
+}
+
+#[test]
+fn test_float() {
+    let circle = Circle::through_points((0.0, 0.0), (2.0, 0.0), (0.0, 2.0)).unwrap();
+    let (x, y) = circle.center().to_f64_pair();
+    assert!((x - 1.0).abs() < 1e-6 && (y - 1.0).abs() < 1e-6);
+    assert!((circle.radius() - 2f32.sqrt()).abs() < 1e-6);
+    assert_eq!(circle.contains((1.0, 1.5)), Containment::Inside);
+    assert_eq!(circle.contains((3.0, 3.0)), Containment::Outside);
+}
+
+#[test]
+fn test_float_rounding() {
+    // None of the computations below are exact in floating point
+    let p = |x: f64, y: f64| Point::new(x, y, Unit);
+    let circle = Circle::from_center_and_radius(p(0.1, 0.2), 0.3);
+    assert_eq!(circle.contains(p(0.4, 0.2)), Containment::OnBoundary);
+    assert_eq!(circle.contains(p(0.1, -0.1)), Containment::OnBoundary);
+    let is_tangent_at = |intersection, (x, y): (f64, f64)| match intersection {
+        CircleIntersection::Tangent(q) => {
+            let (qx, qy) = q.to_f64_pair();
+            (qx - x).abs() < 1e-9 && (qy - y).abs() < 1e-9
+        }
+        _ => false,
+    };
+    assert!(is_tangent_at(
+        circle.intersect_line(Line::spanned_by(p(0.4, 0.0), p(0.4, 1.0))),
+        (0.4, 0.2)
+    ));
+    let other = Circle::from_center_and_radius(p(0.7, 0.2), 0.3);
+    assert!(is_tangent_at(circle.intersect(other), (0.4, 0.2)));
+    assert!(matches!(
+        circle.tangents_from(p(0.4, 0.2)),
+        Tangents::Touching(_)
+    ));
+    assert!(matches!(
+        circle.common_tangents(other).inner,
+        Tangents::Touching(_)
+    ));
+}
+
+fn on_circle(circle: Circle<i64>, (x, y): (f64, f64)) -> bool {
+    let (cx, cy) = circle.center().to_f64_pair();
+    let dist = (x - cx) * (x - cx) + (y - cy) * (y - cy);
+    (dist - circle.sq_radius().as_f64()).abs() < 1e-6
+}
+
+#[test]
+fn test_intersect_line() {
+    let circle = Circle::from_center_and_radius((1, 1), Quot::from(2));
+    assert_eq!(
+        circle.intersect_line(Line::spanned_by((0, 3), (1, 3))),
+        CircleIntersection::Tangent(Point::from((1, 3)))
+    );
+    assert_eq!(
+        circle.intersect_line(Line::spanned_by((0, 4), (1, 4))),
+        CircleIntersection::None
+    );
+    assert_eq!(
+        circle.intersect_line(Line::spanned_by((4, 0), (3, 5))),
+        CircleIntersection::None
+    );
+
+    for &(p1, p2) in &[((0, 0), (1, 0)), ((-1, 2), (3, -1)), ((1, 1), (2, 5))] {
+        let line = Line::spanned_by(p1, p2);
+        match circle.intersect_line(line) {
+            CircleIntersection::Secant([q1, q2]) => {
+                assert!(on_circle(circle, q1) && on_circle(circle, q2));
+                assert!((q1.0 - q2.0).abs() + (q1.1 - q2.1).abs() > 1e-6);
+                for &(x, y) in &[q1, q2] {
+                    let value = line.a as f64 * x + line.b as f64 * y + line.c as f64;
+                    assert!(value.abs() < 1e-6);
+                }
+            }
+            intersection => panic!("expected secant, got {:?}", intersection),
+        }
+    }
+}
+
+#[test]
+fn test_intersect_circle() {
+    let c1 = Circle::from_center_and_radius((0, 0), Quot::from(5));
+    // Internally tangent
+    let c2 = Circle::from_center_and_radius((3, 4), Quot::from(10));
+    assert_eq!(
+        c1.intersect(c2),
+        CircleIntersection::Tangent(Point::from((-3, -4)))
+    );
+    // Externally tangent
+    let c3 = Circle::from_center_and_radius((6, 8), Quot::from(5));
+    assert_eq!(
+        c1.intersect(c3),
+        CircleIntersection::Tangent(Point::from((3, 4)))
+    );
+    // Too far apart and nested
+    let c4 = Circle::from_center_and_radius((20, 0), Quot::from(5));
+    assert_eq!(c1.intersect(c4), CircleIntersection::None);
+    let c5 = Circle::from_center_and_radius((1, 0), Quot::from(1));
+    assert_eq!(c1.intersect(c5), CircleIntersection::None);
+    // Concentric
+    let c6 = Circle::from_center_and_radius((0, 0), Quot::from(4));
+    assert_eq!(c1.intersect(c6), CircleIntersection::None);
+    assert_eq!(c1.radical_line(c6), None);
+    assert_eq!(c1.intersect(c1), CircleIntersection::Coincident);
+
+    let c7 = Circle::from_center_and_radius((4, 3), Quot::from(3));
+    match c1.intersect(c7) {
+        CircleIntersection::Secant([q1, q2]) => {
+            for &q in &[q1, q2] {
+                assert!(on_circle(c1, q) && on_circle(c7, q));
+            }
+        }
+        intersection => panic!("expected secant, got {:?}", intersection),
+    }
+}
+
+#[test]
+fn test_intersect_zero_radius() {
+    let point = Circle::from_center_and_radius((3, 4), Quot::from(0));
+    assert_eq!(
+        point.intersect(point),
+        CircleIntersection::Tangent(Point::from((3, 4)))
+    );
+    let other = Circle::from_center_and_radius((0, 4), Quot::from(0));
+    assert_eq!(point.intersect(other), CircleIntersection::None);
+
+    let circle = Circle::from_center_and_radius((0, 0), Quot::from(5));
+    assert_eq!(
+        point.intersect(circle),
+        CircleIntersection::Tangent(Point::from((3, 4)))
+    );
+    assert_eq!(
+        circle.intersect(point),
+        CircleIntersection::Tangent(Point::from((3, 4)))
+    );
+    assert_eq!(circle.intersect(other), CircleIntersection::None);
+    let center = Circle::from_center_and_radius((0, 0), Quot::from(0));
+    assert_eq!(circle.intersect(center), CircleIntersection::None);
+}
+
+#[test]
+fn test_radical_line() {
+    let c1 = Circle::from_center_and_radius((0, 0), Quot::from(5));
+    let c2 = Circle::from_center_and_radius((8, 0), Quot::from(5));
+    assert_eq!(c1.radical_line(c2), Some(Line::spanned_by((4, 0), (4, 1))));
+    // The radical line exists even for disjoint circles
+    let c3 = Circle::from_center_and_radius((0, 20), Quot::from(1));
+    let line = c1.radical_line(c3).unwrap();
+    let p = line.closest_point_to((0, 0));
+    assert_eq!(p.x(), Quot::from(0));
+}
+
+/// Checks that the line is tangent to the circle, up to floating point error.
+fn is_tangent(circle: Circle<f32>, line: Line<f32>) -> bool {
+    let (cx, cy) = circle.center().to_f64_pair();
+    let (a, b, c) = (line.a as f64, line.b as f64, line.c as f64);
+    let dist = (a * cx + b * cy + c).abs() / (a * a + b * b).sqrt();
+    (dist - circle.radius() as f64).abs() < 1e-4
+}
+
+fn lines(tangents: Tangents<f32>) -> Vec<Line<f32>> {
+    match tangents {
+        Tangents::None => vec![],
+        Tangents::Touching(line) => vec![line],
+        Tangents::Two(lines) => lines.to_vec(),
+        Tangents::Infinite => panic!("infinitely many tangents"),
+    }
+}
+
+#[test]
+fn test_tangents_from() {
+    let circle = Circle::from_center_and_radius((1.0, 2.0), 2.0);
+    let tangents = lines(circle.tangents_from((5.0, -1.0)));
+    assert_eq!(tangents.len(), 2);
+    for &line in &tangents {
+        assert!(is_tangent(circle, line));
+        let (a, b, c) = (line.a, line.b, line.c);
+        assert!((a * 5.0 - b + c).abs() < 1e-4);
+    }
+    assert_ne!(tangents[0], tangents[1]);
+
+    match circle.tangents_from((3.0, 2.0)) {
+        Tangents::Touching(line) => assert_eq!(line, Line::spanned_by((3.0, 0.0), (3.0, 5.0))),
+        tangents => panic!("expected one tangent, got {:?}", tangents),
+    }
+    assert_eq!(circle.tangents_from((2.0, 2.0)), Tangents::None);
+}
+
+#[test]
+fn test_common_tangents() {
+    let c1 = Circle::from_center_and_radius((0.0, 0.0), 2.0);
+    let cases = [
+        // Separate
+        (Circle::from_center_and_radius((6.0, 1.0), 1.0), 2, 2),
+        // Externally tangent
+        (Circle::from_center_and_radius((3.0, 0.0), 1.0), 2, 1),
+        // Intersecting
+        (Circle::from_center_and_radius((2.0, 1.0), 1.5), 2, 0),
+        // Internally tangent
+        (Circle::from_center_and_radius((1.0, 0.0), 1.0), 1, 0),
+        // Nested
+        (Circle::from_center_and_radius((0.5, 0.0), 1.0), 0, 0),
+        (Circle::from_center_and_radius((0.0, 0.0), 1.0), 0, 0),
+    ];
+    for &(c2, outer, inner) in &cases {
+        let tangents = c1.common_tangents(c2);
+        let outer_lines = lines(tangents.outer);
+        let inner_lines = lines(tangents.inner);
+        assert_eq!(outer_lines.len(), outer, "{:?}", c2);
+        assert_eq!(inner_lines.len(), inner, "{:?}", c2);
+        for &line in outer_lines.iter().chain(&inner_lines) {
+            assert!(is_tangent(c1, line) && is_tangent(c2, line), "{:?}", c2);
+        }
+        // The tangents are symmetric
+        let reversed = c2.common_tangents(c1);
+        assert_eq!(lines(reversed.outer).len(), outer);
+        assert_eq!(lines(reversed.inner).len(), inner);
+    }
+
+    assert_eq!(c1.common_tangents(c1).outer, Tangents::Infinite);
+}
@@ -0,0 +1,98 @@
+use comlib_geometry::{
+    convex_hull_in_place, CollinearPoints, Containment, Ordering, Point, Polygon,
+};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+fn dot(direction: (i64, i64), p: Point<i64>) -> Quot<i64> {
+    p.x() * Quot::from(direction.0) + p.y() * Quot::from(direction.1)
+}
+
+#[test]
+fn test_extreme_vertex_small() {
+    let single = Polygon::from(vec![Point::from((1, 2))]);
+    assert_eq!(single.extreme_vertex((1, 1)), Point::from((1, 2)));
+
+    let segment = Polygon::from(vec![Point::from((0, 0)), Point::from((2, 1))]);
+    assert_eq!(segment.extreme_vertex((1, 0)), Point::from((2, 1)));
+    assert_eq!(segment.extreme_vertex((-1, 0)), Point::from((0, 0)));
+
+    let square = Polygon::from(vec![
+        Point::from((0, 0)),
+        Point::from((1, 0)),
+        Point::from((1, 1)),
+        Point::from((0, 1)),
+    ]);
+    assert_eq!(square.extreme_vertex((1, 1)), Point::from((1, 1)));
+    assert_eq!(square.extreme_vertex((-1, 1)), Point::from((0, 1)));
+}
+
+#[test]
+fn test_extreme_vertex_random() {
+    let mut random = lcg(7);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..300 {
+        let n = 1 + next(40) as usize;
+        let range = 1 + next(20) as u64;
+        let mut hull: Vec<Point<i64>> = (0..n)
+            .map(|_| Point::from((next(range), next(range))))
+            .collect();
+        convex_hull_in_place(&mut hull, CollinearPoints::Remove);
+        let shift = next(hull.len() as u64) as usize;
+        hull.rotate_left(shift);
+        let polygon = Polygon::from(hull.clone());
+
+        for _ in 0..20 {
+            let direction = (next(11) - 5, next(11) - 5);
+            let best = hull.iter().map(|&p| dot(direction, p)).max().unwrap();
+            let extreme = polygon.extreme_vertex(direction);
+            assert!(hull.contains(&extreme));
+            assert_eq!(dot(direction, extreme), best, "{:?} {:?}", hull, direction);
+        }
+    }
+}
+
+#[test]
+fn test_tangents_from_random() {
+    let mut random = lcg(9);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..300 {
+        let n = 1 + next(40) as usize;
+        let range = 1 + next(20) as u64;
+        let mut hull: Vec<Point<i64>> = (0..n)
+            .map(|_| Point::from((next(range), next(range))))
+            .collect();
+        convex_hull_in_place(&mut hull, CollinearPoints::Remove);
+        let shift = next(hull.len() as u64) as usize;
+        hull.rotate_left(shift);
+        let polygon = Polygon::from(hull.clone());
+
+        for _ in 0..20 {
+            let p = Point::from((next(range + 6) - 3, next(range + 6) - 3));
+            let tangents = polygon.tangents_from(p);
+            if polygon.contains_convex(p) != Containment::Outside {
+                assert_eq!(tangents, None);
+                continue;
+            }
+            let (a, b) = tangents.unwrap();
+            assert!(hull.contains(&a) && hull.contains(&b));
+            for &q in &hull {
+                assert_ne!(Point::ordering([p, a, q]), Ordering::Clockwise);
+                assert_ne!(Point::ordering([p, b, q]), Ordering::Counterclockwise);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_farthest_point_from() {
+    let polygon = Polygon::from(vec![
+        Point::from((0, 0)),
+        Point::from((6, 0)),
+        Point::from((3, 1)),
+        Point::from((2, 5)),
+    ]);
+    assert_eq!(polygon.farthest_point_from((0, 0)), Point::from((6, 0)));
+    assert_eq!(polygon.farthest_point_from((5, 0)), Point::from((2, 5)));
+    assert_eq!(polygon.farthest_point_from((3, 5)), Point::from((0, 0)));
+}
@@ -0,0 +1,75 @@
+use comlib_geometry::{
+    convex_hull, convex_hull_in_place, CollinearPoints, Containment, Ordering, Point, Polygon,
+};
+use comlib_testing::lcg;
+
+fn points(points: &[(i64, i64)]) -> Vec<Point<i64>> {
+    points.iter().copied().map(Point::from).collect()
+}
+
+#[test]
+fn test_degenerate() {
+    assert_eq!(convex_hull::<i64>(Vec::new()), None);
+    assert_eq!(convex_hull(points(&[(1, 1), (1, 1)])), None);
+
+    let mut hull = points(&[(1, 1), (1, 1), (1, 1)]);
+    convex_hull_in_place(&mut hull, CollinearPoints::Remove);
+    assert_eq!(hull, points(&[(1, 1)]));
+
+    let line = points(&[(2, 2), (0, 0), (1, 1), (3, 3), (1, 1)]);
+    let mut hull = line.clone();
+    convex_hull_in_place(&mut hull, CollinearPoints::Keep);
+    assert_eq!(
+        hull,
+        points(&[(0, 0), (1, 1), (2, 2), (3, 3), (2, 2), (1, 1)])
+    );
+    let mut hull = line;
+    convex_hull_in_place(&mut hull, CollinearPoints::Remove);
+    assert_eq!(hull, points(&[(0, 0), (3, 3)]));
+}
+
+#[test]
+fn test_random() {
+    let mut random = lcg(5);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..500 {
+        let n = 1 + next(30) as usize;
+        let range = 1 + next(8) as u64;
+        let input: Vec<_> = (0..n)
+            .map(|_| Point::from((next(range), next(range))))
+            .collect();
+
+        for &collinear in &[CollinearPoints::Keep, CollinearPoints::Remove] {
+            let mut hull = input.clone();
+            convex_hull_in_place(&mut hull, collinear);
+            assert_eq!(hull[0], *input.iter().min().unwrap());
+            let polygon = Polygon::from(hull.clone());
+            if hull.len() < 3 || polygon.area() == 0.into() {
+                continue;
+            }
+
+            let k = hull.len();
+            for i in 0..k {
+                let ordering = Point::ordering([hull[i], hull[(i + 1) % k], hull[(i + 2) % k]]);
+                match collinear {
+                    CollinearPoints::Keep => assert_ne!(ordering, Ordering::Clockwise),
+                    CollinearPoints::Remove => assert_eq!(ordering, Ordering::Counterclockwise),
+                }
+            }
+            for &p in &input {
+                match polygon.contains(p) {
+                    Containment::Inside => assert!(!hull.contains(&p)),
+                    Containment::OnBoundary => {
+                        if collinear == CollinearPoints::Keep {
+                            assert!(hull.contains(&p));
+                        }
+                    }
+                    Containment::Outside => panic!("{:?} is outside of the hull", p),
+                }
+            }
+            if collinear == CollinearPoints::Keep {
+                assert_eq!(convex_hull(input.clone()), Some(polygon));
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use comlib_geometry::{Containment, Delaunay, Point, Triangle};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+fn points(points: &[(i64, i64)]) -> Vec<Point<i64>> {
+    points.iter().copied().map(Point::from).collect()
+}
+
+fn sq_dist(a: Point<i64>, b: Point<i64>) -> Quot<i64> {
+    let (dx, dy) = (a.x() - b.x(), a.y() - b.y());
+    dx * dx + dy * dy
+}
+
+/// Checks that the triangles are counter-clockwise, cover the convex hull and have empty circumcircles.
+fn check_delaunay(delaunay: &Delaunay) {
+    let points = delaunay.points();
+    let mut total_area = Quot::from(0);
+    for &[a, b, c] in delaunay.triangles() {
+        let triangle = Triangle::new(points[a], points[b], points[c]);
+        assert!(triangle.area() > Quot::from(0));
+        total_area += triangle.area();
+        let circle = triangle.circumcircle().unwrap();
+        for &p in points {
+            assert_ne!(circle.contains(p), Containment::Inside);
+        }
+    }
+    let expected_area =
+        comlib_geometry::convex_hull(points.to_vec()).map_or(Quot::from(0), |hull| hull.area());
+    assert_eq!(total_area, expected_area);
+}
+
+#[test]
+fn test_square() {
+    let delaunay = Delaunay::new(points(&[(0, 0), (2, 0), (2, 2), (0, 2), (0, 0)]));
+    assert_eq!(delaunay.points().len(), 4);
+    assert_eq!(delaunay.triangles().len(), 2);
+    check_delaunay(&delaunay);
+    assert_eq!(delaunay.edges().len(), 5);
+
+    // The diagonal of the square has a Voronoi edge of zero length
+    let vertices = delaunay.voronoi_vertices();
+    assert!(vertices.iter().all(|&v| v == Point::from((1, 1))));
+    let edges = delaunay.voronoi_edges();
+    assert_eq!(edges.len(), 5);
+    assert_eq!(
+        edges
+            .iter()
+            .filter(|edge| edge.vertices.iter().all(Option::is_some))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_collinear() {
+    let delaunay = Delaunay::new(points(&[(0, 0), (3, 3), (1, 1), (2, 2)]));
+    assert!(delaunay.triangles().is_empty());
+    assert_eq!(delaunay.edges(), vec![(0, 1), (1, 2), (2, 3)]);
+    assert_eq!(delaunay.neighbors(1), &[0, 2]);
+    assert_eq!(delaunay.nearest_site((5, 0)), 2);
+    assert_eq!(delaunay.nearest_site((-5, 0)), 0);
+}
+
+#[test]
+fn test_large_coordinates() {
+    let big = 700_000;
+    let delaunay = Delaunay::new(points(&[(-big, -big), (big, -big), (0, big), (1, 2)]));
+    assert_eq!(delaunay.triangles().len(), 3);
+    assert_eq!(delaunay.edges().len(), 6);
+    assert_eq!(
+        delaunay.points()[delaunay.nearest_site((big, big))],
+        Point::from((0, big))
+    );
+}
+
+#[test]
+#[should_panic(expected = "points must satisfy x² + y² <= 1e12")]
+fn test_too_large_coordinates() {
+    Delaunay::new(points(&[(0, 0), (1_000_001, 0), (0, 1)]));
+}
+
+#[test]
+fn test_voronoi_edges() {
+    let delaunay = Delaunay::new(points(&[(0, 0), (4, 0), (2, 3), (2, -3)]));
+    check_delaunay(&delaunay);
+    let vertices = delaunay.voronoi_vertices();
+    for edge in delaunay.voronoi_edges() {
+        let [a, b] = edge.sites;
+        let (a, b) = (delaunay.points()[a], delaunay.points()[b]);
+        for vertex in edge.vertices.iter().flatten() {
+            // Voronoi vertices are equidistant from the sites
+            assert_eq!(sq_dist(vertices[*vertex], a), sq_dist(vertices[*vertex], b));
+        }
+        if let [Some(u), Some(v)] = edge.vertices {
+            // The first site is on the left side of the edge
+            let ordering = Point::ordering([vertices[u], vertices[v], a]);
+            assert!(
+                vertices[u] == vertices[v]
+                    || ordering == comlib_geometry::Ordering::Counterclockwise
+            );
+        }
+    }
+}
+
+#[test]
+fn test_random() {
+    let mut random = lcg(3);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..200 {
+        let n = 1 + next(30) as usize;
+        let range = 1 + next(10) as u64;
+        let input: Vec<_> = (0..n).map(|_| (next(range) - 3, next(range) - 5)).collect();
+        let delaunay = Delaunay::new(points(&input));
+        check_delaunay(&delaunay);
+
+        for _ in 0..10 {
+            let p = Point::from((next(2 * range) - 5, next(2 * range) - 7));
+            let nearest = delaunay.nearest_site(p);
+            let best = delaunay
+                .points()
+                .iter()
+                .map(|&q| sq_dist(p, q))
+                .min()
+                .unwrap();
+            assert_eq!(sq_dist(p, delaunay.points()[nearest]), best);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use comlib_geometry::{convex_hull_3d, Point3, Polyhedron};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+use std::collections::HashSet;
+
+fn points(points: &[(i64, i64, i64)]) -> Vec<Point3> {
+    points.iter().copied().map(Point3::from).collect()
+}
+
+fn sub(a: Point3, b: Point3) -> [i128; 3] {
+    [
+        (a.x - b.x) as i128,
+        (a.y - b.y) as i128,
+        (a.z - b.z) as i128,
+    ]
+}
+
+/// Checks that the faces form a closed surface with all of the points behind every face.
+fn check_hull(hull: &Polyhedron) {
+    let points = hull.points();
+    let edges: HashSet<_> = hull
+        .faces()
+        .iter()
+        .flat_map(|&[a, b, c]| vec![(a, b), (b, c), (c, a)])
+        .collect();
+    assert_eq!(edges.len(), 3 * hull.faces().len());
+    for &(a, b) in &edges {
+        assert!(edges.contains(&(b, a)));
+    }
+
+    for &[a, b, c] in hull.faces() {
+        let [ux, uy, uz] = sub(points[b], points[a]);
+        let [vx, vy, vz] = sub(points[c], points[a]);
+        let normal = [uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx];
+        assert_ne!(normal, [0; 3]);
+        for &p in points {
+            let w = sub(p, points[a]);
+            let dot: i128 = normal.iter().zip(&w).map(|(a, b)| a * b).sum();
+            assert!(dot <= 0);
+        }
+    }
+}
+
+#[test]
+fn test_cube() {
+    let mut cube = Vec::new();
+    for x in 0..=2 {
+        for y in 0..=2 {
+            for z in 0..=2 {
+                cube.push((x, y, z));
+            }
+        }
+    }
+    let hull = convex_hull_3d(points(&cube)).unwrap();
+    check_hull(&hull);
+    assert_eq!(hull.volume(), Quot::from(8));
+    assert!((hull.surface_area() - 24.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_large_coordinates() {
+    let big = 1_000_000_000_000;
+    let mut cube = Vec::new();
+    for &x in &[-big, big] {
+        for &y in &[-big, big] {
+            for &z in &[-big, big] {
+                cube.push((x, y, z));
+            }
+        }
+    }
+    let hull = convex_hull_3d(points(&cube)).unwrap();
+    check_hull(&hull);
+    assert_eq!(hull.volume(), Quot::from(8 * (big as i128).pow(3)));
+}
+
+#[test]
+fn test_degenerate() {
+    assert!(convex_hull_3d(Vec::new()).is_none());
+    assert!(convex_hull_3d(points(&[(1, 2, 3), (1, 2, 3)])).is_none());
+    assert!(convex_hull_3d(points(&[(0, 0, 0), (1, 1, 1), (2, 2, 2), (5, 5, 5)])).is_none());
+    assert!(convex_hull_3d(points(&[(0, 0, 0), (1, 0, 0), (0, 1, 0), (3, 7, 0)])).is_none());
+}
+
+#[test]
+fn test_random() {
+    let mut random = lcg(7);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..200 {
+        let n = 4 + next(40) as usize;
+        let range = 1 + next(10);
+        let mut input = Vec::new();
+        for _ in 0..n {
+            input.push((next(2 * range as u64 + 1), next(5), next(5)));
+        }
+        if let Some(hull) = convex_hull_3d(points(&input)) {
+            check_hull(&hull);
+            assert!(hull.volume() > Quot::from(0));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use comlib_geometry::{Delaunay, Point, PointLocator, Segment};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+/// Finds the height at which a vertical line through the point hits the segment, if it does.
+fn hit(segment: Segment<i64>, p: Point<i64>, upwards: bool) -> Option<Quot<i64>> {
+    let (a, b) = (segment.start(), segment.end());
+    let x = p.x();
+    if a.x() == b.x() {
+        if a.x() != x {
+            return None;
+        }
+        let (low, high) = if a.y() < b.y() {
+            (a.y(), b.y())
+        } else {
+            (b.y(), a.y())
+        };
+        let y = if p.y() < low {
+            low
+        } else if p.y() > high {
+            high
+        } else {
+            p.y()
+        };
+        return Some(y);
+    }
+    let (a, b) = if a.x() < b.x() { (a, b) } else { (b, a) };
+    if x < a.x() || x > b.x() {
+        return None;
+    }
+    let y = a.y() + (b.y() - a.y()) * (x - a.x()) / (b.x() - a.x());
+    if (upwards && y >= p.y()) || (!upwards && y <= p.y()) {
+        Some(y)
+    } else {
+        None
+    }
+}
+
+fn check(segments: &[Segment<i64>], locator: &PointLocator<i64>, p: Point<i64>) {
+    for &upwards in &[false, true] {
+        let hits: Vec<_> = segments
+            .iter()
+            .map(|&s| hit(s, p, upwards).filter(|&y| (y >= p.y()) == upwards || y == p.y()))
+            .collect();
+        let best = hits
+            .iter()
+            .flatten()
+            .copied()
+            .reduce(|a, b| if (a < b) == upwards { a } else { b });
+        let found = if upwards {
+            locator.segment_above(p)
+        } else {
+            locator.segment_below(p)
+        };
+        assert_eq!(found.and_then(|i| hits[i]), best, "{:?} {}", p, upwards);
+    }
+}
+
+#[test]
+fn test_empty() {
+    let locator = PointLocator::<i64>::new(Vec::new());
+    assert_eq!(locator.segment_below((0, 0)), None);
+    assert_eq!(locator.segment_above((0, 0)), None);
+}
+
+#[test]
+fn test_vertical() {
+    let segments = vec![
+        Segment::between((0, 0), (0, 2)).unwrap(),
+        Segment::between((0, 5), (0, 3)).unwrap(),
+        Segment::between((-1, 4), (1, 4)).unwrap(),
+        Segment::between((0, 6), (2, 6)).unwrap(),
+    ];
+    let locator = PointLocator::new(segments);
+    assert_eq!(locator.segment_below((0, 1)), Some(0));
+    assert_eq!(locator.segment_above((0, 1)), Some(0));
+    assert_eq!(
+        locator.segment_below(Point::try_new(0, 5, 2).unwrap()),
+        Some(0)
+    );
+    assert_eq!(
+        locator.segment_above(Point::try_new(0, 5, 2).unwrap()),
+        Some(1)
+    );
+    assert_eq!(locator.segment_above((0, 7)), None);
+    assert_eq!(locator.segment_above((1, 5)), Some(3));
+    assert_eq!(locator.segment_below((1, 5)), Some(2));
+    assert_eq!(locator.segment_below((-1, 0)), None);
+}
+
+#[test]
+fn test_triangulation() {
+    let mut random = lcg(11);
+    let mut next = |m: u64| random(m) as i64;
+    for _ in 0..50 {
+        let n = 1 + next(30) as usize;
+        let points: Vec<Point<i64>> = (0..n).map(|_| Point::from((next(15), next(15)))).collect();
+        let delaunay = Delaunay::new(points);
+        let segments: Vec<_> = delaunay
+            .edges()
+            .into_iter()
+            .map(|(a, b)| Segment::between(delaunay.points()[a], delaunay.points()[b]).unwrap())
+            .collect();
+        let locator = PointLocator::new(segments.clone());
+        for _ in 0..100 {
+            let p = Point::try_new(next(70) - 4, next(70) - 4, 4).unwrap();
+            check(&segments, &locator, p);
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use comlib_geometry::{
+    convex_hull, convex_intersection, intersection_area, Containment, Ordering, Point, Polygon,
+    Segment, SegmentIntersection,
+};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+fn polygon(points: &[(i64, i64)]) -> Polygon<i64> {
+    Polygon::from(points.iter().map(|&p| Point::from(p)).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_contains_square() {
+    let square = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+    assert_eq!(square.contains((1, 1)), Containment::Inside);
+    assert_eq!(square.contains((0, 0)), Containment::OnBoundary);
+    assert_eq!(square.contains((1, 0)), Containment::OnBoundary);
+    assert_eq!(square.contains((2, 1)), Containment::OnBoundary);
+    assert_eq!(square.contains((3, 1)), Containment::Outside);
+    assert_eq!(square.contains((-1, 0)), Containment::Outside);
+    assert_eq!(square.contains((3, 2)), Containment::Outside);
+    assert_eq!(
+        square.contains(Point::try_new(1, 1, 3).unwrap()),
+        Containment::Inside
+    );
+}
+
+#[test]
+fn test_contains_orientation_independent() {
+    let points = [(0, 0), (6, 0), (6, 6), (3, 2), (0, 6)];
+    let ccw = polygon(&points);
+    let mut reversed = points.to_vec();
+    reversed.reverse();
+    let cw = polygon(&reversed);
+    for x in -1..=7 {
+        for y in -1..=7 {
+            assert_eq!(ccw.contains((x, y)), cw.contains((x, y)), "{} {}", x, y);
+        }
+    }
+}
+
+#[test]
+fn test_contains_concave() {
+    // A comb with vertices at the height of the query points
+    let comb = polygon(&[
+        (0, 0),
+        (6, 0),
+        (6, 4),
+        (5, 4),
+        (4, 2),
+        (3, 4),
+        (2, 2),
+        (1, 4),
+        (0, 4),
+    ]);
+    assert_eq!(comb.contains((1, 2)), Containment::Inside);
+    assert_eq!(comb.contains((2, 2)), Containment::OnBoundary);
+    assert_eq!(comb.contains((3, 3)), Containment::Inside);
+    assert_eq!(comb.contains((2, 3)), Containment::Outside);
+    assert_eq!(comb.contains((4, 3)), Containment::Outside);
+    assert_eq!(comb.contains((-1, 2)), Containment::Outside);
+    assert_eq!(comb.contains((5, 2)), Containment::Inside);
+    assert_eq!(comb.contains((3, 4)), Containment::OnBoundary);
+    assert_eq!(comb.contains((2, 4)), Containment::Outside);
+}
+
+#[test]
+fn test_contains_float() {
+    let triangle = Polygon::from(vec![
+        Point::from((0.0, 0.0)),
+        Point::from((4.0, 0.0)),
+        Point::from((0.0, 4.0)),
+    ]);
+    assert_eq!(triangle.contains((1.0, 1.0)), Containment::Inside);
+    assert_eq!(triangle.contains((2.0, 2.0)), Containment::OnBoundary);
+    assert_eq!(triangle.contains((3.0, 3.0)), Containment::Outside);
+}
+
+#[test]
+fn test_contains_convex_matches_contains() {
+    let polygons = [
+        polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]),
+        // Collinear vertices on the first, last and middle edges
+        polygon(&[
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 1),
+            (3, 3),
+            (2, 3),
+            (1, 2),
+            (0, 1),
+        ]),
+        polygon(&[(-2, -1), (5, 0), (1, 4)]),
+        convex_hull(vec![
+            Point::from((0, 0)),
+            Point::from((3, 0)),
+            Point::from((6, 0)),
+            Point::from((6, 3)),
+            Point::from((3, 6)),
+            Point::from((0, 6)),
+            Point::from((0, 3)),
+            Point::from((2, 2)),
+        ])
+        .unwrap(),
+    ];
+    for polygon in &polygons {
+        for x in -3..=7 {
+            for y in -3..=7 {
+                assert_eq!(
+                    polygon.contains_convex((x, y)),
+                    polygon.contains((x, y)),
+                    "{:?} {} {}",
+                    polygon.points().collect::<Vec<_>>(),
+                    x,
+                    y
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_contains_convex_degenerate() {
+    let empty = polygon(&[]);
+    assert_eq!(empty.contains_convex((0, 0)), Containment::Outside);
+    let point = polygon(&[(1, 1)]);
+    assert_eq!(point.contains_convex((1, 1)), Containment::OnBoundary);
+    assert_eq!(point.contains_convex((1, 2)), Containment::Outside);
+    let segment = polygon(&[(0, 0), (2, 2)]);
+    assert_eq!(segment.contains_convex((1, 1)), Containment::OnBoundary);
+    assert_eq!(segment.contains_convex((3, 3)), Containment::Outside);
+    assert_eq!(segment.contains_convex((1, 0)), Containment::Outside);
+}
+
+#[test]
+fn test_is_convex() {
+    assert!(polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]).is_convex());
+    assert!(polygon(&[(0, 2), (2, 2), (2, 0), (0, 0)]).is_convex());
+    assert!(polygon(&[(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)]).is_convex());
+    assert!(!polygon(&[(0, 0), (2, 0), (1, 0), (2, 2), (0, 2)]).is_convex());
+    assert!(!polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]).is_convex());
+    assert!(!polygon(&[(0, 0), (2, 0), (2, 0), (0, 2)]).is_convex());
+    assert!(!polygon(&[(0, 0), (1, 1), (2, 2)]).is_convex());
+    assert!(!polygon(&[(0, 0), (2, 2)]).is_convex());
+    // Pentagram turns consistently but winds around twice
+    assert!(!polygon(&[(0, 3), (2, -3), (-3, 1), (3, 1), (-2, -3)]).is_convex());
+}
+
+#[test]
+fn test_is_simple() {
+    assert!(polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]).is_simple());
+    assert!(polygon(&[(0, 0), (4, 0), (4, 4), (2, 1), (0, 4)]).is_simple());
+    assert!(polygon(&[(0, 0), (1, 0), (2, 0), (2, 2), (0, 2)]).is_simple());
+    assert!(!polygon(&[(0, 0), (2, 2), (2, 0), (0, 2)]).is_simple());
+    assert!(!polygon(&[(0, 0), (2, 0), (1, 0), (2, 2), (0, 2)]).is_simple());
+    assert!(!polygon(&[(0, 0), (1, 1), (2, 2)]).is_simple());
+    assert!(!polygon(&[(0, 3), (2, -3), (-3, 1), (3, 1), (-2, -3)]).is_simple());
+    // Vertex touching a non-adjacent edge
+    assert!(!polygon(&[(0, 0), (4, 0), (4, 4), (2, 0), (0, 4)]).is_simple());
+    // Repeated vertex
+    assert!(!polygon(&[(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 1)]).is_simple());
+}
+
+fn brute_force_is_simple(points: &[(i64, i64)]) -> bool {
+    let n = points.len();
+    let points: Vec<Point<i64>> = points.iter().map(|&p| Point::from(p)).collect();
+    for i in 0..n {
+        if points[i] == points[(i + 1) % n] {
+            return false;
+        }
+    }
+    for i in 0..n {
+        for j in i + 1..n {
+            let s1 = Segment::between(points[i], points[(i + 1) % n]).unwrap();
+            let s2 = Segment::between(points[j], points[(j + 1) % n]).unwrap();
+            let allowed = if (i + 1) % n == j {
+                Some(points[j])
+            } else if (j + 1) % n == i {
+                Some(points[i])
+            } else {
+                None
+            };
+            match (s1.intersect(s2), allowed) {
+                (SegmentIntersection::None, _) => {}
+                (SegmentIntersection::Point(p), Some(allowed)) if p == allowed => {}
+                _ => return false,
+            }
+        }
+    }
+    true
+}
+
+#[test]
+fn test_is_simple_matches_brute_force() {
+    let mut next = lcg(12345);
+    for _ in 0..20000 {
+        let n = 3 + next(5) as usize;
+        let points: Vec<(i64, i64)> = (0..n).map(|_| (next(4) as i64, next(4) as i64)).collect();
+        assert_eq!(
+            polygon(&points).is_simple(),
+            brute_force_is_simple(&points),
+            "{:?}",
+            points
+        );
+    }
+}
+
+#[test]
+fn test_orientation() {
+    let square = [(0, 0), (2, 0), (2, 2), (0, 2)];
+    assert_eq!(polygon(&square).orientation(), Ordering::Counterclockwise);
+    let mut reversed = square.to_vec();
+    reversed.reverse();
+    assert_eq!(polygon(&reversed).orientation(), Ordering::Clockwise);
+    assert_eq!(
+        polygon(&[(0, 0), (1, 1), (2, 2)]).orientation(),
+        Ordering::Collinear
+    );
+}
+
+#[test]
+fn test_centroid() {
+    assert_eq!(
+        polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]).centroid(),
+        Some(Point::from((1, 1)))
+    );
+    // The centroid doesn't depend on the orientation or the starting vertex
+    let triangle = [(0, 0), (5, 1), (1, 4)];
+    let expected = Point::try_new(6, 5, 3);
+    assert_eq!(polygon(&triangle).centroid(), expected);
+    assert_eq!(polygon(&[(1, 4), (5, 1), (0, 0)]).centroid(), expected);
+    assert_eq!(polygon(&[(5, 1), (1, 4), (0, 0)]).centroid(), expected);
+    // The centroid of a concave polygon may lie outside of it
+    let v = polygon(&[(0, 0), (3, 3), (0, 1), (-3, 3)]);
+    assert_eq!(v.centroid(), Point::try_new(0, 4, 3));
+    assert_eq!(polygon(&[(0, 0), (1, 1), (2, 2)]).centroid(), None);
+}
+
+#[test]
+fn test_perimeter() {
+    assert_eq!(polygon(&[(0, 0), (3, 0), (3, 4)]).perimeter(), 12.0);
+    assert_eq!(polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]).perimeter(), 8.0);
+    assert_eq!(polygon(&[(1, 1), (1, 1)]).perimeter(), 0.0);
+}
+
+#[test]
+fn test_indexing() {
+    let p = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+    assert_eq!(p.len(), 4);
+    assert!(!p.is_empty());
+    assert_eq!(p[1], Point::from((2, 0)));
+    assert_eq!(p.cyclic(5), p[1]);
+    assert_eq!(p.cyclic(-5), p[3]);
+    assert_eq!(p.points().len(), 4);
+    assert_eq!(p.points().next_back(), Some(Point::from((0, 2))));
+    assert_eq!(p.clone().into_points(), p.points().collect::<Vec<_>>());
+    assert!(polygon(&[]).is_empty());
+}
+
+#[test]
+fn test_make_ccw() {
+    let mut p = polygon(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+    p.make_ccw();
+    assert_eq!(p, polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+    p.make_ccw();
+    assert_eq!(p, polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]));
+}
+
+#[test]
+fn test_normalize_start() {
+    let expected = polygon(&[(0, 0), (2, 0), (2, 2), (0, 2)]);
+    let mut hull = convex_hull(vec![
+        Point::from((2, 2)),
+        Point::from((0, 2)),
+        Point::from((1, 1)),
+        Point::from((2, 0)),
+        Point::from((0, 0)),
+    ])
+    .unwrap();
+    hull.normalize_start();
+    assert_eq!(hull, expected);
+
+    let mut p = polygon(&[(2, 2), (0, 2), (0, 0), (2, 0)]);
+    p.normalize_start();
+    assert_eq!(p, expected);
+
+    let mut p = polygon(&[(2, 0), (0, 0), (0, 2), (2, 2)]);
+    p.make_ccw();
+    p.normalize_start();
+    assert_eq!(p, expected);
+}
+
+#[test]
+fn test_convex_intersection() {
+    let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+    let triangle = polygon(&[(2, -2), (6, 2), (2, 6)]);
+    let mut intersection = convex_intersection(&square, &triangle).unwrap();
+    intersection.normalize_start();
+    assert_eq!(intersection, polygon(&[(2, 0), (4, 0), (4, 4), (2, 4)]));
+    assert_eq!(intersection_area(&square, &triangle), Quot::from(8));
+    assert_eq!(intersection_area(&triangle, &square), Quot::from(8));
+
+    // Non-integral vertices
+    let triangle = polygon(&[(1, 1), (5, 1), (1, 5)]);
+    let small = polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+    assert_eq!(
+        intersection_area(&small, &triangle),
+        Quot::new(11, 2).unwrap()
+    );
+    let thin = polygon(&[(0, 0), (3, 1), (0, 1)]);
+    assert_eq!(
+        intersection_area(&thin, &polygon(&[(1, 0), (2, 0), (2, 2), (1, 2)])),
+        Quot::new(1, 2).unwrap()
+    );
+
+    // Containment and touching
+    let normalized_intersection = |a: &Polygon<i64>, b: &Polygon<i64>| {
+        let mut intersection = convex_intersection(a, b)?;
+        intersection.normalize_start();
+        Some(intersection)
+    };
+    let inner = polygon(&[(1, 1), (2, 1), (2, 2)]);
+    assert_eq!(
+        normalized_intersection(&square, &inner),
+        Some(inner.clone())
+    );
+    assert_eq!(
+        normalized_intersection(&inner, &square),
+        Some(inner.clone())
+    );
+    assert_eq!(
+        normalized_intersection(&square, &square),
+        Some(square.clone())
+    );
+    let touching = polygon(&[(4, 0), (8, 0), (8, 4), (4, 4)]);
+    assert_eq!(convex_intersection(&square, &touching), None);
+    assert_eq!(intersection_area(&square, &touching), Quot::from(0));
+    let corner = polygon(&[(4, 4), (5, 4), (4, 5)]);
+    assert_eq!(convex_intersection(&square, &corner), None);
+    let far = polygon(&[(10, 10), (11, 10), (10, 11)]);
+    assert_eq!(convex_intersection(&square, &far), None);
+}
+
+#[test]
+fn test_convex_intersection_random() {
+    let mut next = lcg(4242);
+    let random_hull = |next: &mut dyn FnMut(u64) -> u64| loop {
+        let mut points: Vec<Point<i64>> = (0..6)
+            .map(|_| Point::from((next(6) as i64, next(6) as i64)))
+            .collect();
+        points.sort();
+        points.dedup();
+        if let Some(hull) = convex_hull(points) {
+            if hull.is_convex() {
+                return hull;
+            }
+        }
+    };
+    for _ in 0..300 {
+        let a = random_hull(&mut next);
+        let b = random_hull(&mut next);
+        let intersection = convex_intersection(&a, &b);
+        if let Some(intersection) = &intersection {
+            assert!(intersection.is_convex());
+            assert_eq!(intersection.orientation(), Ordering::Counterclockwise);
+        }
+        for x in -1..=13 {
+            for y in -1..=13 {
+                let p = Point::try_new(x, y, 2).unwrap();
+                let (in_a, in_b) = (a.contains_convex(p), b.contains_convex(p));
+                let in_intersection = intersection
+                    .as_ref()
+                    .map_or(Containment::Outside, |intersection| {
+                        intersection.contains(p)
+                    });
+                if in_a == Containment::Inside && in_b == Containment::Inside {
+                    assert_eq!(in_intersection, Containment::Inside);
+                } else if in_a == Containment::Outside || in_b == Containment::Outside {
+                    assert_eq!(in_intersection, Containment::Outside);
+                } else {
+                    assert_ne!(in_intersection, Containment::Inside);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use comlib_geometry::{Line, Point, Segment, SegmentIntersection};
+use comlib_geometry::{
+    Line, LineIntersection, Ordering, Point, Segment, SegmentIntersection, Unit,
+};
+use comlib_math::Quot;
+use std::collections::HashSet;
+use std::num::{NonZeroI128, NonZeroI64};
 
 // TODO: Check vector operations
 
@@ -21,8 +26,48 @@ fn test_point_equality() {
 
     assert_eq!(Point::from((1, 3)), Point::try_new(2, 6, 2).unwrap());
     assert_eq!(
-        Point::try_new(3, 9, 3).unwrap(),
-        Point::try_new(2, 6, 2).unwrap()
+        Point::<i64>::try_new(3, 9, 3).unwrap(),
+        Point::<i64>::try_new(2, 6, 2).unwrap()
+    );
+}
+
+#[test]
+fn test_point_hash_and_order() {
+    let points: HashSet<_> = vec![
+        Point::from((1, 3)),
+        Point::try_new(2, 6, 2).unwrap(),
+        Point::try_new(-3, -9, -3).unwrap(),
+        Point::from((3, 1)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(points.len(), 2);
+
+    assert_eq!(
+        Point::from((1, 3)).cmp(&Point::try_new(-2, -6, -2).unwrap()),
+        std::cmp::Ordering::Equal
+    );
+    assert!(Point::from((1, 3)) < Point::from((2, 0)));
+    assert!(Point::from((1, 3)) < Point::from((1, 4)));
+    assert!(Point::try_new(1, 3, 2).unwrap() < Point::from((1, 3)));
+    assert!(Point::try_new(-1, 0, 2).unwrap() < Point::from((0, 0)));
+}
+
+#[test]
+fn test_line_hash() {
+    let lines: HashSet<_> = vec![
+        Line::spanned_by((1, 1), (3, 5)),
+        Line::spanned_by((3, 5), (1, 1)),
+        Line::spanned_by((-2, -5), (-1, -3)),
+        Line::spanned_by((0, 0), (1, 1)),
+        Line::spanned_by((0, 0), (1, 2)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(lines.len(), 3);
+    assert_ne!(
+        Line::spanned_by((0, 0), (1, 1)),
+        Line::spanned_by((0, 0), (1, 2))
     );
 }
 
@@ -45,6 +90,154 @@ fn test_line_intersect() {
     l1.intersect(l2).unwrap_line();
 }
 
+#[test]
+fn test_f64_coordinates() {
+    let p = |x: f64, y: f64| Point::new(x, y, Unit);
+    // Coordinates near 1e9 are not representable precisely in f32
+    let q = p(1e9 + 0.5, -1e9 - 0.25);
+    assert_eq!(q.to_f64_pair(), (1e9 + 0.5, -1e9 - 0.25));
+    assert_eq!(Point::from((3, 4)).to_f64(), p(3., 4.));
+
+    assert_eq!(
+        Point::ordering([p(0., 0.), p(1e9, 1.), p(2e9, 2.)]),
+        Ordering::Collinear
+    );
+    assert_eq!(
+        Point::ordering([p(0., 0.), p(1e9, 1.), p(2e9, 3.)]),
+        Ordering::Counterclockwise
+    );
+    // Rounding errors don't break collinearity
+    assert_eq!(
+        Point::ordering([p(0.1, 0.1), p(0.2, 0.2), p(0.3, 0.3)]),
+        Ordering::Collinear
+    );
+
+    let l1 = Line::spanned_by(p(1., -2.), p(3., 3.));
+    let l2 = Line::spanned_by(p(1., 1.), p(2., -2.));
+    let (x, y) = l1.intersect(l2).unwrap_point().to_f64_pair();
+    assert!((x - 17. / 11.).abs() < 1e-9);
+    assert!((y + 7. / 11.).abs() < 1e-9);
+}
+
+#[test]
+fn test_ordering_large_coordinates() {
+    let big = 1_000_000_000_000_000_000;
+    assert_eq!(
+        Point::ordering([(-big, -big), (big, big - 1), (big - 1, big)]),
+        Ordering::Counterclockwise
+    );
+    assert_eq!(
+        Point::ordering([(-big, -big), (big - 1, big), (big, big - 1)]),
+        Ordering::Clockwise
+    );
+    assert_eq!(
+        Point::ordering([(-big, -big), (0, 0), (big, big)]),
+        Ordering::Collinear
+    );
+    // Rational points with large divisors
+    let p = |x: i64, y: i64, z: i64| Point::new(x, y, NonZeroI64::new(z).unwrap());
+    assert_eq!(
+        Point::ordering([p(1, 1, big), p(2, 2, big - 1), p(3, 3, big - 2)]),
+        Ordering::Collinear
+    );
+    assert_eq!(
+        Point::ordering([p(1, 1, big), p(2, 2, big - 1), p(3, 4, big - 2)]),
+        Ordering::Counterclockwise
+    );
+}
+
+#[test]
+fn test_ordering_bounds() {
+    let bound = (1 << 62) - 1;
+    assert_eq!(
+        Point::ordering([(-bound, -bound), (bound, -bound), (-bound, bound)]),
+        Ordering::Counterclockwise
+    );
+    assert_eq!(
+        Point::ordering([(-bound, bound), (bound, -bound), (-bound, -bound)]),
+        Ordering::Clockwise
+    );
+}
+
+#[test]
+#[should_panic(expected = "fit in the wide type")]
+fn test_ordering_overflow_panics() {
+    let (min, max) = (i64::MIN + 1, i64::MAX);
+    Point::ordering([(min, min), (max, min), (min, max)]);
+}
+
+#[test]
+fn test_i128_coordinates() {
+    let p = |x: i64, y: i64| Point::from((x, y)).to_i128();
+    let (a, b, c) = (p(0, 0), p(i64::MAX, 1), p(i64::MAX, 2));
+    assert_eq!(Point::ordering([a, b, c]), Ordering::Counterclockwise);
+    assert_eq!(Point::ordering([a, c, b]), Ordering::Clockwise);
+    assert_eq!(b.x(), Quot::from(i64::MAX as i128));
+    assert_eq!(
+        Line::spanned_by(a, b).intersect(Line::spanned_by(p(0, 1), p(2, 1))),
+        LineIntersection::Point(Point::new(
+            i64::MAX as i128,
+            1,
+            NonZeroI128::new(1).unwrap()
+        ))
+    );
+}
+
+#[test]
+fn test_line_parallel_and_perpendicular() {
+    let line = Line::spanned_by((0, 1), (2, 2));
+    let parallel = line.parallel_through((1, -1));
+    assert_eq!(parallel, Line::spanned_by((1, -1), (3, 0)));
+    assert_eq!(line.intersect(parallel), LineIntersection::None);
+    assert_eq!(line.parallel_through((4, 3)), line);
+
+    let perpendicular = line.perpendicular_through((1, -1));
+    assert_eq!(perpendicular, Line::spanned_by((1, -1), (0, 1)));
+    assert_eq!(
+        line.intersect(perpendicular),
+        LineIntersection::Point(Point::from((0, 1)))
+    );
+
+    // Rational points
+    let p = Point::<i64>::try_new(1, 1, 2).unwrap();
+    let vertical = Line::spanned_by((0, 0), (0, 1)).parallel_through(p);
+    assert!(vertical.contains(p));
+    assert!(vertical.contains(Point::<i64>::try_new(1, 7, 2).unwrap()));
+    assert_eq!(
+        vertical.perpendicular_through(p).slope(),
+        Some(Quot::from(0))
+    );
+}
+
+#[test]
+fn test_line_slope_and_intercepts() {
+    let q = |n: i64, d: i64| Quot::new(n, d).unwrap();
+    let line = Line::spanned_by((1, 1), (4, 3));
+    assert_eq!(line.slope(), Some(q(2, 3)));
+    assert_eq!(line.y_intercept(), Some(q(1, 3)));
+    assert_eq!(line.x_intercept(), Some(q(-1, 2)));
+    assert_eq!(line.y_at(q(7, 1)), Some(q(5, 1)));
+    assert_eq!(line.x_at(q(5, 1)), Some(q(7, 1)));
+
+    let vertical = Line::spanned_by((2, 0), (2, 5));
+    assert_eq!(vertical.slope(), None);
+    assert_eq!(vertical.y_intercept(), None);
+    assert_eq!(vertical.x_intercept(), Some(q(2, 1)));
+    assert_eq!(vertical.y_at(q(2, 1)), None);
+    assert_eq!(vertical.x_at(q(9, 1)), Some(q(2, 1)));
+
+    let horizontal = Line::spanned_by((0, -3), (5, -3));
+    assert_eq!(horizontal.slope(), Some(q(0, 1)));
+    assert_eq!(horizontal.x_intercept(), None);
+    assert_eq!(horizontal.y_at(q(100, 1)), Some(q(-3, 1)));
+    assert_eq!(horizontal.x_at(q(-3, 1)), None);
+
+    let float = Line::spanned_by((0.0, 1.0), (2.0, 2.0));
+    assert_eq!(float.slope(), Some(0.5));
+    assert_eq!(float.y_at(4.0), Some(3.0));
+    assert_eq!(Line::spanned_by((1.0, 0.0), (1.0, 2.0)).slope(), None);
+}
+
 #[test]
 fn test_line_closest_point_to() {
     let l1 = Line::spanned_by((1, 3), (2, 1));
@@ -65,6 +258,64 @@ fn test_line_closest_point_to() {
     assert_eq!(l3.closest_point_to((-123, 45)), Point::from((3, 45)));
 }
 
+#[test]
+fn test_line_project() {
+    let line = Line::spanned_by((1, 3), (2, 1));
+    assert_eq!(line.project((7, 1)), Point::from((3, -1)));
+    // Points with non-unit divisor
+    assert_eq!(
+        line.project(Point::try_new(14, 2, 2).unwrap()),
+        Point::from((3, -1))
+    );
+    assert_eq!(
+        line.project(Point::try_new(1, 1, 2).unwrap()),
+        Point::try_new(19, 12, 10).unwrap()
+    );
+    for x in -5..=5 {
+        for y in -5..=5 {
+            for z in 1..=3 {
+                let p = Point::try_new(x, y, z).unwrap();
+                let q = line.project(p);
+                assert!(line.contains(q));
+                assert_eq!(line.project(q), q);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_line_reflect() {
+    let line = Line::spanned_by((0, 1), (1, 1));
+    assert_eq!(line.reflect((3, 4)), Point::from((3, -2)));
+    assert_eq!(line.reflect((3, 1)), Point::from((3, 1)));
+
+    let line = Line::spanned_by((0, 0), (2, 1));
+    assert_eq!(line.reflect((0, 5)), Point::from((4, -3)));
+    assert_eq!(line.reflect((1, 0)), Point::try_new(3, 4, 5).unwrap());
+    for x in -5..=5 {
+        for y in -5..=5 {
+            let p = Point::try_new(x, y, 3).unwrap();
+            assert_eq!(line.reflect(line.reflect(p)), p);
+        }
+    }
+}
+
+#[test]
+fn test_point_reflect_across() {
+    assert_eq!(
+        Point::from((1, 2)).reflect_across((0, 0)),
+        Point::from((-1, -2))
+    );
+    assert_eq!(
+        Point::from((1, 2)).reflect_across(Point::try_new(1, 1, 2).unwrap()),
+        Point::from((0, -1))
+    );
+    assert_eq!(
+        Point::from((1.0, 2.0)).reflect_across((3.0, 3.0)),
+        Point::from((5.0, 4.0))
+    );
+}
+
 #[test]
 fn test_segment_intersection() {
     // -2 -5 7 -5 -3 -5 -4 -5
@@ -98,6 +349,68 @@ fn test_segment_intersection() {
     );
 }
 
+#[test]
+fn test_segment_intersect_param() {
+    let q = |n: i64, d: i64| Quot::new(n, d).unwrap();
+    let s = |x1, y1, x2, y2| Segment::between((x1, y1), (x2, y2)).unwrap();
+
+    // Crossing segments
+    assert_eq!(
+        s(0, 0, 3, 3).intersect_param(s(0, 2, 2, 0)),
+        Some((q(1, 3), q(1, 2)))
+    );
+    assert_eq!(
+        s(0, 2, 2, 0).intersect_param(s(0, 0, 3, 3)),
+        Some((q(1, 2), q(1, 3)))
+    );
+    // Touching at endpoints
+    assert_eq!(
+        s(0, 0, 2, 0).intersect_param(s(2, 0, 2, 5)),
+        Some((q(1, 1), q(0, 1)))
+    );
+    assert_eq!(s(0, 0, 2, 0).intersect_param(s(3, 0, 3, 5)), None);
+    assert_eq!(s(0, 0, 2, 0).intersect_param(s(0, 1, 2, 1)), None);
+    // Overlapping segments use the first point of the overlap along self
+    assert_eq!(
+        s(-2, -5, 7, -5).intersect_param(s(-3, -5, -1, -5)),
+        Some((q(0, 1), q(1, 2)))
+    );
+    assert_eq!(
+        s(7, -5, -2, -5).intersect_param(s(-3, -5, -1, -5)),
+        Some((q(8, 9), q(1, 1)))
+    );
+    assert_eq!(
+        s(0, 0, 0, 8).intersect_param(s(0, 6, 0, 2)),
+        Some((q(1, 4), q(1, 1)))
+    );
+}
+
+#[test]
+fn test_segment_contains() {
+    let segment = Segment::between((1, 1), (7, 4)).unwrap();
+    assert!(segment.contains((1, 1)));
+    assert!(segment.contains((7, 4)));
+    assert!(segment.contains((3, 2)));
+    assert!(segment.contains(Point::<i64>::try_new(4, 3, 2).unwrap()));
+    assert!(!segment.contains((9, 5)));
+    assert!(!segment.contains((-1, 0)));
+    assert!(!segment.contains((3, 3)));
+
+    let vertical = Segment::between((2, 5), (2, -1)).unwrap();
+    assert!(vertical.contains((2, 0)));
+    assert!(!vertical.contains((2, 6)));
+    assert!(!vertical.contains((3, 0)));
+}
+
+#[test]
+fn test_segment_accessors() {
+    let segment = Segment::between((4, 1), (1, 2)).unwrap();
+    assert_eq!(segment.start(), Point::from((4, 1)));
+    assert_eq!(segment.end(), Point::from((1, 2)));
+    assert_eq!(segment.midpoint(), Point::<i64>::try_new(5, 3, 2).unwrap());
+    assert!(segment.contains(segment.midpoint()));
+}
+
 #[test]
 fn test_segment_equality() {
     assert_eq!(
@@ -0,0 +1,63 @@
+use comlib_geometry::{Point, RectangleCounter};
+use comlib_math::Quot;
+use comlib_testing::lcg;
+
+#[test]
+fn test_count_matches_brute_force() {
+    let mut next = lcg(2024);
+    for _ in 0..50 {
+        let n = next(40) as usize;
+        let points: Vec<Point<i64>> = (0..n)
+            .map(|_| Point::from((next(10) as i64, next(10) as i64)))
+            .collect();
+        let counter = RectangleCounter::new(&points);
+        assert_eq!(counter.len(), n);
+        for _ in 0..100 {
+            let (x1, x2) = (next(12) as i64 - 1, next(12) as i64 - 1);
+            let (y1, y2) = (next(12) as i64 - 1, next(12) as i64 - 1);
+            let expected = points
+                .iter()
+                .filter(|p| {
+                    let (x, y) = (p.x(), p.y());
+                    Quot::from(x1) <= x
+                        && x <= Quot::from(x2)
+                        && Quot::from(y1) <= y
+                        && y <= Quot::from(y2)
+                })
+                .count();
+            assert_eq!(
+                counter.count(
+                    Quot::from(x1),
+                    Quot::from(x2),
+                    Quot::from(y1),
+                    Quot::from(y2)
+                ),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_count_rational_and_float() {
+    let points = vec![Point::try_new(1, 1, 2).unwrap(), Point::from((1, 1))];
+    let counter = RectangleCounter::new(&points);
+    let half = Quot::new(1, 2).unwrap();
+    assert_eq!(counter.count(half, half, half, half), 1);
+    assert_eq!(
+        counter.count(Quot::from(0), Quot::from(1), half, Quot::from(1)),
+        2
+    );
+
+    let points = vec![Point::from((0.5, 0.5)), Point::from((1.5, 0.25))];
+    let counter = RectangleCounter::new(&points);
+    assert_eq!(counter.count(0.0, 1.0, 0.0, 1.0), 1);
+    assert_eq!(counter.count(0.0, 2.0, 0.0, 0.3), 1);
+
+    let empty = RectangleCounter::<i64>::new(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(
+        empty.count(Quot::from(0), Quot::from(1), Quot::from(0), Quot::from(1)),
+        0
+    );
+}
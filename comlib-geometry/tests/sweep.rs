@@ -0,0 +1,108 @@
+use comlib_geometry::{
+    any_segment_intersection, segment_intersections, Point, Segment, SegmentIntersection,
+};
+use comlib_testing::lcg;
+
+fn brute_force(segments: &[Segment<i64>]) -> Vec<(Point<i64>, Vec<usize>)> {
+    let mut candidates: Vec<Point<i64>> = segments
+        .iter()
+        .flat_map(|s| vec![s.start(), s.end()])
+        .collect();
+    for (i, &s1) in segments.iter().enumerate() {
+        for &s2 in &segments[i + 1..] {
+            if let SegmentIntersection::Point(p) = s1.intersect(s2) {
+                candidates.push(p);
+            }
+        }
+    }
+    candidates.sort();
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .filter_map(|p| {
+            let indices: Vec<usize> = (0..segments.len())
+                .filter(|&i| segments[i].contains(p))
+                .collect();
+            if indices.len() >= 2 {
+                Some((p, indices))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn segment(a: (i64, i64), b: (i64, i64)) -> Segment<i64> {
+    Segment::between(a, b).unwrap()
+}
+
+#[test]
+fn test_segment_intersections() {
+    let segments = [
+        segment((0, 0), (4, 4)),
+        segment((0, 4), (4, 0)),
+        // Vertical segment through the crossing
+        segment((2, -1), (2, 5)),
+        // Overlapping segment
+        segment((3, 3), (5, 5)),
+        // Touching at an endpoint
+        segment((4, 0), (6, 0)),
+        // Disjoint
+        segment((10, 10), (11, 12)),
+    ];
+    assert_eq!(
+        segment_intersections(&segments),
+        vec![
+            (Point::from((2, 2)), vec![0, 1, 2]),
+            (Point::from((3, 3)), vec![0, 3]),
+            (Point::from((4, 0)), vec![1, 4]),
+            (Point::from((4, 4)), vec![0, 3]),
+        ]
+    );
+    assert_eq!(segment_intersections::<i64>(&[]), vec![]);
+}
+
+#[test]
+fn test_segment_intersections_matches_brute_force() {
+    let mut next = lcg(31337);
+    for _ in 0..2000 {
+        let n = 1 + next(8) as usize;
+        let range = 3 + next(6);
+        let mut segments = Vec::new();
+        while segments.len() < n {
+            let a = (next(range) as i64, next(range) as i64);
+            let b = (next(range) as i64, next(range) as i64);
+            if let Some(segment) = Segment::between(a, b) {
+                segments.push(segment);
+            }
+        }
+        let expected = brute_force(&segments);
+        assert_eq!(segment_intersections(&segments), expected, "{:?}", segments);
+        assert_eq!(
+            any_segment_intersection(&segments).is_some(),
+            !expected.is_empty(),
+            "{:?}",
+            segments
+        );
+        if let Some((i, j)) = any_segment_intersection(&segments) {
+            assert!(i < j);
+            assert_ne!(
+                segments[i].intersect(segments[j]),
+                SegmentIntersection::None
+            );
+        }
+    }
+}
+
+#[test]
+fn test_many_crossings() {
+    // A grid of horizontal and vertical segments
+    let n = 30;
+    let mut segments = Vec::new();
+    for i in 0..n {
+        segments.push(segment((0, i), (n, i)));
+        segments.push(segment((i, -1), (i, n)));
+    }
+    let intersections = segment_intersections(&segments);
+    assert_eq!(intersections.len(), (n * n) as usize);
+}
@@ -0,0 +1,39 @@
+use comlib_geometry::{Containment, Point, Triangle};
+use comlib_math::Quot;
+
+#[test]
+fn test_area() {
+    let triangle = Triangle::new((1, 1), (4, 2), (2, 5));
+    assert_eq!(triangle.area(), Quot::new(11, 2).unwrap());
+    let triangle = Triangle::new((1, 1), (2, 5), (4, 2));
+    assert_eq!(triangle.area(), Quot::new(-11, 2).unwrap());
+    assert!(!triangle.is_degenerate());
+    assert!(Triangle::new((0, 0), (1, 1), (3, 3)).is_degenerate());
+}
+
+#[test]
+fn test_circumcircle() {
+    let triangle = Triangle::new((0, 0), (4, 0), (0, 2));
+    assert_eq!(triangle.circumcenter(), Some(Point::from((2, 1))));
+    assert_eq!(triangle.sq_circumradius(), Some(Quot::from(5)));
+    let circle = triangle.circumcircle().unwrap();
+    assert_eq!(circle.contains((4, 2)), Containment::OnBoundary);
+
+    let triangle = Triangle::new((0, 0), (1, 1), (3, 3));
+    assert_eq!(triangle.circumcircle(), None);
+    assert_eq!(triangle.circumcenter(), None);
+}
+
+#[test]
+fn test_incircle() {
+    let triangle = Triangle::new((0.0, 0.0), (3.0, 0.0), (0.0, 4.0));
+    assert!((triangle.perimeter() - 12.0).abs() < 1e-6);
+    assert!((triangle.inradius().unwrap() - 1.0).abs() < 1e-6);
+    let (x, y) = triangle.incenter().unwrap().to_f64_pair();
+    assert!((x - 1.0).abs() < 1e-6 && (y - 1.0).abs() < 1e-6);
+
+    assert_eq!(
+        Triangle::new((0.0, 0.0), (1.0, 1.0), (2.0, 2.0)).incircle(),
+        None
+    );
+}
@@ -0,0 +1,197 @@
+use crate::{Adjacency, Graph};
+
+/// Bridges, articulation points and biconnected components of an undirected graph.
+///
+/// Computed with a single depth-first search using lowlinks. Parallel edges and self-loops are handled correctly: a
+/// pair of parallel edges is never a bridge.
+///
+/// - A _bridge_ is an edge whose removal increases the number of connected components.
+/// - An _articulation point_, or cut vertex, is a vertex whose removal increases the number of connected components.
+/// - The _2-edge-connected components_ are the components remaining after removing all bridges.
+/// - The _blocks_, or biconnected components, are the maximal subgraphs without articulation points of their own. Two
+///   blocks share at most one vertex, which is an articulation point. An isolated vertex forms a block on its own.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Biconnectivity, Graph};
+/// // Two triangles sharing vertex 2, with a pendant edge at vertex 4
+/// let graph = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+/// let bcc = Biconnectivity::new(&graph);
+/// assert_eq!(bcc.bridges(), &[6]);
+/// assert_eq!(bcc.articulation_points(), &[2, 4]);
+/// assert_eq!(bcc.two_edge_connected_components(), &[vec![0, 1, 2, 3, 4], vec![5]]);
+/// assert_eq!(bcc.blocks(), &[vec![0, 1, 2], vec![2, 3, 4], vec![4, 5]]);
+/// ```
+///
+/// # Time complexity
+/// Construction takes `O(n + m)` time.
+#[derive(Debug, Clone)]
+pub struct Biconnectivity {
+    bridges: Vec<usize>,
+    articulation_points: Vec<usize>,
+    two_edge_components: Vec<Vec<usize>>,
+    /// The index of the 2-edge-connected component of each vertex.
+    two_edge_component: Vec<usize>,
+    blocks: Vec<Vec<usize>>,
+}
+
+impl Biconnectivity {
+    /// Computes the biconnectivity structure of the graph.
+    pub fn new<W>(graph: &Graph<W>) -> Self {
+        let n = graph.vertex_count();
+        let mut order = vec![usize::MAX; n];
+        let mut low = vec![usize::MAX; n];
+        let mut is_articulation_point = vec![false; n];
+        let mut bridges = Vec::new();
+        let mut two_edge_components = Vec::new();
+        let mut blocks = Vec::new();
+        // Vertices whose 2-edge-connected components and blocks are not yet known
+        let mut two_edge_stack = Vec::new();
+        let mut block_stack = Vec::new();
+        let mut time = 0;
+
+        for root in 0..n {
+            if order[root] != usize::MAX {
+                continue;
+            }
+            let mut root_children = 0;
+            // The DFS path with the edge used to enter each vertex and the number of edges explored from it
+            let mut stack = vec![(root, usize::MAX, 0)];
+            order[root] = time;
+            low[root] = time;
+            time += 1;
+            two_edge_stack.push(root);
+            block_stack.push(root);
+
+            while let Some(&mut (vertex, entry, ref mut explored)) = stack.last_mut() {
+                if let Some(&(to, edge)) = graph.adjacent(vertex).get(*explored) {
+                    *explored += 1;
+                    if edge == entry {
+                        continue;
+                    }
+                    if order[to] == usize::MAX {
+                        order[to] = time;
+                        low[to] = time;
+                        time += 1;
+                        two_edge_stack.push(to);
+                        block_stack.push(to);
+                        stack.push((to, edge, 0));
+                    } else {
+                        low[vertex] = low[vertex].min(order[to]);
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                let parent = match stack.last() {
+                    Some(&(parent, _, _)) => parent,
+                    None => break,
+                };
+                low[parent] = low[parent].min(low[vertex]);
+                if low[vertex] > order[parent] {
+                    bridges.push(entry);
+                    two_edge_components.push(pop_until(&mut two_edge_stack, vertex));
+                }
+                if low[vertex] >= order[parent] {
+                    if parent == root {
+                        root_children += 1;
+                    } else {
+                        is_articulation_point[parent] = true;
+                    }
+                    let mut block = pop_until(&mut block_stack, vertex);
+                    block.push(parent);
+                    block.sort_unstable();
+                    blocks.push(block);
+                }
+            }
+
+            if root_children >= 2 {
+                is_articulation_point[root] = true;
+            }
+            block_stack.pop();
+            if root_children == 0 {
+                blocks.push(vec![root]);
+            }
+            two_edge_components.push(pop_until(&mut two_edge_stack, root));
+        }
+
+        bridges.sort_unstable();
+        for component in &mut two_edge_components {
+            component.sort_unstable();
+        }
+        two_edge_components.sort_unstable();
+        blocks.sort_unstable();
+        let mut two_edge_component = vec![0; n];
+        for (index, component) in two_edge_components.iter().enumerate() {
+            for &vertex in component {
+                two_edge_component[vertex] = index;
+            }
+        }
+
+        Self {
+            bridges,
+            articulation_points: (0..n).filter(|&v| is_articulation_point[v]).collect(),
+            two_edge_components,
+            two_edge_component,
+            blocks,
+        }
+    }
+
+    /// Returns the indices of the bridges in increasing order.
+    pub fn bridges(&self) -> &[usize] {
+        &self.bridges
+    }
+
+    /// Returns the articulation points in increasing order.
+    pub fn articulation_points(&self) -> &[usize] {
+        &self.articulation_points
+    }
+
+    /// Returns the 2-edge-connected components.
+    ///
+    /// The vertices of each component are in increasing order, and the components are ordered by their smallest
+    /// vertices.
+    pub fn two_edge_connected_components(&self) -> &[Vec<usize>] {
+        &self.two_edge_components
+    }
+
+    /// Returns the index of the 2-edge-connected component containing the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    pub fn two_edge_connected_component(&self, vertex: usize) -> usize {
+        self.two_edge_component[vertex]
+    }
+
+    /// Returns the blocks as lists of their vertices.
+    ///
+    /// The vertices of each block are in increasing order, and the blocks are in lexicographic order.
+    pub fn blocks(&self) -> &[Vec<usize>] {
+        &self.blocks
+    }
+
+    /// Constructs the block-cut tree of the graph.
+    ///
+    /// The tree has a vertex for each vertex of the graph, followed by a vertex for each block, so that the vertex
+    /// `n + i` corresponds to the `i`th block. Each vertex of the graph is connected to the blocks containing it. Thus
+    /// the articulation points are exactly the original vertices with more than one neighbor. If the graph is not
+    /// connected, the result is a forest.
+    ///
+    /// # Time complexity
+    /// Takes `O(n + m)` time.
+    pub fn block_cut_tree(&self) -> Graph {
+        let n = self.two_edge_component.len();
+        let edges = self
+            .blocks
+            .iter()
+            .enumerate()
+            .flat_map(|(index, block)| block.iter().map(move |&vertex| (vertex, n + index)));
+        Graph::from_edges(n + self.blocks.len(), edges)
+    }
+}
+
+/// Pops the stack until and including the given vertex, returning the popped vertices.
+fn pop_until(stack: &mut Vec<usize>, vertex: usize) -> Vec<usize> {
+    let position = stack.iter().rposition(|&v| v == vertex).unwrap();
+    stack.split_off(position)
+}
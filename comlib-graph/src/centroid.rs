@@ -0,0 +1,166 @@
+use crate::{Adjacency, Graph};
+
+/// Tree formed by the centroids of a [centroid decomposition](centroid_decomposition).
+///
+/// The parent of each centroid is the centroid of the component from which it was split off, and the roots are the
+/// centroids of the trees of the forest. The depth of the centroid tree is `O(log n)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CentroidTree {
+    parent: Vec<Option<usize>>,
+    level: Vec<usize>,
+    order: Vec<usize>,
+}
+
+impl CentroidTree {
+    /// Returns the parent of the vertex in the centroid tree, or `None` if it is a root.
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.parent[vertex]
+    }
+
+    /// Returns the depth of the vertex in the centroid tree, which is the number of times its component was split
+    /// before the vertex became a centroid.
+    pub fn level(&self, vertex: usize) -> usize {
+        self.level[vertex]
+    }
+
+    /// Returns the centroids in the order they were chosen, which is a preorder of the centroid tree.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns an iterator over the ancestors of the vertex in the centroid tree, starting from the vertex itself.
+    pub fn ancestors(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(vertex), move |&v| self.parent[v])
+    }
+}
+
+/// Performs a centroid decomposition of a forest, calling `visit` for each centroid.
+///
+/// The decomposition repeatedly picks a centroid of a component, that is a vertex whose removal leaves no component
+/// with more than half of the vertices, and removes it. For each centroid `visit` is called with the centroid and its
+/// subtrees in the current component, each given as the list of its vertices in BFS order together with their
+/// distances from the centroid in edges. Any path in the forest passes through the centroid chosen first among its
+/// vertices, so problems about paths can be solved by handling only the paths through each centroid within its
+/// component.
+///
+/// Returns the [`CentroidTree`] of the decomposition.
+///
+/// # Panics
+/// Panics if the graph is not a forest.
+///
+/// # Time complexity
+/// Takes `O(n log n)` time, not counting the calls to `visit`. The total size of the subtrees passed to `visit` is
+/// `O(n log n)`.
+///
+/// # Examples
+/// Counting the pairs of vertices at distance at most `k`:
+/// ```
+/// # use comlib_graph::{centroid_decomposition, Graph};
+/// let tree = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 3), (1, 4), (4, 5)]);
+/// let k = 2;
+/// let mut pairs = 0;
+/// centroid_decomposition(&tree, |_, subtrees| {
+///     // Pairs of distances summing to at most k
+///     let count_within = |distances: &[usize]| {
+///         let mut count = 0;
+///         for (i, a) in distances.iter().enumerate() {
+///             count += distances[i + 1..].iter().filter(|&&b| a + b <= k).count();
+///         }
+///         count
+///     };
+///     // Count the pairs through the centroid, including the centroid itself at distance 0, and subtract the pairs
+///     // within a single subtree
+///     let mut all = vec![0];
+///     for subtree in subtrees {
+///         let distances: Vec<_> = subtree.iter().map(|&(_, d)| d).collect();
+///         pairs -= count_within(&distances);
+///         all.extend(distances);
+///     }
+///     pairs += count_within(&all);
+/// });
+/// assert_eq!(pairs, 10);
+/// ```
+pub fn centroid_decomposition<W, F>(tree: &Graph<W>, visit: F) -> CentroidTree
+where
+    F: FnMut(usize, &[Vec<(usize, usize)>]),
+{
+    let mut visit = visit;
+    let n = tree.vertex_count();
+    let mut removed = vec![false; n];
+    let mut size = vec![0; n];
+    let mut centroids = CentroidTree {
+        parent: vec![None; n],
+        level: vec![0; n],
+        order: Vec::with_capacity(n),
+    };
+    // Components to decompose, given by a vertex in them and the centroid they were split off from
+    let mut components: Vec<(usize, Option<usize>)> = (0..n).rev().map(|v| (v, None)).collect();
+    while let Some((start, parent)) = components.pop() {
+        if removed[start] {
+            // The tree of the forest containing the vertex has already been decomposed
+            continue;
+        }
+
+        // Compute the sizes of the subtrees rooted at the start, and walk towards the larger half
+        let component = collect(tree, start, usize::MAX, &removed);
+        for &(vertex, _, _) in &component {
+            size[vertex] = 1;
+        }
+        for &(vertex, parent, _) in component.iter().rev().take(component.len() - 1) {
+            size[parent] += size[vertex];
+        }
+        let total = component.len();
+        let mut centroid = start;
+        while let Some(child) = tree
+            .neighbors(centroid)
+            .find(|&to| !removed[to] && size[to] < size[centroid] && 2 * size[to] > total)
+        {
+            centroid = child;
+        }
+
+        removed[centroid] = true;
+        centroids.parent[centroid] = parent;
+        centroids.level[centroid] = parent.map_or(0, |p| centroids.level[p] + 1);
+        centroids.order.push(centroid);
+        let subtrees: Vec<Vec<(usize, usize)>> = tree
+            .neighbors(centroid)
+            .filter(|&to| !removed[to])
+            .map(|to| {
+                collect(tree, to, centroid, &removed)
+                    .into_iter()
+                    .map(|(vertex, _, distance)| (vertex, distance + 1))
+                    .collect()
+            })
+            .collect();
+        visit(centroid, &subtrees);
+        for subtree in subtrees.iter().rev() {
+            components.push((subtree[0].0, Some(centroid)));
+        }
+    }
+    centroids
+}
+
+/// Collects the vertices reachable from the start without passing through removed vertices or the given vertex, as
+/// triples of the vertex, its parent and its distance from the start, in BFS order.
+fn collect<W>(
+    tree: &Graph<W>,
+    start: usize,
+    from: usize,
+    removed: &[bool],
+) -> Vec<(usize, usize, usize)> {
+    let mut vertices = vec![(start, from, 0)];
+    let mut index = 0;
+    while let Some(&(vertex, parent, distance)) = vertices.get(index) {
+        index += 1;
+        for to in tree.neighbors(vertex) {
+            if to != parent && !removed[to] {
+                vertices.push((to, vertex, distance + 1));
+            }
+        }
+        assert!(
+            vertices.len() <= removed.len(),
+            "the graph must be a forest"
+        );
+    }
+    vertices
+}
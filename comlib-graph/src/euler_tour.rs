@@ -0,0 +1,160 @@
+use std::ops::Range;
+
+use comlib_range::SparseTable;
+
+use crate::{Adjacency, Graph};
+
+/// Pairs of depth and vertex, folded by taking the minimum.
+type DepthTable =
+    SparseTable<(usize, usize), fn(&(usize, usize), &(usize, usize)) -> (usize, usize)>;
+
+/// Euler tour of a rooted tree, answering subtree and lowest common ancestor queries.
+///
+/// The vertices are numbered in preorder, so that the subtree of each vertex occupies a contiguous range of positions.
+/// Storing values of the vertices at their positions in a range query structure, such as [`Bit`], thus turns subtree
+/// queries into range queries. Lowest common ancestors are found with a [`SparseTable`] over the Euler tour, which
+/// lists each vertex on entering it and again after returning from each of its children.
+///
+/// [`Bit`]: comlib_range::Bit
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{EulerTour, Graph};
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = Graph::from_edges(5, vec![(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let tour = EulerTour::new(&tree, 0);
+/// assert_eq!(tour.order(), &[0, 1, 3, 4, 2]);
+/// assert_eq!(tour.subtree(1), 1..4);
+/// assert_eq!(tour.lca(3, 4), 1);
+/// assert_eq!(tour.lca(4, 2), 0);
+/// assert_eq!(tour.distance(3, 2), 3);
+/// ```
+///
+/// # Time complexity
+/// All queries take `O(1)` time.
+#[derive(Debug, Clone)]
+pub struct EulerTour {
+    /// The vertices in preorder.
+    order: Vec<usize>,
+    /// The position of each vertex in `order`.
+    position: Vec<usize>,
+    /// The end of the range of positions of the subtree of each vertex.
+    end: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    /// The index of the first occurrence of each vertex in the Euler tour.
+    first: Vec<usize>,
+    /// The depths and the vertices of the Euler tour.
+    tour: DepthTable,
+}
+
+impl EulerTour {
+    /// Computes the Euler tour of the tree rooted at the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the graph is not a tree or if the root is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n log n)` time.
+    pub fn new<W>(tree: &Graph<W>, root: usize) -> Self {
+        let n = tree.vertex_count();
+        assert!(root < n, "root out of bounds");
+        assert_eq!(tree.edge_count(), n - 1, "the graph must be a tree");
+        let mut order = Vec::with_capacity(n);
+        let mut position = vec![usize::MAX; n];
+        let mut end = vec![0; n];
+        let mut parent = vec![None; n];
+        let mut depth = vec![0; n];
+        let mut first = vec![0; n];
+        let mut tour = Vec::with_capacity(2 * n - 1);
+
+        position[root] = 0;
+        order.push(root);
+        tour.push((0, root));
+        // The path from the root with the number of edges explored from each vertex
+        let mut stack = vec![(root, 0)];
+        while let Some(&mut (vertex, ref mut explored)) = stack.last_mut() {
+            if let Some(&(child, _)) = tree.adjacent(vertex).get(*explored) {
+                *explored += 1;
+                if Some(child) == parent[vertex] {
+                    continue;
+                }
+                assert_eq!(position[child], usize::MAX, "the graph must be a tree");
+                parent[child] = Some(vertex);
+                depth[child] = depth[vertex] + 1;
+                position[child] = order.len();
+                order.push(child);
+                first[child] = tour.len();
+                tour.push((depth[child], child));
+                stack.push((child, 0));
+            } else {
+                stack.pop();
+                end[vertex] = order.len();
+                if let Some(parent) = parent[vertex] {
+                    tour.push((depth[parent], parent));
+                }
+            }
+        }
+        assert_eq!(order.len(), n, "the graph must be a tree");
+
+        Self {
+            order,
+            position,
+            end,
+            parent,
+            depth,
+            first,
+            tour: SparseTable::new(tour, |a, b| *a.min(b)),
+        }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> usize {
+        self.order[0]
+    }
+
+    /// Returns the vertices in preorder.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the position of the vertex in preorder.
+    pub fn position(&self, vertex: usize) -> usize {
+        self.position[vertex]
+    }
+
+    /// Returns the range of positions in preorder occupied by the subtree of the vertex.
+    pub fn subtree(&self, vertex: usize) -> Range<usize> {
+        self.position[vertex]..self.end[vertex]
+    }
+
+    /// Returns the parent of the vertex, or `None` if it is the root.
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.parent[vertex]
+    }
+
+    /// Returns the depth of the vertex, that is its distance from the root.
+    pub fn depth(&self, vertex: usize) -> usize {
+        self.depth[vertex]
+    }
+
+    /// Checks whether the first vertex is an ancestor of the second one. Every vertex is an ancestor of itself.
+    pub fn is_ancestor(&self, ancestor: usize, vertex: usize) -> bool {
+        self.subtree(ancestor).contains(&self.position[vertex])
+    }
+
+    /// Finds the lowest common ancestor of the given vertices.
+    pub fn lca(&self, a: usize, b: usize) -> usize {
+        let (a, b) = (self.first[a], self.first[b]);
+        self.tour.query(a.min(b)..=a.max(b)).unwrap().1
+    }
+
+    /// Computes the number of edges on the path between the given vertices.
+    pub fn distance(&self, a: usize, b: usize) -> usize {
+        self.depth[a] + self.depth[b] - 2 * self.depth[self.lca(a, b)]
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{Adjacency, DiGraph, Graph};
+
+/// Finds an Eulerian path of an undirected graph, that is a walk using every edge exactly once.
+///
+/// Such a path exists if the edges are connected and at most two vertices have odd degree. The path starts from the
+/// smallest vertex of odd degree, or if there are none, from the smallest vertex with any edges, in which case the
+/// path is a circuit. Returns the vertices of the path in order, and the indices of the edges between them, so that
+/// there is one more vertex than there are edges. If the graph has no edges, the path consists of the vertex 0 alone,
+/// unless the graph has no vertices either, in which case the path is empty.
+///
+/// Returns `None` if the graph has no Eulerian path.
+///
+/// # Time complexity
+/// Takes `O(n + m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{eulerian_path, Graph};
+/// let graph = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// assert_eq!(eulerian_path(&graph), Some((vec![2, 1, 0, 2, 3], vec![1, 0, 2, 3])));
+/// let star = Graph::from_edges(4, vec![(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(eulerian_path(&star), None);
+/// ```
+pub fn eulerian_path<W>(graph: &Graph<W>) -> Option<(Vec<usize>, Vec<usize>)> {
+    let n = graph.vertex_count();
+    let odd: Vec<usize> = (0..n).filter(|&v| graph.degree(v) % 2 == 1).collect();
+    let start = match odd.len() {
+        0 => (0..n).find(|&v| graph.degree(v) > 0).unwrap_or(0),
+        2 => odd[0],
+        _ => return None,
+    };
+    hierholzer(graph, start)
+}
+
+/// Finds an Eulerian circuit of an undirected graph, that is a closed walk using every edge exactly once.
+///
+/// Such a circuit exists if the edges are connected and every vertex has even degree. Works like [`eulerian_path`],
+/// but returns `None` if the path would not be closed.
+///
+/// # Time complexity
+/// Takes `O(n + m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{eulerian_circuit, Graph};
+/// let graph = Graph::from_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(eulerian_circuit(&graph), Some((vec![0, 1, 2, 0], vec![0, 1, 2])));
+/// let path = Graph::from_edges(2, vec![(0, 1)]);
+/// assert_eq!(eulerian_circuit(&path), None);
+/// ```
+pub fn eulerian_circuit<W>(graph: &Graph<W>) -> Option<(Vec<usize>, Vec<usize>)> {
+    if (0..graph.vertex_count()).any(|v| graph.degree(v) % 2 == 1) {
+        return None;
+    }
+    eulerian_path(graph)
+}
+
+/// Finds an Eulerian path of a directed graph, that is a walk using every edge exactly once in its direction.
+///
+/// Such a path exists if the edges are connected, and either every vertex has as many outgoing as incoming edges or
+/// exactly one vertex has one more outgoing edge and exactly one vertex has one more incoming edge. The path starts
+/// from the vertex with more outgoing edges, or if there is none, from the smallest vertex with any edges, in which
+/// case the path is a circuit. The result is returned like in [`eulerian_path`].
+///
+/// # Time complexity
+/// Takes `O(n + m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{directed_eulerian_path, DiGraph};
+/// let graph = DiGraph::from_edges(4, vec![(1, 2), (2, 0), (0, 1), (1, 3)]);
+/// assert_eq!(directed_eulerian_path(&graph), Some((vec![1, 2, 0, 1, 3], vec![0, 1, 2, 3])));
+/// let graph = DiGraph::from_edges(3, vec![(0, 1), (2, 1)]);
+/// assert_eq!(directed_eulerian_path(&graph), None);
+/// ```
+pub fn directed_eulerian_path<W>(graph: &DiGraph<W>) -> Option<(Vec<usize>, Vec<usize>)> {
+    let n = graph.vertex_count();
+    let mut balance = vec![0isize; n];
+    for edge in graph.edges() {
+        balance[edge.from] += 1;
+        balance[edge.to] -= 1;
+    }
+    let mut start = None;
+    let mut ends = 0;
+    for (vertex, &balance) in balance.iter().enumerate() {
+        match balance {
+            0 => {}
+            1 if start.is_none() => start = Some(vertex),
+            -1 if ends == 0 => ends += 1,
+            _ => return None,
+        }
+    }
+    let start = match start {
+        Some(start) => start,
+        None if ends == 0 => (0..n).find(|&v| graph.degree(v) > 0).unwrap_or(0),
+        None => return None,
+    };
+    hierholzer(graph, start)
+}
+
+/// Finds an Eulerian circuit of a directed graph, that is a closed walk using every edge exactly once in its
+/// direction.
+///
+/// Such a circuit exists if the edges are connected and every vertex has as many outgoing as incoming edges. Works
+/// like [`directed_eulerian_path`], but returns `None` if the path would not be closed.
+///
+/// # Time complexity
+/// Takes `O(n + m)` time.
+///
+/// # Examples
+/// A binary De Bruijn sequence of order 3, containing every string of three bits exactly once as a cyclic substring:
+/// ```
+/// # use comlib_graph::{directed_eulerian_circuit, DiGraph};
+/// // The vertices are the strings of two bits, and each edge appends a bit
+/// let graph = DiGraph::from_edges(4, (0..8).map(|s| (s >> 1, s & 3)));
+/// let (vertices, _) = directed_eulerian_circuit(&graph).unwrap();
+/// let sequence: String = vertices[1..].iter().map(|v| (b'0' + (v & 1) as u8) as char).collect();
+/// assert_eq!(sequence, "01011100");
+/// ```
+pub fn directed_eulerian_circuit<W>(graph: &DiGraph<W>) -> Option<(Vec<usize>, Vec<usize>)> {
+    let path = directed_eulerian_path(graph)?;
+    if path.0.first() != path.0.last() {
+        return None;
+    }
+    Some(path)
+}
+
+/// Runs Hierholzer's algorithm from the given vertex, returning `None` if not all edges could be reached.
+fn hierholzer<G: Adjacency>(graph: &G, start: usize) -> Option<(Vec<usize>, Vec<usize>)> {
+    let n = graph.vertex_count();
+    let m = graph.edge_count();
+    if n == 0 {
+        return Some((Vec::new(), Vec::new()));
+    }
+    let mut used = vec![false; m];
+    let mut next = vec![0; n];
+    let mut vertices = Vec::with_capacity(m + 1);
+    let mut edges = Vec::with_capacity(m);
+    // The current walk, with the edge by which each vertex was entered. Vertices are moved to the path once all of
+    // their edges have been used, which yields the path in reverse.
+    let mut stack = vec![(start, usize::MAX)];
+    while let Some(&(vertex, entered)) = stack.last() {
+        let adjacent = graph.adjacent(vertex);
+        while next[vertex] < adjacent.len() && used[adjacent[next[vertex]].1] {
+            next[vertex] += 1;
+        }
+        if let Some(&(to, edge)) = adjacent.get(next[vertex]) {
+            used[edge] = true;
+            stack.push((to, edge));
+        } else {
+            stack.pop();
+            vertices.push(vertex);
+            if entered != usize::MAX {
+                edges.push(entered);
+            }
+        }
+    }
+    if edges.len() < m {
+        return None;
+    }
+    vertices.reverse();
+    edges.reverse();
+    Some((vertices, edges))
+}
@@ -0,0 +1,270 @@
+/// Functional graph, in which every vertex has exactly one outgoing edge.
+///
+/// The graph is given by the successor of each vertex. Following the successors from any vertex eventually leads into a
+/// cycle, so the path from a vertex has the shape of the letter ρ: a tail followed by a cycle. The graph computes the
+/// cycles and the tails, and uses binary lifting to find the k-th successor of a vertex even for huge k.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::FunctionalGraph;
+/// // 0 -> 1 -> 2 -> 3 -> 1, 4 -> 4
+/// let graph = FunctionalGraph::new(vec![1, 2, 3, 1, 4]);
+/// assert_eq!(graph.cycles(), &[vec![1, 2, 3], vec![4]]);
+/// assert_eq!(graph.rho(0), (1, 3));
+/// assert_eq!(graph.successor(0, 1_000_000_000_000_000_000), 1);
+/// assert!(!graph.is_on_cycle(0));
+/// assert_eq!(graph.cycle_entry(0), 1);
+/// ```
+///
+/// # Time complexity
+/// All queries take `O(1)` time, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct FunctionalGraph {
+    /// The 2^i-th successors of the vertices for each i such that 2^i is at most the number of vertices.
+    jump: Vec<Vec<usize>>,
+    /// The cycles, each starting from its smallest vertex.
+    cycles: Vec<Vec<usize>>,
+    /// The index of the cycle each vertex leads into.
+    cycle: Vec<usize>,
+    /// The number of steps from each vertex to its cycle.
+    tail: Vec<usize>,
+    /// The first vertex on the cycle reached from each vertex.
+    entry: Vec<usize>,
+}
+
+impl FunctionalGraph {
+    /// Constructs the functional graph with the given successors.
+    ///
+    /// # Panics
+    /// Panics if a successor is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n log n)` time.
+    pub fn new(next: Vec<usize>) -> Self {
+        let n = next.len();
+        assert!(next.iter().all(|&v| v < n), "successor out of bounds");
+
+        // Walk from each unvisited vertex until reaching a visited one, which either lies on the current walk and
+        // closes a new cycle, or has already been processed
+        let mut cycles = Vec::new();
+        let mut cycle = vec![usize::MAX; n];
+        let mut tail = vec![0; n];
+        let mut entry = vec![usize::MAX; n];
+        let mut on_walk = vec![false; n];
+        let mut walk = Vec::new();
+        for start in 0..n {
+            let mut vertex = start;
+            while cycle[vertex] == usize::MAX && !on_walk[vertex] {
+                on_walk[vertex] = true;
+                walk.push(vertex);
+                vertex = next[vertex];
+            }
+            if cycle[vertex] == usize::MAX {
+                // The walk closed a cycle, which is the part of the walk from the vertex onwards
+                let begin = walk.iter().position(|&v| v == vertex).unwrap();
+                let mut vertices = walk.split_off(begin);
+                let smallest = (0..vertices.len()).min_by_key(|&i| vertices[i]).unwrap();
+                vertices.rotate_left(smallest);
+                for &v in &vertices {
+                    cycle[v] = cycles.len();
+                    entry[v] = v;
+                    on_walk[v] = false;
+                }
+                cycles.push(vertices);
+            }
+            for &v in walk.iter().rev() {
+                let to = next[v];
+                cycle[v] = cycle[to];
+                tail[v] = tail[to] + 1;
+                entry[v] = entry[to];
+                on_walk[v] = false;
+            }
+            walk.clear();
+        }
+
+        let mut jump = vec![next];
+        while 1 << jump.len() <= n {
+            let previous = jump.last().unwrap();
+            let level = previous.iter().map(|&v| previous[v]).collect();
+            jump.push(level);
+        }
+        Self {
+            jump,
+            cycles,
+            cycle,
+            tail,
+            entry,
+        }
+    }
+
+    /// Returns the number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.jump[0].len()
+    }
+
+    /// Returns the successor of the vertex.
+    pub fn next(&self, vertex: usize) -> usize {
+        self.jump[0][vertex]
+    }
+
+    /// Returns the vertex reached after following `k` successors from the vertex.
+    ///
+    /// # Time complexity
+    /// Takes `O(log n)` time.
+    pub fn successor(&self, vertex: usize, k: u64) -> usize {
+        let tail = self.tail[vertex] as u64;
+        if k <= tail {
+            return self.lift(vertex, k as usize);
+        }
+        let length = self.cycles[self.cycle[vertex]].len() as u64;
+        self.lift(self.entry[vertex], ((k - tail) % length) as usize)
+    }
+
+    /// Returns the cycles of the graph, each starting from its smallest vertex and following the successors. The cycles
+    /// are ordered by the smallest vertices leading into them.
+    pub fn cycles(&self) -> &[Vec<usize>] {
+        &self.cycles
+    }
+
+    /// Returns the index in [`cycles`](Self::cycles) of the cycle reached from the vertex.
+    pub fn cycle_index(&self, vertex: usize) -> usize {
+        self.cycle[vertex]
+    }
+
+    /// Checks whether the vertex lies on a cycle.
+    pub fn is_on_cycle(&self, vertex: usize) -> bool {
+        self.tail[vertex] == 0
+    }
+
+    /// Returns the number of steps from the vertex until reaching a cycle.
+    pub fn tail_length(&self, vertex: usize) -> usize {
+        self.tail[vertex]
+    }
+
+    /// Returns the first vertex on a cycle reached from the vertex.
+    pub fn cycle_entry(&self, vertex: usize) -> usize {
+        self.entry[vertex]
+    }
+
+    /// Returns the shape of the path from the vertex, as the length of the tail before the cycle and the length of the
+    /// cycle.
+    ///
+    /// The vertices reached after `i` and `j` steps from the vertex are the same if and only if both `i` and `j` are at
+    /// least the length of the tail and their difference is divisible by the length of the cycle.
+    pub fn rho(&self, vertex: usize) -> (usize, usize) {
+        (self.tail[vertex], self.cycles[self.cycle[vertex]].len())
+    }
+
+    /// Follows `k` successors from the vertex, where `k` is less than `2^levels`.
+    fn lift(&self, vertex: usize, k: usize) -> usize {
+        let mut vertex = vertex;
+        for (level, jump) in self.jump.iter().enumerate() {
+            if k >> level & 1 == 1 {
+                vertex = jump[vertex];
+            }
+        }
+        vertex
+    }
+}
+
+/// Folds of values along the paths of a [`FunctionalGraph`].
+///
+/// Each vertex has a value, and the values of the vertices visited while following successors are folded in order
+/// with an associative operation. Together with an identity element this makes the values a monoid. The folds are
+/// computed with binary lifting, and whole cycles are folded by repeated squaring, so that even huge numbers of steps
+/// can be handled.
+///
+/// # Examples
+/// Sum of the values along the path:
+/// ```
+/// # use comlib_graph::{FunctionalGraph, StepFold};
+/// let graph = FunctionalGraph::new(vec![1, 2, 0, 0]);
+/// let sums = StepFold::new(&graph, vec![1, 10, 100, 1000], 0, |a, b| a + b);
+/// assert_eq!(sums.fold(3, 0), (3, 0));
+/// assert_eq!(sums.fold(3, 2), (1, 1001));
+/// assert_eq!(sums.fold(0, 1_000_000), (1, 36_999_964));
+/// ```
+///
+/// # Time complexity
+/// Folding takes `O(log n + log k)` time.
+#[derive(Debug, Clone)]
+pub struct StepFold<'a, T, F> {
+    graph: &'a FunctionalGraph,
+    /// The folds of the values of the first 2^i vertices on the path from each vertex, for each level i of the graph.
+    table: Vec<Vec<T>>,
+    identity: T,
+    op: F,
+}
+
+impl<'a, T: Clone, F: Fn(&T, &T) -> T> StepFold<'a, T, F> {
+    /// Constructs the folds with the given values of the vertices, using the given identity element and associative
+    /// operation.
+    ///
+    /// # Panics
+    /// Panics if the number of values differs from the number of vertices.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n log n)` time.
+    pub fn new(graph: &'a FunctionalGraph, values: Vec<T>, identity: T, op: F) -> Self {
+        assert_eq!(
+            values.len(),
+            graph.vertex_count(),
+            "there must be a value for each vertex"
+        );
+        let mut table = vec![values];
+        for jump in &graph.jump[..graph.jump.len() - 1] {
+            let previous = table.last().unwrap();
+            let level = (0..previous.len())
+                .map(|v| op(&previous[v], &previous[jump[v]]))
+                .collect();
+            table.push(level);
+        }
+        Self {
+            graph,
+            table,
+            identity,
+            op,
+        }
+    }
+
+    /// Follows `k` successors from the vertex, returning the vertex reached and the fold of the values of the `k`
+    /// vertices visited before it, starting from the vertex itself.
+    pub fn fold(&self, vertex: usize, k: u64) -> (usize, T) {
+        let graph = self.graph;
+        let tail = graph.tail[vertex] as u64;
+        if k <= tail {
+            return self.lift(vertex, k as usize, self.identity.clone());
+        }
+        let (entry, accumulator) = self.lift(vertex, tail as usize, self.identity.clone());
+        let length = graph.cycles[graph.cycle[vertex]].len();
+        let (count, remainder) = ((k - tail) / length as u64, (k - tail) % length as u64);
+
+        // Fold the whole cycle from the entry and raise it to the power of the number of rounds
+        let (_, mut round) = self.lift(entry, length, self.identity.clone());
+        let mut rounds = self.identity.clone();
+        let mut count = count;
+        while count > 0 {
+            if count & 1 == 1 {
+                rounds = (self.op)(&rounds, &round);
+            }
+            round = (self.op)(&round, &round);
+            count >>= 1;
+        }
+        let accumulator = (self.op)(&accumulator, &rounds);
+        self.lift(entry, remainder as usize, accumulator)
+    }
+
+    /// Follows `k` successors from the vertex, where `k` is at most the number of vertices, folding the values into
+    /// the accumulator.
+    fn lift(&self, vertex: usize, k: usize, accumulator: T) -> (usize, T) {
+        let mut vertex = vertex;
+        let mut accumulator = accumulator;
+        for (level, jump) in self.graph.jump.iter().enumerate() {
+            if k >> level & 1 == 1 {
+                accumulator = (self.op)(&accumulator, &self.table[level][vertex]);
+                vertex = jump[vertex];
+            }
+        }
+        (vertex, accumulator)
+    }
+}
@@ -0,0 +1,281 @@
+use std::{iter::Map, slice::Iter};
+
+/// Edge of a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    /// The first endpoint, or the source of a directed edge.
+    pub from: usize,
+    /// The second endpoint, or the target of a directed edge.
+    pub to: usize,
+    /// The weight of the edge.
+    pub weight: W,
+}
+
+/// Iterator over the neighbors of a vertex.
+pub type Neighbors<'a> = Map<Iter<'a, (usize, usize)>, fn(&(usize, usize)) -> usize>;
+
+/// Access to the adjacency lists of a graph, allowing algorithms to work on both [`Graph`] and [`DiGraph`].
+///
+/// The vertices of a graph are `0..n` and the edges are numbered `0..m` in the order they were given.
+pub trait Adjacency {
+    /// The type of the weights of the edges.
+    type Weight;
+
+    /// Returns the number of vertices.
+    fn vertex_count(&self) -> usize;
+
+    /// Returns the edges of the graph.
+    fn edges(&self) -> &[Edge<Self::Weight>];
+
+    /// Returns the edges leaving the given vertex as pairs of the other endpoint and the index of the edge.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    fn adjacent(&self, vertex: usize) -> &[(usize, usize)];
+
+    /// Returns the number of edges.
+    fn edge_count(&self) -> usize {
+        self.edges().len()
+    }
+
+    /// Returns the edge with the given index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    fn edge(&self, index: usize) -> &Edge<Self::Weight> {
+        &self.edges()[index]
+    }
+
+    /// Returns an iterator over the vertices adjacent to the given vertex, in the order the edges were given.
+    ///
+    /// A vertex is returned once for each edge connecting it to the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    fn neighbors(&self, vertex: usize) -> Neighbors<'_> {
+        self.adjacent(vertex).iter().map(|&(to, _)| to)
+    }
+
+    /// Returns the number of edges leaving the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    fn degree(&self, vertex: usize) -> usize {
+        self.adjacent(vertex).len()
+    }
+}
+
+/// Adjacency lists stored in a compressed sparse row format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Csr {
+    /// The start of the adjacency list of each vertex in `adjacency`, with an extra value at the end.
+    offsets: Vec<usize>,
+    /// The pairs of the other endpoint and the index of the edge.
+    adjacency: Vec<(usize, usize)>,
+}
+
+impl Csr {
+    /// Builds the adjacency lists of `n` vertices from triples of the vertex, the other endpoint and the index of the
+    /// edge, keeping the order of the triples within each list.
+    fn new(n: usize, entries: &[(usize, usize, usize)]) -> Self {
+        let mut offsets = vec![0; n + 1];
+        for &(from, to, _) in entries {
+            assert!(from < n && to < n, "edge endpoint out of bounds");
+            offsets[from + 1] += 1;
+        }
+        for i in 0..n {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets.clone();
+        let mut adjacency = vec![(0, 0); entries.len()];
+        for &(from, to, index) in entries {
+            adjacency[next[from]] = (to, index);
+            next[from] += 1;
+        }
+        Self { offsets, adjacency }
+    }
+
+    fn get(&self, vertex: usize) -> &[(usize, usize)] {
+        &self.adjacency[self.offsets[vertex]..self.offsets[vertex + 1]]
+    }
+}
+
+/// Undirected graph with weighted edges.
+///
+/// The adjacency lists are stored contiguously, so the graph cannot be modified after construction. Each edge appears
+/// in the adjacency lists of both of its endpoints, and a self-loop appears twice in the list of its vertex.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Adjacency, Graph};
+/// let graph = Graph::from_weighted_edges(4, vec![(0, 1, 5), (1, 2, 3), (2, 0, 4)]);
+/// assert_eq!(graph.vertex_count(), 4);
+/// assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(graph.adjacent(2), &[(1, 1), (0, 2)]);
+/// assert_eq!(graph.edge(2).weight, 4);
+/// assert_eq!(graph.degree(3), 0);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` time, unless otherwise stated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = ()> {
+    csr: Csr,
+    edges: Vec<Edge<W>>,
+}
+
+impl Graph<()> {
+    /// Constructs an unweighted graph with `n` vertices and the given edges.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n + m)` time.
+    pub fn from_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        Self::from_weighted_edges(n, edges.into_iter().map(|(from, to)| (from, to, ())))
+    }
+}
+
+impl<W> Graph<W> {
+    /// Constructs a graph with `n` vertices and the given weighted edges.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n + m)` time.
+    pub fn from_weighted_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, W)>,
+    {
+        let edges: Vec<_> = edges
+            .into_iter()
+            .map(|(from, to, weight)| Edge { from, to, weight })
+            .collect();
+        let mut entries = Vec::with_capacity(2 * edges.len());
+        for (index, edge) in edges.iter().enumerate() {
+            entries.push((edge.from, edge.to, index));
+            entries.push((edge.to, edge.from, index));
+        }
+        Self {
+            csr: Csr::new(n, &entries),
+            edges,
+        }
+    }
+}
+
+impl<W> Adjacency for Graph<W> {
+    type Weight = W;
+
+    fn vertex_count(&self) -> usize {
+        self.csr.offsets.len() - 1
+    }
+
+    fn edges(&self) -> &[Edge<W>] {
+        &self.edges
+    }
+
+    fn adjacent(&self, vertex: usize) -> &[(usize, usize)] {
+        self.csr.get(vertex)
+    }
+}
+
+/// Directed graph with weighted edges.
+///
+/// The adjacency lists are stored contiguously, so the graph cannot be modified after construction. Each edge appears
+/// only in the adjacency list of its source.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Adjacency, DiGraph};
+/// let graph = DiGraph::from_edges(3, vec![(0, 1), (1, 2), (2, 1)]);
+/// assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(graph.reversed().neighbors(1).collect::<Vec<_>>(), vec![0, 2]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` time, unless otherwise stated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiGraph<W = ()> {
+    csr: Csr,
+    edges: Vec<Edge<W>>,
+}
+
+impl DiGraph<()> {
+    /// Constructs an unweighted graph with `n` vertices and the given edges.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n + m)` time.
+    pub fn from_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        Self::from_weighted_edges(n, edges.into_iter().map(|(from, to)| (from, to, ())))
+    }
+}
+
+impl<W> DiGraph<W> {
+    /// Constructs a graph with `n` vertices and the given weighted edges.
+    ///
+    /// # Panics
+    /// Panics if an endpoint of an edge is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n + m)` time.
+    pub fn from_weighted_edges<I>(n: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize, W)>,
+    {
+        let edges: Vec<_> = edges
+            .into_iter()
+            .map(|(from, to, weight)| Edge { from, to, weight })
+            .collect();
+        let entries: Vec<_> = edges
+            .iter()
+            .enumerate()
+            .map(|(index, edge)| (edge.from, edge.to, index))
+            .collect();
+        Self {
+            csr: Csr::new(n, &entries),
+            edges,
+        }
+    }
+
+    /// Constructs the graph with the directions of all edges reversed, keeping the indices of the edges.
+    ///
+    /// # Time complexity
+    /// Takes `O(n + m)` time.
+    pub fn reversed(&self) -> Self
+    where
+        W: Clone,
+    {
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| (edge.to, edge.from, edge.weight.clone()));
+        Self::from_weighted_edges(self.vertex_count(), edges)
+    }
+}
+
+impl<W> Adjacency for DiGraph<W> {
+    type Weight = W;
+
+    fn vertex_count(&self) -> usize {
+        self.csr.offsets.len() - 1
+    }
+
+    fn edges(&self) -> &[Edge<W>] {
+        &self.edges
+    }
+
+    fn adjacent(&self, vertex: usize) -> &[(usize, usize)] {
+        self.csr.get(vertex)
+    }
+}
@@ -0,0 +1,197 @@
+/// The four orthogonal directions as row and column offsets: up, left, right and down.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The eight orthogonal and diagonal directions as row and column offsets, in row-major order.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Dimensions of a two-dimensional grid, treating the grid as an implicit graph.
+///
+/// The cells are given as pairs of a row and a column, and they can be converted to indices in row-major order for
+/// storing data about the cells in flat arrays. Each cell is adjacent to the cells in the given directions, such as
+/// [`DIRECTIONS_4`] or [`DIRECTIONS_8`], which lie within the grid. Traversals take a predicate telling which cells can
+/// be entered, so that no adjacency lists need to be built.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Grid, DIRECTIONS_4};
+/// let map = ["..#.", ".#..", "...#"];
+/// let map: Vec<&[u8]> = map.iter().map(|row| row.as_bytes()).collect();
+/// let grid = Grid::new(3, 4);
+/// let open = |(r, c): (usize, usize)| map[r][c] == b'.';
+/// let distance = grid.bfs(Some((0, 0)), &DIRECTIONS_4, open);
+/// assert_eq!(distance[grid.index((1, 3))], Some(6));
+/// assert_eq!(distance[grid.index((0, 3))], Some(7));
+/// assert_eq!(grid.flood_fill((0, 3), &DIRECTIONS_4, open).len(), 9);
+/// assert_eq!(grid.neighbors((0, 0), &DIRECTIONS_4).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grid {
+    rows: usize,
+    cols: usize,
+}
+
+impl Grid {
+    /// Constructs a grid with the given numbers of rows and columns.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of cells.
+    pub fn len(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    /// Checks whether the grid has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks whether the cell lies within the grid.
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    /// Returns the index of the cell in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the cell is out of bounds.
+    pub fn index(&self, cell: (usize, usize)) -> usize {
+        assert!(self.contains(cell), "cell out of bounds");
+        cell.0 * self.cols + cell.1
+    }
+
+    /// Returns the cell with the given index in row-major order.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn cell(&self, index: usize) -> (usize, usize) {
+        assert!(index < self.len(), "index out of bounds");
+        (index / self.cols, index % self.cols)
+    }
+
+    /// Returns the cell reached by moving from the cell by the given offset, or `None` if it lies outside of the grid.
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+        Some((row, col)).filter(|&cell| self.contains(cell))
+    }
+
+    /// Returns an iterator over the cells adjacent to the cell in the given directions, skipping those outside of the
+    /// grid.
+    pub fn neighbors<'a>(
+        &'a self,
+        cell: (usize, usize),
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&direction| self.step(cell, direction))
+    }
+
+    /// Computes the distances from the sources to all cells using breadth-first search.
+    ///
+    /// Moves in the given directions, entering only cells for which `passable` returns `true`. The sources are entered
+    /// regardless of `passable`. Returns the distances indexed by the [indices](Self::index) of the cells, with `None`
+    /// for unreachable cells.
+    ///
+    /// # Panics
+    /// Panics if a source is out of bounds.
+    ///
+    /// # Time complexity
+    /// Takes `O(n d)` time for n cells and d directions, not counting the calls to `passable`.
+    pub fn bfs<I, P>(
+        &self,
+        sources: I,
+        directions: &[(isize, isize)],
+        passable: P,
+    ) -> Vec<Option<usize>>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        P: FnMut((usize, usize)) -> bool,
+    {
+        let mut passable = passable;
+        let mut distance = vec![None; self.len()];
+        let mut queue = Vec::new();
+        for source in sources {
+            let index = self.index(source);
+            if distance[index].is_none() {
+                distance[index] = Some(0);
+                queue.push(source);
+            }
+        }
+        let mut processed = 0;
+        while let Some(&cell) = queue.get(processed) {
+            processed += 1;
+            let next = distance[self.index(cell)].map(|d| d + 1);
+            for neighbor in self.neighbors(cell, directions) {
+                let index = self.index(neighbor);
+                if distance[index].is_none() && passable(neighbor) {
+                    distance[index] = next;
+                    queue.push(neighbor);
+                }
+            }
+        }
+        distance
+    }
+
+    /// Finds the cells reachable from the start, moving in the given directions and entering only cells for which
+    /// `passable` returns `true`.
+    ///
+    /// Returns the cells in the order they were found, starting from the start itself, which is included regardless of
+    /// `passable`.
+    ///
+    /// # Panics
+    /// Panics if the start is out of bounds.
+    ///
+    /// # Time complexity
+    /// Takes `O(n d)` time for n cells and d directions, not counting the calls to `passable`.
+    pub fn flood_fill<P>(
+        &self,
+        start: (usize, usize),
+        directions: &[(isize, isize)],
+        passable: P,
+    ) -> Vec<(usize, usize)>
+    where
+        P: FnMut((usize, usize)) -> bool,
+    {
+        let mut passable = passable;
+        let mut seen = vec![false; self.len()];
+        seen[self.index(start)] = true;
+        let mut cells = vec![start];
+        let mut processed = 0;
+        while let Some(&cell) = cells.get(processed) {
+            processed += 1;
+            for neighbor in self.neighbors(cell, directions) {
+                let index = self.index(neighbor);
+                if !seen[index] && passable(neighbor) {
+                    seen[index] = true;
+                    cells.push(neighbor);
+                }
+            }
+        }
+        cells
+    }
+}
@@ -0,0 +1,61 @@
+//! # Comlib Graph Algorithms
+//! This library contains graph types and algorithms on them.
+//!
+//! ## Content
+//! - [Undirected](Graph) and [directed](DiGraph) graphs
+//! - [Bipartite matching](BipartiteMatching) with minimum vertex cover and maximum independent set
+//! - [Breadth-first](Bfs) and [depth-first](Dfs) traversals
+//! - [Bridges, articulation points and biconnected components](Biconnectivity)
+//! - [Centroid decomposition](centroid_decomposition)
+//! - Eulerian [paths](eulerian_path) and [circuits](eulerian_circuit), also in [directed](directed_eulerian_path) graphs
+//! - [Euler tour of a tree](EulerTour) with subtree ranges and lowest common ancestors
+//! - [Functional graphs](FunctionalGraph) with cycles, k-th successors and [folds along paths](StepFold)
+//! - [Grids](Grid) as implicit graphs
+//! - [Maximum flow](MaxFlow) with Dinic's algorithm and minimum cut
+//! - [Minimum-cost flow](MinCostFlow) with successive shortest paths
+//! - Minimum spanning tree with [Kruskal's](kruskal), [Prim's](prim) and [Borůvka's](boruvka) algorithms
+//! - [Rerooting](rerooting) tree DP for all roots
+//! - [Rooted trees](RootedTree) with subtree sizes, tree [diameter] and [center]
+//! - [Small-to-large merging](small_to_large) of subtrees
+//! - [Topological sort](topological_sort), also [lexicographically smallest](lexicographic_topological_sort)
+//!
+//! ## Still missing
+//! - Shortest paths
+//! - Strongly connected components
+
+#![warn(missing_docs)]
+
+mod biconnected;
+pub use biconnected::Biconnectivity;
+mod centroid;
+pub use centroid::{centroid_decomposition, CentroidTree};
+mod euler_tour;
+pub use euler_tour::EulerTour;
+mod eulerian;
+pub use eulerian::{
+    directed_eulerian_circuit, directed_eulerian_path, eulerian_circuit, eulerian_path,
+};
+mod functional;
+pub use functional::{FunctionalGraph, StepFold};
+mod graph;
+pub use graph::{Adjacency, DiGraph, Edge, Graph, Neighbors};
+mod grid;
+pub use grid::{Grid, DIRECTIONS_4, DIRECTIONS_8};
+mod matching;
+pub use matching::BipartiteMatching;
+mod max_flow;
+pub use max_flow::MaxFlow;
+mod min_cost_flow;
+pub use min_cost_flow::MinCostFlow;
+mod mst;
+pub use mst::{boruvka, kruskal, prim};
+mod rerooting;
+pub use rerooting::rerooting;
+mod small_to_large;
+pub use small_to_large::small_to_large;
+mod toposort;
+pub use toposort::{lexicographic_topological_sort, topological_sort};
+mod traversal;
+mod tree;
+pub use traversal::{Bfs, Dfs};
+pub use tree::{center, diameter, RootedTree};
@@ -0,0 +1,199 @@
+use crate::{Adjacency, DiGraph};
+
+/// Maximum matching of a bipartite graph, computed with the Hopcroft–Karp algorithm.
+///
+/// The vertices on the left side are numbered `0..left` and those on the right side `0..right`, and each edge connects
+/// a left vertex to a right vertex. In addition to the matching itself, a minimum vertex cover and a maximum
+/// independent set are found with König's theorem: in a bipartite graph, their sizes are the size of the maximum
+/// matching and the number of vertices minus it.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::BipartiteMatching;
+/// let matching = BipartiteMatching::new(3, 3, vec![(0, 0), (0, 1), (1, 0), (2, 0), (2, 2)]);
+/// assert_eq!(matching.size(), 3);
+/// assert_eq!(matching.pairs(), vec![(0, 1), (1, 0), (2, 2)]);
+///
+/// let matching = BipartiteMatching::new(3, 2, vec![(0, 0), (1, 0), (2, 0), (2, 1)]);
+/// assert_eq!(matching.size(), 2);
+/// assert_eq!(matching.min_vertex_cover(), (vec![2], vec![0]));
+/// assert_eq!(matching.max_independent_set(), (vec![0, 1], vec![1]));
+/// ```
+///
+/// # Time complexity
+/// Construction takes `O(m √n)` time. Finding a vertex cover or an independent set takes `O(n + m)` time.
+#[derive(Debug, Clone)]
+pub struct BipartiteMatching {
+    /// The edges from the left vertices to the right vertices, which are offset by the number of left vertices.
+    graph: DiGraph,
+    /// The right vertex matched to each left vertex, or `usize::MAX` if unmatched.
+    left_match: Vec<usize>,
+    /// The left vertex matched to each right vertex, or `usize::MAX` if unmatched.
+    right_match: Vec<usize>,
+}
+
+impl BipartiteMatching {
+    /// Computes a maximum matching of the bipartite graph with the given edges, each given as a pair of a left and a
+    /// right vertex.
+    ///
+    /// # Panics
+    /// Panics if an endpoint is out of bounds.
+    pub fn new<I>(left: usize, right: usize, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let edges = edges.into_iter().map(|(u, v)| {
+            assert!(u < left && v < right, "edge endpoint out of bounds");
+            (u, left + v)
+        });
+        let mut matching = Self {
+            graph: DiGraph::from_edges(left + right, edges),
+            left_match: vec![usize::MAX; left],
+            right_match: vec![usize::MAX; right],
+        };
+        while matching.augment() {}
+        matching
+    }
+
+    /// Returns the number of matched pairs.
+    pub fn size(&self) -> usize {
+        self.left_match.iter().filter(|&&v| v != usize::MAX).count()
+    }
+
+    /// Returns the right vertex matched to the left vertex, or `None` if it is unmatched.
+    pub fn left_match(&self, vertex: usize) -> Option<usize> {
+        Some(self.left_match[vertex]).filter(|&v| v != usize::MAX)
+    }
+
+    /// Returns the left vertex matched to the right vertex, or `None` if it is unmatched.
+    pub fn right_match(&self, vertex: usize) -> Option<usize> {
+        Some(self.right_match[vertex]).filter(|&u| u != usize::MAX)
+    }
+
+    /// Returns the matched pairs of left and right vertices, in increasing order of the left vertices.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        (0..self.left_match.len())
+            .filter_map(|u| self.left_match(u).map(|v| (u, v)))
+            .collect()
+    }
+
+    /// Finds a minimum vertex cover, that is a smallest set of vertices containing an endpoint of every edge.
+    ///
+    /// Returns the left and the right vertices of the cover in increasing order.
+    pub fn min_vertex_cover(&self) -> (Vec<usize>, Vec<usize>) {
+        let (left, right) = self.alternating_reachable();
+        (
+            (0..left.len()).filter(|&u| !left[u]).collect(),
+            (0..right.len()).filter(|&v| right[v]).collect(),
+        )
+    }
+
+    /// Finds a maximum independent set, that is a largest set of vertices no two of which are adjacent. It is the
+    /// complement of the [minimum vertex cover](Self::min_vertex_cover).
+    ///
+    /// Returns the left and the right vertices of the set in increasing order.
+    pub fn max_independent_set(&self) -> (Vec<usize>, Vec<usize>) {
+        let (left, right) = self.alternating_reachable();
+        (
+            (0..left.len()).filter(|&u| left[u]).collect(),
+            (0..right.len()).filter(|&v| !right[v]).collect(),
+        )
+    }
+
+    /// Finds the left and the right vertices reachable from the unmatched left vertices by alternating paths.
+    fn alternating_reachable(&self) -> (Vec<bool>, Vec<bool>) {
+        let offset = self.left_match.len();
+        let mut left = vec![false; offset];
+        let mut right = vec![false; self.right_match.len()];
+        let mut stack: Vec<usize> = (0..offset)
+            .filter(|&u| self.left_match[u] == usize::MAX)
+            .collect();
+        stack.iter().for_each(|&u| left[u] = true);
+        while let Some(u) = stack.pop() {
+            for v in self.graph.neighbors(u) {
+                let v = v - offset;
+                if !right[v] {
+                    right[v] = true;
+                    // The right vertex is matched, as otherwise the matching would not be maximum
+                    let w = self.right_match[v];
+                    if !left[w] {
+                        left[w] = true;
+                        stack.push(w);
+                    }
+                }
+            }
+        }
+        (left, right)
+    }
+
+    /// Runs a phase of the Hopcroft–Karp algorithm, augmenting the matching along a maximal set of vertex-disjoint
+    /// shortest augmenting paths. Returns `false` if there are no augmenting paths.
+    fn augment(&mut self) -> bool {
+        let offset = self.left_match.len();
+        let graph = &self.graph;
+        let adjacent = |u: usize| graph.adjacent(u);
+
+        // Compute the lengths of the shortest alternating paths from the unmatched left vertices
+        let mut level = vec![usize::MAX; offset];
+        let mut queue: Vec<usize> = (0..offset)
+            .filter(|&u| self.left_match[u] == usize::MAX)
+            .collect();
+        queue.iter().for_each(|&u| level[u] = 0);
+        let mut found = false;
+        let mut index = 0;
+        while let Some(&u) = queue.get(index) {
+            index += 1;
+            for &(v, _) in adjacent(u) {
+                let w = self.right_match[v - offset];
+                if w == usize::MAX {
+                    found = true;
+                } else if level[w] == usize::MAX {
+                    level[w] = level[u] + 1;
+                    queue.push(w);
+                }
+            }
+        }
+        if !found {
+            return false;
+        }
+
+        // Find augmenting paths along the levels, keeping the left vertices of the current path on a stack
+        let mut next = vec![0; offset];
+        for start in 0..offset {
+            if self.left_match[start] != usize::MAX {
+                continue;
+            }
+            let mut stack = vec![start];
+            while let Some(&u) = stack.last() {
+                let edges = adjacent(u);
+                if next[u] == edges.len() {
+                    // No augmenting path continues from the vertex
+                    level[u] = usize::MAX;
+                    stack.pop();
+                    if let Some(&previous) = stack.last() {
+                        next[previous] += 1;
+                    }
+                    continue;
+                }
+                let v = edges[next[u]].0 - offset;
+                let w = self.right_match[v];
+                if w == usize::MAX {
+                    // Flip the edges of the path
+                    for &u in &stack {
+                        let v = adjacent(u)[next[u]].0 - offset;
+                        self.left_match[u] = v;
+                        self.right_match[v] = u;
+                        // Keep the paths of the phase vertex-disjoint
+                        level[u] = usize::MAX;
+                    }
+                    break;
+                } else if level[w] == level[u] + 1 {
+                    stack.push(w);
+                } else {
+                    next[u] += 1;
+                }
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,223 @@
+use comlib_math::Integer;
+
+/// Flow network computing maximum flows with Dinic's algorithm.
+///
+/// The network is built by adding directed edges with integral capacities. Computing a flow modifies the residual
+/// capacities of the edges, after which the flow on each edge and a minimum cut can be queried. Computing a flow again,
+/// possibly after adding more edges, augments the existing flow.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::MaxFlow;
+/// let mut network = MaxFlow::new(4);
+/// network.add_edge(0, 1, 3);
+/// network.add_edge(0, 2, 2);
+/// network.add_edge(1, 2, 5);
+/// network.add_edge(1, 3, 2);
+/// network.add_edge(2, 3, 3);
+/// assert_eq!(network.max_flow(0, 3), 5);
+/// assert_eq!(network.flows(), vec![3, 2, 1, 2, 3]);
+/// assert_eq!(network.min_cut(0), vec![true, false, false, false]);
+/// ```
+///
+/// # Time complexity
+/// Computing a maximum flow takes `O(n² m)` time, and `O(m √m)` time on networks with unit capacities. With capacity
+/// scaling it takes `O(n m log U)` time, where U is the largest capacity.
+#[derive(Debug, Clone)]
+pub struct MaxFlow<C> {
+    /// The heads of the half-edges. Edge i consists of the forward half-edge 2i and the backward half-edge 2i + 1.
+    to: Vec<usize>,
+    /// The residual capacities of the half-edges.
+    residual: Vec<C>,
+    /// The half-edges leaving each vertex.
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl<C: Integer> MaxFlow<C> {
+    /// Constructs a network with the given number of vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            to: Vec::new(),
+            residual: Vec::new(),
+            adjacent: vec![Vec::new(); n],
+        }
+    }
+
+    /// Returns the number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.adjacent.len()
+    }
+
+    /// Returns the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.to.len() / 2
+    }
+
+    /// Adds a directed edge with the given capacity, returning its index.
+    ///
+    /// # Panics
+    /// Panics if an endpoint is out of bounds or if the capacity is negative.
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: C) -> usize {
+        let n = self.vertex_count();
+        assert!(from < n && to < n, "edge endpoint out of bounds");
+        assert!(capacity >= C::zero(), "capacity must be non-negative");
+        let edge = self.edge_count();
+        self.adjacent[from].push(2 * edge);
+        self.adjacent[to].push(2 * edge + 1);
+        self.to.push(to);
+        self.to.push(from);
+        self.residual.push(capacity);
+        self.residual.push(C::zero());
+        edge
+    }
+
+    /// Returns the capacity of the edge.
+    pub fn capacity(&self, edge: usize) -> C {
+        self.residual[2 * edge] + self.residual[2 * edge + 1]
+    }
+
+    /// Returns the flow on the edge.
+    pub fn flow(&self, edge: usize) -> C {
+        self.residual[2 * edge + 1]
+    }
+
+    /// Returns the flows on all edges, see [`flow`](Self::flow).
+    pub fn flows(&self) -> Vec<C> {
+        (0..self.edge_count()).map(|edge| self.flow(edge)).collect()
+    }
+
+    /// Augments the flow from the source to the sink to a maximum flow, returning the amount by which it increased.
+    ///
+    /// # Panics
+    /// Panics if the source and the sink are equal or out of bounds.
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> C {
+        self.check_terminals(source, sink);
+        self.augment(source, sink, C::one())
+    }
+
+    /// Augments the flow like [`max_flow`](Self::max_flow), but using capacity scaling.
+    ///
+    /// Capacity scaling first pushes flow only along paths with large residual capacities, halving the threshold in
+    /// each round. This bounds the running time by the logarithm of the largest capacity instead of the square of the
+    /// number of vertices, which helps when the capacities are small compared to the size of the network.
+    ///
+    /// # Panics
+    /// Panics if the source and the sink are equal or out of bounds.
+    pub fn max_flow_with_scaling(&mut self, source: usize, sink: usize) -> C {
+        self.check_terminals(source, sink);
+        let largest = self.residual.iter().copied().max().unwrap_or_else(C::zero);
+        let two = C::one() + C::one();
+        let mut threshold = C::one();
+        while threshold <= largest / two {
+            threshold += threshold;
+        }
+        let mut total = C::zero();
+        loop {
+            total += self.augment(source, sink, threshold);
+            if threshold == C::one() {
+                return total;
+            }
+            threshold /= two;
+        }
+    }
+
+    /// Finds the side of the source in a minimum cut, after a maximum flow has been computed.
+    ///
+    /// Returns for each vertex whether it is reachable from the source in the residual network. The edges from the
+    /// reachable vertices to the rest form a minimum cut, whose capacity equals the value of the maximum flow.
+    ///
+    /// # Time complexity
+    /// Takes `O(n + m)` time.
+    pub fn min_cut(&self, source: usize) -> Vec<bool> {
+        let mut reachable = vec![false; self.vertex_count()];
+        reachable[source] = true;
+        let mut stack = vec![source];
+        while let Some(vertex) = stack.pop() {
+            for &half in &self.adjacent[vertex] {
+                let to = self.to[half];
+                if !reachable[to] && self.residual[half] > C::zero() {
+                    reachable[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        reachable
+    }
+
+    fn check_terminals(&self, source: usize, sink: usize) {
+        let n = self.vertex_count();
+        assert!(source < n && sink < n, "vertex out of bounds");
+        assert_ne!(source, sink, "the source and the sink must differ");
+    }
+
+    /// Runs the phases of Dinic's algorithm using only the half-edges with residual capacity at least the threshold.
+    fn augment(&mut self, source: usize, sink: usize, threshold: C) -> C {
+        let n = self.vertex_count();
+        let mut total = C::zero();
+        let mut level = vec![usize::MAX; n];
+        let mut next = vec![0; n];
+        let mut queue = Vec::with_capacity(n);
+        loop {
+            // Compute the distances from the source in the residual network
+            level.iter_mut().for_each(|level| *level = usize::MAX);
+            level[source] = 0;
+            queue.clear();
+            queue.push(source);
+            let mut index = 0;
+            while let Some(&vertex) = queue.get(index) {
+                index += 1;
+                for &half in &self.adjacent[vertex] {
+                    let to = self.to[half];
+                    if level[to] == usize::MAX && self.residual[half] >= threshold {
+                        level[to] = level[vertex] + 1;
+                        queue.push(to);
+                    }
+                }
+            }
+            if level[sink] == usize::MAX {
+                return total;
+            }
+
+            // Find a blocking flow along the shortest paths, keeping the current path on a stack of half-edges
+            next.iter_mut().for_each(|next| *next = 0);
+            let mut path: Vec<usize> = Vec::new();
+            loop {
+                let vertex = path.last().map_or(source, |&half| self.to[half]);
+                if vertex == sink {
+                    let bottleneck = path.iter().map(|&half| self.residual[half]).min().unwrap();
+                    for &half in &path {
+                        self.residual[half] -= bottleneck;
+                        self.residual[half ^ 1] += bottleneck;
+                    }
+                    total += bottleneck;
+                    // Retreat to the tail of the first half-edge which can no longer be used
+                    let saturated = path
+                        .iter()
+                        .position(|&half| self.residual[half] < threshold)
+                        .unwrap();
+                    path.truncate(saturated);
+                    continue;
+                }
+                let admissible = self.adjacent[vertex][next[vertex]..]
+                    .iter()
+                    .position(|&half| {
+                        self.residual[half] >= threshold
+                            && level[self.to[half]] == level[vertex] + 1
+                    });
+                match admissible {
+                    Some(offset) => {
+                        next[vertex] += offset;
+                        path.push(self.adjacent[vertex][next[vertex]]);
+                    }
+                    None if vertex == source => break,
+                    None => {
+                        // No path to the sink continues from the vertex, so remove it from the level graph
+                        next[vertex] = self.adjacent[vertex].len();
+                        level[vertex] = usize::MAX;
+                        path.pop();
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use comlib_math::{Integer, Signed};
+
+/// Flow network computing minimum-cost flows with successive shortest paths.
+///
+/// The network is built by adding directed edges with integral capacities and costs per unit of flow. Flow is pushed
+/// along the cheapest augmenting paths, found with Dijkstra's algorithm on costs reduced by vertex potentials. The
+/// initial potentials are computed with the Bellman–Ford algorithm, so the costs may be negative as long as the
+/// network has no cycles of negative cost.
+///
+/// Computing a flow augments the existing flow, so the flow can be increased incrementally. The potentials are kept
+/// between the computations from the same source, unless edges are added in between.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::MinCostFlow;
+/// let mut network = MinCostFlow::new(4);
+/// network.add_edge(0, 1, 2, 1);
+/// network.add_edge(0, 2, 1, 2);
+/// network.add_edge(1, 2, 1, 1);
+/// network.add_edge(1, 3, 1, 3);
+/// network.add_edge(2, 3, 2, 1);
+/// assert_eq!(network.slope(0, 3, 10), vec![(0, 0), (2, 6), (3, 10)]);
+/// assert_eq!(network.flows(), vec![2, 1, 1, 1, 2]);
+/// ```
+///
+/// # Time complexity
+/// Computing a flow takes `O(F m log n)` time, where F is the number of augmenting paths, which is at most the value of
+/// the flow. Computing the initial potentials takes `O(n m)` time in the worst case.
+#[derive(Debug, Clone)]
+pub struct MinCostFlow<T> {
+    /// The heads of the half-edges. Edge i consists of the forward half-edge 2i and the backward half-edge 2i + 1.
+    to: Vec<usize>,
+    /// The residual capacities of the half-edges.
+    residual: Vec<T>,
+    /// The costs of the half-edges, the backward ones having the negated costs.
+    cost: Vec<T>,
+    /// The half-edges leaving each vertex.
+    adjacent: Vec<Vec<usize>>,
+    /// The source and potentials making the reduced costs of the half-edges with residual capacity reachable from it
+    /// non-negative, or `None` if they have to be recomputed.
+    potential: Option<(usize, Vec<T>)>,
+}
+
+impl<T: Integer + Signed> MinCostFlow<T> {
+    /// Constructs a network with the given number of vertices and no edges.
+    pub fn new(n: usize) -> Self {
+        Self {
+            to: Vec::new(),
+            residual: Vec::new(),
+            cost: Vec::new(),
+            adjacent: vec![Vec::new(); n],
+            potential: None,
+        }
+    }
+
+    /// Returns the number of vertices.
+    pub fn vertex_count(&self) -> usize {
+        self.adjacent.len()
+    }
+
+    /// Returns the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.to.len() / 2
+    }
+
+    /// Adds a directed edge with the given capacity and cost per unit of flow, returning its index.
+    ///
+    /// # Panics
+    /// Panics if an endpoint is out of bounds or if the capacity is negative.
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: T, cost: T) -> usize {
+        let n = self.vertex_count();
+        assert!(from < n && to < n, "edge endpoint out of bounds");
+        assert!(capacity >= T::zero(), "capacity must be non-negative");
+        let edge = self.edge_count();
+        self.adjacent[from].push(2 * edge);
+        self.adjacent[to].push(2 * edge + 1);
+        self.to.push(to);
+        self.to.push(from);
+        self.residual.push(capacity);
+        self.residual.push(T::zero());
+        self.cost.push(cost);
+        self.cost.push(-cost);
+        self.potential = None;
+        edge
+    }
+
+    /// Returns the capacity of the edge.
+    pub fn capacity(&self, edge: usize) -> T {
+        self.residual[2 * edge] + self.residual[2 * edge + 1]
+    }
+
+    /// Returns the cost of the edge per unit of flow.
+    pub fn cost(&self, edge: usize) -> T {
+        self.cost[2 * edge]
+    }
+
+    /// Returns the flow on the edge.
+    pub fn flow(&self, edge: usize) -> T {
+        self.residual[2 * edge + 1]
+    }
+
+    /// Returns the flows on all edges, see [`flow`](Self::flow).
+    pub fn flows(&self) -> Vec<T> {
+        (0..self.edge_count()).map(|edge| self.flow(edge)).collect()
+    }
+
+    /// Augments the flow from the source to the sink by at most `limit` units, at the minimum cost.
+    ///
+    /// Returns the amount of flow pushed and its cost. The flow is a maximum flow if the amount is less than the limit.
+    /// Passing [`Integer::MAX`] as the limit thus computes a minimum-cost maximum flow.
+    ///
+    /// # Panics
+    /// Panics if the source and the sink are equal or out of bounds, or if the network contains a cycle of negative
+    /// cost.
+    pub fn min_cost_flow(&mut self, source: usize, sink: usize, limit: T) -> (T, T) {
+        *self.slope(source, sink, limit).last().unwrap()
+    }
+
+    /// Augments the flow like [`min_cost_flow`](Self::min_cost_flow), returning the breakpoints of the cost as a
+    /// function of the amount of flow.
+    ///
+    /// The minimum cost of pushing a given amount of flow is a convex piecewise linear function of the amount. Returns
+    /// the pairs of the amount and the cost at the ends of its linear pieces, starting from `(0, 0)`.
+    ///
+    /// # Panics
+    /// Panics if the source and the sink are equal or out of bounds, or if the network contains a cycle of negative
+    /// cost.
+    pub fn slope(&mut self, source: usize, sink: usize, limit: T) -> Vec<(T, T)> {
+        let n = self.vertex_count();
+        assert!(source < n && sink < n, "vertex out of bounds");
+        assert_ne!(source, sink, "the source and the sink must differ");
+        let mut potential = match self.potential.take() {
+            Some((previous, potential)) if previous == source => potential,
+            _ => self.initial_potential(source),
+        };
+
+        let mut breakpoints = vec![(T::zero(), T::zero())];
+        let mut flow = T::zero();
+        let mut cost = T::zero();
+        let mut last_slope = None;
+        let mut distance = vec![None; n];
+        let mut parent = vec![usize::MAX; n];
+        let mut heap = BinaryHeap::new();
+        while flow < limit {
+            // Find the cheapest path with Dijkstra's algorithm on the reduced costs
+            distance.iter_mut().for_each(|distance| *distance = None);
+            distance[source] = Some(T::zero());
+            heap.push(Reverse((T::zero(), source)));
+            while let Some(Reverse((d, vertex))) = heap.pop() {
+                if distance[vertex] != Some(d) {
+                    continue;
+                }
+                for &half in &self.adjacent[vertex] {
+                    if self.residual[half] == T::zero() {
+                        continue;
+                    }
+                    let to = self.to[half];
+                    let reduced = d + self.cost[half] + potential[vertex] - potential[to];
+                    if distance[to].is_none_or(|current| reduced < current) {
+                        distance[to] = Some(reduced);
+                        parent[to] = half;
+                        heap.push(Reverse((reduced, to)));
+                    }
+                }
+            }
+            if distance[sink].is_none() {
+                break;
+            }
+            // Vertices which are unreachable now remain so, and thus their potentials do not matter
+            for (potential, distance) in potential.iter_mut().zip(&distance) {
+                if let Some(distance) = distance {
+                    *potential += *distance;
+                }
+            }
+
+            let mut amount = limit - flow;
+            let mut vertex = sink;
+            while vertex != source {
+                amount = amount.min(self.residual[parent[vertex]]);
+                vertex = self.to[parent[vertex] ^ 1];
+            }
+            let mut vertex = sink;
+            while vertex != source {
+                self.residual[parent[vertex]] -= amount;
+                self.residual[parent[vertex] ^ 1] += amount;
+                vertex = self.to[parent[vertex] ^ 1];
+            }
+            let slope = potential[sink] - potential[source];
+            flow += amount;
+            cost += amount * slope;
+            if last_slope == Some(slope) {
+                breakpoints.pop();
+            }
+            breakpoints.push((flow, cost));
+            last_slope = Some(slope);
+        }
+        self.potential = Some((source, potential));
+        breakpoints
+    }
+
+    /// Computes the distances from the source over the half-edges with residual capacity with the Bellman–Ford
+    /// algorithm, using a queue of the vertices whose distance changed.
+    fn initial_potential(&self, source: usize) -> Vec<T> {
+        let n = self.vertex_count();
+        let mut distance = vec![None; n];
+        let mut queued = vec![false; n];
+        // The number of edges on the path giving the current distance of each vertex
+        let mut length = vec![0; n];
+        let mut queue = VecDeque::new();
+        distance[source] = Some(T::zero());
+        queue.push_back(source);
+        while let Some(vertex) = queue.pop_front() {
+            queued[vertex] = false;
+            let d = distance[vertex].unwrap();
+            for &half in &self.adjacent[vertex] {
+                if self.residual[half] == T::zero() {
+                    continue;
+                }
+                let to = self.to[half];
+                let candidate = d + self.cost[half];
+                if distance[to].is_none_or(|current| candidate < current) {
+                    distance[to] = Some(candidate);
+                    length[to] = length[vertex] + 1;
+                    assert!(
+                        length[to] < n,
+                        "the network must not contain negative cycles"
+                    );
+                    if !queued[to] {
+                        queued[to] = true;
+                        queue.push_back(to);
+                    }
+                }
+            }
+        }
+        // Vertices unreachable from the source never become reachable, so their potentials are arbitrary
+        distance
+            .into_iter()
+            .map(|d| d.unwrap_or_else(T::zero))
+            .collect()
+    }
+}
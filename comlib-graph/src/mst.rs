@@ -0,0 +1,163 @@
+use std::ops::Add;
+
+use comlib_common::{Dsu, IndexedHeap};
+
+use crate::{Adjacency, Graph};
+
+/// Computes a minimum spanning forest of the graph using Kruskal's algorithm.
+///
+/// Returns the total weight of the forest and the indices of its edges in increasing order. If the graph is connected,
+/// the forest is a minimum spanning tree.
+///
+/// # Time complexity
+/// Takes `O(m log m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{kruskal, Graph};
+/// let graph = Graph::from_weighted_edges(4, vec![(0, 1, 4), (1, 2, 1), (2, 0, 2), (2, 3, 7), (3, 0, 5)]);
+/// assert_eq!(kruskal(&graph), (8, vec![1, 2, 4]));
+/// ```
+pub fn kruskal<W>(graph: &Graph<W>) -> (W, Vec<usize>)
+where
+    W: Ord + Clone + Add<Output = W> + Default,
+{
+    let edges = graph.edges();
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&a, &b| edges[a].weight.cmp(&edges[b].weight));
+    let mut dsu = Dsu::new(graph.vertex_count());
+    let mut forest: Vec<usize> = order
+        .into_iter()
+        .filter(|&e| dsu.union(edges[e].from, edges[e].to))
+        .collect();
+    forest.sort_unstable();
+    (total_weight(graph, &forest), forest)
+}
+
+/// Computes a minimum spanning forest of the graph using Prim's algorithm.
+///
+/// Returns the same kind of result as [`kruskal`]. This is faster than Kruskal's algorithm on dense graphs, where the
+/// number of edges is close to quadratic.
+///
+/// # Time complexity
+/// Takes `O(m log n)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{prim, Graph};
+/// let graph = Graph::from_weighted_edges(4, vec![(0, 1, 4), (1, 2, 1), (2, 0, 2), (2, 3, 7), (3, 0, 5)]);
+/// assert_eq!(prim(&graph), (8, vec![1, 2, 4]));
+/// ```
+pub fn prim<W>(graph: &Graph<W>) -> (W, Vec<usize>)
+where
+    W: Ord + Clone + Add<Output = W> + Default,
+{
+    let n = graph.vertex_count();
+    let mut in_tree = vec![false; n];
+    // The cheapest edge connecting each vertex to the tree, with the edge index breaking ties
+    let mut heap = IndexedHeap::new(n);
+    let mut forest = Vec::with_capacity(n.saturating_sub(1));
+    for root in 0..n {
+        if in_tree[root] {
+            continue;
+        }
+        let mut next = Some(root);
+        while let Some(vertex) = next {
+            in_tree[vertex] = true;
+            for &(to, edge) in graph.adjacent(vertex) {
+                if !in_tree[to] {
+                    heap.push_or_decrease(to, (graph.edge(edge).weight.clone(), edge));
+                }
+            }
+            next = heap.pop().map(|(to, (_, edge))| {
+                forest.push(edge);
+                to
+            });
+        }
+    }
+    forest.sort_unstable();
+    (total_weight(graph, &forest), forest)
+}
+
+/// Computes a minimum spanning forest using Borůvka's algorithm, with the edges given implicitly.
+///
+/// The algorithm proceeds in rounds. On each round `cheapest` is called with the component of each vertex, numbered
+/// `0..k`, and it must return the cheapest edge `(from, to, weight)` leaving each component, or `None` if there are no
+/// such edges. The edges are typically indexed by the component, but any order is accepted. The cheapest edges are then
+/// added to the forest, merging the components. This allows computing spanning trees of complete graphs whose edge
+/// weights have structure, such as the minimum XOR spanning tree, where the cheapest edges can be found faster than by
+/// enumerating all edges.
+///
+/// Returns the edges of the forest. Ties between equal weights may be broken arbitrarily.
+///
+/// # Time complexity
+/// Makes `O(log n)` calls to `cheapest`, and takes `O(n α(n))` time per call in addition to that.
+///
+/// # Examples
+/// Minimum spanning tree of the complete graph where the weight of the edge between `a` and `b` is `|a - b| % 3`:
+/// ```
+/// # use comlib_graph::boruvka;
+/// let n = 5;
+/// let forest = boruvka(n, |component| {
+///     let mut best: Vec<Option<(usize, usize, usize)>> = vec![None; n];
+///     for a in 0..n {
+///         for b in 0..n {
+///             let edge = (a, b, (a.max(b) - a.min(b)) % 3);
+///             let best = &mut best[component[a]];
+///             if component[a] != component[b] && best.is_none_or(|best| edge.2 < best.2) {
+///                 *best = Some(edge);
+///             }
+///         }
+///     }
+///     best
+/// });
+/// assert_eq!(forest.len(), 4);
+/// assert_eq!(forest.iter().map(|edge| edge.2).sum::<usize>(), 2);
+/// ```
+pub fn boruvka<W, F>(n: usize, cheapest: F) -> Vec<(usize, usize, W)>
+where
+    F: FnMut(&[usize]) -> Vec<Option<(usize, usize, W)>>,
+{
+    let mut cheapest = cheapest;
+    let mut dsu = Dsu::new(n);
+    let mut forest = Vec::with_capacity(n.saturating_sub(1));
+    loop {
+        // Number the components by their representatives
+        let mut index = vec![usize::MAX; n];
+        let mut count = 0;
+        let component: Vec<usize> = (0..n)
+            .map(|vertex| {
+                let root = dsu.find(vertex);
+                if index[root] == usize::MAX {
+                    index[root] = count;
+                    count += 1;
+                }
+                index[root]
+            })
+            .collect();
+        if count <= 1 {
+            break;
+        }
+
+        let before = forest.len();
+        for (from, to, weight) in cheapest(&component).into_iter().flatten() {
+            if dsu.union(from, to) {
+                forest.push((from, to, weight));
+            }
+        }
+        if forest.len() == before {
+            break;
+        }
+    }
+    forest
+}
+
+/// Sums the weights of the given edges.
+fn total_weight<W>(graph: &Graph<W>, edges: &[usize]) -> W
+where
+    W: Clone + Add<Output = W> + Default,
+{
+    edges.iter().fold(W::default(), |sum, &edge| {
+        sum + graph.edge(edge).weight.clone()
+    })
+}
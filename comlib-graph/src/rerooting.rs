@@ -0,0 +1,120 @@
+use crate::{Adjacency, Graph};
+
+/// Computes a tree DP for every vertex of a forest as the root.
+///
+/// The value of a subtree is computed bottom-up: the values of the subtrees of the children of its root are combined
+/// with `merge`, starting from `identity`, and the result is turned into the value of the subtree with
+/// `add_root(merged, root, edge)`, where `edge` is the id of the edge from the root of the subtree to its parent, or
+/// `None` when the subtree is the whole tree. Rerooting reuses the values of the subtrees, so that the DP is computed
+/// for all roots at the cost of computing it for a single root.
+///
+/// `merge` must be associative and commutative and have `identity` as its identity element, as the values of the
+/// children are merged in an unspecified order.
+///
+/// Returns the value of the whole tree containing each vertex when rooted at that vertex.
+///
+/// # Panics
+/// Panics if the graph is not a forest.
+///
+/// # Time complexity
+/// Takes `O(n)` time, calling `merge` and `add_root` `O(n)` times.
+///
+/// # Examples
+/// The sum of the distances to all other vertices, with the subtree values being pairs of the number of vertices and
+/// the sum of their distances to the parent of the subtree:
+/// ```
+/// # use comlib_graph::{rerooting, Adjacency, Graph};
+/// // 0 -1- 1 -2- 2
+/// //       |
+/// //       5
+/// //       |
+/// //       3
+/// let tree = Graph::from_weighted_edges(4, vec![(0, 1, 1), (1, 2, 2), (1, 3, 5)]);
+/// let sums = rerooting(
+///     &tree,
+///     (0, 0),
+///     |a, b| (a.0 + b.0, a.1 + b.1),
+///     |&(count, sum), _, edge| {
+///         let weight = edge.map_or(0, |edge| tree.edge(edge).weight);
+///         (count + 1, sum + (count + 1) * weight)
+///     },
+/// );
+/// let sums: Vec<_> = sums.into_iter().map(|(_, sum)| sum).collect();
+/// assert_eq!(sums, vec![10, 8, 12, 18]);
+/// ```
+pub fn rerooting<W, T, M, R>(tree: &Graph<W>, identity: T, merge: M, add_root: R) -> Vec<T>
+where
+    T: Clone,
+    M: FnMut(&T, &T) -> T,
+    R: FnMut(&T, usize, Option<usize>) -> T,
+{
+    let mut merge = merge;
+    let mut add_root = add_root;
+    let n = tree.vertex_count();
+
+    // Root each tree of the forest at its smallest vertex and list the vertices in BFS order
+    let mut order = Vec::with_capacity(n);
+    let mut parent = vec![usize::MAX; n];
+    let mut parent_edge = vec![usize::MAX; n];
+    let mut visited = vec![false; n];
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut index = order.len();
+        order.push(root);
+        while let Some(&vertex) = order.get(index) {
+            index += 1;
+            for &(to, edge) in tree.adjacent(vertex) {
+                if edge == parent_edge[vertex] {
+                    continue;
+                }
+                assert!(!visited[to], "the graph must be a forest");
+                visited[to] = true;
+                parent[to] = vertex;
+                parent_edge[to] = edge;
+                order.push(to);
+            }
+        }
+    }
+    let edge_to_parent = |vertex: usize| Some(parent_edge[vertex]).filter(|&e| e != usize::MAX);
+
+    // The values of the subtrees below each vertex
+    let mut merged = vec![identity.clone(); n];
+    let mut down = vec![identity.clone(); n];
+    for &vertex in order.iter().rev() {
+        down[vertex] = add_root(&merged[vertex], vertex, edge_to_parent(vertex));
+        if parent[vertex] != usize::MAX {
+            merged[parent[vertex]] = merge(&merged[parent[vertex]], &down[vertex]);
+        }
+    }
+
+    // The values of the rest of the tree above each vertex, seen as a subtree of the vertex
+    let mut up = vec![identity.clone(); n];
+    let mut result = vec![identity.clone(); n];
+    let mut prefix = Vec::new();
+    for &vertex in &order {
+        let children: Vec<(usize, usize)> = tree
+            .adjacent(vertex)
+            .iter()
+            .copied()
+            .filter(|&(_, edge)| edge != parent_edge[vertex])
+            .collect();
+        // Merge the values around the vertex once from each side, so that each child can exclude itself
+        prefix.clear();
+        prefix.push(up[vertex].clone());
+        for &(child, _) in &children {
+            let value = merge(prefix.last().unwrap(), &down[child]);
+            prefix.push(value);
+        }
+        result[vertex] = add_root(prefix.last().unwrap(), vertex, None);
+        let mut suffix = identity.clone();
+        for (i, &(child, edge)) in children.iter().enumerate().rev() {
+            let others = merge(&prefix[i], &suffix);
+            up[child] = add_root(&others, vertex, Some(edge));
+            suffix = merge(&down[child], &suffix);
+        }
+    }
+    result
+}
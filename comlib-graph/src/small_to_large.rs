@@ -0,0 +1,122 @@
+use crate::{Adjacency, Dfs, Graph};
+
+/// Visits the subtrees of a rooted tree by merging the smaller subtrees into the larger ones, also known as DSU on tree.
+///
+/// Maintains a collection of vertices in `state`, into which vertices are inserted with `add` and from which they are
+/// deleted with `remove`. For each vertex `answer` is called exactly when the collection consists of the subtree of
+/// the vertex, so that it can compute the answer for the subtree. The collection of the child with the largest subtree
+/// is kept and the other subtrees are added to it, so that each vertex is added and removed only `O(log n)` times.
+/// The collection is empty once the function returns.
+///
+/// The callbacks receive the state as their first argument, so that they can share it mutably.
+///
+/// # Panics
+/// Panics if the graph is not a tree or if the root is out of bounds.
+///
+/// # Time complexity
+/// Takes `O(n log n)` time, calling `add` and `remove` `O(n log n)` times and `answer` n times.
+///
+/// # Examples
+/// Counting the distinct colors in each subtree:
+/// ```
+/// # use comlib_graph::{small_to_large, Graph};
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = Graph::from_edges(5, vec![(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let color = [0, 1, 0, 1, 2];
+/// // The number of vertices of each color, and the number of distinct colors
+/// let mut state = (vec![0; 3], 0);
+/// let mut distinct = vec![0; 5];
+/// small_to_large(
+///     &tree,
+///     0,
+///     &mut state,
+///     |(count, colors), v| {
+///         count[color[v]] += 1;
+///         if count[color[v]] == 1 {
+///             *colors += 1;
+///         }
+///     },
+///     |(count, colors), v| {
+///         count[color[v]] -= 1;
+///         if count[color[v]] == 0 {
+///             *colors -= 1;
+///         }
+///     },
+///     |(_, colors), v| distinct[v] = *colors,
+/// );
+/// assert_eq!(distinct, vec![3, 2, 1, 1, 1]);
+/// ```
+pub fn small_to_large<W, S, A, R, Q>(
+    tree: &Graph<W>,
+    root: usize,
+    state: &mut S,
+    add: A,
+    remove: R,
+    answer: Q,
+) where
+    A: FnMut(&mut S, usize),
+    R: FnMut(&mut S, usize),
+    Q: FnMut(&mut S, usize),
+{
+    let (mut add, mut remove, mut answer) = (add, remove, answer);
+    let n = tree.vertex_count();
+    assert!(root < n, "root out of bounds");
+    assert_eq!(tree.edge_count(), n - 1, "the graph must be a tree");
+
+    // In preorder the subtree of each vertex occupies a contiguous range of positions
+    let mut dfs = Dfs::new(tree, root);
+    let order: Vec<usize> = dfs.by_ref().collect();
+    assert_eq!(order.len(), n, "the graph must be a tree");
+    let parent = dfs.parents();
+    let mut position = vec![0; n];
+    for (i, &vertex) in order.iter().enumerate() {
+        position[vertex] = i;
+    }
+    let mut size = vec![1; n];
+    let mut heavy = vec![usize::MAX; n];
+    for &vertex in order.iter().rev() {
+        if let Some(parent) = parent[vertex] {
+            size[parent] += size[vertex];
+            if heavy[parent] == usize::MAX || size[vertex] > size[heavy[parent]] {
+                heavy[parent] = vertex;
+            }
+        }
+    }
+    let heavy = &heavy;
+    let subtree = |vertex: usize| &order[position[vertex]..position[vertex] + size[vertex]];
+    let light_children = |vertex: usize| {
+        tree.neighbors(vertex)
+            .filter(move |&to| Some(to) != parent[vertex] && to != heavy[vertex])
+    };
+
+    // Each task is a vertex with a flag telling whether its collection is kept, and whether the subtrees of its
+    // children have already been visited
+    let mut tasks = vec![(root, false, false)];
+    while let Some((vertex, keep, visited)) = tasks.pop() {
+        if !visited {
+            // Visit the light children first, then the heavy child whose collection is kept, and finally the vertex
+            tasks.push((vertex, keep, true));
+            if heavy[vertex] != usize::MAX {
+                tasks.push((heavy[vertex], true, false));
+            }
+            tasks.extend(light_children(vertex).map(|child| (child, false, false)));
+            continue;
+        }
+        for child in light_children(vertex) {
+            for &v in subtree(child) {
+                add(state, v);
+            }
+        }
+        add(state, vertex);
+        answer(state, vertex);
+        if !keep {
+            for &v in subtree(vertex) {
+                remove(state, v);
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{Adjacency, DiGraph};
+
+/// Computes a topological order of the vertices of a directed graph using Kahn's algorithm.
+///
+/// Returns the vertices ordered so that every edge goes from an earlier vertex to a later one. If the graph contains a
+/// cycle, returns instead the vertices of some cycle in order, so that there is an edge from each vertex to the next
+/// and from the last vertex to the first.
+///
+/// # Time complexity
+/// Takes `O(n + m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{topological_sort, DiGraph};
+/// let dag = DiGraph::from_edges(4, vec![(2, 1), (1, 0), (2, 3), (3, 0)]);
+/// assert_eq!(topological_sort(&dag), Ok(vec![2, 1, 3, 0]));
+///
+/// let cyclic = DiGraph::from_edges(4, vec![(0, 1), (1, 2), (2, 3), (3, 1)]);
+/// assert_eq!(topological_sort(&cyclic), Err(vec![1, 2, 3]));
+/// ```
+pub fn topological_sort<W>(graph: &DiGraph<W>) -> Result<Vec<usize>, Vec<usize>> {
+    let mut indegree = indegrees(graph);
+    let mut order: Vec<usize> = (0..graph.vertex_count())
+        .filter(|&v| indegree[v] == 0)
+        .collect();
+    // The order doubles as the queue of vertices to process
+    let mut processed = 0;
+    while let Some(&vertex) = order.get(processed) {
+        processed += 1;
+        for to in graph.neighbors(vertex) {
+            indegree[to] -= 1;
+            if indegree[to] == 0 {
+                order.push(to);
+            }
+        }
+    }
+    finish(graph, order)
+}
+
+/// Computes the lexicographically smallest topological order of the vertices of a directed graph.
+///
+/// Works like [`topological_sort`], including returning a cycle if the graph contains one, but always picks the
+/// smallest vertex whose predecessors have all been picked.
+///
+/// # Time complexity
+/// Takes `O(n log n + m)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{lexicographic_topological_sort, DiGraph};
+/// let dag = DiGraph::from_edges(4, vec![(2, 1), (1, 0), (2, 3), (3, 0)]);
+/// assert_eq!(lexicographic_topological_sort(&dag), Ok(vec![2, 1, 3, 0]));
+/// let dag = DiGraph::from_edges(4, vec![(3, 1), (2, 0)]);
+/// assert_eq!(lexicographic_topological_sort(&dag), Ok(vec![2, 0, 3, 1]));
+/// ```
+pub fn lexicographic_topological_sort<W>(graph: &DiGraph<W>) -> Result<Vec<usize>, Vec<usize>> {
+    let mut indegree = indegrees(graph);
+    let mut available: BinaryHeap<_> = (0..graph.vertex_count())
+        .filter(|&v| indegree[v] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(graph.vertex_count());
+    while let Some(Reverse(vertex)) = available.pop() {
+        order.push(vertex);
+        for to in graph.neighbors(vertex) {
+            indegree[to] -= 1;
+            if indegree[to] == 0 {
+                available.push(Reverse(to));
+            }
+        }
+    }
+    finish(graph, order)
+}
+
+/// Computes the number of edges entering each vertex.
+fn indegrees<W>(graph: &DiGraph<W>) -> Vec<usize> {
+    let mut indegree = vec![0; graph.vertex_count()];
+    for edge in graph.edges() {
+        indegree[edge.to] += 1;
+    }
+    indegree
+}
+
+/// Returns the order if it contains all vertices, and otherwise a cycle among the vertices missing from it.
+fn finish<W>(graph: &DiGraph<W>, order: Vec<usize>) -> Result<Vec<usize>, Vec<usize>> {
+    let n = graph.vertex_count();
+    if order.len() == n {
+        return Ok(order);
+    }
+
+    // Every vertex missing from the order has a predecessor which is also missing, so following the predecessors
+    // eventually leads into a cycle
+    let mut ordered = vec![false; n];
+    for &vertex in &order {
+        ordered[vertex] = true;
+    }
+    let mut predecessor = vec![usize::MAX; n];
+    for edge in graph.edges() {
+        if !ordered[edge.from] && !ordered[edge.to] {
+            predecessor[edge.to] = edge.from;
+        }
+    }
+    let mut seen = vec![false; n];
+    let mut vertex = (0..n).find(|&v| !ordered[v]).unwrap();
+    while !seen[vertex] {
+        seen[vertex] = true;
+        vertex = predecessor[vertex];
+    }
+    let mut cycle = vec![vertex];
+    let mut current = predecessor[vertex];
+    while current != vertex {
+        cycle.push(current);
+        current = predecessor[current];
+    }
+    cycle.reverse();
+    // Rotate the cycle to start from its smallest vertex
+    let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(smallest);
+    Err(cycle)
+}
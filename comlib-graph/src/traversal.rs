@@ -0,0 +1,207 @@
+use std::collections::VecDeque;
+
+use crate::Adjacency;
+
+/// Breadth-first traversal of a graph.
+///
+/// Iterates over the vertices reachable from the given sources in the order they are visited, that is in increasing
+/// order of their distances from the sources. The parent and the depth of each visited vertex in the BFS tree can be
+/// queried during and after the traversal.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Bfs, Graph};
+/// let graph = Graph::from_edges(5, vec![(0, 1), (0, 2), (2, 3), (1, 3)]);
+/// let mut bfs = Bfs::new(&graph, 0);
+/// assert_eq!(bfs.by_ref().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+/// assert_eq!(bfs.depth(3), Some(2));
+/// assert_eq!(bfs.parent(3), Some(1));
+/// assert_eq!(bfs.depth(4), None);
+/// ```
+///
+/// # Time complexity
+/// Traversing the whole graph takes `O(n + m)` time.
+#[derive(Debug, Clone)]
+pub struct Bfs<'a, G> {
+    graph: &'a G,
+    /// The vertices visited but not yet returned.
+    queue: VecDeque<usize>,
+    parent: Vec<Option<usize>>,
+    depth: Vec<Option<usize>>,
+}
+
+impl<'a, G: Adjacency> Bfs<'a, G> {
+    /// Starts a traversal from the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        Self::from_sources(graph, Some(start))
+    }
+
+    /// Starts a traversal from all of the given vertices at once, each of which has depth zero.
+    ///
+    /// # Panics
+    /// Panics if a vertex is out of bounds.
+    pub fn from_sources<I: IntoIterator<Item = usize>>(graph: &'a G, sources: I) -> Self {
+        let n = graph.vertex_count();
+        let mut bfs = Self {
+            graph,
+            queue: VecDeque::new(),
+            parent: vec![None; n],
+            depth: vec![None; n],
+        };
+        for source in sources {
+            if bfs.depth[source].is_none() {
+                bfs.depth[source] = Some(0);
+                bfs.queue.push_back(source);
+            }
+        }
+        bfs
+    }
+
+    /// Returns the parent of the vertex in the BFS tree, or `None` if it is a source or has not been visited.
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.parent[vertex]
+    }
+
+    /// Returns the distance of the vertex from the sources, or `None` if it has not been visited.
+    pub fn depth(&self, vertex: usize) -> Option<usize> {
+        self.depth[vertex]
+    }
+
+    /// Returns the parents of all vertices, see [`parent`](Self::parent).
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Returns the depths of all vertices, see [`depth`](Self::depth).
+    pub fn depths(&self) -> &[Option<usize>] {
+        &self.depth
+    }
+}
+
+impl<G: Adjacency> Iterator for Bfs<'_, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let vertex = self.queue.pop_front()?;
+        let depth = self.depth[vertex].map(|depth| depth + 1);
+        for to in self.graph.neighbors(vertex) {
+            if self.depth[to].is_none() {
+                self.depth[to] = depth;
+                self.parent[to] = Some(vertex);
+                self.queue.push_back(to);
+            }
+        }
+        Some(vertex)
+    }
+}
+
+/// Depth-first traversal of a graph.
+///
+/// Iterates over the vertices reachable from the given roots in preorder, exploring the edges in the order of the
+/// adjacency lists just like a recursive implementation would. The parent and the depth of each visited vertex in the
+/// DFS forest can be queried during and after the traversal.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Dfs, Graph};
+/// let graph = Graph::from_edges(6, vec![(0, 1), (0, 2), (2, 3), (1, 3), (4, 5)]);
+/// let mut dfs = Dfs::from_roots(&graph, 0..6);
+/// assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![0, 1, 3, 2, 4, 5]);
+/// assert_eq!(dfs.depth(2), Some(3));
+/// assert_eq!(dfs.parent(2), Some(3));
+/// assert_eq!(dfs.parent(4), None);
+/// ```
+///
+/// # Time complexity
+/// Traversing the whole graph takes `O(n + m)` time.
+#[derive(Debug, Clone)]
+pub struct Dfs<'a, G> {
+    graph: &'a G,
+    /// The roots from which to continue once the current tree has been traversed.
+    roots: VecDeque<usize>,
+    /// The path from the current root, with the number of edges explored from each vertex.
+    stack: Vec<(usize, usize)>,
+    parent: Vec<Option<usize>>,
+    depth: Vec<Option<usize>>,
+}
+
+impl<'a, G: Adjacency> Dfs<'a, G> {
+    /// Starts a traversal from the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the vertex is out of bounds.
+    pub fn new(graph: &'a G, start: usize) -> Self {
+        Self::from_roots(graph, Some(start))
+    }
+
+    /// Starts a traversal which traverses from each of the given vertices in order, skipping those already visited.
+    ///
+    /// Passing `0..n` as the roots traverses the whole graph.
+    ///
+    /// # Panics
+    /// Panics if a vertex is out of bounds.
+    pub fn from_roots<I: IntoIterator<Item = usize>>(graph: &'a G, roots: I) -> Self {
+        let n = graph.vertex_count();
+        Self {
+            graph,
+            roots: roots.into_iter().collect(),
+            stack: Vec::new(),
+            parent: vec![None; n],
+            depth: vec![None; n],
+        }
+    }
+
+    /// Returns the parent of the vertex in the DFS forest, or `None` if it is a root or has not been visited.
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.parent[vertex]
+    }
+
+    /// Returns the depth of the vertex in the DFS forest, or `None` if it has not been visited.
+    pub fn depth(&self, vertex: usize) -> Option<usize> {
+        self.depth[vertex]
+    }
+
+    /// Returns the parents of all vertices, see [`parent`](Self::parent).
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Returns the depths of all vertices, see [`depth`](Self::depth).
+    pub fn depths(&self) -> &[Option<usize>] {
+        &self.depth
+    }
+}
+
+impl<G: Adjacency> Iterator for Dfs<'_, G> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some(&mut (vertex, ref mut explored)) = self.stack.last_mut() {
+            let adjacent = self.graph.adjacent(vertex);
+            if *explored == adjacent.len() {
+                self.stack.pop();
+                continue;
+            }
+            let (to, _) = adjacent[*explored];
+            *explored += 1;
+            if self.depth[to].is_none() {
+                self.depth[to] = Some(self.stack.len());
+                self.parent[to] = Some(vertex);
+                self.stack.push((to, 0));
+                return Some(to);
+            }
+        }
+        // The current tree has been traversed, so continue from the next unvisited root
+        while let Some(root) = self.roots.pop_front() {
+            if self.depth[root].is_none() {
+                self.depth[root] = Some(0);
+                self.stack.push((root, 0));
+                return Some(root);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{Adjacency, Bfs, Graph};
+
+/// Rooted tree with the parents, depths and subtree sizes of its vertices.
+///
+/// All of the arrays are computed in a single breadth-first traversal from the root. The traversal order is also
+/// stored, so that bottom-up computations can process the vertices in reverse order.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{Graph, RootedTree};
+/// //     0
+/// //    / \
+/// //   1   2
+/// //  / \
+/// // 3   4
+/// let tree = Graph::from_edges(5, vec![(0, 1), (0, 2), (1, 3), (1, 4)]);
+/// let rooted = RootedTree::new(&tree, 0);
+/// assert_eq!(rooted.order(), &[0, 1, 2, 3, 4]);
+/// assert_eq!(rooted.parent(3), Some(1));
+/// assert_eq!(rooted.depth(4), 2);
+/// assert_eq!(rooted.subtree_size(1), 3);
+/// ```
+///
+/// # Time complexity
+/// All queries take `O(1)` time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RootedTree {
+    /// The vertices in BFS order.
+    order: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    subtree_size: Vec<usize>,
+}
+
+impl RootedTree {
+    /// Roots the tree at the given vertex.
+    ///
+    /// # Panics
+    /// Panics if the graph is not a tree or if the root is out of bounds.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n)` time.
+    pub fn new<W>(tree: &Graph<W>, root: usize) -> Self {
+        let n = tree.vertex_count();
+        assert!(root < n, "root out of bounds");
+        assert_eq!(tree.edge_count(), n - 1, "the graph must be a tree");
+        let mut bfs = Bfs::new(tree, root);
+        let order: Vec<usize> = bfs.by_ref().collect();
+        assert_eq!(order.len(), n, "the graph must be a tree");
+        let parent = bfs.parents().to_vec();
+        let depth = bfs.depths().iter().map(|depth| depth.unwrap()).collect();
+        let mut subtree_size = vec![1; n];
+        for &vertex in order.iter().rev() {
+            if let Some(parent) = parent[vertex] {
+                subtree_size[parent] += subtree_size[vertex];
+            }
+        }
+        Self {
+            order,
+            parent,
+            depth,
+            subtree_size,
+        }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> usize {
+        self.order[0]
+    }
+
+    /// Returns the vertices in BFS order, in which each vertex comes after its parent.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the parent of the vertex, or `None` if it is the root.
+    pub fn parent(&self, vertex: usize) -> Option<usize> {
+        self.parent[vertex]
+    }
+
+    /// Returns the depth of the vertex, that is its distance from the root.
+    pub fn depth(&self, vertex: usize) -> usize {
+        self.depth[vertex]
+    }
+
+    /// Returns the number of vertices in the subtree of the vertex, including the vertex itself.
+    pub fn subtree_size(&self, vertex: usize) -> usize {
+        self.subtree_size[vertex]
+    }
+
+    /// Returns the parents of all vertices, see [`parent`](Self::parent).
+    pub fn parents(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Returns the depths of all vertices, see [`depth`](Self::depth).
+    pub fn depths(&self) -> &[usize] {
+        &self.depth
+    }
+
+    /// Returns the subtree sizes of all vertices, see [`subtree_size`](Self::subtree_size).
+    pub fn subtree_sizes(&self) -> &[usize] {
+        &self.subtree_size
+    }
+}
+
+/// Finds a longest path in a tree, counting the edges.
+///
+/// The path is found with two breadth-first searches: the vertex farthest from any vertex is an end of a longest path,
+/// and the vertex farthest from it is the other end. Returns the vertices of the path in order, so that the diameter
+/// of the tree is one less than the number of vertices returned.
+///
+/// # Panics
+/// Panics if the graph is not a tree.
+///
+/// # Time complexity
+/// Takes `O(n)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{diameter, Graph};
+/// let tree = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 3), (1, 4), (4, 5)]);
+/// assert_eq!(diameter(&tree), vec![3, 2, 1, 4, 5]);
+/// ```
+pub fn diameter<W>(tree: &Graph<W>) -> Vec<usize> {
+    let n = tree.vertex_count();
+    assert!(n > 0, "the graph must be a tree");
+    let start = RootedTree::new(tree, 0).order.pop().unwrap();
+    let rooted = RootedTree::new(tree, start);
+    let end = *rooted.order.last().unwrap();
+    std::iter::successors(Some(end), |&v| rooted.parent[v]).collect()
+}
+
+/// Finds the center of a tree, that is the vertices minimizing the distance to the farthest vertex.
+///
+/// The center consists of the middle vertex or the two middle vertices of any longest path, and thus contains either
+/// one vertex or two adjacent vertices. The vertices are returned in increasing order.
+///
+/// # Panics
+/// Panics if the graph is not a tree.
+///
+/// # Time complexity
+/// Takes `O(n)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_graph::{center, Graph};
+/// let tree = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 3), (1, 4), (4, 5)]);
+/// assert_eq!(center(&tree), vec![1]);
+/// let path = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(center(&path), vec![1, 2]);
+/// ```
+pub fn center<W>(tree: &Graph<W>) -> Vec<usize> {
+    let path = diameter(tree);
+    let mut center = path[(path.len() - 1) / 2..=path.len() / 2].to_vec();
+    center.sort_unstable();
+    center
+}
@@ -0,0 +1,107 @@
+use comlib_graph::{Adjacency, Biconnectivity, Graph};
+use comlib_testing::lcg;
+
+#[test]
+fn test_biconnectivity() {
+    // Parallel edges and a self-loop
+    let graph = Graph::from_edges(5, vec![(0, 1), (1, 0), (1, 2), (2, 2), (3, 2)]);
+    let bcc = Biconnectivity::new(&graph);
+    assert_eq!(bcc.bridges(), &[2, 4]);
+    assert_eq!(bcc.articulation_points(), &[1, 2]);
+    assert_eq!(
+        bcc.two_edge_connected_components(),
+        &[vec![0, 1], vec![2], vec![3], vec![4]]
+    );
+    assert_eq!(bcc.two_edge_connected_component(1), 0);
+    assert_eq!(bcc.two_edge_connected_component(4), 3);
+    assert_eq!(bcc.blocks(), &[vec![0, 1], vec![1, 2], vec![2, 3], vec![4]]);
+
+    let tree = bcc.block_cut_tree();
+    assert_eq!(tree.vertex_count(), 9);
+    assert_eq!(tree.edge_count(), 7);
+    assert_eq!(tree.neighbors(2).collect::<Vec<_>>(), vec![6, 7]);
+    assert_eq!(tree.neighbors(4).collect::<Vec<_>>(), vec![8]);
+}
+
+#[test]
+fn test_biconnectivity_random() {
+    let mut random = lcg(1);
+
+    /// Counts the connected components, ignoring the given vertex and edge.
+    fn components(n: usize, edges: &[(usize, usize)], vertex: usize, edge: usize) -> usize {
+        let mut label: Vec<usize> = (0..n).collect();
+        for _ in 0..n {
+            for (i, &(a, b)) in edges.iter().enumerate() {
+                if i != edge && a != vertex && b != vertex {
+                    let min = label[a].min(label[b]);
+                    label[a] = min;
+                    label[b] = min;
+                }
+            }
+        }
+        (0..n).filter(|&v| v != vertex && label[v] == v).count()
+    }
+
+    for n in 1..16 {
+        let m = random(2 * n as u64) as usize;
+        let edges: Vec<_> = (0..m)
+            .map(|_| (random(n as u64) as usize, random(n as u64) as usize))
+            .collect();
+        let graph = Graph::from_edges(n, edges.clone());
+        let bcc = Biconnectivity::new(&graph);
+        let base = components(n, &edges, usize::MAX, usize::MAX);
+
+        let bridges: Vec<_> = (0..m)
+            .filter(|&e| components(n, &edges, usize::MAX, e) > base)
+            .collect();
+        assert_eq!(bcc.bridges(), &bridges[..]);
+        let articulation_points: Vec<_> = (0..n)
+            .filter(|&v| {
+                let isolated = edges.iter().all(|&(a, b)| a != v && b != v);
+                components(n, &edges, v, usize::MAX) > base - isolated as usize
+            })
+            .collect();
+        assert_eq!(bcc.articulation_points(), &articulation_points[..]);
+
+        // Vertices are 2-edge-connected exactly when they are connected without the bridges
+        let mut label: Vec<usize> = (0..n).collect();
+        for _ in 0..n {
+            for (i, &(x, y)) in edges.iter().enumerate() {
+                if !bridges.contains(&i) {
+                    let min = label[x].min(label[y]);
+                    label[x] = min;
+                    label[y] = min;
+                }
+            }
+        }
+        for a in 0..n {
+            for b in 0..n {
+                assert_eq!(
+                    bcc.two_edge_connected_component(a) == bcc.two_edge_connected_component(b),
+                    label[a] == label[b]
+                );
+            }
+        }
+
+        // Every non-loop edge lies in exactly one block, and the blocks meet only at articulation points
+        for &(a, b) in &edges {
+            if a != b {
+                let count = bcc
+                    .blocks()
+                    .iter()
+                    .filter(|block| block.contains(&a) && block.contains(&b))
+                    .count();
+                assert_eq!(count, 1);
+            }
+        }
+        for v in 0..n {
+            let count = bcc
+                .blocks()
+                .iter()
+                .filter(|block| block.contains(&v))
+                .count();
+            assert_eq!(count > 1, articulation_points.contains(&v));
+            assert!(count >= 1);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use comlib_graph::{centroid_decomposition, Bfs, Graph};
+use comlib_testing::lcg;
+
+#[test]
+fn test_centroid_decomposition() {
+    // Path 0 - 1 - 2 - 3 - 4 - 5 - 6 and an isolated vertex 7
+    let tree = Graph::from_edges(8, (0..6).map(|v| (v, v + 1)));
+    let mut visited = Vec::new();
+    let centroids = centroid_decomposition(&tree, |centroid, subtrees| {
+        visited.push((centroid, subtrees.to_vec()));
+    });
+    assert_eq!(centroids.order(), &[3, 1, 0, 2, 5, 4, 6, 7]);
+    assert_eq!(centroids.parent(3), None);
+    assert_eq!(centroids.parent(0), Some(1));
+    assert_eq!(centroids.level(6), 2);
+    assert_eq!(centroids.ancestors(4).collect::<Vec<_>>(), vec![4, 5, 3]);
+    assert_eq!(
+        visited[0],
+        (
+            3,
+            vec![vec![(2, 1), (1, 2), (0, 3)], vec![(4, 1), (5, 2), (6, 3)]]
+        )
+    );
+    assert_eq!(visited[7], (7, vec![]));
+}
+
+#[test]
+#[should_panic(expected = "must be a forest")]
+fn test_centroid_decomposition_cycle() {
+    let graph = Graph::from_edges(3, vec![(0, 1), (1, 2), (2, 0)]);
+    centroid_decomposition(&graph, |_, _| {});
+}
+
+#[test]
+fn test_centroid_decomposition_random() {
+    let mut random = lcg(1);
+
+    for n in 1..40 {
+        // Random forest
+        let edges: Vec<_> = (1..n)
+            .map(|v| (v, random(v as u64) as usize))
+            .filter(|&(v, _)| v % 8 != 5)
+            .collect();
+        let tree = Graph::from_edges(n, edges);
+        let distance: Vec<Vec<Option<usize>>> = (0..n)
+            .map(|v| {
+                let mut bfs = Bfs::new(&tree, v);
+                bfs.by_ref().for_each(drop);
+                bfs.depths().to_vec()
+            })
+            .collect();
+
+        // Count the pairs at each distance through the centroids
+        let mut pairs = vec![0; n];
+        let centroids = centroid_decomposition(&tree, |centroid, subtrees| {
+            let mut total = 0;
+            for subtree in subtrees {
+                for &(vertex, d) in subtree {
+                    assert_eq!(distance[centroid][vertex], Some(d));
+                    pairs[d] += 1;
+                }
+                total += subtree.len();
+            }
+            for (i, a) in subtrees.iter().enumerate() {
+                for b in &subtrees[i + 1..] {
+                    for &(_, x) in a {
+                        for &(_, y) in b {
+                            pairs[x + y] += 1;
+                        }
+                    }
+                }
+            }
+            // The component of the centroid has at most twice the size of any of its subtrees
+            assert!(subtrees
+                .iter()
+                .all(|subtree| 2 * subtree.len() <= total + 1));
+        });
+        for (d, &count) in pairs.iter().enumerate().skip(1) {
+            let expected = (0..n)
+                .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                .filter(|&(a, b)| distance[a][b] == Some(d))
+                .count();
+            assert_eq!(count, expected);
+        }
+        let mut order = centroids.order().to_vec();
+        order.sort_unstable();
+        assert_eq!(order, (0..n).collect::<Vec<_>>());
+        for v in 0..n {
+            assert!(1 << centroids.level(v) <= n);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use comlib_graph::{EulerTour, Graph};
+use comlib_range::Bit;
+use comlib_testing::lcg;
+
+#[test]
+fn test_euler_tour() {
+    let tree = Graph::from_edges(1, vec![]);
+    let tour = EulerTour::new(&tree, 0);
+    assert_eq!(tour.root(), 0);
+    assert_eq!(tour.lca(0, 0), 0);
+    assert_eq!(tour.subtree(0), 0..1);
+
+    // Subtree sums with a binary indexed tree
+    let tree = Graph::from_edges(6, vec![(3, 1), (1, 0), (2, 1), (3, 4), (4, 5)]);
+    let tour = EulerTour::new(&tree, 3);
+    assert_eq!(tour.order(), &[3, 1, 0, 2, 4, 5]);
+    let values = [1, 2, 4, 8, 16, 32];
+    let bit = Bit::from(
+        tour.order()
+            .iter()
+            .map(|&v| values[v])
+            .collect::<Vec<i32>>(),
+    );
+    assert_eq!(bit.sum(tour.subtree(1)), 7);
+    assert_eq!(bit.sum(tour.subtree(4)), 48);
+    assert_eq!(bit.sum(tour.subtree(3)), 63);
+    assert!(tour.is_ancestor(1, 2));
+    assert!(!tour.is_ancestor(2, 1));
+    assert_eq!(tour.parent(3), None);
+    assert_eq!(tour.parent(5), Some(4));
+}
+
+#[test]
+#[should_panic(expected = "must be a tree")]
+fn test_euler_tour_not_a_tree() {
+    let graph = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 0)]);
+    let _ = EulerTour::new(&graph, 0);
+}
+
+#[test]
+fn test_euler_tour_random() {
+    let mut random = lcg(1);
+
+    for n in 1..40 {
+        let parent: Vec<usize> = (1..n).map(|v| random(v as u64) as usize).collect();
+        let tree = Graph::from_edges(n, (1..n).map(|v| (v, parent[v - 1])));
+        let tour = EulerTour::new(&tree, 0);
+        let ancestors = |mut v: usize| {
+            let mut path = vec![v];
+            while v != 0 {
+                v = parent[v - 1];
+                path.push(v);
+            }
+            path
+        };
+        for a in 0..n {
+            assert_eq!(tour.depth(a), ancestors(a).len() - 1);
+            assert_eq!(
+                tour.subtree(a).len(),
+                (0..n).filter(|&v| ancestors(v).contains(&a)).count()
+            );
+            for b in 0..n {
+                let (path_a, path_b) = (ancestors(a), ancestors(b));
+                let lca = *path_a.iter().find(|v| path_b.contains(v)).unwrap();
+                assert_eq!(tour.lca(a, b), lca);
+                assert_eq!(tour.is_ancestor(a, b), path_b.contains(&a));
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use comlib_graph::{
+    directed_eulerian_circuit, directed_eulerian_path, eulerian_circuit, eulerian_path, Adjacency,
+    DiGraph, Graph,
+};
+use comlib_testing::lcg;
+
+#[test]
+fn test_eulerian_path() {
+    let graph = Graph::from_edges(3, vec![]);
+    assert_eq!(eulerian_path(&graph), Some((vec![0], vec![])));
+    assert_eq!(eulerian_circuit(&graph), Some((vec![0], vec![])));
+    let empty = Graph::from_edges(0, vec![]);
+    assert_eq!(eulerian_path(&empty), Some((vec![], vec![])));
+    assert_eq!(eulerian_circuit(&empty), Some((vec![], vec![])));
+    let empty = DiGraph::from_edges(0, vec![]);
+    assert_eq!(directed_eulerian_path(&empty), Some((vec![], vec![])));
+    assert_eq!(directed_eulerian_circuit(&empty), Some((vec![], vec![])));
+
+    // Self-loops and parallel edges
+    let graph = Graph::from_edges(3, vec![(1, 1), (1, 2), (2, 1), (2, 2)]);
+    let (vertices, edges) = eulerian_circuit(&graph).unwrap();
+    assert_eq!(vertices.len(), 5);
+    assert_eq!(vertices[0], 1);
+    assert_eq!(vertices[4], 1);
+    assert_eq!(edges.len(), 4);
+
+    // Disconnected edges
+    let graph = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(eulerian_path(&graph), None);
+
+    let graph = DiGraph::from_edges(3, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+    assert!(directed_eulerian_circuit(&graph).is_some());
+    let graph = DiGraph::from_edges(3, vec![(0, 1), (1, 2)]);
+    assert_eq!(
+        directed_eulerian_path(&graph),
+        Some((vec![0, 1, 2], vec![0, 1]))
+    );
+    assert_eq!(directed_eulerian_circuit(&graph), None);
+    let graph = DiGraph::from_edges(4, vec![(0, 1), (0, 2), (3, 0)]);
+    assert_eq!(directed_eulerian_path(&graph), None);
+}
+
+/// Checks that the result is a valid walk using each edge once.
+fn check<G: Adjacency>(graph: &G, directed: bool, (vertices, edges): &(Vec<usize>, Vec<usize>)) {
+    assert_eq!(vertices.len(), edges.len() + 1);
+    let mut sorted = edges.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, (0..graph.edge_count()).collect::<Vec<_>>());
+    for (i, &e) in edges.iter().enumerate() {
+        let edge = graph.edge(e);
+        let (a, b) = (vertices[i], vertices[i + 1]);
+        assert!((edge.from, edge.to) == (a, b) || (!directed && (edge.from, edge.to) == (b, a)));
+    }
+}
+
+#[test]
+fn test_eulerian_path_random() {
+    let mut random = lcg(1);
+
+    for _ in 0..500 {
+        let n = 1 + random(6) as usize;
+        let m = 1 + random(8) as usize;
+        let pairs: Vec<(usize, usize)> = (0..m)
+            .map(|_| (random(n as u64) as usize, random(n as u64) as usize))
+            .collect();
+
+        // Connectivity of the edges, ignoring directions
+        let undirected = Graph::from_edges(n, pairs.iter().copied());
+        let mut seen = vec![false; n];
+        let mut stack = vec![pairs[0].0];
+        seen[pairs[0].0] = true;
+        while let Some(v) = stack.pop() {
+            for to in undirected.neighbors(v) {
+                if !seen[to] {
+                    seen[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        let connected = pairs.iter().all(|&(a, _)| seen[a]);
+
+        let odd = (0..n).filter(|&v| undirected.degree(v) % 2 == 1).count();
+        let path = eulerian_path(&undirected);
+        assert_eq!(path.is_some(), connected && odd <= 2);
+        if let Some(path) = &path {
+            check(&undirected, false, path);
+        }
+        let circuit = eulerian_circuit(&undirected);
+        assert_eq!(circuit.is_some(), connected && odd == 0);
+        if let Some(circuit) = &circuit {
+            check(&undirected, false, circuit);
+            assert_eq!(circuit.0.first(), circuit.0.last());
+        }
+
+        let directed = DiGraph::from_edges(n, pairs.iter().copied());
+        let mut balance = vec![0i32; n];
+        for &(a, b) in &pairs {
+            balance[a] += 1;
+            balance[b] -= 1;
+        }
+        let unbalanced = balance.iter().map(|b| b.abs()).sum::<i32>();
+        let path = directed_eulerian_path(&directed);
+        assert_eq!(path.is_some(), connected && unbalanced <= 2);
+        if let Some(path) = &path {
+            check(&directed, true, path);
+        }
+        let circuit = directed_eulerian_circuit(&directed);
+        assert_eq!(circuit.is_some(), connected && unbalanced == 0);
+        if let Some(circuit) = &circuit {
+            check(&directed, true, circuit);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use comlib_graph::{FunctionalGraph, StepFold};
+use comlib_testing::lcg;
+
+#[test]
+fn test_functional_graph() {
+    let graph = FunctionalGraph::new(vec![]);
+    assert_eq!(graph.vertex_count(), 0);
+    assert!(graph.cycles().is_empty());
+
+    let graph = FunctionalGraph::new(vec![0]);
+    assert_eq!(graph.successor(0, u64::MAX), 0);
+    assert_eq!(graph.rho(0), (0, 1));
+
+    // 0 -> 5 -> 5, 1 -> 3 -> 2 -> 1, 4 -> 2
+    let graph = FunctionalGraph::new(vec![5, 3, 1, 2, 2, 5]);
+    assert_eq!(graph.cycles(), &[vec![5], vec![1, 3, 2]]);
+    assert_eq!(graph.cycle_index(4), 1);
+    assert_eq!(graph.cycle_entry(4), 2);
+    assert_eq!(graph.tail_length(4), 1);
+    assert!(graph.is_on_cycle(3));
+    assert_eq!(graph.next(4), 2);
+    assert_eq!(graph.successor(4, 3), 3);
+
+    // Composition of affine functions, which is not commutative
+    let values = vec![(2, 1), (3, 0), (1, 5), (1, 1), (5, 2), (2, 2)];
+    let compose = |f: &(u64, u64), g: &(u64, u64)| (f.0 * g.0 % 1000, (f.1 * g.0 + g.1) % 1000);
+    let folds = StepFold::new(&graph, values, (1, 0), compose);
+    assert_eq!(folds.fold(4, 4), (2, (15, 22)));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_functional_graph_out_of_bounds() {
+    let _ = FunctionalGraph::new(vec![1, 2]);
+}
+
+#[test]
+fn test_functional_graph_random() {
+    let mut random = lcg(1);
+
+    for n in 1..40 {
+        let next: Vec<usize> = (0..n).map(|_| random(n as u64) as usize).collect();
+        let graph = FunctionalGraph::new(next.clone());
+        let values: Vec<(u64, u64)> = (0..n).map(|_| (random(1000), random(1000))).collect();
+        let compose = |f: &(u64, u64), g: &(u64, u64)| (f.0 * g.0 % 1000, (f.1 * g.0 + g.1) % 1000);
+        let folds = StepFold::new(&graph, values.clone(), (1, 0), compose);
+
+        for start in 0..n {
+            // Walk until a vertex repeats
+            let mut path = vec![start];
+            let mut seen = vec![usize::MAX; n];
+            seen[start] = 0;
+            loop {
+                let to = next[*path.last().unwrap()];
+                if seen[to] != usize::MAX {
+                    let tail = seen[to];
+                    assert_eq!(graph.rho(start), (tail, path.len() - tail));
+                    assert_eq!(graph.cycle_entry(start), to);
+                    assert_eq!(graph.tail_length(start), tail);
+                    let cycle = &graph.cycles()[graph.cycle_index(start)];
+                    assert!(cycle.contains(&to));
+                    assert_eq!(cycle.len(), path.len() - tail);
+                    break;
+                }
+                seen[to] = path.len();
+                path.push(to);
+            }
+
+            let mut vertex = start;
+            let mut fold = (1, 0);
+            for k in 0..3 * n as u64 {
+                assert_eq!(graph.successor(start, k), vertex);
+                assert_eq!(folds.fold(start, k), (vertex, fold));
+                fold = compose(&fold, &values[vertex]);
+                vertex = next[vertex];
+            }
+
+            // Large numbers of steps agree with smaller ones modulo the cycle
+            let (tail, length) = graph.rho(start);
+            let k = 1_000_000_000_000_000_000 + random(1000);
+            let small = tail as u64 + (k - tail as u64) % length as u64;
+            assert_eq!(graph.successor(start, k), graph.successor(start, small));
+        }
+    }
+}
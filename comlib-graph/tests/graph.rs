@@ -0,0 +1,43 @@
+use comlib_graph::{Adjacency, DiGraph, Edge, Graph};
+
+#[test]
+fn test_graph() {
+    let graph = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 2), (3, 1)]);
+    assert_eq!(graph.vertex_count(), 4);
+    assert_eq!(graph.edge_count(), 4);
+    assert_eq!(graph.neighbors(1).collect::<Vec<_>>(), vec![0, 2, 3]);
+    assert_eq!(graph.adjacent(2), &[(1, 1), (2, 2), (2, 2)]);
+    assert_eq!(graph.degree(2), 3);
+    assert_eq!(
+        graph.edge(3),
+        &Edge {
+            from: 3,
+            to: 1,
+            weight: ()
+        }
+    );
+
+    let empty = Graph::from_edges(0, vec![]);
+    assert_eq!(empty.vertex_count(), 0);
+    assert!(empty.edges().is_empty());
+}
+
+#[test]
+fn test_digraph() {
+    let graph = DiGraph::from_weighted_edges(3, vec![(0, 1, 'a'), (2, 0, 'b'), (0, 2, 'c')]);
+    assert_eq!(graph.adjacent(0), &[(1, 0), (2, 2)]);
+    assert_eq!(graph.degree(1), 0);
+    assert_eq!(graph.edge(1).weight, 'b');
+
+    let reversed = graph.reversed();
+    assert_eq!(reversed.adjacent(0), &[(2, 1)]);
+    assert_eq!(reversed.adjacent(2), &[(0, 2)]);
+    assert_eq!(reversed.edge(0).from, 1);
+    assert_eq!(reversed.reversed(), graph);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_graph_out_of_bounds() {
+    let _ = Graph::from_edges(2, vec![(0, 2)]);
+}
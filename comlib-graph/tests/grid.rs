@@ -0,0 +1,102 @@
+use comlib_graph::{Grid, DIRECTIONS_4, DIRECTIONS_8};
+use comlib_testing::lcg;
+
+#[test]
+fn test_grid() {
+    let grid = Grid::new(3, 5);
+    assert_eq!(grid.len(), 15);
+    assert_eq!(grid.index((2, 1)), 11);
+    assert_eq!(grid.cell(11), (2, 1));
+    assert!(!grid.contains((3, 0)));
+    assert_eq!(grid.step((0, 4), (0, 1)), None);
+    assert_eq!(grid.step((1, 4), (1, -1)), Some((2, 3)));
+    assert_eq!(grid.neighbors((1, 1), &DIRECTIONS_8).count(), 8);
+    assert_eq!(
+        grid.neighbors((2, 4), &DIRECTIONS_8).collect::<Vec<_>>(),
+        vec![(1, 3), (1, 4), (2, 3)]
+    );
+    assert!(Grid::new(0, 4).is_empty());
+
+    // Diagonal moves pass through the gaps of a wall
+    let map = ["..#..", ".#...", "#...."];
+    let open = |(r, c): (usize, usize)| map[r].as_bytes()[c] == b'.';
+    let four = grid.bfs(vec![(0, 0)], &DIRECTIONS_4, open);
+    assert_eq!(four[grid.index((0, 4))], None);
+    let eight = grid.bfs(vec![(0, 0)], &DIRECTIONS_8, open);
+    assert_eq!(eight[grid.index((0, 4))], Some(4));
+    assert_eq!(
+        grid.flood_fill((0, 0), &DIRECTIONS_4, open),
+        vec![(0, 0), (0, 1), (1, 0)]
+    );
+    assert_eq!(grid.flood_fill((0, 0), &DIRECTIONS_8, open).len(), 12);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_grid_out_of_bounds() {
+    let _ = Grid::new(2, 2).index((0, 2));
+}
+
+#[test]
+fn test_grid_random() {
+    let mut random = lcg(1);
+
+    for _ in 0..100 {
+        let grid = Grid::new(1 + random(8) as usize, 1 + random(8) as usize);
+        let open: Vec<bool> = (0..grid.len()).map(|_| random(3) > 0).collect();
+        let sources: Vec<(usize, usize)> = (0..2)
+            .map(|_| grid.cell(random(grid.len() as u64) as usize))
+            .collect();
+        for directions in [&DIRECTIONS_4[..], &DIRECTIONS_8[..]] {
+            let distance = grid.bfs(sources.iter().copied(), directions, |cell| {
+                open[grid.index(cell)]
+            });
+
+            // Relax the distances until they stabilize
+            let mut expected = vec![None; grid.len()];
+            for &source in &sources {
+                expected[grid.index(source)] = Some(0);
+            }
+            loop {
+                let mut changed = false;
+                for index in 0..grid.len() {
+                    let cell = grid.cell(index);
+                    if expected[index] == Some(0) || !open[index] {
+                        continue;
+                    }
+                    let best = directions
+                        .iter()
+                        .filter_map(|&(dr, dc)| {
+                            let row = cell.0 as isize + dr;
+                            let col = cell.1 as isize + dc;
+                            if row < 0 || col < 0 || !grid.contains((row as usize, col as usize)) {
+                                return None;
+                            }
+                            expected[grid.index((row as usize, col as usize))].map(|d: usize| d + 1)
+                        })
+                        .min();
+                    if best.is_some() && best != expected[index] {
+                        expected[index] = best;
+                        changed = true;
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+            assert_eq!(distance, expected);
+
+            let mut filled = grid.flood_fill(sources[0], directions, |cell| open[grid.index(cell)]);
+            filled.sort_unstable();
+            let mut reachable: Vec<(usize, usize)> = (0..grid.len())
+                .filter(|&index| {
+                    grid.bfs(Some(sources[0]), directions, |cell| open[grid.index(cell)])[index]
+                        .is_some()
+                })
+                .map(|index| grid.cell(index))
+                .collect();
+            reachable.sort_unstable();
+            assert_eq!(filled, reachable);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use comlib_graph::{BipartiteMatching, MaxFlow};
+use comlib_testing::lcg;
+
+#[test]
+fn test_bipartite_matching() {
+    let matching = BipartiteMatching::new(0, 0, vec![]);
+    assert_eq!(matching.size(), 0);
+    assert_eq!(matching.min_vertex_cover(), (vec![], vec![]));
+
+    // A path needing a long augmenting path
+    let matching = BipartiteMatching::new(
+        4,
+        4,
+        vec![(0, 0), (1, 0), (1, 1), (2, 1), (2, 2), (3, 2), (3, 3)],
+    );
+    assert_eq!(matching.size(), 4);
+    assert_eq!(matching.left_match(0), Some(0));
+    assert_eq!(matching.right_match(3), Some(3));
+
+    let matching = BipartiteMatching::new(3, 2, vec![(0, 1), (2, 1)]);
+    assert_eq!(matching.size(), 1);
+    assert_eq!(matching.left_match(1), None);
+    assert_eq!(matching.right_match(0), None);
+    assert_eq!(matching.min_vertex_cover(), (vec![], vec![1]));
+    assert_eq!(matching.max_independent_set(), (vec![0, 1, 2], vec![0]));
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_bipartite_matching_out_of_bounds() {
+    let _ = BipartiteMatching::new(2, 1, vec![(0, 1)]);
+}
+
+#[test]
+fn test_bipartite_matching_random() {
+    let mut random = lcg(1);
+
+    for _ in 0..300 {
+        let left = random(10) as usize;
+        let right = random(10) as usize;
+        let m = if left * right > 0 { random(30) } else { 0 };
+        let edges: Vec<(usize, usize)> = (0..m)
+            .map(|_| (random(left as u64) as usize, random(right as u64) as usize))
+            .collect();
+        let matching = BipartiteMatching::new(left, right, edges.iter().copied());
+
+        let mut network = MaxFlow::new(left + right + 2);
+        for u in 0..left {
+            network.add_edge(left + right, u, 1);
+        }
+        for v in 0..right {
+            network.add_edge(left + v, left + right + 1, 1);
+        }
+        for &(u, v) in &edges {
+            network.add_edge(u, left + v, 1);
+        }
+        let size = network.max_flow(left + right, left + right + 1);
+        assert_eq!(matching.size(), size);
+
+        let pairs = matching.pairs();
+        assert_eq!(pairs.len(), size);
+        for &(u, v) in &pairs {
+            assert!(edges.contains(&(u, v)));
+            assert_eq!(matching.left_match(u), Some(v));
+            assert_eq!(matching.right_match(v), Some(u));
+        }
+
+        let (cover_left, cover_right) = matching.min_vertex_cover();
+        assert_eq!(cover_left.len() + cover_right.len(), size);
+        for &(u, v) in &edges {
+            assert!(cover_left.contains(&u) || cover_right.contains(&v));
+        }
+        let (set_left, set_right) = matching.max_independent_set();
+        assert_eq!(set_left.len() + set_right.len(), left + right - size);
+        for &(u, v) in &edges {
+            assert!(!set_left.contains(&u) || !set_right.contains(&v));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use comlib_graph::MaxFlow;
+use comlib_testing::lcg;
+
+#[test]
+fn test_max_flow() {
+    // Two disjoint paths and a bottleneck
+    let mut network = MaxFlow::new(6);
+    network.add_edge(0, 1, 10);
+    network.add_edge(0, 2, 10);
+    network.add_edge(1, 3, 4);
+    network.add_edge(2, 4, 9);
+    network.add_edge(3, 5, 10);
+    network.add_edge(4, 5, 10);
+    network.add_edge(1, 4, 8);
+    assert_eq!(network.max_flow(0, 5), 14);
+    assert_eq!(
+        network.min_cut(0),
+        vec![true, true, true, false, true, false]
+    );
+
+    // Augmenting after adding an edge continues from the current flow
+    network.add_edge(2, 3, 6);
+    assert_eq!(network.max_flow(0, 5), 6);
+    assert_eq!(network.flow(7), 6);
+    assert_eq!(network.capacity(7), 6);
+
+    // Disconnected sink
+    let mut network = MaxFlow::<u32>::new(3);
+    network.add_edge(0, 1, 5);
+    assert_eq!(network.max_flow_with_scaling(0, 2), 0);
+    assert_eq!(network.min_cut(0), vec![true, true, false]);
+}
+
+#[test]
+#[should_panic(expected = "must differ")]
+fn test_max_flow_same_terminals() {
+    let mut network = MaxFlow::<i32>::new(2);
+    network.max_flow(1, 1);
+}
+
+#[test]
+fn test_max_flow_random() {
+    let mut random = lcg(1);
+
+    for _ in 0..300 {
+        let n = 2 + random(8) as usize;
+        let m = random(25) as usize;
+        let edges: Vec<(usize, usize, i64)> = (0..m)
+            .map(|_| {
+                (
+                    random(n as u64) as usize,
+                    random(n as u64) as usize,
+                    random(20) as i64,
+                )
+            })
+            .collect();
+        let mut plain = MaxFlow::new(n);
+        let mut scaling = MaxFlow::new(n);
+        for &(from, to, capacity) in &edges {
+            plain.add_edge(from, to, capacity);
+            scaling.add_edge(from, to, capacity);
+        }
+        let flow = plain.max_flow(0, n - 1);
+        assert_eq!(scaling.max_flow_with_scaling(0, n - 1), flow);
+
+        for network in [&plain, &scaling] {
+            // The flow is feasible and conserved
+            let mut excess = vec![0; n];
+            for (e, &(from, to, capacity)) in edges.iter().enumerate() {
+                let f = network.flow(e);
+                assert!(0 <= f && f <= capacity);
+                excess[from] -= f;
+                excess[to] += f;
+            }
+            assert_eq!(excess[n - 1], flow);
+            assert!(excess[1..n - 1].iter().all(|&e| e == 0));
+
+            // The capacity of the cut equals the flow, which proves that the flow is maximum
+            let side = network.min_cut(0);
+            assert!(side[0] && !side[n - 1]);
+            let cut: i64 = edges
+                .iter()
+                .filter(|&&(from, to, _)| side[from] && !side[to])
+                .map(|&(_, _, capacity)| capacity)
+                .sum();
+            assert_eq!(cut, flow);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use comlib_graph::MinCostFlow;
+use comlib_testing::lcg;
+
+#[test]
+fn test_min_cost_flow() {
+    // Assignment of three workers to three jobs
+    let costs = [[4, 1, 3], [2, 0, 5], [3, 2, 2]];
+    let mut network = MinCostFlow::new(8);
+    for (i, row) in costs.iter().enumerate() {
+        network.add_edge(6, i, 1, 0);
+        network.add_edge(3 + i, 7, 1, 0);
+        for (j, &cost) in row.iter().enumerate() {
+            network.add_edge(i, 3 + j, 1, cost);
+        }
+    }
+    assert_eq!(network.min_cost_flow(6, 7, i32::MAX), (3, 5));
+
+    // Negative costs and incremental augmentation
+    let mut network = MinCostFlow::new(4);
+    network.add_edge(0, 1, 2, -3);
+    network.add_edge(1, 3, 1, 1);
+    network.add_edge(1, 2, 2, 4);
+    network.add_edge(2, 3, 3, -1);
+    network.add_edge(0, 2, 1, 2);
+    assert_eq!(network.min_cost_flow(0, 3, 1), (1, -2));
+    assert_eq!(network.slope(0, 3, 5), vec![(0, 0), (1, 0), (2, 1)]);
+    assert_eq!(network.flows(), vec![2, 1, 1, 2, 1]);
+    assert_eq!(network.min_cost_flow(0, 3, 5), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "negative cycles")]
+fn test_min_cost_flow_negative_cycle() {
+    let mut network = MinCostFlow::new(3);
+    network.add_edge(0, 1, 1, 1);
+    network.add_edge(1, 2, 1, -2);
+    network.add_edge(2, 1, 1, 0);
+    network.min_cost_flow(0, 2, 1);
+}
+
+/// Pushes the flow one unit at a time along cheapest paths found with the Bellman–Ford algorithm.
+fn brute_force(n: usize, edges: &[(usize, usize, i64, i64)], limit: i64) -> (i64, i64) {
+    let mut residual: Vec<(usize, usize, i64, i64)> = Vec::new();
+    for &(from, to, capacity, cost) in edges {
+        residual.push((from, to, capacity, cost));
+        residual.push((to, from, 0, -cost));
+    }
+    let (mut flow, mut total) = (0, 0);
+    while flow < limit {
+        let mut distance = vec![None; n];
+        let mut parent = vec![usize::MAX; n];
+        distance[0] = Some(0);
+        for _ in 0..n {
+            for (i, &(from, to, capacity, cost)) in residual.iter().enumerate() {
+                if let (Some(d), true) = (distance[from], capacity > 0) {
+                    if distance[to].is_none_or(|current| d + cost < current) {
+                        distance[to] = Some(d + cost);
+                        parent[to] = i;
+                    }
+                }
+            }
+        }
+        let cost = match distance[n - 1] {
+            Some(cost) => cost,
+            None => break,
+        };
+        let mut vertex = n - 1;
+        while vertex != 0 {
+            let edge = parent[vertex];
+            residual[edge].2 -= 1;
+            residual[edge ^ 1].2 += 1;
+            vertex = residual[edge].0;
+        }
+        flow += 1;
+        total += cost;
+    }
+    (flow, total)
+}
+
+#[test]
+fn test_min_cost_flow_random() {
+    let mut random = lcg(1);
+
+    for _ in 0..300 {
+        let n = 2 + random(7) as usize;
+        let m = random(20) as usize;
+        // Negative costs only on edges towards larger vertices and large costs on the rest, so that there are no
+        // negative cycles
+        let edges: Vec<(usize, usize, i64, i64)> = (0..m)
+            .map(|_| {
+                let from = random(n as u64) as usize;
+                let to = random(n as u64) as usize;
+                let cost = random(20) as i64 + if from < to { -8 } else { 8 * n as i64 };
+                (from, to, random(5) as i64, cost)
+            })
+            .collect();
+        let mut network = MinCostFlow::new(n);
+        for &(from, to, capacity, cost) in &edges {
+            network.add_edge(from, to, capacity, cost);
+        }
+
+        let (mut flow, mut cost) = (0, 0);
+        for step in 1..=4 {
+            let slope = network.slope(0, n - 1, step);
+            for pair in slope.windows(2) {
+                assert!(pair[0].0 < pair[1].0);
+            }
+            let (f, c) = *slope.last().unwrap();
+            flow += f;
+            cost += c;
+            assert_eq!((flow, cost), brute_force(n, &edges, flow));
+        }
+        let (f, c) = network.min_cost_flow(0, n - 1, i64::MAX);
+        flow += f;
+        cost += c;
+        assert_eq!((flow, cost), brute_force(n, &edges, i64::MAX));
+
+        let mut excess = vec![0; n];
+        let mut total = 0;
+        for (e, &(from, to, capacity, c)) in edges.iter().enumerate() {
+            let f = network.flow(e);
+            assert!(0 <= f && f <= capacity);
+            excess[from] -= f;
+            excess[to] += f;
+            total += f * c;
+        }
+        assert_eq!(excess[n - 1], flow);
+        assert!(excess[1..n - 1].iter().all(|&e| e == 0));
+        assert_eq!(total, cost);
+    }
+}
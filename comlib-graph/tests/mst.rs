@@ -0,0 +1,90 @@
+use comlib_graph::{boruvka, kruskal, prim, Graph};
+use comlib_testing::lcg;
+
+#[test]
+fn test_minimum_spanning_forest() {
+    let graph = Graph::from_weighted_edges(
+        6,
+        vec![
+            (0, 1, 3),
+            (1, 1, 0),
+            (1, 2, 3),
+            (0, 2, 3),
+            (3, 4, -2),
+            (4, 3, -5),
+        ],
+    );
+    assert_eq!(kruskal(&graph), (1, vec![0, 2, 5]));
+    assert_eq!(prim(&graph).0, 1);
+    assert_eq!(prim(&graph).1.len(), 3);
+
+    let empty = Graph::<i32>::from_weighted_edges(0, vec![]);
+    assert_eq!(kruskal(&empty), (0, vec![]));
+    assert_eq!(prim(&empty), (0, vec![]));
+}
+
+#[test]
+fn test_minimum_spanning_forest_random() {
+    let mut random = lcg(1);
+
+    for n in 1..30 {
+        let m = random(3 * n as u64) as usize;
+        let edges: Vec<_> = (0..m)
+            .map(|_| {
+                (
+                    random(n as u64) as usize,
+                    random(n as u64) as usize,
+                    random(20),
+                )
+            })
+            .collect();
+        let graph = Graph::from_weighted_edges(n, edges.clone());
+        let (weight, forest) = kruskal(&graph);
+        let (prim_weight, prim_forest) = prim(&graph);
+        assert_eq!(prim_weight, weight);
+        assert_eq!(prim_forest.len(), forest.len());
+
+        let boruvka_forest = boruvka(n, |component| {
+            let mut best: Vec<Option<(usize, usize, u64)>> = vec![None; n];
+            for &(a, b, w) in &edges {
+                for &(from, to) in &[(a, b), (b, a)] {
+                    let best = &mut best[component[from]];
+                    if component[from] != component[to] && best.is_none_or(|best| w < best.2) {
+                        *best = Some((from, to, w));
+                    }
+                }
+            }
+            best
+        });
+        assert_eq!(boruvka_forest.len(), forest.len());
+        assert_eq!(boruvka_forest.iter().map(|e| e.2).sum::<u64>(), weight);
+    }
+}
+
+#[test]
+fn test_minimum_xor_spanning_tree() {
+    let values = [5u32, 1, 12, 7, 3, 14, 9, 0];
+    let n = values.len();
+    let complete = Graph::from_weighted_edges(
+        n,
+        (0..n).flat_map(|a| (a + 1..n).map(move |b| (a, b, values[a] ^ values[b]))),
+    );
+    let forest = boruvka(n, |component| {
+        let count = component.iter().max().unwrap() + 1;
+        let mut best: Vec<Option<(usize, usize, u32)>> = vec![None; count];
+        for a in 0..n {
+            for b in 0..n {
+                let weight = values[a] ^ values[b];
+                let best = &mut best[component[a]];
+                if component[a] != component[b] && best.is_none_or(|best| weight < best.2) {
+                    *best = Some((a, b, weight));
+                }
+            }
+        }
+        best
+    });
+    assert_eq!(
+        forest.iter().map(|e| e.2).sum::<u32>(),
+        kruskal(&complete).0
+    );
+}
@@ -0,0 +1,77 @@
+use comlib_graph::{rerooting, Adjacency, Graph};
+use comlib_testing::lcg;
+
+#[test]
+fn test_rerooting() {
+    // Number of vertices on the longest path from the root
+    let tree = Graph::from_edges(6, vec![(0, 1), (1, 2), (2, 3), (1, 4), (4, 5)]);
+    let heights = rerooting(&tree, 0, |a, b| *a.max(b), |&h, _, _| h + 1);
+    assert_eq!(heights, vec![4, 3, 4, 5, 4, 5]);
+
+    // Single vertex and a forest
+    let tree = Graph::from_edges(1, vec![]);
+    assert_eq!(rerooting(&tree, 0, |a, b| a + b, |&s, _, _| s + 1), vec![1]);
+    let forest = Graph::from_edges(5, vec![(0, 3), (4, 2)]);
+    assert_eq!(
+        rerooting(&forest, 0, |a, b| a + b, |&s, _, _| s + 1),
+        vec![2, 1, 2, 2, 2]
+    );
+}
+
+#[test]
+#[should_panic(expected = "must be a forest")]
+fn test_rerooting_cycle() {
+    let graph = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+    let _ = rerooting(&graph, 0, |a, b| a + b, |&s, _, _| s + 1);
+}
+
+/// Computes the value of the subtree of `vertex` when its parent is `from`.
+fn brute_force(tree: &Graph<u64>, vertex: usize, from: Option<(usize, usize)>) -> (u64, u64, u64) {
+    // Number of vertices, sum of distances to the parent and product of the vertex labels modulo a prime
+    let mut merged = (0, 0, 1);
+    for &(to, edge) in tree.adjacent(vertex) {
+        if Some(edge) != from.map(|(_, e)| e) {
+            let child = brute_force(tree, to, Some((vertex, edge)));
+            merged = (
+                merged.0 + child.0,
+                merged.1 + child.1,
+                merged.2 * child.2 % 1_000_000_007,
+            );
+        }
+    }
+    let weight = from.map_or(0, |(_, edge)| tree.edge(edge).weight);
+    (
+        merged.0 + 1,
+        merged.1 + (merged.0 + 1) * weight,
+        merged.2 * (vertex as u64 + 2) % 1_000_000_007,
+    )
+}
+
+#[test]
+fn test_rerooting_random() {
+    let mut random = lcg(1);
+
+    for n in 1..30 {
+        let edges: Vec<_> = (1..n)
+            .map(|v| (v, random(v as u64) as usize, random(10)))
+            .filter(|&(v, _, _)| v % 7 != 3)
+            .collect();
+        let tree = Graph::from_weighted_edges(n, edges);
+        let values = rerooting(
+            &tree,
+            (0, 0, 1),
+            |a, b| (a.0 + b.0, a.1 + b.1, a.2 * b.2 % 1_000_000_007),
+            |merged, vertex, edge| {
+                let weight = edge.map_or(0, |edge| tree.edge(edge).weight);
+                (
+                    merged.0 + 1,
+                    merged.1 + (merged.0 + 1) * weight,
+                    merged.2 * (vertex as u64 + 2) % 1_000_000_007,
+                )
+            },
+        );
+        for (root, value) in values.into_iter().enumerate() {
+            assert_eq!(value, brute_force(&tree, root, None));
+        }
+    }
+}
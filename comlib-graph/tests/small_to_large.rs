@@ -0,0 +1,94 @@
+use comlib_graph::{small_to_large, Graph, RootedTree};
+use comlib_testing::lcg;
+
+#[test]
+fn test_small_to_large() {
+    let tree = Graph::from_edges(1, vec![]);
+    let mut answers = vec![];
+    small_to_large(
+        &tree,
+        0,
+        &mut 0,
+        |s, _| *s += 1,
+        |s, _| *s -= 1,
+        |s, v| answers.push((v, *s)),
+    );
+    assert_eq!(answers, vec![(0, 1)]);
+
+    // Sums of the subtrees of a path rooted in the middle
+    let tree = Graph::from_edges(5, vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+    let mut sums = vec![0; 5];
+    let mut total = 0;
+    small_to_large(
+        &tree,
+        2,
+        &mut total,
+        |s, v| *s += v,
+        |s, v| *s -= v,
+        |s, v| sums[v] = *s,
+    );
+    assert_eq!(sums, vec![0, 1, 10, 7, 4]);
+    assert_eq!(total, 0);
+}
+
+#[test]
+#[should_panic(expected = "must be a tree")]
+fn test_small_to_large_not_a_tree() {
+    let graph = Graph::from_edges(4, vec![(0, 1), (1, 2), (2, 0)]);
+    small_to_large(&graph, 0, &mut (), |_, _| {}, |_, _| {}, |_, _| {});
+}
+
+#[test]
+fn test_small_to_large_random() {
+    let mut random = lcg(1);
+
+    for n in 1..60 {
+        let parent: Vec<usize> = (1..n).map(|v| random(v as u64) as usize).collect();
+        let tree = Graph::from_edges(n, (1..n).map(|v| (v, parent[v - 1])));
+        let root = random(n as u64) as usize;
+        let rooted = RootedTree::new(&tree, root);
+
+        // The collection as a set of vertices, checking that no vertex is added twice or removed while missing
+        let mut collection = (vec![false; n], 0);
+        let mut operations = 0;
+        let mut subtrees = vec![Vec::new(); n];
+        small_to_large(
+            &tree,
+            root,
+            &mut collection,
+            |(present, count), v| {
+                assert!(!present[v]);
+                present[v] = true;
+                *count += 1;
+            },
+            |(present, count), v| {
+                assert!(present[v]);
+                present[v] = false;
+                *count -= 1;
+            },
+            |(present, _), v| {
+                operations += 1;
+                subtrees[v] = (0..n).filter(|&u| present[u]).collect();
+            },
+        );
+        assert_eq!(collection.1, 0);
+        assert_eq!(operations, n);
+
+        for (v, subtree) in subtrees.iter().enumerate() {
+            let expected: Vec<usize> = (0..n)
+                .filter(|&u| std::iter::successors(Some(u), |&u| rooted.parent(u)).any(|u| u == v))
+                .collect();
+            assert_eq!(*subtree, expected);
+        }
+    }
+
+    // Each vertex is added a logarithmic number of times even on a caterpillar
+    let n = 1 << 12;
+    let tree = Graph::from_edges(
+        n,
+        (1..n).map(|v| (v, if v % 2 == 0 { v - 2 } else { v - 1 })),
+    );
+    let mut adds = 0u64;
+    small_to_large(&tree, 0, &mut adds, |s, _| *s += 1, |_, _| {}, |_, _| {});
+    assert!(adds <= 2 * n as u64);
+}
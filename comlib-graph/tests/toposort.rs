@@ -0,0 +1,71 @@
+use comlib_graph::{lexicographic_topological_sort, topological_sort, DiGraph};
+use comlib_testing::lcg;
+
+/// Checks that the result is a valid topological order or a valid cycle.
+fn check(graph_edges: &[(usize, usize)], n: usize, result: &Result<Vec<usize>, Vec<usize>>) {
+    match result {
+        Ok(order) => {
+            let mut position = vec![usize::MAX; n];
+            for (i, &v) in order.iter().enumerate() {
+                assert_eq!(position[v], usize::MAX);
+                position[v] = i;
+            }
+            assert_eq!(order.len(), n);
+            for &(a, b) in graph_edges {
+                assert!(position[a] < position[b]);
+            }
+        }
+        Err(cycle) => {
+            assert!(!cycle.is_empty());
+            for i in 0..cycle.len() {
+                let edge = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                assert!(graph_edges.contains(&edge));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_topological_sort() {
+    let graph = DiGraph::from_edges(0, vec![]);
+    assert_eq!(topological_sort(&graph), Ok(vec![]));
+
+    let graph = DiGraph::from_edges(3, vec![(1, 1)]);
+    assert_eq!(topological_sort(&graph), Err(vec![1]));
+    assert_eq!(lexicographic_topological_sort(&graph), Err(vec![1]));
+
+    let graph = DiGraph::from_edges(5, vec![(4, 0), (3, 0), (1, 2)]);
+    assert_eq!(topological_sort(&graph), Ok(vec![1, 3, 4, 2, 0]));
+    assert_eq!(
+        lexicographic_topological_sort(&graph),
+        Ok(vec![1, 2, 3, 4, 0])
+    );
+}
+
+#[test]
+fn test_topological_sort_random() {
+    let mut random = lcg(1);
+
+    for n in 1..30 {
+        for acyclic in [false, true] {
+            let mut edges = Vec::new();
+            for _ in 0..n {
+                let (a, b) = (random(n as u64) as usize, random(n as u64) as usize);
+                if !acyclic || a < b {
+                    edges.push((a, b));
+                }
+            }
+            let graph = DiGraph::from_edges(n, edges.clone());
+            let result = topological_sort(&graph);
+            check(&edges, n, &result);
+            let lexicographic = lexicographic_topological_sort(&graph);
+            check(&edges, n, &lexicographic);
+            assert_eq!(result.is_ok(), lexicographic.is_ok());
+            if acyclic {
+                assert!(result.is_ok());
+                // All edges go forward, so the identity is the smallest order
+                assert_eq!(lexicographic, Ok((0..n).collect::<Vec<_>>()));
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use comlib_graph::{Bfs, Dfs, DiGraph, Graph};
+use comlib_testing::lcg;
+
+#[test]
+fn test_bfs() {
+    let graph = DiGraph::from_edges(6, vec![(0, 1), (1, 2), (3, 2), (2, 4), (4, 0)]);
+    let mut bfs = Bfs::new(&graph, 1);
+    assert_eq!(bfs.by_ref().collect::<Vec<_>>(), vec![1, 2, 4, 0]);
+    assert_eq!(
+        bfs.depths(),
+        &[Some(3), Some(0), Some(1), None, Some(2), None]
+    );
+    assert_eq!(
+        bfs.parents(),
+        &[Some(4), None, Some(1), None, Some(2), None]
+    );
+
+    let bfs = Bfs::from_sources(&graph, vec![3, 0, 3]);
+    assert_eq!(bfs.collect::<Vec<_>>(), vec![3, 0, 2, 1, 4]);
+}
+
+#[test]
+fn test_dfs() {
+    let graph = Graph::from_edges(7, vec![(0, 1), (1, 2), (0, 2), (2, 3), (4, 5)]);
+    let mut dfs = Dfs::new(&graph, 0);
+    assert_eq!(dfs.by_ref().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(dfs.depths()[..4], [Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(dfs.parent(3), Some(2));
+    assert_eq!(dfs.depth(4), None);
+
+    let dfs = Dfs::from_roots(&graph, (0..7).rev());
+    assert_eq!(dfs.collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_traversals_random() {
+    let mut random = lcg(1);
+
+    for n in 1..20 {
+        let edges: Vec<_> = (0..2 * n)
+            .map(|_| (random(n as u64) as usize, random(n as u64) as usize))
+            .collect();
+        let graph = Graph::from_edges(n, edges.clone());
+
+        // Compute the distances by relaxing the edges until nothing changes
+        let mut distance = vec![usize::MAX; n];
+        distance[0] = 0;
+        for _ in 0..n {
+            for &(a, b) in &edges {
+                distance[b] = distance[b].min(distance[a].saturating_add(1));
+                distance[a] = distance[a].min(distance[b].saturating_add(1));
+            }
+        }
+
+        let mut bfs = Bfs::new(&graph, 0);
+        let order: Vec<_> = bfs.by_ref().collect();
+        assert!(order.windows(2).all(|w| distance[w[0]] <= distance[w[1]]));
+        for v in 0..n {
+            let expected = Some(distance[v]).filter(|&d| d != usize::MAX);
+            assert_eq!(bfs.depth(v), expected);
+            if let Some(parent) = bfs.parent(v) {
+                assert_eq!(distance[parent] + 1, distance[v]);
+            }
+        }
+
+        let mut dfs = Dfs::from_roots(&graph, 0..n);
+        let order: Vec<_> = dfs.by_ref().collect();
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+        for v in 0..n {
+            match dfs.parent(v) {
+                Some(parent) => assert_eq!(dfs.depth(parent).unwrap() + 1, dfs.depth(v).unwrap()),
+                None => assert_eq!(dfs.depth(v), Some(0)),
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use comlib_graph::{center, diameter, Graph, RootedTree};
+use comlib_testing::lcg;
+
+#[test]
+fn test_rooted_tree() {
+    let tree = Graph::from_edges(1, vec![]);
+    let rooted = RootedTree::new(&tree, 0);
+    assert_eq!(rooted.root(), 0);
+    assert_eq!(rooted.subtree_sizes(), &[1]);
+    assert_eq!(diameter(&tree), vec![0]);
+    assert_eq!(center(&tree), vec![0]);
+
+    let tree = Graph::from_edges(6, vec![(3, 1), (1, 0), (2, 1), (3, 4), (4, 5)]);
+    let rooted = RootedTree::new(&tree, 3);
+    assert_eq!(rooted.order(), &[3, 1, 4, 0, 2, 5]);
+    assert_eq!(
+        rooted.parents(),
+        &[Some(1), Some(3), Some(1), None, Some(3), Some(4)]
+    );
+    assert_eq!(rooted.depths(), &[2, 1, 2, 0, 1, 2]);
+    assert_eq!(rooted.subtree_sizes(), &[1, 3, 1, 6, 2, 1]);
+    assert_eq!(diameter(&tree).len(), 5);
+    assert_eq!(center(&tree), vec![3]);
+}
+
+#[test]
+#[should_panic(expected = "must be a tree")]
+fn test_rooted_tree_not_connected() {
+    let graph = Graph::from_edges(4, vec![(0, 1), (1, 0), (2, 3)]);
+    let _ = RootedTree::new(&graph, 0);
+}
+
+#[test]
+fn test_diameter_random() {
+    let mut random = lcg(1);
+
+    for n in 1..40 {
+        let parent: Vec<usize> = (1..n).map(|v| random(v as u64) as usize).collect();
+        let tree = Graph::from_edges(n, (1..n).map(|v| (v, parent[v - 1])));
+        let rooted: Vec<RootedTree> = (0..n).map(|v| RootedTree::new(&tree, v)).collect();
+        let eccentricity: Vec<usize> = rooted
+            .iter()
+            .map(|r| *r.depths().iter().max().unwrap())
+            .collect();
+
+        let path = diameter(&tree);
+        assert_eq!(path.len() - 1, *eccentricity.iter().max().unwrap());
+        for pair in path.windows(2) {
+            assert!(
+                rooted[0].parent(pair[0]) == Some(pair[1])
+                    || rooted[0].parent(pair[1]) == Some(pair[0])
+            );
+        }
+
+        let radius = *eccentricity.iter().min().unwrap();
+        let expected: Vec<usize> = (0..n).filter(|&v| eccentricity[v] == radius).collect();
+        assert_eq!(center(&tree), expected);
+
+        for r in &rooted {
+            for v in 0..n {
+                let size = (0..n)
+                    .filter(|&u| std::iter::successors(Some(u), |&u| r.parent(u)).any(|u| u == v))
+                    .count();
+                assert_eq!(r.subtree_size(v), size);
+            }
+        }
+    }
+}
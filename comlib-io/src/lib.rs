@@ -65,7 +65,7 @@ where
         }
 
         // Trim control characters from the end
-        while line.chars().last().map_or(false, char::is_control) {
+        while line.chars().last().is_some_and(char::is_control) {
             line.pop();
         }
 
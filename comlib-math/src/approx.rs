@@ -0,0 +1,151 @@
+use crate::Float;
+use std::cmp::Ordering;
+
+/// Tolerance used for comparing floating-point numbers.
+///
+/// Two numbers are considered equal if their difference is at most `absolute`, or at most `relative` times the larger
+/// of their absolute values. The absolute tolerance handles numbers close to zero, and the relative one handles large
+/// numbers whose precision is worse than the absolute tolerance.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::Eps;
+/// use std::cmp::Ordering;
+/// let eps = Eps::absolute(1e-6);
+/// assert!(eps.approx_eq(0.1 + 0.2, 0.3));
+/// assert!(!eps.approx_eq(1.0, 1.001));
+/// assert_eq!(eps.approx_cmp(1.0, 1.001), Ordering::Less);
+/// assert!(Eps::<f64>::DEFAULT.approx_eq(1e20, 1e20 + 1e5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Eps<T> {
+    /// Largest difference considered equal regardless of the magnitude of the numbers.
+    pub absolute: T,
+    /// Largest difference considered equal, relative to the magnitude of the numbers.
+    pub relative: T,
+}
+
+impl Eps<f32> {
+    /// Default tolerance for [`f32`], used by [`AlmostEq`].
+    pub const DEFAULT: Self = Self {
+        absolute: 1e-5,
+        relative: 1e-5,
+    };
+}
+
+impl Eps<f64> {
+    /// Default tolerance for [`f64`], used by [`AlmostEq`].
+    pub const DEFAULT: Self = Self {
+        absolute: 1e-9,
+        relative: 1e-9,
+    };
+}
+
+impl<T: Float> Eps<T> {
+    /// Constructs a new tolerance from absolute and relative tolerances.
+    pub fn new(absolute: T, relative: T) -> Self {
+        Self { absolute, relative }
+    }
+
+    /// Constructs a new tolerance which only uses an absolute tolerance.
+    pub fn absolute(absolute: T) -> Self {
+        Self::new(absolute, T::zero())
+    }
+
+    /// Checks whether the numbers are equal within the tolerance.
+    pub fn approx_eq(self, a: T, b: T) -> bool {
+        let difference = (a - b).get_abs();
+        let (a, b) = (a.get_abs(), b.get_abs());
+        let magnitude = if a > b { a } else { b };
+        difference <= self.absolute || difference <= self.relative * magnitude
+    }
+
+    /// Compares the numbers, considering them equal if they are within the tolerance.
+    ///
+    /// Note that the resulting ordering is not transitive.
+    pub fn approx_cmp(self, a: T, b: T) -> Ordering {
+        if self.approx_eq(a, b) {
+            Ordering::Equal
+        } else if a < b {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    /// Checks whether the number is zero within the absolute tolerance.
+    pub fn is_zero(self, value: T) -> bool {
+        value.get_abs() <= self.absolute
+    }
+}
+
+/// Trait for comparing numbers while allowing for rounding errors.
+///
+/// Integers are compared exactly, whereas floating-point numbers are compared using their default [`Eps`]. This allows
+/// writing generic code that works for both exact and inexact numbers.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::AlmostEq;
+/// fn is_right_triangle<T: AlmostEq + std::ops::Add<Output = T> + std::ops::Mul<Output = T>>(
+///     a: T,
+///     b: T,
+///     c: T,
+/// ) -> bool {
+///     (a * a + b * b).approx_eq(c * c)
+/// }
+/// assert!(is_right_triangle(3, 4, 5));
+/// assert!(is_right_triangle(0.3, 0.4, 0.5));
+/// assert!(!is_right_triangle(0.3, 0.4, 0.51));
+/// ```
+pub trait AlmostEq: Copy {
+    /// Checks whether the numbers are approximately equal.
+    fn approx_eq(self, rhs: Self) -> bool;
+
+    /// Compares the numbers, considering approximately equal numbers equal.
+    fn approx_cmp(self, rhs: Self) -> Ordering;
+}
+
+macro_rules! impl_almost_eq {
+    (exact $t:ty) => {
+        impl AlmostEq for $t {
+            #[inline(always)]
+            fn approx_eq(self, rhs: Self) -> bool {
+                self == rhs
+            }
+
+            #[inline(always)]
+            fn approx_cmp(self, rhs: Self) -> Ordering {
+                self.cmp(&rhs)
+            }
+        }
+    };
+    (epsilon $t:ty) => {
+        impl AlmostEq for $t {
+            #[inline(always)]
+            fn approx_eq(self, rhs: Self) -> bool {
+                Eps::<$t>::DEFAULT.approx_eq(self, rhs)
+            }
+
+            #[inline(always)]
+            fn approx_cmp(self, rhs: Self) -> Ordering {
+                Eps::<$t>::DEFAULT.approx_cmp(self, rhs)
+            }
+        }
+    };
+}
+
+impl_almost_eq!(exact i8);
+impl_almost_eq!(exact i16);
+impl_almost_eq!(exact i32);
+impl_almost_eq!(exact i64);
+impl_almost_eq!(exact i128);
+impl_almost_eq!(exact isize);
+impl_almost_eq!(exact u8);
+impl_almost_eq!(exact u16);
+impl_almost_eq!(exact u32);
+impl_almost_eq!(exact u64);
+impl_almost_eq!(exact u128);
+impl_almost_eq!(exact usize);
+impl_almost_eq!(epsilon f32);
+impl_almost_eq!(epsilon f64);
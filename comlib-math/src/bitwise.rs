@@ -0,0 +1,230 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Calls `f(low, high)` for every pair of indices differing only in a single bit, where `low` has the bit unset.
+///
+/// The pairs are visited one bit at a time, starting from the lowest one, which is the order needed by all the
+/// transforms in this module.
+fn for_each_bit_pair<T, F>(values: &mut [T], mut f: F)
+where
+    T: Copy,
+    F: FnMut(&mut T, &mut T),
+{
+    assert!(
+        values.len().is_power_of_two(),
+        "length must be a power of two"
+    );
+    let mut bit = 1;
+    while bit < values.len() {
+        for chunk in values.chunks_mut(2 * bit) {
+            let (low, high) = chunk.split_at_mut(bit);
+            for (low, high) in low.iter_mut().zip(high) {
+                f(low, high);
+            }
+        }
+        bit *= 2;
+    }
+}
+
+/// Transforms `values` in place so that `values[mask]` becomes the sum of the original values over all submasks of
+/// `mask`.
+///
+/// Also known as the sum over subsets (SOS) transform. The inverse is [`subset_mobius`].
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of `values`.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::subset_zeta;
+/// let mut values = vec![1, 2, 3, 4];
+/// subset_zeta(&mut values);
+/// assert_eq!(values, vec![1, 1 + 2, 1 + 3, 1 + 2 + 3 + 4]);
+/// ```
+pub fn subset_zeta<T>(values: &mut [T])
+where
+    T: Copy + Add<Output = T>,
+{
+    for_each_bit_pair(values, |low, high| *high = *high + *low);
+}
+
+/// Inverts [`subset_zeta`] in place.
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of `values`.
+pub fn subset_mobius<T>(values: &mut [T])
+where
+    T: Copy + Sub<Output = T>,
+{
+    for_each_bit_pair(values, |low, high| *high = *high - *low);
+}
+
+/// Transforms `values` in place so that `values[mask]` becomes the sum of the original values over all supermasks of
+/// `mask`.
+///
+/// The inverse is [`superset_mobius`].
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of `values`.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::superset_zeta;
+/// let mut values = vec![1, 2, 3, 4];
+/// superset_zeta(&mut values);
+/// assert_eq!(values, vec![1 + 2 + 3 + 4, 2 + 4, 3 + 4, 4]);
+/// ```
+pub fn superset_zeta<T>(values: &mut [T])
+where
+    T: Copy + Add<Output = T>,
+{
+    for_each_bit_pair(values, |low, high| *low = *low + *high);
+}
+
+/// Inverts [`superset_zeta`] in place.
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of `values`.
+pub fn superset_mobius<T>(values: &mut [T])
+where
+    T: Copy + Sub<Output = T>,
+{
+    for_each_bit_pair(values, |low, high| *low = *low - *high);
+}
+
+/// Applies the unnormalized [Walsh–Hadamard transform] to `values` in place.
+///
+/// Applying the transform twice multiplies every value by the length of `values`.
+///
+/// # Panics
+/// Panics if the length of `values` is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of `values`.
+///
+/// [Walsh–Hadamard transform]: https://en.wikipedia.org/wiki/Fast_Walsh%E2%80%93Hadamard_transform
+pub fn walsh_hadamard<T>(values: &mut [T])
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    for_each_bit_pair(values, |low, high| {
+        let (a, b) = (*low, *high);
+        *low = a + b;
+        *high = a - b;
+    });
+}
+
+/// Multiplies two transformed sequences pointwise.
+fn pointwise_product<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    a.iter().zip(b).map(|(&a, &b)| a * b).collect()
+}
+
+/// Computes the OR convolution of two sequences.
+///
+/// The result satisfies `result[k] = Σ a[i] * b[j]` over all `i | j == k`.
+///
+/// # Panics
+/// Panics if the sequences are of different lengths or if the length is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of the sequences.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::or_convolution;
+/// assert_eq!(or_convolution(&[1, 2], &[3, 4]), vec![1 * 3, 1 * 4 + 2 * 3 + 2 * 4]);
+/// ```
+pub fn or_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    assert_eq!(a.len(), b.len(), "sequences must be of the same length");
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    subset_zeta(&mut a);
+    subset_zeta(&mut b);
+    let mut result = pointwise_product(&a, &b);
+    subset_mobius(&mut result);
+    result
+}
+
+/// Computes the AND convolution of two sequences.
+///
+/// The result satisfies `result[k] = Σ a[i] * b[j]` over all `i & j == k`.
+///
+/// # Panics
+/// Panics if the sequences are of different lengths or if the length is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of the sequences.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::and_convolution;
+/// assert_eq!(and_convolution(&[1, 2], &[3, 4]), vec![1 * 3 + 1 * 4 + 2 * 3, 2 * 4]);
+/// ```
+pub fn and_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    assert_eq!(a.len(), b.len(), "sequences must be of the same length");
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    superset_zeta(&mut a);
+    superset_zeta(&mut b);
+    let mut result = pointwise_product(&a, &b);
+    superset_mobius(&mut result);
+    result
+}
+
+/// Computes the XOR convolution of two sequences.
+///
+/// The result satisfies `result[k] = Σ a[i] * b[j]` over all `i ^ j == k`.
+///
+/// The inverse transform divides by the length of the sequences, so `T` must support exact division by powers of two.
+/// This holds for integers, and for [`ModInt`](crate::ModInt) with an odd prime modulus that implements [`Default`],
+/// such as [`Mod1e9p7`](crate::Mod1e9p7).
+///
+/// # Panics
+/// Panics if the sequences are of different lengths or if the length is not a power of two.
+///
+/// # Time complexity
+/// Takes O(n log n) time, where n is the length of the sequences.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::xor_convolution;
+/// assert_eq!(xor_convolution(&[1, 2], &[3, 4]), vec![1 * 3 + 2 * 4, 1 * 4 + 2 * 3]);
+/// ```
+pub fn xor_convolution<T>(a: &[T], b: &[T]) -> Vec<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + From<u8>,
+{
+    assert_eq!(a.len(), b.len(), "sequences must be of the same length");
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    walsh_hadamard(&mut a);
+    walsh_hadamard(&mut b);
+    let mut result = pointwise_product(&a, &b);
+    walsh_hadamard(&mut result);
+
+    let mut length = T::from(1);
+    for _ in 0..result.len().trailing_zeros() {
+        length = length + length;
+    }
+    for value in &mut result {
+        *value = *value / length;
+    }
+    result
+}
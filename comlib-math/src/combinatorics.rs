@@ -0,0 +1,163 @@
+use crate::{ModInt, Modulus};
+
+/// Constructs the sequence `0, 1, ..., n` in the given modulus.
+fn counting<M: Modulus>(n: usize, modulus: M) -> Vec<ModInt<M>> {
+    let one = ModInt::from((M::Base::from(1), modulus));
+    let mut values = Vec::with_capacity(n + 1);
+    values.push(ModInt::from((M::Base::from(0), modulus)));
+    for i in 1..=n {
+        values.push(values[i - 1] + one);
+    }
+    values
+}
+
+/// Tabulates the unsigned [Stirling numbers of the first kind] up to `n`.
+///
+/// The returned table is triangular: `table[i][k]` is the number of permutations of `i` elements with exactly `k`
+/// cycles, for `0 <= k <= i`.
+///
+/// # Time complexity
+/// Takes O(n²) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{stirling1_table, Mod1e9p7};
+/// let table = stirling1_table(4, Mod1e9p7);
+/// let row: Vec<u64> = table[4].iter().map(|v| v.into_inner()).collect();
+/// assert_eq!(row, [0, 6, 11, 6, 1]);
+/// ```
+///
+/// [Stirling numbers of the first kind]: https://en.wikipedia.org/wiki/Stirling_numbers_of_the_first_kind
+pub fn stirling1_table<M: Modulus>(n: usize, modulus: M) -> Vec<Vec<ModInt<M>>> {
+    let numbers = counting(n, modulus);
+    let zero = numbers[0];
+    let mut table: Vec<Vec<ModInt<M>>> = Vec::with_capacity(n + 1);
+    table.push(vec![ModInt::from((M::Base::from(1), modulus))]);
+    for i in 1..=n {
+        // c(i, k) = (i - 1) c(i - 1, k) + c(i - 1, k - 1)
+        let previous = &table[i - 1];
+        let mut row = vec![zero; i + 1];
+        for (k, value) in row.iter_mut().enumerate().skip(1) {
+            let stay = previous.get(k).map_or(zero, |&c| numbers[i - 1] * c);
+            *value = stay + previous[k - 1];
+        }
+        table.push(row);
+    }
+    table
+}
+
+/// Tabulates the [Stirling numbers of the second kind] up to `n`.
+///
+/// The returned table is triangular: `table[i][k]` is the number of ways to partition a set of `i` elements into
+/// exactly `k` non-empty subsets, for `0 <= k <= i`.
+///
+/// # Time complexity
+/// Takes O(n²) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{stirling2_table, Mod1e9p7};
+/// let table = stirling2_table(4, Mod1e9p7);
+/// let row: Vec<u64> = table[4].iter().map(|v| v.into_inner()).collect();
+/// assert_eq!(row, [0, 1, 7, 6, 1]);
+/// ```
+///
+/// [Stirling numbers of the second kind]: https://en.wikipedia.org/wiki/Stirling_numbers_of_the_second_kind
+pub fn stirling2_table<M: Modulus>(n: usize, modulus: M) -> Vec<Vec<ModInt<M>>> {
+    let numbers = counting(n, modulus);
+    let zero = numbers[0];
+    let mut table: Vec<Vec<ModInt<M>>> = Vec::with_capacity(n + 1);
+    table.push(vec![ModInt::from((M::Base::from(1), modulus))]);
+    for i in 1..=n {
+        // S(i, k) = k S(i - 1, k) + S(i - 1, k - 1)
+        let previous = &table[i - 1];
+        let mut row = vec![zero; i + 1];
+        for (k, value) in row.iter_mut().enumerate().skip(1) {
+            let stay = previous.get(k).map_or(zero, |&s| numbers[k] * s);
+            *value = stay + previous[k - 1];
+        }
+        table.push(row);
+    }
+    table
+}
+
+/// Computes the [Bell numbers] `B(0), B(1), ..., B(n)`.
+///
+/// `B(i)` is the number of ways to partition a set of `i` elements into non-empty subsets. The numbers are computed
+/// using the Bell triangle, so only O(n) memory is needed.
+///
+/// # Time complexity
+/// Takes O(n²) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{bell_numbers, Mod1e9p7};
+/// let bell: Vec<u64> = bell_numbers(6, Mod1e9p7).iter().map(|v| v.into_inner()).collect();
+/// assert_eq!(bell, [1, 1, 2, 5, 15, 52, 203]);
+/// ```
+///
+/// [Bell numbers]: https://en.wikipedia.org/wiki/Bell_number
+pub fn bell_numbers<M: Modulus>(n: usize, modulus: M) -> Vec<ModInt<M>> {
+    let one = ModInt::from((M::Base::from(1), modulus));
+    let mut bell = Vec::with_capacity(n + 1);
+    bell.push(one);
+
+    // Each row of the Bell triangle starts with the last value of the previous row, and every other value is the sum
+    // of its left neighbour and the value above that neighbour. The first value of row i is B(i).
+    let mut row = vec![one];
+    for _ in 1..=n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(*row.last().unwrap());
+        for (j, &above) in row.iter().enumerate() {
+            next.push(next[j] + above);
+        }
+        bell.push(next[0]);
+        row = next;
+    }
+    bell
+}
+
+/// Computes the [partition numbers] `p(0), p(1), ..., p(n)`.
+///
+/// `p(i)` is the number of ways to write `i` as a sum of positive integers, disregarding the order of the summands.
+/// The numbers are computed using Euler's [pentagonal number theorem].
+///
+/// # Time complexity
+/// Takes O(n √n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{partition_numbers, Mod1e9p7};
+/// let p: Vec<u64> = partition_numbers(7, Mod1e9p7).iter().map(|v| v.into_inner()).collect();
+/// assert_eq!(p, [1, 1, 2, 3, 5, 7, 11, 15]);
+/// ```
+///
+/// [partition numbers]: https://en.wikipedia.org/wiki/Partition_function_(number_theory)
+/// [pentagonal number theorem]: https://en.wikipedia.org/wiki/Pentagonal_number_theorem
+pub fn partition_numbers<M: Modulus>(n: usize, modulus: M) -> Vec<ModInt<M>> {
+    let zero = ModInt::from((M::Base::from(0), modulus));
+    let mut partitions = vec![zero; n + 1];
+    partitions[0] = ModInt::from((M::Base::from(1), modulus));
+    for i in 1..=n {
+        // p(i) = sum over k >= 1 of (-1)^(k+1) (p(i - k(3k-1)/2) + p(i - k(3k+1)/2))
+        let mut sum = zero;
+        for k in 1.. {
+            let first = k * (3 * k - 1) / 2;
+            if first > i {
+                break;
+            }
+            let mut term = partitions[i - first];
+            let second = first + k;
+            if second <= i {
+                term += partitions[i - second];
+            }
+            if k % 2 == 1 {
+                sum += term;
+            } else {
+                sum -= term;
+            }
+        }
+        partitions[i] = sum;
+    }
+    partitions
+}
@@ -3,19 +3,48 @@
 //!
 //! ## Content
 //! - [Greatest common divisor](gcd)
+//! - [Approximate comparison of floating-point numbers](AlmostEq)
 //! - [Modular integers](ModInt)
-//! - [Sieve of Eratosthenes](PrimeSieve)
+//! - [Matrices with determinants and inverses](Matrix)
+//! - [Linear programming](simplex())
+//! - [Binary search on monotone predicates](binary_search_first)
+//! - [Ternary search](ternary_search_max)
+//! - [Sieve of Eratosthenes](PrimeSieve), also in a [memory-efficient variant](BitPrimeSieve)
 //! - [Primality test](is_prime)
 //! - [Factorization](factorize)
+//! - [Divisors](divisors)
+//! - [Floor sum](floor_sum)
+//! - [Blocks of equal quotients](floor_div_blocks)
 //! - [Modular exponentiation](mod_pow)
+//! - [Primitive roots](primitive_root), [discrete logarithms](discrete_log), and [modular roots](mod_nth_root)
 //! - [Finding next permutation of a list](next_permutation)
 //! - [Iterating over subsets](subsets())
+//! - [Iterating over subsets of fixed size](combinations)
+//! - [Iterating over submasks](submasks)
+//! - [Iterating over subsets in Gray code order](gray_codes)
+//! - [Sum over subsets](subset_zeta) and [bitwise convolutions](xor_convolution)
+//! - [Stirling numbers](stirling2_table), [Bell numbers](bell_numbers), and [partition numbers](partition_numbers)
 //!
 //! ## Still missing
 //! - Fourier Transform, both number theoretic and complex
 
 #![warn(missing_docs)]
 
+mod approx;
+pub use approx::{AlmostEq, Eps};
+
+mod bitwise;
+pub use bitwise::{
+    and_convolution, or_convolution, subset_mobius, subset_zeta, superset_mobius, superset_zeta,
+    walsh_hadamard, xor_convolution,
+};
+
+mod combinatorics;
+pub use combinatorics::{bell_numbers, partition_numbers, stirling1_table, stirling2_table};
+
+mod matrix;
+pub use matrix::Matrix;
+
 mod modint;
 pub use modint::{
     InvertibleModulus, Mod1e9p7, ModInt, Modulus, RuntimeModulus, RuntimePrimeModulus,
@@ -25,13 +54,30 @@ mod numtraits;
 pub use numtraits::{Float, Integer, NonZero, Numeric, Sign, Signed};
 
 mod number_theory;
-pub use number_theory::{factorize, gcd, is_prime, lcm, mod_pow, PrimeSieve};
+pub use number_theory::{
+    discrete_log, divisor_count, divisor_sum, divisors, factorize, factorize_u128,
+    factorize_u128_with_rng, factorize_with_rng, floor_div_blocks, floor_sum, gcd, is_prime,
+    is_prime_u128, lcm, mod_nth_root, mod_pow, mul_mod_u64, primitive_root, BitPrimeSieve,
+    BitPrimeSievePrimes, FloorDivBlocks, PrimeSieve, SieveTables,
+};
 
 mod permutations;
 pub use permutations::next_permutation;
 
 mod quot;
-pub use quot::Quot;
+pub use quot::{Convergents, Quot};
+
+mod search;
+pub use search::{
+    binary_search_f64, binary_search_first, ternary_search_max, ternary_search_max_f64,
+    ternary_search_min, ternary_search_min_f64,
+};
+
+mod simplex;
+pub use simplex::{simplex, LpResult};
 
 pub mod subsets;
-pub use subsets::subsets;
+pub use subsets::{
+    combinations, gray_code, gray_code_index, gray_codes, next_same_popcount, submasks, subsets,
+    Combinations, GrayCodes, Submasks,
+};
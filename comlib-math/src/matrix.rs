@@ -0,0 +1,412 @@
+use crate::{InvertibleModulus, ModInt};
+use std::{
+    fmt,
+    ops::{Add, Index, IndexMut, Mul},
+};
+
+/// Dense matrix stored in row-major order.
+///
+/// Determinants and inverses are provided for matrices over [`i64`] (determinant only), [`f64`], and [`ModInt`].
+///
+/// # Examples
+/// Counting the spanning trees of a graph using [Kirchhoff's theorem]:
+/// ```
+/// # use comlib_math::Matrix;
+/// // Laplacian of a 4-cycle with the last row and column removed
+/// let laplacian = Matrix::from_rows(vec![
+///     vec![2, -1, 0],
+///     vec![-1, 2, -1],
+///     vec![0, -1, 2],
+/// ]);
+/// assert_eq!(laplacian.determinant(), 4);
+/// ```
+///
+/// [Kirchhoff's theorem]: https://en.wikipedia.org/wiki/Kirchhoff%27s_theorem
+#[derive(Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Constructs a new matrix from the given rows.
+    ///
+    /// # Panics
+    /// Panics if the rows are of different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        let row_count = rows.len();
+        let mut data = Vec::with_capacity(row_count * cols);
+        for row in rows {
+            assert_eq!(row.len(), cols, "all rows must be of the same length");
+            data.extend(row);
+        }
+        Self {
+            rows: row_count,
+            cols,
+            data,
+        }
+    }
+
+    /// Constructs a new matrix of the given size by calling `f(row, col)` for each element.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
+            }
+        }
+        Self { rows, cols, data }
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Checks whether the matrix is square.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the given row as a slice.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the given row as a mutable slice.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Swaps two rows of the matrix.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            for j in 0..self.cols {
+                self.data.swap(a * self.cols + j, b * self.cols + j);
+            }
+        }
+    }
+
+    /// Turns the matrix into a vector of rows.
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let cols = self.cols;
+        let mut data = self.data.into_iter();
+        (0..self.rows)
+            .map(|_| data.by_ref().take(cols).collect())
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        debug_assert!(col < self.cols, "column index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        debug_assert!(col < self.cols, "column index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.rows).map(|i| self.row(i)))
+            .finish()
+    }
+}
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two matrices.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of the left matrix doesn't match the number of rows of the right matrix, or if
+    /// the inner dimension is 0.
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        assert_eq!(self.cols, rhs.rows, "dimensions must match");
+        assert!(self.cols > 0, "inner dimension must be positive");
+        Matrix::from_fn(self.rows, rhs.cols, |i, j| {
+            (1..self.cols).fold(self[(i, 0)] * rhs[(0, j)], |sum, k| {
+                sum + self[(i, k)] * rhs[(k, j)]
+            })
+        })
+    }
+}
+
+impl Matrix<i64> {
+    /// Computes the determinant of the matrix.
+    ///
+    /// Uses the fraction-free [Bareiss algorithm] with 128-bit intermediate values. Every intermediate value is a minor
+    /// of the matrix, and the algorithm multiplies two of them together, so the minors must stay below 2⁶³ in absolute
+    /// value. By Hadamard's inequality this holds whenever n^(n/2) · max|aᵢⱼ|ⁿ < 2⁶³, e.g. for 3×3 matrices with entries
+    /// up to 10⁶ or for 10×10 matrices with entries up to 20. Larger inputs may still work, but are not
+    /// guaranteed to.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square, if an intermediate value overflows [`i128`], or if the determinant doesn't
+    /// fit in [`i64`].
+    ///
+    /// # Time complexity
+    /// Takes O(n³) time.
+    ///
+    /// [Bareiss algorithm]: https://en.wikipedia.org/wiki/Bareiss_algorithm
+    pub fn determinant(&self) -> i64 {
+        assert!(
+            self.is_square(),
+            "determinant is only defined for square matrices"
+        );
+        let n = self.rows;
+        let mut a = Matrix::from_fn(n, n, |i, j| self[(i, j)] as i128);
+        let mut negate = false;
+        let mut previous_pivot = 1;
+
+        for k in 0..n {
+            let pivot_row = match (k..n).find(|&i| a[(i, k)] != 0) {
+                Some(row) => row,
+                None => return 0,
+            };
+            if pivot_row != k {
+                a.swap_rows(pivot_row, k);
+                negate = !negate;
+            }
+
+            // Every intermediate value is a minor of the original matrix, so the division is always exact
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let value = a[(i, j)]
+                        .checked_mul(a[(k, k)])
+                        .zip(a[(i, k)].checked_mul(a[(k, j)]))
+                        .and_then(|(x, y)| x.checked_sub(y))
+                        .expect("intermediate value to fit in i128");
+                    a[(i, j)] = value / previous_pivot;
+                }
+            }
+            previous_pivot = a[(k, k)];
+        }
+
+        let determinant = if n == 0 { 1 } else { a[(n - 1, n - 1)] };
+        let determinant = if negate { -determinant } else { determinant };
+        crate::Integer::try_narrow(determinant).expect("determinant to fit in i64")
+    }
+}
+
+impl Matrix<f64> {
+    /// Constructs an identity matrix of the given size.
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { 1.0 } else { 0.0 })
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// Uses Gaussian elimination with partial pivoting.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Time complexity
+    /// Takes O(n³) time.
+    pub fn determinant(&self) -> f64 {
+        assert!(
+            self.is_square(),
+            "determinant is only defined for square matrices"
+        );
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut determinant = 1.0;
+
+        for k in 0..n {
+            let pivot_row = Self::pivot_row(&a, k);
+            if a[(pivot_row, k)] == 0.0 {
+                return 0.0;
+            }
+            if pivot_row != k {
+                a.swap_rows(pivot_row, k);
+                determinant = -determinant;
+            }
+            determinant *= a[(k, k)];
+
+            for i in k + 1..n {
+                let factor = a[(i, k)] / a[(k, k)];
+                for j in k..n {
+                    a[(i, j)] -= factor * a[(k, j)];
+                }
+            }
+        }
+
+        determinant
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// Uses Gauss-Jordan elimination with partial pivoting. Returns `None` if the matrix is singular, i.e. if a pivot
+    /// is exactly zero. Nearly singular matrices produce numerically unstable results.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square.
+    ///
+    /// # Time complexity
+    /// Takes O(n³) time.
+    pub fn inverse(&self) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "inverse is only defined for square matrices"
+        );
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inverse = Self::identity(n);
+
+        for k in 0..n {
+            let pivot_row = Self::pivot_row(&a, k);
+            if a[(pivot_row, k)] == 0.0 {
+                return None;
+            }
+            a.swap_rows(pivot_row, k);
+            inverse.swap_rows(pivot_row, k);
+
+            let scale = 1.0 / a[(k, k)];
+            for j in 0..n {
+                a[(k, j)] *= scale;
+                inverse[(k, j)] *= scale;
+            }
+
+            for i in 0..n {
+                if i != k && a[(i, k)] != 0.0 {
+                    let factor = a[(i, k)];
+                    for j in 0..n {
+                        a[(i, j)] -= factor * a[(k, j)];
+                        inverse[(i, j)] -= factor * inverse[(k, j)];
+                    }
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+
+    /// Finds the row with the largest absolute value in the given column, starting from the diagonal.
+    fn pivot_row(a: &Self, col: usize) -> usize {
+        (col..a.rows)
+            .max_by(|&i, &j| a[(i, col)].abs().total_cmp(&a[(j, col)].abs()))
+            .unwrap()
+    }
+}
+
+impl<M: InvertibleModulus> Matrix<ModInt<M>> {
+    /// Constructs an identity matrix of the given size in the given modulus.
+    pub fn identity(n: usize, modulus: M) -> Self {
+        let zero = ModInt::from((M::Base::from(0), modulus));
+        let one = ModInt::from((M::Base::from(1), modulus));
+        Self::from_fn(n, n, |i, j| if i == j { one } else { zero })
+    }
+
+    /// Computes the determinant of the matrix.
+    ///
+    /// Uses Gaussian elimination in the modular field, so the modulus should be a prime.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square or if it is empty, as the modulus of an empty matrix is unknown.
+    ///
+    /// # Time complexity
+    /// Takes O(n³) time, plus the time taken by n modular inversions.
+    pub fn determinant(&self) -> ModInt<M> {
+        assert!(
+            self.is_square(),
+            "determinant is only defined for square matrices"
+        );
+        assert!(self.rows > 0, "matrix must not be empty");
+        let n = self.rows;
+        let zero = ModInt::from((M::Base::from(0), self[(0, 0)].modulus()));
+        let mut a = self.clone();
+        let mut determinant = ModInt::from((M::Base::from(1), self[(0, 0)].modulus()));
+
+        for k in 0..n {
+            let pivot_row = match (k..n).find(|&i| a[(i, k)] != zero) {
+                Some(row) => row,
+                None => return zero,
+            };
+            if pivot_row != k {
+                a.swap_rows(pivot_row, k);
+                determinant = zero - determinant;
+            }
+            determinant *= a[(k, k)];
+
+            let pivot_inverse = a[(k, k)].inv();
+            for i in k + 1..n {
+                let factor = a[(i, k)] * pivot_inverse;
+                if factor != zero {
+                    for j in k..n {
+                        let value = factor * a[(k, j)];
+                        a[(i, j)] -= value;
+                    }
+                }
+            }
+        }
+
+        determinant
+    }
+
+    /// Computes the inverse of the matrix.
+    ///
+    /// Uses Gauss-Jordan elimination in the modular field, so the modulus should be a prime. Returns `None` if the
+    /// matrix is singular.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square or if it is empty, as the modulus of an empty matrix is unknown.
+    ///
+    /// # Time complexity
+    /// Takes O(n³) time, plus the time taken by n modular inversions.
+    pub fn inverse(&self) -> Option<Self> {
+        assert!(
+            self.is_square(),
+            "inverse is only defined for square matrices"
+        );
+        assert!(self.rows > 0, "matrix must not be empty");
+        let n = self.rows;
+        let modulus = self[(0, 0)].modulus();
+        let zero = ModInt::from((M::Base::from(0), modulus));
+        let mut a = self.clone();
+        let mut inverse = Self::identity(n, modulus);
+
+        for k in 0..n {
+            let pivot_row = (k..n).find(|&i| a[(i, k)] != zero)?;
+            a.swap_rows(pivot_row, k);
+            inverse.swap_rows(pivot_row, k);
+
+            let scale = a[(k, k)].inv();
+            for j in 0..n {
+                a[(k, j)] *= scale;
+                inverse[(k, j)] *= scale;
+            }
+
+            for i in 0..n {
+                let factor = a[(i, k)];
+                if i != k && factor != zero {
+                    for j in 0..n {
+                        let (value, inverse_value) = (factor * a[(k, j)], factor * inverse[(k, j)]);
+                        a[(i, j)] -= value;
+                        inverse[(i, j)] -= inverse_value;
+                    }
+                }
+            }
+        }
+
+        Some(inverse)
+    }
+}
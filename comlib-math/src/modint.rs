@@ -1,6 +1,7 @@
+use crate::{Integer, Numeric};
 use std::{
     fmt,
-    ops::{Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Deref, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
 /// Modulus contains the modulus and the actual storage type of [`ModInt`].
@@ -8,24 +9,8 @@ pub trait Modulus: Copy {
     /// Type for holding values mod `modulus()`.
     ///
     /// Must be large enough to contain the square of `modulus() - 1`, that is the maximum value which can be encountered
-    /// during multiplication.
-    type Base: Copy
-        + fmt::Display
-        + fmt::Debug
-        + Add<Output = Self::Base>
-        + Sub<Output = Self::Base>
-        + Mul<Output = Self::Base>
-        + Div<Output = Self::Base>
-        + Rem<Output = Self::Base>
-        + AddAssign
-        + SubAssign
-        + MulAssign
-        + DivAssign
-        + RemAssign
-        + Eq
-        + Ord
-        + Default
-        + From<u8>;
+    /// during multiplication. This is checked when constructing a [`ModInt`] in debug builds.
+    type Base: Integer + From<u8>;
 
     /// Modulus in which computations should be done.
     fn modulus(self) -> Self::Base;
@@ -55,6 +40,23 @@ pub trait InvertibleModulus: Modulus {
 /// [`M::modulus`]: Modulus::modulus
 pub struct ModInt<M: Modulus>(M::Base, M);
 
+/// Checks in debug builds that the base type of the modulus can hold the square of `modulus - 1`.
+///
+/// Otherwise multiplication would silently overflow in release builds and produce wrong results.
+#[inline(always)]
+fn debug_check_modulus<M: Modulus>(modulus: M) {
+    debug_assert!(
+        {
+            let m = modulus.modulus();
+            m <= M::Base::one()
+                || (m - M::Base::one())
+                    .checked_mul(m - M::Base::one())
+                    .is_some()
+        },
+        "the base type of the modulus must be able to hold (modulus - 1)^2"
+    );
+}
+
 impl<M: Modulus> ModInt<M> {
     /// Computes the value raised to the given power.
     pub fn pow(self, rhs: usize) -> Self {
@@ -62,7 +64,7 @@ impl<M: Modulus> ModInt<M> {
             Self(M::Base::from(1) % self.1.modulus(), self.1)
         } else if rhs == 1 {
             self
-        } else if rhs % 2 == 0 {
+        } else if rhs.is_multiple_of(2) {
             let p = self.pow(rhs / 2);
             p * p
         } else {
@@ -94,7 +96,7 @@ impl<M: Modulus> ModInt<M> {
 
 impl<M: Modulus> Clone for ModInt<M> {
     fn clone(&self) -> Self {
-        Self(self.0, self.1)
+        *self
     }
 }
 impl<M: Modulus> Copy for ModInt<M> {}
@@ -104,7 +106,9 @@ where
     M: Default,
 {
     fn default() -> Self {
-        Self(Default::default(), Default::default())
+        let modulus: M = Default::default();
+        debug_check_modulus(modulus);
+        Self(Default::default(), modulus)
     }
 }
 
@@ -132,6 +136,7 @@ where
 {
     fn from(val: u8) -> Self {
         let modulus: M = Default::default();
+        debug_check_modulus(modulus);
         Self(M::Base::from(val) % modulus.modulus(), modulus)
     }
 }
@@ -143,6 +148,7 @@ where
 {
     fn from(val: u64) -> Self {
         let modulus: M = Default::default();
+        debug_check_modulus(modulus);
         Self(M::Base::from(val) % modulus.modulus(), modulus)
     }
 }
@@ -156,6 +162,7 @@ impl<M: Modulus> Deref for ModInt<M> {
 
 impl<M: Modulus> From<(M::Base, M)> for ModInt<M> {
     fn from((val, modulus): (M::Base, M)) -> Self {
+        debug_check_modulus(modulus);
         Self(val % modulus.modulus(), modulus)
     }
 }
@@ -206,6 +213,7 @@ impl<M: Modulus> SubAssign for ModInt<M> {
 
 impl<M: Modulus + InvertibleModulus> Div for ModInt<M> {
     type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.inv()
     }
@@ -249,24 +257,7 @@ pub struct RuntimePrimeModulus<T>(T);
 
 impl<T> Modulus for RuntimePrimeModulus<T>
 where
-    T: Copy
-        + fmt::Display
-        + fmt::Debug
-        + Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Rem<Output = T>
-        + AddAssign
-        + SubAssign
-        + MulAssign
-        + DivAssign
-        + RemAssign
-        + Eq
-        + Ord
-        + Default
-        + From<u8>
-        + Into<usize>,
+    T: Integer + From<u8> + Into<usize>,
 {
     type Base = T;
 
@@ -278,24 +269,7 @@ where
 
 impl<T> InvertibleModulus for RuntimePrimeModulus<T>
 where
-    T: Copy
-        + fmt::Display
-        + fmt::Debug
-        + Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Div<Output = T>
-        + Rem<Output = T>
-        + AddAssign
-        + SubAssign
-        + MulAssign
-        + DivAssign
-        + RemAssign
-        + Eq
-        + Ord
-        + Default
-        + From<u8>
-        + Into<usize>,
+    T: Integer + From<u8> + Into<usize>,
 {
     /// Computes the inverse of the given [`ModInt`].
     ///
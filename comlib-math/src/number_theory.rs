@@ -1,8 +1,8 @@
-use std::iter::once;
+use std::{collections::HashMap, iter::once};
 
 use crate::Integer;
 use ::comlib_common::MiniMap;
-use rand::{thread_rng, Rng};
+use rand::{distributions::uniform::SampleUniform, thread_rng, Rng};
 
 /// Computes the greatest common divisor of the given numbers.
 ///
@@ -25,65 +25,137 @@ pub fn lcm<I: Integer>(a: I, b: I) -> I {
 
 /// Raises base to given exponent in the given modulus.
 ///
-/// Note that it's up to the caller to ensure that the type can store (modulus-1)^2. If this is not the case, it is
-/// undefined what this function returns.
+/// The intermediate products are computed in [`Integer::Wide`], so this works for any modulus of types narrower than
+/// 128 bits. For 128-bit types it's up to the caller to ensure that the type can store (modulus-1)^2. If this is not
+/// the case, it is undefined what this function returns. This is checked in debug builds.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::mod_pow;
+/// assert_eq!(mod_pow(3u64, 4, 7), 4);
+/// // The modulus doesn't need to fit in 32 bits
+/// assert_eq!(mod_pow(2u64, 64, u64::MAX), 1);
+/// ```
 pub fn mod_pow<I: Integer>(base: I, exponent: I, modulus: I) -> I {
+    debug_assert!(
+        modulus <= I::one()
+            || (modulus - I::one())
+                .widen()
+                .checked_mul((modulus - I::one()).widen())
+                .is_some(),
+        "the type must be able to hold (modulus - 1)^2"
+    );
+    let mul_mod = |a: I, b: I| {
+        I::try_narrow(a.widen() * b.widen() % modulus.widen()).expect("a reduced value to fit")
+    };
     if exponent.is_zero() {
         I::one() % modulus
     } else if exponent.is_one() {
         base % modulus
     } else if (exponent % I::from_int(2)).is_zero() {
         let p = mod_pow(base, exponent / I::from_int(2), modulus);
-        (p * p) % modulus
+        mul_mod(p, p)
     } else {
-        (base * mod_pow(base, exponent - I::from_int(1), modulus)) % modulus
+        mul_mod(
+            base % modulus,
+            mod_pow(base, exponent - I::from_int(1), modulus),
+        )
     }
 }
 
+/// Computes `a * b % m` without overflow.
+///
+/// The product is computed using 128-bit arithmetic, so this works for all 64-bit moduli.
+///
+/// # Panics
+/// Panics if `m` is 0.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::mul_mod_u64;
+/// let m = 1_000_000_000_000_000_003;
+/// assert_eq!(mul_mod_u64(m - 1, m - 1, m), 1);
+/// ```
+#[inline(always)]
+pub fn mul_mod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
 /// Checks whether a given number is a prime.
 ///
-/// Implements deterministic [Miller-Rabin primality test] for all 64-bit integers.
+/// Implements deterministic [Miller-Rabin primality test] for all 64-bit integers. Small factors are first ruled out
+/// using trial division, and the smallest sufficient set of witnesses is chosen based on the size of the candidate.
+///
+/// # Time complexity
+/// The test takes O(log n) time.
 ///
 /// [Miller-Rabin primality test]: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
 pub fn is_prime(candidate: u64) -> bool {
-    // 2 is the only even prime
-    if candidate <= 2 || candidate % 2 == 0 {
-        return candidate == 2;
+    // Trial division quickly rules out most of the composite numbers
+    const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for &p in SMALL_PRIMES.iter() {
+        if candidate.is_multiple_of(p) {
+            return candidate == p;
+        }
+    }
+    // The smallest composite number without a small prime factor is 41^2
+    if candidate < 41 * 41 {
+        return candidate > 1;
     }
 
+    // Bases which allow testing all numbers below the given bound
+    // https://miller-rabin.appspot.com/
+    const BASES_32: [u64; 3] = [2, 7, 61];
+    const BASES_64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+    let bases: &[u64] = if candidate < 4_759_123_141 {
+        &BASES_32
+    } else {
+        &BASES_64
+    };
+
+    miller_rabin(candidate, bases)
+}
+
+/// Runs the [Miller-Rabin primality test] on an odd candidate using the given witnesses.
+///
+/// Returns `false` if any of the witnesses proves that the candidate is composite. Bases divisible by the candidate
+/// don't witness anything and are skipped.
+///
+/// [Miller-Rabin primality test]: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+fn miller_rabin<W: MontgomeryWord>(candidate: W, bases: &[W]) -> bool {
     // Write candidate as 2^r * d + 1
-    let r = (candidate - 1).trailing_zeros();
-    let d = candidate / 2u64.pow(r);
-    debug_assert!(d % 2 == 1, "d has to be odd");
-    debug_assert_eq!(2u64.pow(r) * d + 1, candidate);
+    let mut d = candidate - W::one();
+    let mut r = 0;
+    while d % W::from_int(2) == W::zero() {
+        d /= W::from_int(2);
+        r += 1;
+    }
 
-    // Bases which allow testing all 64-bit numbers
-    // https://miller-rabin.appspot.com/
-    const BASES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+    let mont = Montgomery::new(candidate);
+    let one = mont.to_mont(W::one());
+    let minus_one = mont.to_mont(candidate - W::one());
 
-    'witness_loop: for &base in BASES.iter() {
+    'witness_loop: for &base in bases {
         // We need to reduce the base to modulo candidate
         let base = base % candidate;
-        // If the base is a multiple of the candidate, it must be that the candidate is a prime.
-        if base == 0 {
-            return true;
+        if base == W::zero() {
+            continue;
         }
 
         // Compute base^d mod candidate
-        let x = mod_pow(base as u128, d as u128, candidate as u128) as u64;
+        let mut x = mont.pow(mont.to_mont(base), d);
 
-        if x == 1 || x == candidate - 1 {
+        if x == one || x == minus_one {
             // Possibly prime, but might be that base is just a strong liar
             continue;
-        } else {
-            // Repeatedly square x to find out whether it is a square root of 1
-            let mut x = x;
-            for _ in 0..r - 1 {
-                x = ((x as u128 * x as u128) % candidate as u128) as u64;
-                if x == candidate - 1 {
-                    // Possibly prime
-                    continue 'witness_loop;
-                }
+        }
+
+        // Repeatedly square x to find out whether it is a square root of 1
+        for _ in 1..r {
+            x = mont.mul(x, x);
+            if x == minus_one {
+                // Possibly prime
+                continue 'witness_loop;
             }
         }
 
@@ -95,66 +167,735 @@ pub fn is_prime(candidate: u64) -> bool {
     true
 }
 
+/// Unsigned integer types usable as the words of [`Montgomery`] arithmetic.
+trait MontgomeryWord: Integer + SampleUniform {
+    /// Number of bits in the type.
+    const BITS: u32;
+
+    /// Computes `self * rhs` modulo `2^BITS`.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Computes `self + rhs` modulo `2^BITS`.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Computes `self - rhs` modulo `2^BITS`.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Computes `self + rhs` modulo `2^BITS`, also returning whether the sum overflowed.
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    /// Computes the full product of two words, returned as the low and high halves.
+    fn mul_wide(self, rhs: Self) -> (Self, Self);
+
+    /// Checks whether the number is a prime.
+    fn is_prime(self) -> bool;
+}
+
+macro_rules! impl_montgomery_word {
+    ($t:ty, $mul_wide:expr, $is_prime:expr) => {
+        impl MontgomeryWord for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline(always)]
+            fn wrapping_mul(self, rhs: $t) -> $t {
+                <$t>::wrapping_mul(self, rhs)
+            }
+
+            #[inline(always)]
+            fn wrapping_add(self, rhs: $t) -> $t {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn wrapping_sub(self, rhs: $t) -> $t {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            #[inline(always)]
+            fn overflowing_add(self, rhs: $t) -> ($t, bool) {
+                <$t>::overflowing_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn mul_wide(self, rhs: $t) -> ($t, $t) {
+                $mul_wide(self, rhs)
+            }
+
+            fn is_prime(self) -> bool {
+                $is_prime(self)
+            }
+        }
+    };
+}
+
+impl_montgomery_word!(
+    u64,
+    |a: u64, b: u64| {
+        let product = a as u128 * b as u128;
+        (product as u64, (product >> 64) as u64)
+    },
+    is_prime
+);
+impl_montgomery_word!(u128, mul_u128_wide, is_prime_u128);
+
+/// Arithmetic in [Montgomery form] modulo an odd modulus.
+///
+/// Replaces the slow division of each modular multiplication by a couple of multiplications. The Montgomery radix is
+/// `2^BITS` of the word type.
+///
+/// [Montgomery form]: https://en.wikipedia.org/wiki/Montgomery_modular_multiplication
+#[derive(Debug, Clone, Copy)]
+struct Montgomery<W> {
+    /// The modulus.
+    modulus: W,
+    /// Inverse of the modulus modulo the radix.
+    inv: W,
+    /// Square of the radix modulo the modulus, used for converting values into Montgomery form.
+    r2: W,
+}
+
+impl<W: MontgomeryWord> Montgomery<W> {
+    /// Constructs a new `Montgomery` for the given odd modulus.
+    fn new(modulus: W) -> Self {
+        let two = W::from_int(2);
+        debug_assert!(
+            modulus % two == W::one(),
+            "Montgomery form requires an odd modulus"
+        );
+
+        // Newton's iteration doubles the number of correct low bits on each round. The modulus is its own inverse
+        // modulo 8, so log2(BITS) - 1 rounds are enough.
+        let mut inv = modulus;
+        for _ in 1..W::BITS.trailing_zeros() {
+            inv = inv.wrapping_mul(two.wrapping_sub(modulus.wrapping_mul(inv)));
+        }
+        debug_assert!(modulus.wrapping_mul(inv) == W::one());
+
+        // There may be no wider type, so compute the square of the radix by doubling the radix another BITS times
+        let mut r2 = (W::MAX % modulus + W::one()) % modulus;
+        for _ in 0..W::BITS {
+            r2 = Self::add_mod(r2, r2, modulus);
+        }
+
+        Self { modulus, inv, r2 }
+    }
+
+    /// Computes `a + b` modulo the modulus, where both values are less than the modulus.
+    #[inline(always)]
+    fn add_mod(a: W, b: W, modulus: W) -> W {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= modulus {
+            sum.wrapping_sub(modulus)
+        } else {
+            sum
+        }
+    }
+
+    /// Computes `(high * 2^BITS + low) / 2^BITS` modulo the modulus.
+    ///
+    /// The value must be less than `modulus * 2^BITS`.
+    #[inline(always)]
+    fn reduce(self, (low, high): (W, W)) -> W {
+        // Choose m such that value - m * modulus is divisible by 2^BITS. The low halves then cancel out exactly.
+        let m = low.wrapping_mul(self.inv);
+        let (_, mn) = m.mul_wide(self.modulus);
+        if high >= mn {
+            high - mn
+        } else {
+            high.wrapping_sub(mn).wrapping_add(self.modulus)
+        }
+    }
+
+    /// Converts the value into Montgomery form.
+    #[inline(always)]
+    fn to_mont(self, value: W) -> W {
+        self.reduce((value % self.modulus).mul_wide(self.r2))
+    }
+
+    /// Multiplies two values in Montgomery form.
+    #[inline(always)]
+    fn mul(self, a: W, b: W) -> W {
+        self.reduce(a.mul_wide(b))
+    }
+
+    /// Raises a value in Montgomery form to the given power.
+    fn pow(self, base: W, exponent: W) -> W {
+        let two = W::from_int(2);
+        let mut result = self.to_mont(W::one());
+        let mut base = base;
+        let mut exponent = exponent;
+        while exponent > W::zero() {
+            if exponent % two == W::one() {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent /= two;
+        }
+        result
+    }
+}
+
 /// Factorizes the given integer into its prime factors.
 ///
-/// Implements [Pollard's rho algorithm] to find the factorization.
+/// Implements [Pollard's rho algorithm] to find the factorization. The randomness is drawn from [`thread_rng`]. Use
+/// [`factorize_with_rng`] for reproducible results.
+///
+/// Returns the factors as pairs indicating the prime and the number of times its present in the factorization.
+/// The factorization is ordered in increasing order by the prime.
 ///
 /// # Time complexity
 /// The expected time-complexity is O(n^(1/4)).
 ///
 /// [Pollard's rho algorithm]: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
 pub fn factorize(n: u64) -> Vec<(u64, usize)> {
+    factorize_with_rng(n, &mut thread_rng())
+}
+
+/// Factorizes the given integer into its prime factors using the given random number generator.
+///
+/// Works like [`factorize`], but draws the starting points of [Pollard's rho algorithm] from the given `rng`. This
+/// makes the running time reproducible when the generator is seeded.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::factorize_with_rng;
+/// use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// assert_eq!(factorize_with_rng(360, &mut rng), [(2, 3), (3, 2), (5, 1)]);
+/// ```
+///
+/// [Pollard's rho algorithm]: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+pub fn factorize_with_rng<R: Rng + ?Sized>(n: u64, rng: &mut R) -> Vec<(u64, usize)> {
     let mut factors = MiniMap::new();
     let mut n = n;
     // Take all trivial twos
-    while n % 2 == 0 {
+    while n.is_multiple_of(2) && n > 0 {
         *factors.entry(2).or_insert(0) += 1;
         n /= 2;
     }
 
-    fn factorize(n: u64, factors: &mut MiniMap<u64, usize>) {
+    fn factorize<R: Rng + ?Sized>(n: u64, factors: &mut MiniMap<u64, usize>, rng: &mut R) {
         if n == 1 {
             // Do nothing
         } else if is_prime(n) {
             // The only factor of a prime is itself
             *factors.entry(n).or_insert(0) += 1;
         } else {
-            // Use the Pollard's rho algorithm with polynomial (x^2 + c) starting at a random x and using random c.
+            let d = find_divisor(n, rng);
+            factorize(d, factors, rng);
+            factorize(n / d, factors, rng);
+        }
+    }
+
+    if n > 1 {
+        factorize(n, &mut factors, rng);
+    }
+
+    factors.into_inner()
+}
+
+/// Finds a non-trivial divisor of an odd composite number.
+///
+/// Uses Brent's variant of Pollard's rho algorithm: the cycle is detected by comparing against a fixed point which is
+/// moved at powers of two, and the differences are multiplied together so that only one gcd is needed per batch.
+fn find_divisor<W: MontgomeryWord, R: Rng + ?Sized>(n: W, rng: &mut R) -> W {
+    debug_assert!(n % W::from_int(2) == W::one() && !n.is_prime());
+
+    /// Number of steps whose differences are accumulated before computing a gcd.
+    const BATCH: u64 = 128;
+
+    let mont = Montgomery::new(n);
+    let diff = |a: W, b: W| a.max(b) - a.min(b);
+
+    loop {
+        // Use the polynomial (x^2 + c) starting at a random point and using random c. The values are kept in Montgomery
+        // form, which is just as good a pseudorandom sequence.
+        let c = rng.gen_range(W::one()..n);
+        let f = |x: W| Montgomery::add_mod(mont.mul(x, x), c, n);
+
+        let mut y = rng.gen_range(W::zero()..n);
+        let mut x = y;
+        let mut saved_y = y;
+        let mut product = W::one();
+        let mut d = W::one();
+        let mut length = 1;
+
+        while d == W::one() {
+            x = y;
+            for _ in 0..length {
+                y = f(y);
+            }
+
+            let mut steps = 0;
+            while steps < length && d == W::one() {
+                saved_y = y;
+                for _ in 0..BATCH.min(length - steps) {
+                    y = f(y);
+                    product = mont.mul(product, diff(x, y));
+                }
+                d = gcd(product, n);
+                steps += BATCH;
+            }
+
+            length *= 2;
+        }
+
+        if d == n {
+            // The batch overshot. Redo the last batch one step at a time.
             loop {
-                let mut x = thread_rng().gen_range(1..n) as u128;
-                let c = thread_rng().gen_range(1..n) as u128;
-                let mut y = x;
-                let n = n as u128;
-
-                loop {
-                    x = (x * x + c) % n;
-                    y = (y * y + c) % n;
-                    y = (y * y + c) % n;
-                    let d = gcd(x.max(y) - x.min(y), n);
-                    if d != 1 {
-                        if d == n {
-                            // Failed :E
-                            // -> try with different x and c
-                            break;
-                        } else {
-                            // d is a factor
-                            factorize(d as u64, factors);
-                            factorize((n / d) as u64, factors);
-                            return;
-                        }
-                    }
+                saved_y = f(saved_y);
+                d = gcd(diff(x, saved_y), n);
+                if d != W::one() {
+                    break;
                 }
             }
         }
+
+        if d != n {
+            return d;
+        }
+        // Failed :E
+        // -> try with different starting point and c
+    }
+}
+
+/// Checks whether a given 128-bit number is a prime.
+///
+/// Numbers fitting in 64 bits are delegated to [`is_prime`]. Larger numbers are checked with the [Miller-Rabin
+/// primality test] using the first 20 primes as witnesses. This is deterministic for all numbers below 3.3 * 10^24, and
+/// no composite number passing all of these witnesses is known above that.
+///
+/// # Time complexity
+/// The test takes O(log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::is_prime_u128;
+/// assert!(is_prime_u128(170_141_183_460_469_231_731_687_303_715_884_105_727)); // 2^127 - 1
+/// assert!(!is_prime_u128(1_000_000_007 * 998_244_353 * 1_000_000_009));
+/// ```
+///
+/// [Miller-Rabin primality test]: https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test
+pub fn is_prime_u128(candidate: u128) -> bool {
+    if candidate <= u64::MAX as u128 {
+        return is_prime(candidate as u64);
+    }
+
+    const BASES: [u128; 20] = [
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71,
+    ];
+    // Trial division by the bases is cheap and makes sure no base is divisible by the candidate
+    if BASES.iter().any(|&p| candidate.is_multiple_of(p)) {
+        return false;
+    }
+
+    miller_rabin(candidate, &BASES)
+}
+
+/// Computes the full 256-bit product of two 128-bit integers, returned as the low and high halves.
+fn mul_u128_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a0, a1) = (a & MASK, a >> 64);
+    let (b0, b1) = (b & MASK, b >> 64);
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+
+    // The middle column is less than 3 * 2^64, so it doesn't overflow
+    let middle = (p00 >> 64) + (p01 & MASK) + (p10 & MASK);
+    let low = (p00 & MASK) | (middle << 64);
+    let high = p11 + (p01 >> 64) + (p10 >> 64) + (middle >> 64);
+    (low, high)
+}
+
+/// Factorizes the given 128-bit integer into its prime factors.
+///
+/// Works like [`factorize`], but accepts 128-bit integers. The randomness is drawn from [`thread_rng`]. Use
+/// [`factorize_u128_with_rng`] for reproducible results.
+///
+/// # Time complexity
+/// The expected time-complexity is O(n^(1/4)). In practice this means that numbers whose second largest prime factor
+/// is at most around 10^14 can be factorized quickly.
+pub fn factorize_u128(n: u128) -> Vec<(u128, usize)> {
+    factorize_u128_with_rng(n, &mut thread_rng())
+}
+
+/// Factorizes the given 128-bit integer into its prime factors using the given random number generator.
+///
+/// Works like [`factorize_u128`], but draws the starting points of [Pollard's rho algorithm] from the given `rng`.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::factorize_u128_with_rng;
+/// use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let n = 1_000_000_007u128 * 1_000_000_007 * 998_244_353 * 4;
+/// assert_eq!(
+///     factorize_u128_with_rng(n, &mut rng),
+///     [(2, 2), (998_244_353, 1), (1_000_000_007, 2)]
+/// );
+/// ```
+///
+/// [Pollard's rho algorithm]: https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm
+pub fn factorize_u128_with_rng<R: Rng + ?Sized>(n: u128, rng: &mut R) -> Vec<(u128, usize)> {
+    let mut factors = MiniMap::new();
+    let mut n = n;
+    while n.is_multiple_of(2) && n > 0 {
+        *factors.entry(2).or_insert(0) += 1;
+        n /= 2;
+    }
+
+    fn factorize<R: Rng + ?Sized>(n: u128, factors: &mut MiniMap<u128, usize>, rng: &mut R) {
+        if n <= u64::MAX as u128 {
+            // Small enough for the faster 64-bit arithmetic
+            for (p, count) in factorize_with_rng(n as u64, rng) {
+                *factors.entry(p as u128).or_insert(0) += count;
+            }
+        } else if is_prime_u128(n) {
+            *factors.entry(n).or_insert(0) += 1;
+        } else {
+            let d = find_divisor(n, rng);
+            factorize(d, factors, rng);
+            factorize(n / d, factors, rng);
+        }
     }
 
     if n > 1 {
-        factorize(n, &mut factors);
+        factorize(n, &mut factors, rng);
     }
 
     factors.into_inner()
 }
 
+/// Computes the inverse of `a` modulo `m` using the extended Euclidean algorithm.
+///
+/// Returns `None` if `a` and `m` are not coprime.
+fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128 % m as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    if old_r == 1 {
+        Some(old_s.rem_euclid(m as i128) as u64)
+    } else {
+        None
+    }
+}
+
+/// Finds the smallest [primitive root] modulo the given prime.
+///
+/// A primitive root is a number whose powers generate all non-zero residues modulo the prime.
+///
+/// The modulus must be a prime. Otherwise the result is unspecified.
+///
+/// # Time complexity
+/// Factorizing `p - 1` takes expected O(p^(1/4)) time, after which each candidate is checked in O(log² p) time. The
+/// smallest primitive root is known to be small, so only a few candidates need to be checked.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::primitive_root;
+/// assert_eq!(primitive_root(7), 3);
+/// assert_eq!(primitive_root(998_244_353), 3);
+/// assert_eq!(primitive_root(1_000_000_007), 5);
+/// ```
+///
+/// [primitive root]: https://en.wikipedia.org/wiki/Primitive_root_modulo_n
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let factors = factorize(p - 1);
+    (2..p)
+        .find(|&g| {
+            factors
+                .iter()
+                .all(|&(q, _)| mod_pow(g, (p - 1) / q, p) != 1)
+        })
+        .expect("a prime to have a primitive root")
+}
+
+/// Computes the [discrete logarithm], i.e. the smallest `x ≥ 0` such that `a^x ≡ b (mod m)`.
+///
+/// Returns `None` if no such `x` exists. The modulus doesn't need to be a prime, and `a` doesn't need to be coprime
+/// with it.
+///
+/// # Panics
+/// Panics if `m` is 0.
+///
+/// # Time complexity
+/// Implements the baby-step giant-step algorithm which takes O(√m) time and memory.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::discrete_log;
+/// assert_eq!(discrete_log(2, 3, 11), Some(8)); // 2^8 = 256 = 23 * 11 + 3
+/// assert_eq!(discrete_log(2, 0, 8), Some(3));
+/// assert_eq!(discrete_log(2, 3, 7), None);
+/// ```
+///
+/// [discrete logarithm]: https://en.wikipedia.org/wiki/Discrete_logarithm
+pub fn discrete_log(a: u64, b: u64, m: u64) -> Option<u64> {
+    assert!(m > 0, "modulus must be positive");
+    let (mut a, mut b, mut m) = (a % m, b % m, m);
+
+    // Divide out the common factors of a and m. This turns the equation into k * a^x ≡ b with a coprime to m.
+    let mut k = 1 % m;
+    let mut offset = 0;
+    loop {
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if b == k {
+            return Some(offset);
+        }
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+        b /= g;
+        m /= g;
+        offset += 1;
+        k = mul_mod_u64(k, a / g, m);
+        a %= m;
+    }
+
+    // Baby steps: store b * a^j for each j, keeping the largest j to find the smallest x
+    let n = (m as f64).sqrt() as u64 + 1;
+    let mut baby_steps = HashMap::new();
+    let mut value = b;
+    for j in 0..=n {
+        baby_steps.insert(value, j);
+        value = mul_mod_u64(value, a, m);
+    }
+
+    // Giant steps: find i such that k * a^(i * n) ≡ b * a^j
+    let giant_step = mod_pow(a, n, m);
+    let mut value = k;
+    for i in 1..=n {
+        value = mul_mod_u64(value, giant_step, m);
+        if let Some(&j) = baby_steps.get(&value) {
+            return Some(offset + i * n - j);
+        }
+    }
+    None
+}
+
+/// Computes an `n`th root of `a` modulo the prime `p`, i.e. some `x` such that `x^n ≡ a (mod p)`.
+///
+/// Returns `None` if no such `x` exists. If there are several roots, any of them may be returned. The modulus must be
+/// a prime. Otherwise the result is unspecified.
+///
+/// # Time complexity
+/// The root is found by taking the [discrete logarithm](discrete_log) of `a` with respect to a
+/// [primitive root](primitive_root), so it takes O(√p) time and memory.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{mod_nth_root, mod_pow};
+/// let x = mod_nth_root(2, 2, 7).unwrap();
+/// assert_eq!(x * x % 7, 2);
+/// let x = mod_nth_root(10, 3, 1_000_000_007).unwrap();
+/// assert_eq!(mod_pow(x, 3, 1_000_000_007), 10);
+/// assert_eq!(mod_nth_root(3, 2, 7), None);
+/// ```
+pub fn mod_nth_root(a: u64, n: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if n == 0 {
+        // Every non-zero number raised to 0 is 1
+        return if a == 1 % p { Some(1 % p) } else { None };
+    }
+    if a == 0 {
+        return Some(0);
+    }
+
+    // Writing a = g^k, the problem reduces to solving n * y ≡ k (mod p - 1) for the root x = g^y
+    let g = primitive_root(p);
+    let k = discrete_log(g, a, p).expect("a primitive root to generate every non-zero residue");
+    let order = p - 1;
+    let d = gcd(n % order, order);
+    let d = if d == 0 { order } else { d };
+    if !k.is_multiple_of(d) {
+        return None;
+    }
+    let reduced_order = order / d;
+    let y = if reduced_order == 1 {
+        0
+    } else {
+        let inverse = mod_inverse(n / d % reduced_order, reduced_order)
+            .expect("n / d to be coprime with (p - 1) / d");
+        mul_mod_u64(k / d, inverse, reduced_order)
+    };
+    Some(mod_pow(g, y, p))
+}
+
+/// Returns an iterator over the blocks of equal values of `n / i` for `i = 1, 2, ..., n`.
+///
+/// Each block is returned as `(l, r, q)`, meaning that `n / i == q` for all `l ≤ i ≤ r`. The blocks are returned in
+/// increasing order of `l`, and thus in decreasing order of `q`. There are at most 2√n blocks.
+///
+/// # Examples
+/// Computing the sum of `n / i` over `i = 1, 2, ..., n` in O(√n) time:
+/// ```
+/// # use comlib_math::floor_div_blocks;
+/// assert_eq!(
+///     floor_div_blocks(10).collect::<Vec<_>>(),
+///     [(1, 1, 10), (2, 2, 5), (3, 3, 3), (4, 5, 2), (6, 10, 1)]
+/// );
+/// let sum: u64 = floor_div_blocks(10).map(|(l, r, q)| (r - l + 1) * q).sum();
+/// assert_eq!(sum, 27);
+/// ```
+pub fn floor_div_blocks(n: u64) -> FloorDivBlocks {
+    FloorDivBlocks {
+        n,
+        next: Some(1).filter(|_| n > 0),
+    }
+}
+
+/// Iterator over the blocks of equal values of `n / i`.
+///
+/// This struct is created by [`floor_div_blocks`].
+#[derive(Debug, Clone)]
+pub struct FloorDivBlocks {
+    n: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for FloorDivBlocks {
+    type Item = (u64, u64, u64);
+
+    fn next(&mut self) -> Option<(u64, u64, u64)> {
+        let l = self.next?;
+        let q = self.n / l;
+        // The largest i with n / i == q
+        let r = self.n / q;
+        self.next = Some(r + 1).filter(|_| r < self.n);
+        Some((l, r, q))
+    }
+}
+
+/// Computes the sum of `floor((a * i + b) / m)` over `i = 0, 1, ..., n - 1`.
+///
+/// The sum counts the lattice points under a line, and it is computed with a Euclidean-like algorithm which repeatedly
+/// swaps the roles of the axes.
+///
+/// # Panics
+/// Panics if `n` is negative, if `m` is not positive, or if the result doesn't fit in [`i64`].
+///
+/// # Time complexity
+/// Takes O(log m) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::floor_sum;
+/// // floor(1/3) + floor(3/3) + floor(5/3) + floor(7/3)
+/// assert_eq!(floor_sum(4, 3, 2, 1), 0 + 1 + 1 + 2);
+/// assert_eq!(floor_sum(3, 2, -1, 0), 0 - 1 - 1);
+/// ```
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(n >= 0, "n must be non-negative");
+    assert!(m > 0, "m must be positive");
+
+    let (n, m, a, b) = (n as i128, m as i128, a as i128, b as i128);
+    // Move negative parts of a and b out of the sum so that the main loop only deals with non-negative values
+    let mut sum = 0;
+    let a_rem = a.rem_euclid(m);
+    sum += n * (n - 1) / 2 * ((a - a_rem) / m);
+    let b_rem = b.rem_euclid(m);
+    sum += n * ((b - b_rem) / m);
+
+    let (mut n, mut m, mut a, mut b) = (n, m, a_rem, b_rem);
+    loop {
+        if a >= m {
+            sum += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            sum += n * (b / m);
+            b %= m;
+        }
+
+        // Count the lattice points under the line by swapping the axes
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+
+    i64::try_narrow(sum).expect("floor sum to fit in i64")
+}
+
+/// Lists all positive divisors of the given number in increasing order.
+///
+/// The divisors are constructed from the prime factorization computed by [`factorize`]. Returns an empty list for 0.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::divisors;
+/// assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+/// assert_eq!(divisors(1), [1]);
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return vec![];
+    }
+    divisors_from_factorization(&factorize(n))
+}
+
+/// Counts the positive divisors of the given number.
+///
+/// Returns 0 for 0.
+pub fn divisor_count(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n).iter().map(|&(_, k)| k as u64 + 1).product()
+}
+
+/// Computes the sum of the positive divisors of the given number.
+///
+/// Returns 0 for 0. Note that the sum may overflow for numbers close to [`u64::MAX`].
+pub fn divisor_sum(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    factorize(n)
+        .iter()
+        .map(|&(p, k)| {
+            // 1 + p + p^2 + ... + p^k
+            let mut sum = 1;
+            let mut power = 1;
+            for _ in 0..k {
+                power *= p;
+                sum += power;
+            }
+            sum
+        })
+        .product()
+}
+
+/// Lists all divisors described by the given prime factorization in increasing order.
+fn divisors_from_factorization(factors: &[(u64, usize)]) -> Vec<u64> {
+    let mut divisors = vec![1];
+    for &(p, k) in factors {
+        // Multiply each of the divisors found so far by every power of p
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..k {
+            power *= p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
 /// Sieve of Eratosthenes.
 ///
 /// Sieve of Eratosthenes can be quickly used to determine whether a number is a prime and to find out its prime
@@ -176,10 +917,10 @@ impl PrimeSieve {
     pub fn new(n: u64) -> Self {
         let mut sieve = vec![0; n as usize + 1];
 
-        for i in once(2).chain((3..=n).step_by(2)) {
+        for i in once(2).filter(|&i| i <= n).chain((3..=n).step_by(2)) {
             if sieve[i as usize] == 0 {
                 // Found a prime, hence we need to mark all higher multiples as non-primes
-                for j in (i..=n).step_by(i as usize).skip(0) {
+                for j in (i..=n).step_by(i as usize) {
                     sieve[j as usize] = i;
                 }
             }
@@ -213,8 +954,265 @@ impl PrimeSieve {
         factors.into_inner()
     }
 
+    /// Lists all positive divisors of the given number in increasing order.
+    ///
+    /// Works like [`divisors`], but uses the sieve for factorizing the number.
+    pub fn divisors(&self, n: u64) -> Vec<u64> {
+        if n == 0 {
+            return vec![];
+        }
+        divisors_from_factorization(&self.factorize(n))
+    }
+
+    /// Computes the smallest prime factor, the Möbius function, Euler's totient function and the number of divisors for
+    /// every number in the sieve.
+    ///
+    /// All four tables are filled in a single pass, so prefer this over the separate methods when several of them are
+    /// needed.
+    ///
+    /// Takes O(n) time.
+    pub fn tables(&self) -> SieveTables {
+        let len = self.0.len();
+        let mut tables = SieveTables {
+            smallest_factor: vec![0; len],
+            mobius: vec![0; len],
+            phi: vec![0; len],
+            divisor_count: vec![0; len],
+        };
+        // Exponent of the largest prime factor
+        let mut exponents = vec![0; len];
+        if len > 1 {
+            tables.mobius[1] = 1;
+            tables.phi[1] = 1;
+            tables.divisor_count[1] = 1;
+        }
+        for n in 2..len {
+            let p = self.0[n];
+            let rest = n / p as usize;
+            // The sieve stores the largest prime factor, so the smallest one is found in the rest of the number
+            tables.smallest_factor[n] = if rest == 1 {
+                p
+            } else {
+                tables.smallest_factor[rest]
+            };
+            if (rest as u64).is_multiple_of(p) {
+                exponents[n] = exponents[rest] + 1;
+                tables.mobius[n] = 0;
+                tables.phi[n] = tables.phi[rest] * p;
+                tables.divisor_count[n] =
+                    tables.divisor_count[rest] / exponents[n] * (exponents[n] + 1);
+            } else {
+                exponents[n] = 1;
+                tables.mobius[n] = -tables.mobius[rest];
+                tables.phi[n] = tables.phi[rest] * (p - 1);
+                tables.divisor_count[n] = tables.divisor_count[rest] * 2;
+            }
+        }
+        tables
+    }
+
+    /// Computes the smallest prime factor for every number in the sieve.
+    ///
+    /// Like the sieve itself, the table contains 0 for 0 and 1. See [`tables`](Self::tables) for computing several
+    /// tables at once.
+    ///
+    /// Takes O(n) time.
+    pub fn smallest_factor_table(&self) -> Vec<u64> {
+        self.tables().smallest_factor
+    }
+
+    /// Computes the [Möbius function] for every number in the sieve.
+    ///
+    /// The table contains 0 for 0. See [`tables`](Self::tables) for computing several tables at once.
+    ///
+    /// Takes O(n) time.
+    ///
+    /// [Möbius function]: https://en.wikipedia.org/wiki/M%C3%B6bius_function
+    pub fn mobius_table(&self) -> Vec<i8> {
+        self.tables().mobius
+    }
+
+    /// Computes [Euler's totient function] for every number in the sieve.
+    ///
+    /// The table contains 0 for 0. See [`tables`](Self::tables) for computing several tables at once.
+    ///
+    /// Takes O(n) time.
+    ///
+    /// [Euler's totient function]: https://en.wikipedia.org/wiki/Euler%27s_totient_function
+    pub fn phi_table(&self) -> Vec<u64> {
+        self.tables().phi
+    }
+
+    /// Computes the number of positive divisors for every number in the sieve.
+    ///
+    /// The table contains 0 for 0. See [`tables`](Self::tables) for computing several tables at once.
+    ///
+    /// Takes O(n) time.
+    pub fn divisor_count_table(&self) -> Vec<u64> {
+        self.tables().divisor_count
+    }
+
+    /// Returns an iterator over the primes in the sieve in increasing order.
+    pub fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        (2..self.0.len() as u64).filter(move |&i| self.0[i as usize] == i)
+    }
+
     /// Turns the sieve into raw vector telling the largest prime divisor for each index.
     pub fn into_inner(self) -> Vec<u64> {
         self.0
     }
 }
+
+/// Arithmetic function tables computed by [`PrimeSieve::tables`].
+///
+/// Each table is indexed by the number and covers the same range as the sieve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SieveTables {
+    /// Smallest prime factor of each number, or 0 for 0 and 1.
+    pub smallest_factor: Vec<u64>,
+    /// [Möbius function](https://en.wikipedia.org/wiki/M%C3%B6bius_function) of each number, or 0 for 0.
+    pub mobius: Vec<i8>,
+    /// [Euler's totient function](https://en.wikipedia.org/wiki/Euler%27s_totient_function) of each number, or 0 for 0.
+    pub phi: Vec<u64>,
+    /// Number of positive divisors of each number, or 0 for 0.
+    pub divisor_count: Vec<u64>,
+}
+
+/// Memory-efficient sieve of Eratosthenes.
+///
+/// Unlike [`PrimeSieve`], this sieve only stores a single bit for each odd number, so it needs only n/16 bytes of memory.
+/// This makes sieving up to around 10⁹ feasible. On the other hand, it can't be used for factorizing numbers.
+///
+/// # Time complexity
+/// The construction of the sieve takes O(n log log n) time. After this checking whether a number is a prime takes O(1)
+/// time.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::BitPrimeSieve;
+/// let sieve = BitPrimeSieve::new(30);
+/// assert!(sieve.is_prime(29));
+/// assert_eq!(sieve.primes().collect::<Vec<_>>(), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// assert_eq!(sieve.count_primes(), 10);
+/// assert_eq!(sieve.nth_prime(3), Some(7));
+/// ```
+pub struct BitPrimeSieve {
+    n: u64,
+    /// Bit `i` is set if `2i + 1` is composite. Bits past n are set as well.
+    composite: Vec<u64>,
+}
+
+impl BitPrimeSieve {
+    /// Constructs a new [`BitPrimeSieve`] containing the numbers up to and including `n`.
+    ///
+    /// Takes O(n log log n) time.
+    pub fn new(n: u64) -> Self {
+        let odd_count = n / 2 + n % 2;
+        let words = (odd_count as usize).div_ceil(64);
+        let mut composite = vec![0u64; words];
+
+        // 1 is not a prime, and bits past n must not be reported as primes
+        if let Some(first) = composite.first_mut() {
+            *first |= 1;
+        }
+        if !odd_count.is_multiple_of(64) {
+            composite[words - 1] |= !0 << (odd_count % 64);
+        }
+
+        let mut i = 3;
+        while i * i <= n {
+            if composite[(i / 2 / 64) as usize] >> (i / 2 % 64) & 1 == 0 {
+                // Even multiples are not stored, so step over them
+                for j in (i * i..=n).step_by(2 * i as usize) {
+                    composite[(j / 2 / 64) as usize] |= 1 << (j / 2 % 64);
+                }
+            }
+            i += 2;
+        }
+
+        Self { n, composite }
+    }
+
+    /// Checks whether the given number is a prime.
+    ///
+    /// # Panics
+    /// Panics if the number is larger than the size of the sieve.
+    pub fn is_prime(&self, n: u64) -> bool {
+        assert!(n <= self.n, "number must be in the sieve");
+        if n.is_multiple_of(2) {
+            n == 2
+        } else {
+            self.composite[(n / 2 / 64) as usize] >> (n / 2 % 64) & 1 == 0
+        }
+    }
+
+    /// Returns an iterator over the primes in the sieve in increasing order.
+    pub fn primes(&self) -> BitPrimeSievePrimes<'_> {
+        BitPrimeSievePrimes {
+            two: self.n >= 2,
+            words: &self.composite,
+            word_index: 0,
+            remaining: self.composite.first().map_or(0, |&word| !word),
+        }
+    }
+
+    /// Counts the number of primes in the sieve, i.e. the number of primes not larger than `n`.
+    pub fn count_primes(&self) -> usize {
+        let odd_primes: u32 = self.composite.iter().map(|word| word.count_zeros()).sum();
+        odd_primes as usize + (self.n >= 2) as usize
+    }
+
+    /// Finds the `k`th prime in the sieve, starting from 0. In other words, `nth_prime(0)` is 2.
+    ///
+    /// Returns `None` if the sieve contains at most `k` primes.
+    pub fn nth_prime(&self, k: usize) -> Option<u64> {
+        if k == 0 {
+            return self.primes().next();
+        }
+
+        // Skip whole words before finding the prime inside the correct word
+        let mut remaining = k - 1;
+        for (word_index, &word) in self.composite.iter().enumerate() {
+            let mut primes = !word;
+            let count = primes.count_ones() as usize;
+            if remaining < count {
+                for _ in 0..remaining {
+                    primes &= primes - 1;
+                }
+                return Some(2 * (64 * word_index as u64 + primes.trailing_zeros() as u64) + 1);
+            }
+            remaining -= count;
+        }
+        None
+    }
+}
+
+/// Iterator over the primes of a [`BitPrimeSieve`].
+///
+/// This struct is created by [`BitPrimeSieve::primes`].
+pub struct BitPrimeSievePrimes<'a> {
+    two: bool,
+    words: &'a [u64],
+    word_index: usize,
+    /// Bits of the current word corresponding to primes that haven't been returned yet.
+    remaining: u64,
+}
+
+impl Iterator for BitPrimeSievePrimes<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+
+        while self.remaining == 0 {
+            self.word_index += 1;
+            self.remaining = !*self.words.get(self.word_index)?;
+        }
+        let bit = self.remaining.trailing_zeros() as u64;
+        self.remaining &= self.remaining - 1;
+        Some(2 * (64 * self.word_index as u64 + bit) + 1)
+    }
+}
@@ -115,6 +115,48 @@ pub trait NonZero: Sized + Copy {
 pub trait Integer: Numeric + Eq + Ord {
     /// The corresponding non-zeroable type.
     type NonZero: NonZero<Base = Self>;
+
+    /// Integer type which is at least twice as wide, used for computing intermediate values without overflow.
+    ///
+    /// For the widest integer types this is the type itself.
+    type Wide: Integer;
+
+    /// Converts the number into the wide type.
+    fn widen(self) -> Self::Wide;
+
+    /// Converts the number back from the wide type.
+    ///
+    /// Returns `None` if the value doesn't fit in the type.
+    fn try_narrow(value: Self::Wide) -> Option<Self>;
+
+    /// Converts the given floating-point number to the type, truncating the fractional part.
+    ///
+    /// Returns `None` if the value is not finite or doesn't fit in the type.
+    fn try_from_f64(value: f64) -> Option<Self>;
+
+    /// The smallest value representable by the type.
+    const MIN: Self;
+
+    /// The largest value representable by the type.
+    const MAX: Self;
+
+    /// Computes `self + rhs`, returning `None` if the result overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Computes `self - rhs`, returning `None` if the result overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Computes `self * rhs`, returning `None` if the result overflows.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Computes `self + rhs`, saturating at the bounds of the type instead of overflowing.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Computes `self - rhs`, saturating at the bounds of the type instead of overflowing.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Computes `self * rhs`, saturating at the bounds of the type instead of overflowing.
+    fn saturating_mul(self, rhs: Self) -> Self;
 }
 
 macro_rules! impl_numeric {
@@ -173,10 +215,65 @@ macro_rules! impl_numeric {
     };
 }
 macro_rules! impl_integer {
-    ($t:ty, $nonzero:ty) => {
+    ($t:ty, $nonzero:ty, $wide:ty) => {
         impl_numeric!($t);
         impl Integer for $t {
             type NonZero = $nonzero;
+            type Wide = $wide;
+
+            #[inline(always)]
+            fn widen(self) -> $wide {
+                self as $wide
+            }
+
+            #[inline(always)]
+            #[allow(clippy::useless_conversion)]
+            fn try_narrow(value: $wide) -> Option<$t> {
+                std::convert::TryFrom::try_from(value).ok()
+            }
+
+            #[inline(always)]
+            fn try_from_f64(value: f64) -> Option<$t> {
+                // Both bounds are powers of two and hence exactly representable
+                if value >= <$t>::MIN as f64 && value < <$t>::MAX as f64 + 1.0 {
+                    Some(value as $t)
+                } else {
+                    None
+                }
+            }
+
+            const MIN: $t = <$t>::MIN;
+            const MAX: $t = <$t>::MAX;
+
+            #[inline(always)]
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            #[inline(always)]
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: $t) -> $t {
+                <$t>::saturating_add(self, rhs)
+            }
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: $t) -> $t {
+                <$t>::saturating_sub(self, rhs)
+            }
+
+            #[inline(always)]
+            fn saturating_mul(self, rhs: $t) -> $t {
+                <$t>::saturating_mul(self, rhs)
+            }
         }
 
         impl NonZero for $nonzero {
@@ -193,29 +290,32 @@ macro_rules! impl_integer {
             }
         }
     };
-    ($t:ty, $nonzero:ty, signed) => {
-        impl_integer!($t, $nonzero);
+    ($t:ty, $nonzero:ty, $wide:ty, signed) => {
+        impl_integer!($t, $nonzero, $wide);
         impl_numeric!($t, signed_only);
     };
 }
 
-impl_integer!(u8, num::NonZeroU8);
-impl_integer!(u16, num::NonZeroU16);
-impl_integer!(u32, num::NonZeroU32);
-impl_integer!(u64, num::NonZeroU64);
-impl_integer!(u128, num::NonZeroU128);
-impl_integer!(usize, num::NonZeroUsize);
-impl_integer!(i8, num::NonZeroI8, signed);
-impl_integer!(i16, num::NonZeroI16, signed);
-impl_integer!(i32, num::NonZeroI32, signed);
-impl_integer!(i64, num::NonZeroI64, signed);
-impl_integer!(i128, num::NonZeroI128, signed);
-impl_integer!(isize, num::NonZeroIsize, signed);
+impl_integer!(u8, num::NonZeroU8, u16);
+impl_integer!(u16, num::NonZeroU16, u32);
+impl_integer!(u32, num::NonZeroU32, u64);
+impl_integer!(u64, num::NonZeroU64, u128);
+impl_integer!(u128, num::NonZeroU128, u128);
+impl_integer!(usize, num::NonZeroUsize, u128);
+impl_integer!(i8, num::NonZeroI8, i16, signed);
+impl_integer!(i16, num::NonZeroI16, i32, signed);
+impl_integer!(i32, num::NonZeroI32, i64, signed);
+impl_integer!(i64, num::NonZeroI64, i128, signed);
+impl_integer!(i128, num::NonZeroI128, i128, signed);
+impl_integer!(isize, num::NonZeroIsize, i128, signed);
 
 /// Trait implemented by floating-point numbers.
 pub trait Float: Numeric + Signed {
     /// Computes the square root of the number
     fn get_sqrt(self) -> Self;
+
+    /// Computes the sine and the cosine of the number, in radians.
+    fn get_sin_cos(self) -> (Self, Self);
 }
 
 macro_rules! impl_float {
@@ -227,6 +327,11 @@ macro_rules! impl_float {
             fn get_sqrt(self) -> $t {
                 self.sqrt()
             }
+
+            #[inline(always)]
+            fn get_sin_cos(self) -> ($t, $t) {
+                self.sin_cos()
+            }
         }
     };
 }
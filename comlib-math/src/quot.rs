@@ -1,9 +1,12 @@
 use crate::{gcd, Integer, NonZero, Numeric, Signed};
-use std::{fmt, ops};
+use std::{fmt, hash, ops};
 
 /// A quotient. Represents a rational number as `numerator/denominator`.
 ///
 /// The denominator is stored as non-zeroable type, so this type benefits from the niche optimization.
+///
+/// Arithmetic and comparisons compute the intermediate values in [`Integer::Wide`], so they don't overflow as long as
+/// the normalized result fits in `T`. Operations panic if the result doesn't fit.
 #[derive(Clone, Copy)]
 pub struct Quot<T: Integer> {
     numerator: T,
@@ -43,15 +46,33 @@ impl<T: Integer> Quot<T> {
 
     /// Turns the value into normalized form.
     ///
-    /// The numerator and the denominator of a quotient in the normalized form don't have a shared divisor.
+    /// The numerator and the denominator of a quotient in the normalized form don't have a shared divisor, and the
+    /// denominator is positive.
     fn normalized(self) -> Self {
-        let div = gcd(self.numerator, self.denominator.get());
+        let mut div = gcd(self.numerator, self.denominator.get());
+        // Make sure that the denominator is positive
+        if self.denominator.get() / div < T::zero() {
+            div = T::zero() - div;
+        }
         Self {
             numerator: self.numerator / div,
             denominator: unsafe { T::NonZero::new_unchecked(self.denominator.get() / div) },
         }
     }
 
+    /// Constructs a new normalized `Quot` from a numerator and a denominator of the wide type.
+    ///
+    /// Returns `None` if the denominator is 0. Panics if the normalized quotient doesn't fit in `T`.
+    fn from_wide(numerator: T::Wide, denominator: T::Wide) -> Option<Self> {
+        let wide = Quot::<T::Wide>::new(numerator, denominator)?;
+        let numerator = T::try_narrow(wide.numerator()).expect("Quot numerator overflowed");
+        let denominator = T::try_narrow(wide.denominator()).expect("Quot denominator overflowed");
+        Some(Self {
+            numerator,
+            denominator: unsafe { T::NonZero::new_unchecked(denominator) },
+        })
+    }
+
     /// Gets the numerator of the quotient.
     pub fn numerator(self) -> T {
         self.numerator
@@ -66,7 +87,271 @@ impl<T: Integer> Quot<T> {
     ///
     /// This gives out the integral part of the quotient.
     pub fn trunc(self) -> T {
-        self.numerator() / self.denominator() * self.denominator()
+        self.numerator() / self.denominator()
+    }
+
+    /// Computes the largest integer less than or equal to the quotient.
+    pub fn floor(self) -> T {
+        let trunc = self.trunc();
+        if self.numerator() < T::zero() && !self.is_integer() {
+            trunc - T::one()
+        } else {
+            trunc
+        }
+    }
+
+    /// Computes the smallest integer greater than or equal to the quotient.
+    pub fn ceil(self) -> T {
+        let trunc = self.trunc();
+        if self.numerator() > T::zero() && !self.is_integer() {
+            trunc + T::one()
+        } else {
+            trunc
+        }
+    }
+
+    /// Rounds the quotient to the nearest integer.
+    ///
+    /// Half-way cases are rounded away from zero, like [`f64::round`] does.
+    pub fn round(self) -> T {
+        // Compute (2n ± d) / 2d, which truncates towards zero
+        let two = T::Wide::from_int(2);
+        let numerator = two * self.numerator().widen();
+        let denominator = self.denominator().widen();
+        let rounded = if self.numerator() < T::zero() {
+            (numerator - denominator) / (two * denominator)
+        } else {
+            (numerator + denominator) / (two * denominator)
+        };
+        T::try_narrow(rounded).expect("rounded value to fit the type")
+    }
+
+    /// Checks whether the quotient is an integer.
+    pub fn is_integer(self) -> bool {
+        self.denominator().is_one()
+    }
+
+    /// Computes the reciprocal `1/self` of the quotient.
+    ///
+    /// # Panics
+    /// Panics if the quotient is zero.
+    pub fn recip(self) -> Self {
+        Self::new(self.denominator(), self.numerator()).expect("cannot take reciprocal of zero")
+    }
+
+    /// Raises the quotient to the given integer power.
+    ///
+    /// Negative exponents raise the reciprocal of the quotient to the corresponding positive power.
+    ///
+    /// # Panics
+    /// Panics if the exponent is negative and the quotient is zero.
+    pub fn pow(self, exponent: i32) -> Self {
+        let mut base = if exponent < 0 { self.recip() } else { self };
+        let mut exponent = exponent.unsigned_abs();
+        let mut result = Self::from(T::one());
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+
+    /// Finds a quotient equal to the given floating-point number.
+    ///
+    /// Expands the number as a [continued fraction] and returns the first convergent which converts back to exactly the
+    /// same `f64`. If no such convergent fits in `T`, the closest one which fits is returned. Returns `None` if the
+    /// number is not finite or its integral part doesn't fit in `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_math::Quot;
+    /// assert_eq!(Quot::<i64>::from_f64(0.1), Quot::new(1, 10));
+    /// assert_eq!(Quot::<i64>::from_f64(-2.75), Quot::new(-11, 4));
+    /// ```
+    ///
+    /// [continued fraction]: https://en.wikipedia.org/wiki/Continued_fraction
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        // Convergents h/k satisfy h_i = a_i h_{i-1} + h_{i-2} and similarly for k
+        let (mut h0, mut h1) = (T::Wide::zero(), T::Wide::one());
+        let (mut k0, mut k1) = (T::Wide::one(), T::Wide::zero());
+        let mut x = value;
+        let mut best = None;
+        loop {
+            let a = x.floor();
+            let term = match T::try_from_f64(a) {
+                Some(term) => term.widen(),
+                None => break,
+            };
+            let h2 = term * h1 + h0;
+            let k2 = term * k1 + k0;
+            let convergent = match (T::try_narrow(h2), T::try_narrow(k2)) {
+                (Some(h), Some(k)) => Self::new(h, k).unwrap(),
+                _ => break,
+            };
+            best = Some(convergent);
+
+            let fraction = x - a;
+            if convergent.as_f64() == value || fraction == 0.0 {
+                break;
+            }
+            x = 1.0 / fraction;
+            h0 = h1;
+            h1 = h2;
+            k0 = k1;
+            k1 = k2;
+        }
+        best
+    }
+
+    /// Computes the [continued fraction] expansion of the quotient.
+    ///
+    /// The first term is the floor of the quotient, and the rest are positive. The last term is greater than 1 unless
+    /// the expansion has only one term.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_math::Quot;
+    /// assert_eq!(Quot::new(415, 93).unwrap().continued_fraction(), vec![4, 2, 6, 7]);
+    /// assert_eq!(Quot::new(-7, 3).unwrap().continued_fraction(), vec![-3, 1, 2]);
+    /// ```
+    ///
+    /// [continued fraction]: https://en.wikipedia.org/wiki/Continued_fraction
+    pub fn continued_fraction(self) -> Vec<T> {
+        let mut convergents = self.convergents();
+        std::iter::from_fn(|| convergents.next_term()).collect()
+    }
+
+    /// Returns an iterator over the convergents of the continued fraction expansion of the quotient.
+    ///
+    /// The convergents are the best rational approximations of the quotient, and the last one is the quotient itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_math::Quot;
+    /// let q = Quot::new(415, 93).unwrap();
+    /// let convergents: Vec<_> = q.convergents().collect();
+    /// assert_eq!(
+    ///     convergents,
+    ///     vec![
+    ///         Quot::from(4),
+    ///         Quot::new(9, 2).unwrap(),
+    ///         Quot::new(58, 13).unwrap(),
+    ///         q,
+    ///     ]
+    /// );
+    /// ```
+    pub fn convergents(self) -> Convergents<T> {
+        Convergents {
+            remaining: Some(self),
+            numerators: (T::Wide::zero(), T::Wide::one()),
+            denominators: (T::Wide::one(), T::Wide::zero()),
+        }
+    }
+
+    /// Finds the closest quotient whose denominator is at most `max_denominator`.
+    ///
+    /// If two quotients are equally close, the one with the smaller denominator is returned.
+    ///
+    /// # Panics
+    /// Panics if `max_denominator` is less than 1.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_math::Quot;
+    /// let pi = Quot::new(3_141_592_653_589_793i64, 1_000_000_000_000_000).unwrap();
+    /// assert_eq!(pi.limit_denominator(10), Quot::new(22, 7).unwrap());
+    /// assert_eq!(pi.limit_denominator(1000), Quot::new(355, 113).unwrap());
+    /// ```
+    pub fn limit_denominator(self, max_denominator: T) -> Self {
+        assert!(
+            max_denominator >= T::one(),
+            "max_denominator must be positive"
+        );
+        if self.denominator() <= max_denominator {
+            return self;
+        }
+
+        // Find the last convergent whose denominator is within the limit. The quotient itself is the last convergent,
+        // so this stops before running out of convergents.
+        let mut convergents = self.convergents();
+        loop {
+            let mut advanced = convergents.clone();
+            match advanced.next() {
+                Some(convergent) if convergent.denominator() <= max_denominator => {
+                    convergents = advanced
+                }
+                _ => break,
+            }
+        }
+        let (p0, p1) = convergents.numerators;
+        let (q0, q1) = convergents.denominators;
+
+        // The best approximation is either the last convergent or the largest semiconvergent before it
+        let k = (max_denominator.widen() - q0) / q1;
+        let semiconvergent = Self::from_wide(p0 + k * p1, q0 + k * q1).unwrap();
+        let convergent = Self::from_wide(p1, q1).unwrap();
+        let distance = |other: Self| {
+            if self > other {
+                self - other
+            } else {
+                other - self
+            }
+        };
+        if distance(convergent) <= distance(semiconvergent) {
+            convergent
+        } else {
+            semiconvergent
+        }
+    }
+
+    /// Finds the simplest quotient in the closed interval `[lo, hi]`.
+    ///
+    /// The simplest quotient is the one with the smallest denominator, and among those the one with the smallest
+    /// absolute value. This is the quotient closest to the root of the [Stern–Brocot tree].
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`.
+    ///
+    /// # Time complexity
+    /// Takes O(log max(denominator)) arithmetic operations.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_math::Quot;
+    /// let q = |n, d| Quot::new(n, d).unwrap();
+    /// assert_eq!(Quot::simplest_between(q(3, 10), q(4, 10)), q(1, 3));
+    /// assert_eq!(Quot::simplest_between(q(-5, 2), q(-3, 2)), q(-2, 1));
+    /// assert_eq!(Quot::simplest_between(q(-1, 2), q(3, 2)), q(0, 1));
+    /// ```
+    ///
+    /// [Stern–Brocot tree]: https://en.wikipedia.org/wiki/Stern%E2%80%93Brocot_tree
+    pub fn simplest_between(lo: Self, hi: Self) -> Self {
+        assert!(lo <= hi, "interval must not be empty");
+        let ceil = lo.ceil();
+        if Self::from(ceil) <= hi {
+            // There is an integer in the interval, so pick the one closest to zero
+            return if lo > Self::zero() {
+                Self::from(ceil)
+            } else if hi < Self::zero() {
+                Self::from(hi.floor())
+            } else {
+                Self::zero()
+            };
+        }
+
+        // Both ends share the integer part, so recurse on the reciprocals of the fractional parts
+        let floor = lo.floor();
+        let inner = Self::simplest_between((hi - floor).recip(), (lo - floor).recip());
+        inner.recip() + floor
     }
 
     /// Computes the absolute value of the quotient.
@@ -81,6 +366,46 @@ impl<T: Integer> Quot<T> {
     }
 }
 
+/// Iterator over the convergents of a quotient.
+///
+/// This struct is created by [`Quot::convergents`].
+#[derive(Clone, Debug)]
+pub struct Convergents<T: Integer> {
+    remaining: Option<Quot<T>>,
+    numerators: (T::Wide, T::Wide),
+    denominators: (T::Wide, T::Wide),
+}
+
+impl<T: Integer> Convergents<T> {
+    /// Advances to the next convergent, returning the term of the continued fraction used for it.
+    fn next_term(&mut self) -> Option<T> {
+        let x = self.remaining?;
+        let term = x.floor();
+        let fraction = x - term;
+        self.remaining = if fraction.numerator() == T::zero() {
+            None
+        } else {
+            Some(fraction.recip())
+        };
+
+        // Convergents h/k satisfy h_i = a_i h_{i-1} + h_{i-2} and similarly for k
+        let (h0, h1) = self.numerators;
+        let (k0, k1) = self.denominators;
+        self.numerators = (h1, term.widen() * h1 + h0);
+        self.denominators = (k1, term.widen() * k1 + k0);
+        Some(term)
+    }
+}
+
+impl<T: Integer> Iterator for Convergents<T> {
+    type Item = Quot<T>;
+
+    fn next(&mut self) -> Option<Quot<T>> {
+        self.next_term()?;
+        Some(Quot::from_wide(self.numerators.1, self.denominators.1).unwrap())
+    }
+}
+
 impl<T: Integer> fmt::Debug for Quot<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}/{:?}", self.numerator(), self.denominator())
@@ -97,6 +422,14 @@ impl<T: Integer> fmt::Display for Quot<T> {
     }
 }
 
+/// The quotient is always stored in normalized form, so equal quotients hash to the same value.
+impl<T: Integer + hash::Hash> hash::Hash for Quot<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.numerator().hash(state);
+        self.denominator().hash(state);
+    }
+}
+
 impl<T: Integer> Default for Quot<T> {
     fn default() -> Self {
         Self::zero()
@@ -113,9 +446,10 @@ where
 
     fn add(self, rhs: R) -> Self::Output {
         let rhs = rhs.into();
-        Self::new(
-            self.numerator() * rhs.denominator() + rhs.numerator() * self.denominator(),
-            self.denominator() * rhs.denominator(),
+        Self::from_wide(
+            self.numerator().widen() * rhs.denominator().widen()
+                + rhs.numerator().widen() * self.denominator().widen(),
+            self.denominator().widen() * rhs.denominator().widen(),
         )
         .unwrap()
     }
@@ -129,9 +463,10 @@ where
 
     fn sub(self, rhs: R) -> Self::Output {
         let rhs = rhs.into();
-        Self::new(
-            self.numerator() * rhs.denominator() - rhs.numerator() * self.denominator(),
-            self.denominator() * rhs.denominator(),
+        Self::from_wide(
+            self.numerator().widen() * rhs.denominator().widen()
+                - rhs.numerator().widen() * self.denominator().widen(),
+            self.denominator().widen() * rhs.denominator().widen(),
         )
         .unwrap()
     }
@@ -156,9 +491,9 @@ where
 
     fn mul(self, rhs: R) -> Self::Output {
         let rhs = rhs.into();
-        Self::new(
-            self.numerator() * rhs.numerator(),
-            self.denominator() * rhs.denominator(),
+        Self::from_wide(
+            self.numerator().widen() * rhs.numerator().widen(),
+            self.denominator().widen() * rhs.denominator().widen(),
         )
         .unwrap()
     }
@@ -172,9 +507,9 @@ where
 
     fn div(self, rhs: R) -> Self::Output {
         let rhs = rhs.into();
-        Self::new(
-            self.numerator() * rhs.denominator(),
-            self.denominator() * rhs.numerator(),
+        Self::from_wide(
+            self.numerator().widen() * rhs.denominator().widen(),
+            self.denominator().widen() * rhs.numerator().widen(),
         )
         .unwrap()
     }
@@ -257,7 +592,8 @@ impl<T: Integer> PartialOrd for Quot<T> {
 
 impl<T: Integer> Ord for Quot<T> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        (self.numerator() * other.denominator()).cmp(&(other.numerator() * self.denominator()))
+        (self.numerator().widen() * other.denominator().widen())
+            .cmp(&(other.numerator().widen() * self.denominator().widen()))
     }
 }
 
@@ -329,5 +665,173 @@ mod tests {
         assert_eq!(Quot::new(2, -1).unwrap().denominator(), 1);
         assert_eq!(Quot::new(-2, 1).unwrap().denominator(), 1);
         assert_eq!(Quot::new(-2, -1).unwrap().denominator(), 1);
+        assert_eq!(Quot::new(-7, 2).unwrap().denominator(), 2);
+        assert_eq!(Quot::new(7, -2).unwrap().denominator(), 2);
+        assert_eq!(Quot::new(-7, -2).unwrap().denominator(), 2);
+    }
+
+    #[test]
+    fn large_values_do_not_overflow() {
+        let big = 3_000_000_000_000_000_000i64;
+        let a = Quot::new(big, 7).unwrap();
+        let b = Quot::new(big - 1, 7).unwrap();
+        assert!(a > b);
+        assert!(Quot::new(big, big - 1).unwrap() > Quot::from(1));
+        assert_eq!(a - b, Quot::new(1, 7).unwrap());
+        assert_eq!(a * Quot::new(7, big).unwrap(), Quot::from(1));
+        assert_eq!(a / a, Quot::from(1));
+        assert_eq!(
+            Quot::new(1, big).unwrap() + Quot::new(1, big).unwrap(),
+            Quot::new(1, big / 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn equal_quotients_hash_equally() {
+        use std::collections::HashSet;
+        let set: HashSet<_> = vec![
+            Quot::new(1, 2).unwrap(),
+            Quot::new(2, 4).unwrap(),
+            Quot::new(-3, -6).unwrap(),
+            Quot::new(-1, 2).unwrap(),
+            Quot::new(1, -2).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn rounding() {
+        let q = |n, d| Quot::new(n, d).unwrap();
+        assert_eq!(q(7, 2).trunc(), 3);
+        assert_eq!(q(-7, 2).trunc(), -3);
+        assert_eq!(q(7, 2).floor(), 3);
+        assert_eq!(q(-7, 2).floor(), -4);
+        assert_eq!(q(7, 2).ceil(), 4);
+        assert_eq!(q(-7, 2).ceil(), -3);
+        assert_eq!(q(6, 2).floor(), 3);
+        assert_eq!(q(6, 2).ceil(), 3);
+        assert_eq!(q(7, 2).round(), 4);
+        assert_eq!(q(-7, 2).round(), -4);
+        assert_eq!(q(7, 3).round(), 2);
+        assert_eq!(q(-8, 3).round(), -3);
+        assert_eq!(q(7, 2) % 2, q(3, 2));
+        assert!(q(6, 2).is_integer());
+        assert!(!q(7, 2).is_integer());
+    }
+
+    #[test]
+    fn pow_and_recip() {
+        let q = |n, d| Quot::new(n, d).unwrap();
+        assert_eq!(q(-2, 3).recip(), q(-3, 2));
+        assert_eq!(q(2, 3).pow(0), q(1, 1));
+        assert_eq!(q(2, 3).pow(3), q(8, 27));
+        assert_eq!(q(2, 3).pow(-2), q(9, 4));
+        assert_eq!(q(-1, 2).pow(5), q(-1, 32));
+    }
+
+    #[test]
+    fn from_f64() {
+        assert_eq!(Quot::<i64>::from_f64(0.0), Quot::new(0, 1));
+        assert_eq!(Quot::<i64>::from_f64(3.0), Quot::new(3, 1));
+        assert_eq!(Quot::<i64>::from_f64(0.375), Quot::new(3, 8));
+        assert_eq!(Quot::<i64>::from_f64(1.0 / 3.0), Quot::new(1, 3));
+        assert_eq!(Quot::<i64>::from_f64(-0.1), Quot::new(-1, 10));
+        assert_eq!(
+            Quot::<i32>::from_f64(std::f64::consts::PI)
+                .map(Quot::as_f64)
+                .map(|x| (x - std::f64::consts::PI).abs() < 1e-9),
+            Some(true)
+        );
+        assert_eq!(Quot::<i8>::from_f64(1000.0), None);
+        assert_eq!(Quot::<i64>::from_f64(f64::NAN), None);
+    }
+
+    #[test]
+    fn continued_fractions() {
+        let q = |n, d| Quot::new(n, d).unwrap();
+        assert_eq!(q(0, 1).continued_fraction(), vec![0]);
+        assert_eq!(q(5, 1).continued_fraction(), vec![5]);
+        assert_eq!(q(1, 3).continued_fraction(), vec![0, 3]);
+        assert_eq!(q(-1, 2).continued_fraction(), vec![-1, 2]);
+        assert_eq!(
+            Quot::new(13u32, 8).unwrap().continued_fraction(),
+            vec![1, 1, 1, 1, 2]
+        );
+
+        for n in -30..=30 {
+            for d in 1..=30 {
+                let x = q(n, d);
+                let convergents: Vec<_> = x.convergents().collect();
+                assert_eq!(convergents.len(), x.continued_fraction().len());
+                assert_eq!(*convergents.last().unwrap(), x);
+                // Each convergent is at least as close as the previous one
+                let distances: Vec<_> = convergents.iter().map(|&c| (c - x).abs()).collect();
+                assert!(distances.windows(2).all(|w| w[1] <= w[0]));
+            }
+        }
+    }
+
+    #[test]
+    fn limit_denominator() {
+        let q = |n, d| Quot::new(n, d).unwrap();
+        assert_eq!(q(3, 7).limit_denominator(10), q(3, 7));
+        assert_eq!(q(-22, 7).limit_denominator(1), q(-3, 1));
+        assert_eq!(q(1, 2).limit_denominator(1), q(0, 1));
+
+        // Compare against brute force
+        for max in 1..=6 {
+            let candidates: Vec<_> = (1..=max)
+                .flat_map(|d| (-13 * d..=13 * d).map(move |n| q(n, d)))
+                .collect();
+            for n in -12..=12i64 {
+                for d in 1..=12 {
+                    let x = q(n, d);
+                    let best = candidates
+                        .iter()
+                        .copied()
+                        .min_by_key(|&c| ((c - x).abs(), c.denominator()))
+                        .unwrap();
+                    assert_eq!(x.limit_denominator(max), best, "{:?} {}", x, max);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn simplest_between() {
+        let q = |n, d| Quot::new(n, d).unwrap();
+        assert_eq!(Quot::simplest_between(q(1, 2), q(1, 2)), q(1, 2));
+        assert_eq!(Quot::simplest_between(q(5, 7), q(3, 4)), q(3, 4));
+        assert_eq!(Quot::simplest_between(q(7, 2), q(9, 2)), q(4, 1));
+        assert_eq!(Quot::simplest_between(q(-9, 2), q(-7, 2)), q(-4, 1));
+        assert_eq!(Quot::simplest_between(q(-5, 7), q(-2, 3)), q(-2, 3));
+
+        // Compare against brute force. The candidates are sorted from the simplest, so the first one in the range is
+        // the answer.
+        let fractions: Vec<_> = (1..=6)
+            .flat_map(|d| (-12..=12).map(move |n| q(n, d)))
+            .collect();
+        let mut candidates: Vec<_> = (1..=6)
+            .flat_map(|d| (-12 * d..=12 * d).map(move |n| q(n, d)))
+            .collect();
+        candidates.sort_by_key(|&c| (c.denominator(), c.abs()));
+        for &lo in &fractions {
+            for &hi in fractions.iter().filter(|&&hi| hi >= lo) {
+                let best = candidates
+                    .iter()
+                    .copied()
+                    .find(|&c| lo <= c && c <= hi)
+                    .unwrap();
+                assert_eq!(Quot::simplest_between(lo, hi), best);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflowing_result_panics() {
+        let _ = Quot::from(i64::MAX) + Quot::from(1);
     }
 }
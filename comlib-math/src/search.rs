@@ -0,0 +1,197 @@
+use crate::Integer;
+
+/// Finds the first integer in the half-open range `[lo, hi)` for which the predicate holds.
+///
+/// The predicate must be monotone, i.e. false for some prefix of the range and true for the rest. Returns `hi` if the
+/// predicate is false for the whole range. This is the integer equivalent of [`slice::partition_point`] with the
+/// predicate negated.
+///
+/// # Panics
+/// Panics if `lo > hi`.
+///
+/// # Time complexity
+/// Calls `pred` O(log(hi - lo)) times.
+///
+/// # Examples
+/// Finding the integer square root:
+/// ```
+/// # use comlib_math::binary_search_first;
+/// let n = 1_000_000_007u64;
+/// let root = binary_search_first(0, n + 1, |x| x * x > n) - 1;
+/// assert_eq!(root, 31622);
+/// ```
+pub fn binary_search_first<T, F>(lo: T, hi: T, mut pred: F) -> T
+where
+    T: Integer,
+    F: FnMut(T) -> bool,
+{
+    assert!(lo <= hi, "range must be valid");
+    let (mut lo, mut hi) = (lo, hi);
+    let two = T::from_int(2);
+    while lo < hi {
+        let mid = lo + (hi - lo) / two;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + T::one();
+        }
+    }
+    lo
+}
+
+/// Finds the boundary of a monotone predicate on the real interval `[lo, hi]`.
+///
+/// The predicate must be false below some boundary and true above it. Runs the given number of bisection steps and
+/// returns the smallest value found for which the predicate holds, or `hi` if no such value was found. 100 iterations
+/// is enough to reach the precision limit of [`f64`] on any reasonable interval.
+///
+/// # Time complexity
+/// Calls `pred` `iterations` times.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::binary_search_f64;
+/// let root = binary_search_f64(0.0, 2.0, |x| x * x >= 2.0, 100);
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+/// ```
+pub fn binary_search_f64<F>(lo: f64, hi: f64, mut pred: F, iterations: usize) -> f64
+where
+    F: FnMut(f64) -> bool,
+{
+    let (mut lo, mut hi) = (lo, hi);
+    for _ in 0..iterations {
+        let mid = lo + (hi - lo) / 2.0;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// Finds the argument maximizing a unimodal function on the inclusive integer range `[lo, hi]`.
+///
+/// The function must be strictly increasing up to its maximum and strictly decreasing after it. If there are several
+/// maximums next to each other, any one of them may be returned. Plateaus elsewhere may lead to wrong results.
+///
+/// # Panics
+/// Panics if `lo > hi`.
+///
+/// # Time complexity
+/// Calls `f` O(log(hi - lo)) times.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::ternary_search_max;
+/// assert_eq!(ternary_search_max(-100, 100, |x: i64| -(x - 17) * (x - 17)), 17);
+/// ```
+pub fn ternary_search_max<T, U, F>(lo: T, hi: T, mut f: F) -> T
+where
+    T: Integer,
+    U: PartialOrd,
+    F: FnMut(T) -> U,
+{
+    assert!(lo <= hi, "range must not be empty");
+    let (mut lo, mut hi) = (lo, hi);
+    let (one, two, three) = (T::one(), T::from_int(2), T::from_int(3));
+    while hi - lo > two {
+        let third = (hi - lo) / three;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1 + one;
+        } else {
+            hi = m2 - one;
+        }
+    }
+
+    let mut best = lo;
+    let mut best_value = f(lo);
+    let mut x = lo;
+    while x < hi {
+        x += one;
+        let value = f(x);
+        if value > best_value {
+            best = x;
+            best_value = value;
+        }
+    }
+    best
+}
+
+/// Finds the argument minimizing a unimodal function on the inclusive integer range `[lo, hi]`.
+///
+/// This is the counterpart of [`ternary_search_max`], and the same requirements apply with the directions reversed.
+///
+/// # Panics
+/// Panics if `lo > hi`.
+///
+/// # Time complexity
+/// Calls `f` O(log(hi - lo)) times.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::ternary_search_min;
+/// assert_eq!(ternary_search_min(0u32, 1000, |x| (x as i64 - 333).abs()), 333);
+/// ```
+pub fn ternary_search_min<T, U, F>(lo: T, hi: T, mut f: F) -> T
+where
+    T: Integer,
+    U: PartialOrd,
+    F: FnMut(T) -> U,
+{
+    ternary_search_max(lo, hi, |x| std::cmp::Reverse(f(x)))
+}
+
+/// Finds the argument maximizing a unimodal function on the real interval `[lo, hi]`.
+///
+/// Runs the given number of iterations, each of which shrinks the interval to two thirds of its length. 100
+/// iterations is enough to shrink any interval representable in [`f64`] to its precision limit.
+///
+/// # Time complexity
+/// Calls `f` `2 * iterations` times.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::ternary_search_max_f64;
+/// let x = ternary_search_max_f64(0.0, 3.0, f64::sin, 100);
+/// assert!((x - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+/// ```
+pub fn ternary_search_max_f64<U, F>(lo: f64, hi: f64, mut f: F, iterations: usize) -> f64
+where
+    U: PartialOrd,
+    F: FnMut(f64) -> U,
+{
+    let (mut lo, mut hi) = (lo, hi);
+    for _ in 0..iterations {
+        let m1 = lo + (hi - lo) / 3.0;
+        let m2 = hi - (hi - lo) / 3.0;
+        if f(m1) < f(m2) {
+            lo = m1;
+        } else {
+            hi = m2;
+        }
+    }
+    lo + (hi - lo) / 2.0
+}
+
+/// Finds the argument minimizing a unimodal function on the real interval `[lo, hi]`.
+///
+/// This is the counterpart of [`ternary_search_max_f64`].
+///
+/// # Time complexity
+/// Calls `f` `2 * iterations` times.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::ternary_search_min_f64;
+/// let x = ternary_search_min_f64(-10.0, 10.0, |x| (x - 1.5) * (x - 1.5), 100);
+/// assert!((x - 1.5).abs() < 1e-6);
+/// ```
+pub fn ternary_search_min_f64<U, F>(lo: f64, hi: f64, mut f: F, iterations: usize) -> f64
+where
+    U: PartialOrd,
+    F: FnMut(f64) -> U,
+{
+    ternary_search_max_f64(lo, hi, |x| std::cmp::Reverse(f(x)), iterations)
+}
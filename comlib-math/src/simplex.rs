@@ -0,0 +1,198 @@
+use std::mem;
+
+/// Tolerance used when comparing values in the tableau against zero.
+const EPS: f64 = 1e-9;
+
+/// Result of solving a linear program using [`simplex`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum LpResult {
+    /// The program has an optimal solution.
+    Optimal {
+        /// The optimal value of the objective function.
+        value: f64,
+        /// A point in which the optimal value is attained.
+        solution: Vec<f64>,
+    },
+    /// No point satisfies the constraints.
+    Infeasible,
+    /// The objective function can be made arbitrarily large.
+    Unbounded,
+}
+
+/// Maximizes `c·x` subject to `Ax ≤ b` and `x ≥ 0`.
+///
+/// Uses the two-phase [simplex algorithm] with Bland's rule to avoid cycling. All computations are done using
+/// [`f64`], and values within 10⁻⁹ of zero are treated as zero.
+///
+/// # Panics
+/// Panics if the dimensions of `a`, `b`, and `c` don't match.
+///
+/// # Time complexity
+/// Exponential in the worst case, but usually fast in practice. Each pivot takes O(nm) time, where `n` is the number
+/// of variables and `m` is the number of constraints.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::{simplex, LpResult};
+/// // Maximize 3x + 2y subject to x + y ≤ 4, x + 3y ≤ 6, x ≤ 3
+/// let a = vec![vec![1.0, 1.0], vec![1.0, 3.0], vec![1.0, 0.0]];
+/// let b = vec![4.0, 6.0, 3.0];
+/// let c = vec![3.0, 2.0];
+/// match simplex(&a, &b, &c) {
+///     LpResult::Optimal { value, solution } => {
+///         assert!((value - 11.0).abs() < 1e-9);
+///         assert!((solution[0] - 3.0).abs() < 1e-9);
+///         assert!((solution[1] - 1.0).abs() < 1e-9);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+///
+/// [simplex algorithm]: https://en.wikipedia.org/wiki/Simplex_algorithm
+pub fn simplex(a: &[Vec<f64>], b: &[f64], c: &[f64]) -> LpResult {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "A and b must have the same number of rows"
+    );
+    assert!(
+        a.iter().all(|row| row.len() == c.len()),
+        "A must have as many columns as there are variables"
+    );
+    Tableau::new(a, b, c).solve()
+}
+
+/// Simplex tableau.
+///
+/// Rows `0..m` correspond to the constraints, row `m` to the objective function, and row `m + 1` to the auxiliary
+/// objective used in the first phase. Column `n` corresponds to the auxiliary variable and column `n + 1` to the
+/// right-hand side.
+struct Tableau {
+    m: usize,
+    n: usize,
+    d: Vec<Vec<f64>>,
+    /// Basic variables. Variables `0..n` are the original ones, `n..n + m` are the slack variables, and `-1` is the
+    /// auxiliary variable.
+    basic: Vec<isize>,
+    /// Non-basic variables.
+    non_basic: Vec<isize>,
+}
+
+impl Tableau {
+    fn new(a: &[Vec<f64>], b: &[f64], c: &[f64]) -> Self {
+        let m = b.len();
+        let n = c.len();
+        let mut d = vec![vec![0.0; n + 2]; m + 2];
+        for (i, (row, &b)) in a.iter().zip(b).enumerate() {
+            d[i][..n].copy_from_slice(row);
+            d[i][n] = -1.0;
+            d[i][n + 1] = b;
+        }
+        for (j, &c) in c.iter().enumerate() {
+            d[m][j] = -c;
+        }
+        d[m + 1][n] = 1.0;
+
+        let basic = (n..n + m).map(|i| i as isize).collect();
+        let non_basic = (0..n).map(|j| j as isize).chain(Some(-1)).collect();
+        Self {
+            m,
+            n,
+            d,
+            basic,
+            non_basic,
+        }
+    }
+
+    fn pivot(&mut self, r: usize, s: usize) {
+        let pivot_row = mem::take(&mut self.d[r]);
+        let inv = 1.0 / pivot_row[s];
+        for (i, row) in self.d.iter_mut().enumerate() {
+            if i != r && row[s].abs() > EPS {
+                let factor = row[s] * inv;
+                for (value, &pivot_value) in row.iter_mut().zip(&pivot_row) {
+                    *value -= pivot_value * factor;
+                }
+                row[s] = pivot_row[s] * factor;
+            }
+        }
+        self.d[r] = pivot_row;
+
+        for (j, value) in self.d[r].iter_mut().enumerate() {
+            if j != s {
+                *value *= inv;
+            }
+        }
+        for (i, row) in self.d.iter_mut().enumerate() {
+            if i != r {
+                row[s] *= -inv;
+            }
+        }
+        self.d[r][s] = inv;
+        mem::swap(&mut self.basic[r], &mut self.non_basic[s]);
+    }
+
+    /// Finds the entering column with the smallest value in the given row, breaking ties by the variable index.
+    fn entering_column(&self, row: usize, skip: isize) -> Option<usize> {
+        (0..=self.n)
+            .filter(|&j| self.non_basic[j] != skip)
+            .min_by(|&i, &j| {
+                let (x, y) = (self.d[row][i], self.d[row][j]);
+                x.total_cmp(&y)
+                    .then(self.non_basic[i].cmp(&self.non_basic[j]))
+            })
+    }
+
+    /// Runs the simplex iterations on the given phase. Returns `false` if the objective is unbounded.
+    fn simplex(&mut self, phase: usize) -> bool {
+        let objective = self.m + phase - 1;
+        loop {
+            let s = self.entering_column(objective, -(phase as isize)).unwrap();
+            if self.d[objective][s] >= -EPS {
+                return true;
+            }
+
+            let (n, d, basic) = (self.n, &self.d, &self.basic);
+            let r = (0..self.m).filter(|&i| d[i][s] > EPS).min_by(|&i, &j| {
+                let (x, y) = (d[i][n + 1] / d[i][s], d[j][n + 1] / d[j][s]);
+                x.total_cmp(&y).then(basic[i].cmp(&basic[j]))
+            });
+            match r {
+                Some(r) => self.pivot(r, s),
+                None => return false,
+            }
+        }
+    }
+
+    fn solve(mut self) -> LpResult {
+        let (m, n) = (self.m, self.n);
+        let r = (0..m).min_by(|&i, &j| self.d[i][n + 1].total_cmp(&self.d[j][n + 1]));
+        if let Some(r) = r.filter(|&r| self.d[r][n + 1] < -EPS) {
+            // The origin is not feasible, so find a feasible starting point first
+            self.pivot(r, n);
+            if !self.simplex(2) || self.d[m + 1][n + 1] < -EPS {
+                return LpResult::Infeasible;
+            }
+            for i in 0..m {
+                if self.basic[i] == -1 {
+                    let s = self.entering_column(i, isize::MIN).unwrap();
+                    self.pivot(i, s);
+                }
+            }
+        }
+
+        if !self.simplex(1) {
+            return LpResult::Unbounded;
+        }
+        let mut solution = vec![0.0; n];
+        for (i, &variable) in self.basic.iter().enumerate() {
+            if (0..n as isize).contains(&variable) {
+                solution[variable as usize] = self.d[i][n + 1];
+            }
+        }
+        LpResult::Optimal {
+            value: self.d[m][n + 1],
+            solution,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Module containing functions and structs for iterating subsets.
 //!
-//! Most users only need [`subsets`] method. See its documentation for examples.
+//! Most users only need [`subsets`] and [`combinations`] methods. See their documentation for examples.
 
 use std::ops::RangeInclusive;
 
@@ -45,10 +45,15 @@ use std::ops::RangeInclusive;
 pub fn subsets(n: usize) -> Subsets {
     debug_assert!(n <= 64, "Subsets supports at most 64 element sets");
     Subsets {
-        mask_iter: 0..=(u64::MAX >> (64 - n)),
+        mask_iter: 0..=low_bits(n),
     }
 }
 
+/// Returns a mask with the lowest `n` bits set.
+fn low_bits(n: usize) -> u64 {
+    u64::MAX.checked_shr(64 - n as u32).unwrap_or(0)
+}
+
 /// Iterator over subsets.
 ///
 /// Use [`subsets`] to construct. See its documentation for more usage examples.
@@ -70,6 +75,231 @@ impl Iterator for Subsets {
     }
 }
 
+/// Constructs an iterator over subsets of exactly `k` elements of a set of given size.
+///
+/// The subsets are yielded in increasing order of their masks. For example the 2-subsets of `[1, 2, 3]` are `[1, 2]`,
+/// `[1, 3]`, and `[2, 3]`.
+///
+/// The iterator uses [Gosper's hack] to jump directly from one subset to the next, so it takes time proportional to
+/// the number of `k`-subsets instead of all subsets.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::combinations;
+/// let items = ['a', 'b', 'c', 'd'];
+/// let pairs: Vec<String> = combinations(items.len(), 2)
+///     .map(|subset| subset.select(&items).collect())
+///     .collect();
+/// assert_eq!(pairs, ["ab", "ac", "bc", "ad", "bd", "cd"]);
+/// ```
+///
+/// [Gosper's hack]: https://en.wikipedia.org/wiki/Combinatorial_number_system#Applications
+pub fn combinations(n: usize, k: usize) -> Combinations {
+    debug_assert!(n <= 64, "Combinations supports at most 64 element sets");
+    Combinations {
+        mask: if k <= n { Some(low_bits(k)) } else { None },
+        n,
+    }
+}
+
+/// Iterator over subsets of fixed size.
+///
+/// Use [`combinations`] to construct. See its documentation for more usage examples.
+#[derive(Debug, Clone)]
+pub struct Combinations {
+    /// Mask of the next subset, if there is one.
+    mask: Option<u64>,
+    /// Size of the whole set.
+    n: usize,
+}
+
+impl Iterator for Combinations {
+    type Item = Subset;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = self.mask?;
+        self.mask = next_same_popcount(mask).filter(|&next| self.n == 64 || next >> self.n == 0);
+        Some(Subset { mask })
+    }
+}
+
+/// Computes the next larger mask with the same number of set bits.
+///
+/// Returns `None` if there is no such 64-bit mask. This includes the empty mask, which is the only mask without set
+/// bits.
+///
+/// The computation is known as [Gosper's hack]. It is used by [`combinations`] to iterate subsets of fixed size.
+///
+/// # Examples
+/// ```
+/// # use comlib_math::next_same_popcount;
+/// assert_eq!(next_same_popcount(0b0011), Some(0b0101));
+/// assert_eq!(next_same_popcount(0b0110), Some(0b1001));
+/// assert_eq!(next_same_popcount(0), None);
+/// assert_eq!(next_same_popcount(u64::MAX << 1), None);
+/// ```
+///
+/// [Gosper's hack]: https://en.wikipedia.org/wiki/Combinatorial_number_system#Applications
+pub fn next_same_popcount(mask: u64) -> Option<u64> {
+    if mask == 0 {
+        return None;
+    }
+    // Move the lowest block of ones one step up, and pack the rest of the block to the bottom
+    let lowest = mask & mask.wrapping_neg();
+    let ripple = mask.checked_add(lowest)?;
+    Some((((ripple ^ mask) >> 2) / lowest) | ripple)
+}
+
+/// Computes the `i`th [Gray code].
+///
+/// Consecutive Gray codes differ by exactly one bit: going from `gray_code(i - 1)` to `gray_code(i)` flips the bit
+/// `i.trailing_zeros()`.
+///
+/// [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_code(i: u64) -> u64 {
+    i ^ (i >> 1)
+}
+
+/// Computes the index of the given [Gray code], i.e. the inverse of [`gray_code`].
+///
+/// [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_code_index(code: u64) -> u64 {
+    let mut index = code;
+    let mut shift = 1;
+    while shift < 64 {
+        index ^= index >> shift;
+        shift *= 2;
+    }
+    index
+}
+
+/// Constructs an iterator over subsets of given size in [Gray code] order.
+///
+/// Each subset differs from the previous one by exactly one element, which allows updating a value computed for a
+/// subset in O(1) time when moving to the next one. The element toggled at step `i` is `i.trailing_zeros()`, which is
+/// also returned by [`GrayCodes::next_with_change`].
+///
+/// # Examples
+/// ```
+/// # use comlib_math::gray_codes;
+/// // Compute all subset sums, each with one addition or subtraction
+/// let items = [3, 5, 7];
+/// let mut codes = gray_codes(items.len());
+/// let mut sum = 0;
+/// let mut sums = vec![];
+/// while let Some((subset, changed)) = codes.next_with_change() {
+///     if let Some(i) = changed {
+///         if subset.contains(i) {
+///             sum += items[i];
+///         } else {
+///             sum -= items[i];
+///         }
+///     }
+///     assert_eq!(sum, subset.select(&items).sum::<i32>());
+///     sums.push(sum);
+/// }
+/// assert_eq!(sums, [0, 3, 8, 5, 12, 15, 10, 7]);
+/// ```
+///
+/// [Gray code]: https://en.wikipedia.org/wiki/Gray_code
+pub fn gray_codes(n: usize) -> GrayCodes {
+    debug_assert!(n <= 64, "GrayCodes supports at most 64 element sets");
+    GrayCodes {
+        index_iter: 0..=low_bits(n),
+    }
+}
+
+/// Iterator over subsets in Gray code order.
+///
+/// Use [`gray_codes`] to construct. See its documentation for more usage examples.
+#[derive(Debug, Clone)]
+pub struct GrayCodes {
+    index_iter: RangeInclusive<u64>,
+}
+
+impl GrayCodes {
+    /// Returns the next subset together with the element which was toggled to reach it.
+    ///
+    /// The element is `None` for the first subset, which is always the empty set.
+    pub fn next_with_change(&mut self) -> Option<(Subset, Option<usize>)> {
+        let index = self.index_iter.next()?;
+        let changed = if index == 0 {
+            None
+        } else {
+            Some(index.trailing_zeros() as usize)
+        };
+        Some((
+            Subset {
+                mask: gray_code(index),
+            },
+            changed,
+        ))
+    }
+}
+
+impl Iterator for GrayCodes {
+    type Item = Subset;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.index_iter.next().map(|index| Subset {
+            mask: gray_code(index),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Forward size hint to the inner iterator
+        self.index_iter.size_hint()
+    }
+}
+
+/// Constructs an iterator over all submasks of the given mask.
+///
+/// The submasks are yielded in decreasing order, starting from the mask itself and ending with the empty mask. Iterating
+/// over the submasks of every mask of an `n`-bit set takes `O(3^n)` time in total, which is the basis for many subset
+/// dynamic programming algorithms.
+///
+/// See also [`Subset::submasks`].
+///
+/// # Examples
+/// ```
+/// # use comlib_math::submasks;
+/// let masks: Vec<u64> = submasks(0b1010).map(|subset| subset.mask).collect();
+/// assert_eq!(masks, [0b1010, 0b1000, 0b0010, 0b0000]);
+/// ```
+pub fn submasks(mask: u64) -> Submasks {
+    Submasks {
+        mask,
+        next: Some(mask),
+    }
+}
+
+/// Iterator over submasks of a mask.
+///
+/// Use [`submasks`] or [`Subset::submasks`] to construct.
+#[derive(Debug, Clone)]
+pub struct Submasks {
+    /// The mask whose submasks are iterated.
+    mask: u64,
+    /// The next submask to yield, if there is one.
+    next: Option<u64>,
+}
+
+impl Iterator for Submasks {
+    type Item = Subset;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = if current == 0 {
+            None
+        } else {
+            // Removing one from the submask clears its lowest bit and sets all bits below it. Masking then keeps only
+            // the bits belonging to the mask, giving the next smaller submask.
+            Some((current - 1) & self.mask)
+        };
+        Some(Subset { mask: current })
+    }
+}
+
 /// Subset of some elements.
 ///
 /// Use [`subsets`] to construct an iterator to get [`Subset`]s.
@@ -106,6 +336,13 @@ impl Subset {
     pub fn len(self) -> usize {
         self.mask.count_ones() as usize
     }
+
+    /// Iterates over all subsets of this subset.
+    ///
+    /// See [`submasks`] for details.
+    pub fn submasks(self) -> Submasks {
+        submasks(self.mask)
+    }
 }
 
 /// An iterator selecting elements based on a subset.
@@ -122,7 +359,7 @@ impl<I: Iterator> Iterator for SubsetIter<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(item) = self.iter.next() {
+        for item in self.iter.by_ref() {
             let selects = self.mask & 1 != 0;
             self.mask >>= 1;
             if selects {
@@ -0,0 +1,33 @@
+use comlib_math::{AlmostEq, Eps};
+use std::cmp::Ordering;
+
+#[test]
+fn test_eps() {
+    let eps = Eps::absolute(1e-3);
+    assert!(eps.approx_eq(1.0, 1.0005));
+    assert!(eps.approx_eq(-1.0, -1.001));
+    assert!(!eps.approx_eq(1.0, 1.002));
+    assert_eq!(eps.approx_cmp(1.0, 1.002), Ordering::Less);
+    assert_eq!(eps.approx_cmp(1.002, 1.0), Ordering::Greater);
+    assert_eq!(eps.approx_cmp(1.0005, 1.0), Ordering::Equal);
+    assert!(eps.is_zero(-0.0009));
+    assert!(!eps.is_zero(0.0011));
+
+    let relative = Eps::new(0.0, 1e-6);
+    assert!(relative.approx_eq(1e12, 1e12 + 1e5));
+    assert!(!relative.approx_eq(1e-12, 2e-12));
+    assert!(!relative.is_zero(1e-300));
+}
+
+#[test]
+fn test_almost_eq() {
+    assert!(0.1f64.approx_eq(0.3 - 0.2));
+    assert!(!0.1f64.approx_eq(0.1 + 1e-6));
+    assert!(0.1f32.approx_eq(0.3 - 0.2));
+    assert_eq!((0.1f64 + 0.2).approx_cmp(0.3), Ordering::Equal);
+    assert_eq!(1.0f64.approx_cmp(2.0), Ordering::Less);
+
+    assert!(5i64.approx_eq(5));
+    assert!(!5u8.approx_eq(6));
+    assert_eq!(7usize.approx_cmp(6), Ordering::Greater);
+}
@@ -0,0 +1,104 @@
+use comlib_math::{
+    and_convolution, or_convolution, subset_mobius, subset_zeta, superset_mobius, superset_zeta,
+    walsh_hadamard, xor_convolution, Mod1e9p7, ModInt,
+};
+
+fn sequence(len: usize, seed: i64) -> Vec<i64> {
+    (0..len as i64)
+        .map(|i| (i * 37 + seed * 11) % 23 - 11)
+        .collect()
+}
+
+fn brute_force_convolution(a: &[i64], b: &[i64], op: impl Fn(usize, usize) -> usize) -> Vec<i64> {
+    let mut result = vec![0; a.len()];
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            result[op(i, j)] += a[i] * b[j];
+        }
+    }
+    result
+}
+
+#[test]
+fn test_zeta_transforms() {
+    for bits in 0..6 {
+        let original = sequence(1 << bits, bits as i64);
+
+        let mut values = original.clone();
+        subset_zeta(&mut values);
+        for mask in 0..values.len() {
+            let expected: i64 = (0..values.len())
+                .filter(|&sub| sub & mask == sub)
+                .map(|sub| original[sub])
+                .sum();
+            assert_eq!(values[mask], expected);
+        }
+        subset_mobius(&mut values);
+        assert_eq!(values, original);
+
+        let mut values = original.clone();
+        superset_zeta(&mut values);
+        for mask in 0..values.len() {
+            let expected: i64 = (0..values.len())
+                .filter(|&sup| sup & mask == mask)
+                .map(|sup| original[sup])
+                .sum();
+            assert_eq!(values[mask], expected);
+        }
+        superset_mobius(&mut values);
+        assert_eq!(values, original);
+
+        let mut values = original.clone();
+        walsh_hadamard(&mut values);
+        walsh_hadamard(&mut values);
+        let scaled: Vec<_> = original.iter().map(|x| x << bits).collect();
+        assert_eq!(values, scaled);
+    }
+}
+
+#[test]
+fn test_convolutions() {
+    for bits in 0..6 {
+        let a = sequence(1 << bits, 1);
+        let b = sequence(1 << bits, 2);
+        assert_eq!(
+            or_convolution(&a, &b),
+            brute_force_convolution(&a, &b, |i, j| i | j)
+        );
+        assert_eq!(
+            and_convolution(&a, &b),
+            brute_force_convolution(&a, &b, |i, j| i & j)
+        );
+        assert_eq!(
+            xor_convolution(&a, &b),
+            brute_force_convolution(&a, &b, |i, j| i ^ j)
+        );
+    }
+}
+
+#[test]
+fn test_convolutions_modint() {
+    let to_modint = |values: &[i64]| -> Vec<ModInt<Mod1e9p7>> {
+        values
+            .iter()
+            .map(|&x| ModInt::from((x + 1_000_000_007) as u64))
+            .collect()
+    };
+    let a = sequence(16, 3);
+    let b = sequence(16, 4);
+    let (ma, mb) = (to_modint(&a), to_modint(&b));
+    assert_eq!(
+        xor_convolution(&ma, &mb),
+        to_modint(&brute_force_convolution(&a, &b, |i, j| i ^ j))
+    );
+    assert_eq!(
+        or_convolution(&ma, &mb),
+        to_modint(&brute_force_convolution(&a, &b, |i, j| i | j))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_non_power_of_two_length_panics() {
+    subset_zeta(&mut [1, 2, 3]);
+}
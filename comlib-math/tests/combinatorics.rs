@@ -0,0 +1,60 @@
+use comlib_math::{
+    bell_numbers, partition_numbers, stirling1_table, stirling2_table, Mod1e9p7, ModInt,
+    RuntimePrimeModulus,
+};
+
+fn values(row: &[ModInt<Mod1e9p7>]) -> Vec<u64> {
+    row.iter().map(|v| v.into_inner()).collect()
+}
+
+#[test]
+fn test_stirling_numbers() {
+    let first = stirling1_table(6, Mod1e9p7);
+    assert_eq!(values(&first[0]), [1]);
+    assert_eq!(values(&first[1]), [0, 1]);
+    assert_eq!(values(&first[5]), [0, 24, 50, 35, 10, 1]);
+    assert_eq!(values(&first[6]), [0, 120, 274, 225, 85, 15, 1]);
+
+    let second = stirling2_table(6, Mod1e9p7);
+    assert_eq!(values(&second[0]), [1]);
+    assert_eq!(values(&second[1]), [0, 1]);
+    assert_eq!(values(&second[5]), [0, 1, 15, 25, 10, 1]);
+    assert_eq!(values(&second[6]), [0, 1, 31, 90, 65, 15, 1]);
+
+    // Row sums of the first kind give factorials and of the second kind Bell numbers
+    let bell = bell_numbers(6, Mod1e9p7);
+    let mut factorial = 1;
+    for i in 0..=6 {
+        if i > 0 {
+            factorial *= i as u64;
+        }
+        let sum: u64 = values(&first[i]).iter().sum();
+        assert_eq!(sum, factorial);
+        let sum: u64 = values(&second[i]).iter().sum();
+        assert_eq!(sum, bell[i].into_inner());
+    }
+}
+
+#[test]
+fn test_bell_numbers() {
+    assert_eq!(values(&bell_numbers(0, Mod1e9p7)), [1]);
+    assert_eq!(
+        values(&bell_numbers(10, Mod1e9p7)),
+        [1, 1, 2, 5, 15, 52, 203, 877, 4140, 21147, 115975]
+    );
+
+    // Values are reduced in the given modulus
+    let bell = bell_numbers(10, RuntimePrimeModulus::from(1009usize));
+    assert_eq!(bell[10].into_inner(), 115975 % 1009);
+}
+
+#[test]
+fn test_partition_numbers() {
+    let p = values(&partition_numbers(30, Mod1e9p7));
+    assert_eq!(p[..11], [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42]);
+    assert_eq!(p[30], 5604);
+    assert_eq!(
+        partition_numbers(100, Mod1e9p7)[100].into_inner(),
+        190569292
+    );
+}
@@ -0,0 +1,113 @@
+use comlib_math::{Matrix, Mod1e9p7, ModInt};
+
+fn modint(value: u64) -> ModInt<Mod1e9p7> {
+    ModInt::from(value)
+}
+
+#[test]
+fn test_matrix_construction() {
+    let m = Matrix::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(m.rows(), 2);
+    assert_eq!(m.cols(), 3);
+    assert_eq!(m[(1, 0)], 4);
+    assert_eq!(m.row(0), [1, 2, 3]);
+    assert_eq!(m, Matrix::from_fn(2, 3, |i, j| 3 * i + j + 1));
+    assert_eq!(m.into_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+}
+
+#[test]
+fn test_matrix_multiplication() {
+    let a = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    let b = Matrix::from_rows(vec![vec![1, 0, -1], vec![2, 1, 0]]);
+    assert_eq!(
+        &a * &b,
+        Matrix::from_rows(vec![vec![5, 2, -1], vec![11, 4, -3], vec![17, 6, -5]])
+    );
+}
+
+#[test]
+fn test_integer_determinant() {
+    assert_eq!(Matrix::<i64>::from_rows(vec![]).determinant(), 1);
+    assert_eq!(Matrix::from_rows(vec![vec![7i64]]).determinant(), 7);
+    assert_eq!(
+        Matrix::from_rows(vec![vec![0i64, 1], vec![1, 0]]).determinant(),
+        -1
+    );
+    assert_eq!(
+        Matrix::from_rows(vec![vec![2i64, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]).determinant(),
+        49
+    );
+    assert_eq!(
+        Matrix::from_rows(vec![vec![1i64, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).determinant(),
+        0
+    );
+    // Values whose products overflow i64
+    let big = 3_000_000_000i64;
+    assert_eq!(
+        Matrix::from_rows(vec![vec![big, big - 1], vec![big + 1, big]]).determinant(),
+        1
+    );
+}
+
+#[test]
+#[should_panic(expected = "intermediate value to fit in i128")]
+fn test_integer_determinant_overflow() {
+    // The determinant is 0, but the intermediate minors are far too large
+    let big = 10_000_000_000_000i64;
+    Matrix::from_rows(vec![
+        vec![big, 0, big],
+        vec![0, big, big],
+        vec![big, big, 2 * big],
+    ])
+    .determinant();
+}
+
+#[test]
+fn test_float_determinant_and_inverse() {
+    let m = Matrix::from_rows(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+    assert!((m.determinant() - 10.0).abs() < 1e-9);
+
+    let inverse = m.inverse().unwrap();
+    let expected = [[0.6, -0.7], [-0.2, 0.4]];
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((inverse[(i, j)] - expected[i][j]).abs() < 1e-9);
+        }
+    }
+
+    let product = &m * &inverse;
+    let identity = Matrix::<f64>::identity(2);
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((product[(i, j)] - identity[(i, j)]).abs() < 1e-9);
+        }
+    }
+
+    let singular = Matrix::from_rows(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    assert_eq!(singular.determinant(), 0.0);
+    assert!(singular.inverse().is_none());
+}
+
+#[test]
+fn test_modular_determinant_and_inverse() {
+    let m = Matrix::from_rows(vec![
+        vec![modint(2), modint(0) - modint(3), modint(1)],
+        vec![modint(2), modint(0), modint(0) - modint(1)],
+        vec![modint(1), modint(4), modint(5)],
+    ]);
+    assert_eq!(m.determinant(), modint(49));
+
+    let inverse = m.inverse().unwrap();
+    assert_eq!(
+        &m * &inverse,
+        Matrix::<ModInt<Mod1e9p7>>::identity(3, Mod1e9p7)
+    );
+    assert_eq!(
+        &inverse * &m,
+        Matrix::<ModInt<Mod1e9p7>>::identity(3, Mod1e9p7)
+    );
+
+    let singular = Matrix::from_rows(vec![vec![modint(1), modint(2)], vec![modint(2), modint(4)]]);
+    assert_eq!(singular.determinant(), modint(0));
+    assert!(singular.inverse().is_none());
+}
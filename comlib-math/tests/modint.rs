@@ -19,3 +19,35 @@ fn test_modular_power() {
         ModInt::<Mod1e9p7>::from(1u64)
     );
 }
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Mod1e9p7U32;
+impl Modulus for Mod1e9p7U32 {
+    type Base = u32;
+
+    fn modulus(self) -> u32 {
+        1_000_000_007
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "(modulus - 1)^2")]
+fn test_too_narrow_base_is_detected() {
+    let _ = ModInt::<Mod1e9p7U32>::from(2u8);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "(modulus - 1)^2")]
+fn test_too_narrow_mod_pow_is_detected() {
+    mod_pow(2u128, 10, u128::MAX / 3);
+}
+
+#[test]
+fn test_wide_enough_base_is_accepted() {
+    let p = RuntimePrimeModulus::from(251u16);
+    assert_eq!(*ModInt::from((250, p)).pow(2), 1);
+    assert_eq!(mod_pow(2u64, 10, 1_000_000_007), 1024);
+    assert_eq!(mod_pow(2u32, 31, 1_000_000_007), 147_483_634);
+}
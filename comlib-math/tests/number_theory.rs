@@ -1,4 +1,9 @@
-use comlib_math::{factorize, gcd, is_prime, PrimeSieve};
+use comlib_math::{
+    discrete_log, divisor_count, divisor_sum, divisors, factorize, factorize_u128_with_rng,
+    factorize_with_rng, floor_div_blocks, floor_sum, gcd, is_prime, is_prime_u128, mod_nth_root,
+    mod_pow, mul_mod_u64, primitive_root, BitPrimeSieve, PrimeSieve,
+};
+use rand::{rngs::StdRng, SeedableRng};
 
 #[test]
 fn test_gcd() {
@@ -15,6 +20,80 @@ fn test_prime_sieve_construction() {
     assert_eq!(sieve.into_inner(), [0, 0, 2, 3, 2, 5, 3, 7, 2, 3, 5]);
 }
 
+#[test]
+fn test_prime_sieve_primes() {
+    let sieve = PrimeSieve::new(30);
+    assert_eq!(
+        sieve.primes().collect::<Vec<_>>(),
+        [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+    );
+}
+
+#[test]
+fn test_prime_sieve_tables() {
+    let n = 2000;
+    let sieve = PrimeSieve::new(n);
+    let smallest_factors = sieve.smallest_factor_table();
+    let mobius = sieve.mobius_table();
+    let phi = sieve.phi_table();
+    let divisor_counts = sieve.divisor_count_table();
+    assert_eq!(&smallest_factors[..2], [0, 0]);
+    assert_eq!((mobius[0], phi[0], divisor_counts[0]), (0, 0, 0));
+
+    for i in 1..=n {
+        let factorization = sieve.factorize(i);
+        let smallest = factorization.first().map_or(0, |&(p, _)| p);
+        assert_eq!(smallest_factors[i as usize], smallest);
+
+        let squarefree = factorization.iter().all(|&(_, e)| e == 1);
+        let expected_mobius = match (squarefree, factorization.len() % 2) {
+            (false, _) => 0,
+            (true, 0) => 1,
+            (true, _) => -1,
+        };
+        assert_eq!(mobius[i as usize], expected_mobius);
+
+        let expected_phi = (1..=i).filter(|&j| gcd(i, j) == 1).count() as u64;
+        assert_eq!(phi[i as usize], expected_phi);
+
+        assert_eq!(divisor_counts[i as usize], divisor_count(i));
+    }
+
+    let tables = sieve.tables();
+    assert_eq!(tables.smallest_factor, smallest_factors);
+    assert_eq!(tables.mobius, mobius);
+    assert_eq!(tables.phi, phi);
+    assert_eq!(tables.divisor_count, divisor_counts);
+}
+
+#[test]
+fn test_bit_prime_sieve_against_prime_sieve() {
+    for n in 0..200 {
+        let sieve = PrimeSieve::new(n);
+        let bit_sieve = BitPrimeSieve::new(n);
+        for i in 0..=n {
+            assert_eq!(bit_sieve.is_prime(i), sieve.is_prime(i), "{} {}", n, i);
+        }
+        let primes: Vec<_> = sieve.primes().collect();
+        assert_eq!(bit_sieve.primes().collect::<Vec<_>>(), primes);
+        assert_eq!(bit_sieve.count_primes(), primes.len());
+        for (k, &prime) in primes.iter().enumerate() {
+            assert_eq!(bit_sieve.nth_prime(k), Some(prime));
+        }
+        assert_eq!(bit_sieve.nth_prime(primes.len()), None);
+    }
+}
+
+#[test]
+fn test_bit_prime_sieve_large() {
+    let sieve = BitPrimeSieve::new(10_000_000);
+    assert_eq!(sieve.count_primes(), 664_579);
+    assert_eq!(sieve.nth_prime(0), Some(2));
+    assert_eq!(sieve.nth_prime(99_999), Some(1_299_709));
+    assert_eq!(sieve.primes().last(), Some(9_999_991));
+    assert!(sieve.primes().take(1000).all(is_prime));
+}
+
 #[test]
 fn test_prime_sieve_is_prime() {
     let sieve = PrimeSieve::new(10_000);
@@ -93,6 +172,46 @@ fn test_factorize() {
     assert_eq!(factorize(450775), [(5, 2), (13, 1), (19, 1), (73, 1)]);
 }
 
+#[test]
+fn test_factorize_large() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(
+        factorize_with_rng(998_244_359_987_710_471, &mut rng),
+        [(998_244_353, 1), (1_000_000_007, 1)]
+    );
+    assert_eq!(
+        factorize_with_rng(4_611_686_014_132_420_609, &mut rng),
+        [(2_147_483_647, 2)]
+    );
+    assert_eq!(
+        factorize_with_rng(u64::MAX, &mut rng),
+        [
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (6_700_417, 1)
+        ]
+    );
+    assert_eq!(
+        factorize_with_rng(i64::MAX as u64, &mut rng),
+        [
+            (7, 2),
+            (73, 1),
+            (127, 1),
+            (337, 1),
+            (92737, 1),
+            (649_657, 1)
+        ]
+    );
+    assert_eq!(
+        factorize_with_rng(999_999_999_999_999_989, &mut rng),
+        [(999_999_999_999_999_989, 1)]
+    );
+}
+
 #[test]
 fn test_factorize_against_sieve() {
     let n = if cfg!(debug_assertions) {
@@ -106,3 +225,197 @@ fn test_factorize_against_sieve() {
         assert_eq!(factorize(i), sieve.factorize(i), "Failed {}", i);
     }
 }
+
+#[test]
+fn test_is_prime_large() {
+    // Strong pseudoprimes to several small bases
+    assert!(!is_prime(3_215_031_751));
+    assert!(!is_prime(4_759_123_141));
+    assert!(!is_prime(341_550_071_728_321));
+    assert!(!is_prime(3_825_123_056_546_413_051));
+    // Square of a prime
+    assert!(!is_prime(4_611_686_014_132_420_609));
+
+    assert!(is_prime(4_294_967_291));
+    assert!(is_prime(1_000_000_000_000_000_003));
+    assert!(is_prime(2_305_843_009_213_693_951));
+    assert!(is_prime(18_446_744_073_709_551_557));
+    assert!(!is_prime(18_446_744_073_709_551_556));
+}
+
+#[test]
+fn test_divisors() {
+    assert_eq!(divisors(0), []);
+    assert_eq!(divisors(1), [1]);
+    assert_eq!(divisors(7), [1, 7]);
+    assert_eq!(divisors(36), [1, 2, 3, 4, 6, 9, 12, 18, 36]);
+    assert_eq!(divisors(1_000_000_007), [1, 1_000_000_007]);
+
+    assert_eq!(divisor_count(1), 1);
+    assert_eq!(divisor_count(36), 9);
+    assert_eq!(divisor_count(720_720), 240);
+
+    assert_eq!(divisor_sum(1), 1);
+    assert_eq!(divisor_sum(6), 12);
+    assert_eq!(divisor_sum(36), 91);
+}
+
+#[test]
+fn test_divisors_against_sieve() {
+    let n = 10_000;
+    let sieve = PrimeSieve::new(n);
+    for i in 1..=n {
+        let expected: Vec<_> = (1..=i).filter(|d| i % d == 0).collect();
+        assert_eq!(divisors(i), expected, "Failed {}", i);
+        assert_eq!(sieve.divisors(i), expected, "Failed {}", i);
+        assert_eq!(divisor_count(i), expected.len() as u64, "Failed {}", i);
+        assert_eq!(divisor_sum(i), expected.iter().sum(), "Failed {}", i);
+    }
+}
+
+#[test]
+fn test_floor_sum() {
+    for n in 0..10i64 {
+        for m in 1..8 {
+            for a in -10..10 {
+                for b in -10..10 {
+                    let expected: i64 = (0..n).map(|i| (a * i + b).div_euclid(m)).sum();
+                    assert_eq!(floor_sum(n, m, a, b), expected, "{} {} {} {}", n, m, a, b);
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        floor_sum(1_000_000_000, 1_000_000_000, 999_999_999, 999_999_999),
+        499_999_999_500_000_000
+    );
+}
+
+#[test]
+fn test_is_prime_u128() {
+    for n in 0..1000u128 {
+        assert_eq!(is_prime_u128(n), is_prime(n as u64));
+    }
+    // Primes around 2^64
+    assert!(is_prime_u128(18_446_744_073_709_551_557));
+    assert!(is_prime_u128(18_446_744_073_709_551_629));
+    // 2^64 + 1
+    assert!(!is_prime_u128(18_446_744_073_709_551_617));
+    // 2^89 - 1 and 2^127 - 1 are Mersenne primes
+    assert!(is_prime_u128((1 << 89) - 1));
+    assert!(is_prime_u128((1 << 127) - 1));
+    assert!(!is_prime_u128((1 << 67) - 1));
+    assert!(!is_prime_u128(u128::MAX));
+    // Products of two primes near 2^64
+    let p = 18_446_744_073_709_551_557u128;
+    assert!(!is_prime_u128(p * 3_037_000_493));
+    assert!(!is_prime_u128(
+        4_294_967_291 * 4_294_967_279 * 4_294_967_231
+    ));
+}
+
+#[test]
+fn test_factorize_u128() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(factorize_u128_with_rng(0, &mut rng), []);
+    assert_eq!(factorize_u128_with_rng(1, &mut rng), []);
+    assert_eq!(factorize_u128_with_rng(1 << 100, &mut rng), [(2, 100)]);
+
+    let p = 18_446_744_073_709_551_557u128;
+    assert_eq!(factorize_u128_with_rng(p * 3, &mut rng), [(3, 1), (p, 1)]);
+    // 2^67 - 1 = 193707721 * 761838257287
+    assert_eq!(
+        factorize_u128_with_rng((1 << 67) - 1, &mut rng),
+        [(193_707_721, 1), (761_838_257_287, 1)]
+    );
+    let (a, b, c) = (4_294_967_231u128, 4_294_967_279, 4_294_967_291);
+    assert_eq!(
+        factorize_u128_with_rng(a * a * b * c, &mut rng),
+        [(a, 2), (b, 1), (c, 1)]
+    );
+    let (a, b) = (999_999_999_989u128, 1_000_000_000_039);
+    assert_eq!(factorize_u128_with_rng(a * b, &mut rng), [(a, 1), (b, 1)]);
+}
+
+#[test]
+fn test_primitive_root() {
+    assert_eq!(primitive_root(2), 1);
+    assert_eq!(primitive_root(3), 2);
+    assert_eq!(primitive_root(7), 3);
+    assert_eq!(primitive_root(998_244_353), 3);
+    for p in PrimeSieve::new(200).primes() {
+        let g = primitive_root(p);
+        let mut seen = vec![false; p as usize];
+        let mut x = 1;
+        for _ in 0..p - 1 {
+            seen[x as usize] = true;
+            x = x * g % p;
+        }
+        assert_eq!(seen.iter().filter(|&&s| s).count() as u64, p - 1);
+    }
+}
+
+#[test]
+fn test_discrete_log() {
+    for m in 1..60u64 {
+        for a in 0..m {
+            for b in 0..m {
+                let expected = (0..2 * m).find(|&x| mod_pow(a, x, m) == b % m);
+                assert_eq!(discrete_log(a, b, m), expected, "{} {} {}", a, b, m);
+            }
+        }
+    }
+    let p = 1_000_000_007;
+    assert_eq!(
+        discrete_log(5, mod_pow(5, 123_456_789, p), p),
+        Some(123_456_789)
+    );
+}
+
+#[test]
+fn test_mod_nth_root() {
+    for p in PrimeSieve::new(60).primes() {
+        for n in 0..10 {
+            for a in 0..p {
+                let exists = (0..p).any(|x| mod_pow(x, n, p) == a);
+                match mod_nth_root(a, n, p) {
+                    Some(x) => assert_eq!(mod_pow(x, n, p), a, "{} {} {}", a, n, p),
+                    None => assert!(!exists, "{} {} {}", a, n, p),
+                }
+            }
+        }
+    }
+    let p = 998_244_353u64;
+    let x = mod_nth_root(12345, 7, p).unwrap();
+    assert_eq!(mod_pow(x, 7, p), 12345);
+}
+
+#[test]
+fn test_floor_div_blocks() {
+    assert_eq!(floor_div_blocks(0).next(), None);
+    assert_eq!(floor_div_blocks(1).collect::<Vec<_>>(), [(1, 1, 1)]);
+    for n in 0..500u64 {
+        let mut expected_l = 1;
+        for (l, r, q) in floor_div_blocks(n) {
+            assert_eq!(l, expected_l);
+            assert!(l <= r);
+            assert!((l..=r).all(|i| n / i == q));
+            assert!(r == n || n / (r + 1) != q);
+            expected_l = r + 1;
+        }
+        assert_eq!(expected_l, n + 1);
+    }
+    assert_eq!(floor_div_blocks(1_000_000_000_000).count(), 1_999_999);
+}
+
+#[test]
+fn test_mod_pow_large_modulus() {
+    let m = 18_446_744_073_709_551_557u64;
+    // Fermat's little theorem
+    assert_eq!(mod_pow(123_456_789, m - 1, m), 1);
+    assert_eq!(mod_pow(m - 1, 2, m), 1);
+    assert_eq!(mod_pow(u64::MAX, 1, m), u64::MAX % m);
+    assert_eq!(mul_mod_u64(u64::MAX, u64::MAX, m), mod_pow(u64::MAX, 2, m));
+    assert_eq!(mul_mod_u64(1 << 40, 1 << 40, (1 << 61) - 1), 1 << 19);
+}
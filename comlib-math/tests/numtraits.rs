@@ -0,0 +1,44 @@
+use comlib_math::Integer;
+
+/// Sums the values, returning `None` on overflow.
+fn checked_sum<T: Integer>(values: &[T]) -> Option<T> {
+    values
+        .iter()
+        .try_fold(T::zero(), |sum, &value| sum.checked_add(value))
+}
+
+/// Computes the product of the values, saturating on overflow.
+fn saturating_product<T: Integer>(values: &[T]) -> T {
+    values
+        .iter()
+        .fold(T::one(), |product, &value| product.saturating_mul(value))
+}
+
+#[test]
+fn test_bounds() {
+    assert_eq!(<u8 as Integer>::MIN, 0);
+    assert_eq!(<u8 as Integer>::MAX, 255);
+    assert_eq!(<i32 as Integer>::MIN, i32::MIN);
+    assert_eq!(<i128 as Integer>::MAX, i128::MAX);
+}
+
+#[test]
+fn test_checked_operations() {
+    assert_eq!(checked_sum(&[100u8, 100, 55]), Some(255));
+    assert_eq!(checked_sum(&[100u8, 100, 56]), None);
+    assert_eq!(checked_sum(&[i64::MAX, -1, 1]), Some(i64::MAX));
+    assert_eq!(Integer::checked_sub(0u32, 1), None);
+    assert_eq!(Integer::checked_sub(-5i8, 3), Some(-8));
+    assert_eq!(Integer::checked_mul(1u64 << 32, 1 << 31), Some(1 << 63));
+    assert_eq!(Integer::checked_mul(1u64 << 32, 1 << 32), None);
+}
+
+#[test]
+fn test_saturating_operations() {
+    assert_eq!(saturating_product(&[16u8, 16]), 255);
+    assert_eq!(saturating_product(&[-1000i16, 1000]), i16::MIN);
+    assert_eq!(saturating_product(&[3i16, 5]), 15);
+    assert_eq!(Integer::saturating_add(i32::MAX, 1), i32::MAX);
+    assert_eq!(Integer::saturating_sub(1usize, 2), 0);
+    assert_eq!(Integer::saturating_sub(i64::MIN, 1), i64::MIN);
+}
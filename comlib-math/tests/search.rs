@@ -0,0 +1,68 @@
+use comlib_math::{
+    binary_search_f64, binary_search_first, ternary_search_max, ternary_search_max_f64,
+    ternary_search_min, ternary_search_min_f64,
+};
+
+#[test]
+fn test_binary_search_first() {
+    for lo in -10..=10i32 {
+        for hi in lo..=10 {
+            for boundary in lo - 1..=hi + 1 {
+                let expected = boundary.max(lo).min(hi);
+                assert_eq!(binary_search_first(lo, hi, |x| x >= boundary), expected);
+            }
+        }
+    }
+    assert_eq!(
+        binary_search_first(0u64, u64::MAX, |x| x >= 1 << 63),
+        1 << 63
+    );
+    assert_eq!(binary_search_first(0usize, 0, |_| unreachable!()), 0);
+}
+
+#[test]
+fn test_binary_search_f64() {
+    let x = binary_search_f64(0.0, 100.0, |x| x * x * x >= 27.0, 100);
+    assert!((x - 3.0).abs() < 1e-9);
+    assert_eq!(binary_search_f64(0.0, 1.0, |_| false, 100), 1.0);
+    assert!(binary_search_f64(0.0, 1.0, |_| true, 100) < 1e-12);
+}
+
+#[test]
+fn test_ternary_search_integer() {
+    for peak in -20..=20i64 {
+        for lo in -20..=peak {
+            for hi in peak..=20 {
+                assert_eq!(ternary_search_max(lo, hi, |x| -(x - peak).abs()), peak);
+                assert_eq!(
+                    ternary_search_min(lo, hi, |x| (x - peak) * (x - peak)),
+                    peak
+                );
+            }
+        }
+    }
+    // Maximum at the boundary
+    assert_eq!(ternary_search_max(0u64, 1_000_000, |x| x), 1_000_000);
+    assert_eq!(ternary_search_min(0u64, 1_000_000, |x| x), 0);
+    assert_eq!(ternary_search_max(5u8, 5, |x| x), 5);
+}
+
+#[test]
+fn test_ternary_search_integer_call_count() {
+    let mut calls = 0;
+    ternary_search_max(0u64, 1 << 40, |x| {
+        calls += 1;
+        -((x as i64 - 12345).abs())
+    });
+    assert!(calls < 200);
+}
+
+#[test]
+fn test_ternary_search_f64() {
+    let x = ternary_search_max_f64(-5.0, 5.0, |x| -(x - 2.25) * (x - 2.25) + 3.0, 100);
+    assert!((x - 2.25).abs() < 1e-6);
+    let x = ternary_search_min_f64(0.0, 10.0, |x: f64| (x - 7.0).abs(), 100);
+    assert!((x - 7.0).abs() < 1e-9);
+    let x = ternary_search_min_f64(0.0, 10.0, |x| x, 100);
+    assert!(x.abs() < 1e-9);
+}
@@ -0,0 +1,62 @@
+use comlib_math::{simplex, LpResult};
+
+fn assert_optimal(result: LpResult, expected_value: f64, expected_solution: &[f64]) {
+    match result {
+        LpResult::Optimal { value, solution } => {
+            assert!((value - expected_value).abs() < 1e-6, "value {}", value);
+            assert_eq!(solution.len(), expected_solution.len());
+            for (x, y) in solution.iter().zip(expected_solution) {
+                assert!((x - y).abs() < 1e-6, "solution {:?}", solution);
+            }
+        }
+        result => panic!("expected an optimal solution, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_simplex_optimal() {
+    let a = vec![vec![1.0, 1.0], vec![1.0, 3.0], vec![1.0, 0.0]];
+    assert_optimal(
+        simplex(&a, &[4.0, 6.0, 3.0], &[3.0, 2.0]),
+        11.0,
+        &[3.0, 1.0],
+    );
+
+    // Classic textbook example
+    let a = vec![vec![1.0, 0.0], vec![0.0, 2.0], vec![3.0, 2.0]];
+    assert_optimal(
+        simplex(&a, &[4.0, 12.0, 18.0], &[3.0, 5.0]),
+        36.0,
+        &[2.0, 6.0],
+    );
+
+    // No constraints and a non-positive objective
+    assert_optimal(simplex(&[], &[], &[-1.0, 0.0]), 0.0, &[0.0, 0.0]);
+}
+
+#[test]
+fn test_simplex_infeasible_origin() {
+    // x + y ≥ 2 is written as -x - y ≤ -2
+    let a = vec![vec![-1.0, -1.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+    assert_optimal(
+        simplex(&a, &[-2.0, 3.0, 1.0], &[-1.0, -2.0]),
+        -2.0,
+        &[2.0, 0.0],
+    );
+
+    // Equality x + 2y = 4 expressed using two inequalities
+    let a = vec![vec![1.0, 2.0], vec![-1.0, -2.0]];
+    assert_optimal(simplex(&a, &[4.0, -4.0], &[1.0, 1.0]), 4.0, &[4.0, 0.0]);
+}
+
+#[test]
+fn test_simplex_infeasible() {
+    let a = vec![vec![1.0, 1.0], vec![-1.0, -1.0]];
+    assert_eq!(simplex(&a, &[1.0, -2.0], &[1.0, 1.0]), LpResult::Infeasible);
+}
+
+#[test]
+fn test_simplex_unbounded() {
+    let a = vec![vec![1.0, -1.0]];
+    assert_eq!(simplex(&a, &[1.0], &[1.0, 1.0]), LpResult::Unbounded);
+}
@@ -1,4 +1,6 @@
-use comlib_math::subsets;
+use comlib_math::{
+    combinations, gray_code, gray_code_index, gray_codes, next_same_popcount, submasks, subsets,
+};
 
 #[test]
 fn test_subsets() {
@@ -52,3 +54,111 @@ fn test_subset_contains() {
         }
     }
 }
+
+#[test]
+fn test_subsets_of_empty_set() {
+    assert_eq!(subsets(0).count(), 1);
+}
+
+#[test]
+fn test_combinations() {
+    let items = [1, 2, 3, 4];
+
+    let pairs: Vec<Vec<_>> = combinations(items.len(), 2)
+        .map(|subset| subset.select(&items).copied().collect())
+        .collect();
+    assert_eq!(
+        pairs,
+        [
+            vec![1, 2],
+            vec![1, 3],
+            vec![2, 3],
+            vec![1, 4],
+            vec![2, 4],
+            vec![3, 4]
+        ]
+    );
+
+    assert_eq!(combinations(4, 0).map(|s| s.mask).collect::<Vec<_>>(), [0]);
+    assert_eq!(combinations(4, 4).map(|s| s.mask).collect::<Vec<_>>(), [15]);
+    assert_eq!(combinations(4, 5).count(), 0);
+}
+
+#[test]
+fn test_combinations_against_subsets() {
+    for n in 0..=10 {
+        for k in 0..=n {
+            let expected: Vec<_> = subsets(n).filter(|subset| subset.len() == k).collect();
+            let actual: Vec<_> = combinations(n, k).collect();
+            assert_eq!(actual, expected, "n = {}, k = {}", n, k);
+        }
+    }
+
+    // Large sets must not overflow
+    assert_eq!(combinations(64, 1).count(), 64);
+    assert_eq!(combinations(64, 63).count(), 64);
+    assert_eq!(combinations(64, 64).count(), 1);
+}
+
+#[test]
+fn test_submasks() {
+    let masks: Vec<_> = submasks(0b1101).map(|subset| subset.mask).collect();
+    assert_eq!(
+        masks,
+        [0b1101, 0b1100, 0b1001, 0b1000, 0b0101, 0b0100, 0b0001, 0b0000]
+    );
+    assert_eq!(submasks(0).count(), 1);
+    assert_eq!(submasks(u64::MAX >> 44).count(), 1 << 20);
+
+    // Total number of (mask, submask) pairs over n bits is 3^n
+    let total: usize = subsets(8).map(|subset| subset.submasks().count()).sum();
+    assert_eq!(total, 3usize.pow(8));
+
+    for subset in subsets(6) {
+        for submask in subset.submasks() {
+            assert_eq!(submask.mask & !subset.mask, 0);
+        }
+    }
+}
+
+#[test]
+fn test_gray_codes() {
+    let masks: Vec<_> = gray_codes(3).map(|subset| subset.mask).collect();
+    assert_eq!(
+        masks,
+        [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]
+    );
+    assert_eq!(gray_codes(0).count(), 1);
+
+    let mut codes = gray_codes(10);
+    let mut previous = codes.next_with_change().unwrap();
+    assert_eq!(previous, (subsets(0).next().unwrap(), None));
+    let mut seen = vec![false; 1 << 10];
+    seen[0] = true;
+    while let Some((subset, changed)) = codes.next_with_change() {
+        assert_eq!(subset.mask ^ previous.0.mask, 1 << changed.unwrap());
+        assert!(!seen[subset.mask as usize]);
+        seen[subset.mask as usize] = true;
+        previous = (subset, changed);
+    }
+    assert!(seen.iter().all(|&seen| seen));
+
+    for i in 0..1000 {
+        assert_eq!(gray_code_index(gray_code(i)), i);
+    }
+    assert_eq!(gray_code_index(gray_code(u64::MAX)), u64::MAX);
+}
+
+#[test]
+fn test_next_same_popcount() {
+    let mut mask = 0b111;
+    let mut count = 1;
+    while let Some(next) = next_same_popcount(mask) {
+        assert!(next > mask);
+        assert_eq!(next.count_ones(), 3);
+        mask = next;
+        count += 1;
+    }
+    assert_eq!(mask, 0b111 << 61);
+    assert_eq!(count, 64 * 63 * 62 / 6);
+}
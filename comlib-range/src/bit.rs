@@ -1,8 +1,10 @@
 use std::{
     fmt,
-    ops::{Add, AddAssign, Bound, RangeBounds, Sub, SubAssign},
+    ops::{Add, AddAssign, Range, RangeBounds, Sub, SubAssign},
 };
 
+use crate::to_index_range;
+
 /// Binary indexed tree.
 ///
 /// [Binary indexed tree](https://en.wikipedia.org/wiki/Binary_indexed_tree), also known as Fenwick tree, is a data
@@ -10,10 +12,17 @@ use std::{
 ///
 /// Note that unlike most binary indexed tree implementations, this implementation uses 0-indexing instead of 1-indexing.
 ///
+/// The tree stores the zero value given at construction, so the values need not implement [`Default`]. This allows
+/// using types such as modular integers with a modulus chosen at runtime, see [`with_zero`](Self::with_zero).
+///
 /// # Time complexity
 /// All operations on binary indexed tree take `O(log n)` time, unless otherwise stated.
 #[derive(Clone, Eq, PartialEq)]
-pub struct Bit<T>(Vec<T>);
+pub struct Bit<T> {
+    tree: Vec<T>,
+    /// The identity of addition.
+    zero: T,
+}
 
 impl<T> Bit<T> {
     /// Computes the sum of values up to and including the given index.
@@ -21,11 +30,11 @@ impl<T> Bit<T> {
     where
         T: Add<Output = T> + Clone,
     {
-        let mut sum = self.0[index].clone();
+        let mut sum = self.tree[index].clone();
         let mut index = index;
         while index > (index + 1) & (!index) {
             index -= (index + 1) & (!index);
-            sum = sum + self.0[index].clone();
+            sum = sum + self.tree[index].clone();
         }
 
         sum
@@ -34,24 +43,18 @@ impl<T> Bit<T> {
     /// Computes the sum of values on the given range.
     pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> T
     where
-        T: Add<Output = T> + Sub<Output = T> + Clone + Default,
+        T: Add<Output = T> + Sub<Output = T> + Clone,
     {
-        // Find the sum until the non-inclusive lower bound.
-        let lower = match range.start_bound() {
-            Bound::Excluded(i) => self.sum_until(*i),
-            Bound::Included(0) | Bound::Unbounded => Default::default(),
-            Bound::Included(i) => self.sum_until(i - 1),
-        };
-
-        // Find the sum until the inclusive upper bound.
-        let upper = match range.end_bound() {
-            Bound::Excluded(0) => Default::default(),
-            Bound::Excluded(i) => self.sum_until(i - 1),
-            Bound::Included(i) => self.sum_until(*i),
-            Bound::Unbounded => self.sum_until(self.0.len() - 1),
-        };
-
-        upper - lower
+        let Range { start, end } = to_index_range(range, self.tree.len());
+        if start >= end {
+            return self.zero.clone();
+        }
+        let upper = self.sum_until(end - 1);
+        if start == 0 {
+            upper
+        } else {
+            upper - self.sum_until(start - 1)
+        }
     }
 
     /// Increases the value at the given index by the given value.
@@ -60,66 +63,251 @@ impl<T> Bit<T> {
         T: AddAssign + Clone,
     {
         let mut index = index;
-        while index < self.0.len() {
-            self.0[index] += value.clone();
+        while index < self.tree.len() {
+            self.tree[index] += value.clone();
             index += (index + 1) & (!index);
         }
     }
 
+    /// Appends the given value to the end of the array.
+    pub fn push(&mut self, value: T)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone,
+    {
+        // The new node stores the sum of the values on a range ending at it
+        let index = self.tree.len();
+        let start = index + 1 - ((index + 1) & (!index));
+        let node = self.sum(start..index) + value;
+        self.tree.push(node);
+    }
+
+    /// Removes the last value of the array and returns it, or `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone,
+    {
+        // No other node depends on the last one
+        let index = self.tree.len().checked_sub(1)?;
+        let start = index + 1 - ((index + 1) & (!index));
+        let rest = self.sum(start..index);
+        self.tree.pop().map(|node| node - rest)
+    }
+
+    /// Returns the number of values in the array.
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    /// Checks whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Gets the value at the given index.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn get(&self, index: usize) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone,
+    {
+        assert!(index < self.tree.len(), "index out of bounds");
+        self.sum(index..=index)
+    }
+
+    /// Resizes the array to the given length, filling new slots with the given value.
+    ///
+    /// # Time complexity
+    /// Shrinking takes `O(1)` time per removed value and growing takes `O(log n)` time per added value.
+    pub fn resize(&mut self, len: usize, value: T)
+    where
+        T: Add<Output = T> + Sub<Output = T> + Clone,
+    {
+        // No node depends on the nodes after it
+        self.tree.truncate(len);
+        while self.tree.len() < len {
+            self.push(value.clone());
+        }
+    }
+
+    /// Returns an iterator over the values of the array.
+    ///
+    /// # Time complexity
+    /// Takes `O(n)` time to recover all of the values before iteration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_range::Bit;
+    /// let mut bit = Bit::from(vec![1, 2, 3]);
+    /// bit.add(1, 5);
+    /// assert_eq!(bit.iter().collect::<Vec<_>>(), vec![1, 7, 3]);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = T> + ExactSizeIterator
+    where
+        T: SubAssign + Clone,
+    {
+        // Undo the construction, removing the contribution of each node from its parent
+        let mut data = self.tree.clone();
+        let n = data.len();
+        for index in (0..n).rev() {
+            let parent = index | (index + 1);
+            if parent < n {
+                let v = data[index].clone();
+                data[parent] -= v;
+            }
+        }
+        data.into_iter()
+    }
+
+    /// Finds the smallest index such that the sum of values up to and including it is at least the given target.
+    ///
+    /// Returns the length of the array if there is no such index. The values must be non-negative, so that the prefix
+    /// sums are non-decreasing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_range::Bit;
+    /// let bit = Bit::from(vec![1, 0, 2, 3]);
+    /// assert_eq!(bit.lower_bound(0), 0);
+    /// assert_eq!(bit.lower_bound(2), 2);
+    /// assert_eq!(bit.lower_bound(4), 3);
+    /// assert_eq!(bit.lower_bound(7), 4);
+    /// ```
+    pub fn lower_bound(&self, target: T) -> usize
+    where
+        T: Add<Output = T> + Clone + PartialOrd,
+    {
+        self.partition_point(|sum| *sum < target)
+    }
+
+    /// Finds the `k`th smallest element, counting from zero, when the values are the counts of the indices.
+    ///
+    /// Returns `None` if there are at most `k` elements in total. This allows using the tree as a multiset of small
+    /// integers.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_range::Bit;
+    /// // The multiset {1, 1, 3}
+    /// let mut bit = Bit::from(vec![0, 2, 0, 1]);
+    /// assert_eq!(bit.kth(0), Some(1));
+    /// assert_eq!(bit.kth(2), Some(3));
+    /// assert_eq!(bit.kth(3), None);
+    /// bit.sub(1, 1);
+    /// assert_eq!(bit.kth(1), Some(3));
+    /// ```
+    pub fn kth(&self, k: T) -> Option<usize>
+    where
+        T: Add<Output = T> + Clone + PartialOrd,
+    {
+        let index = self.partition_point(|sum| *sum <= k);
+        if index < self.tree.len() {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Finds the number of prefix sums, starting from the sum of the first value, which satisfy the predicate.
+    ///
+    /// The predicate must hold for a prefix of the prefix sums, which is found by walking down the implicit tree.
+    fn partition_point<F>(&self, pred: F) -> usize
+    where
+        T: Add<Output = T> + Clone,
+        F: Fn(&T) -> bool,
+    {
+        let n = self.tree.len();
+        if n == 0 {
+            return 0;
+        }
+        // The node at index `len + step - 1` covers the `step` values following the first `len` values
+        let mut len = 0;
+        let mut sum = self.zero.clone();
+        let mut step = 1 << (usize::BITS - 1 - n.leading_zeros());
+        while step > 0 {
+            if len + step <= n {
+                let next = sum.clone() + self.tree[len + step - 1].clone();
+                if pred(&next) {
+                    len += step;
+                    sum = next;
+                }
+            }
+            step >>= 1;
+        }
+        len
+    }
+
     /// Decreases the value at the given index by the given value.
     pub fn sub(&mut self, index: usize, value: T)
     where
         T: SubAssign + Clone,
     {
         let mut index = index;
-        while index < self.0.len() {
-            self.0[index] -= value.clone();
+        while index < self.tree.len() {
+            self.tree[index] -= value.clone();
             index += (index + 1) & (!index);
         }
     }
 }
 
-impl<T> From<Vec<T>> for Bit<T>
+impl<T> Bit<T>
 where
     T: AddAssign + Clone,
 {
-    /// Constructs binary indexed tree from the given `Vec`.
+    /// Constructs binary indexed tree from the given `Vec`, using the given value as zero.
     ///
     /// # Time complexity
-    /// Construction takes `O(n log n)` time.
-    fn from(data: Vec<T>) -> Self {
-        let mut data = data;
-        let n = data.len();
-        for index in (0..n).rev() {
-            let offset = index + 1;
-            let data = data.split_at_mut(index + 1);
-            let v = &data.0[index];
-            let data = data.1;
-
-            let mut index = index + ((index + 1) & (!index));
-            while index < n {
-                data[index - offset] += v.clone();
-                index += (index + 1) & (!index);
+    /// Construction takes `O(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_range::Bit;
+    /// # use comlib_math::{ModInt, RuntimePrimeModulus};
+    /// let p = RuntimePrimeModulus::from(7u8);
+    /// let values = [3, 5, 6].iter().map(|&v| ModInt::from((v, p))).collect();
+    /// let bit = Bit::with_zero(values, ModInt::from((0, p)));
+    /// assert_eq!(*bit.sum(..), 0);
+    /// assert_eq!(*bit.sum(1..), 4);
+    /// ```
+    pub fn with_zero(data: Vec<T>, zero: T) -> Self {
+        let mut tree = data;
+        let n = tree.len();
+        // Each node is complete once the nodes before it have been processed, and it contributes only to its parent
+        for index in 0..n {
+            let parent = index | (index + 1);
+            if parent < n {
+                let v = tree[index].clone();
+                tree[parent] += v;
             }
         }
-        Self(data)
+        Self { tree, zero }
+    }
+}
+
+impl<T> From<Vec<T>> for Bit<T>
+where
+    T: AddAssign + Clone + Default,
+{
+    /// Constructs binary indexed tree from the given `Vec`, using the default value as zero.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n)` time.
+    fn from(data: Vec<T>) -> Self {
+        Self::with_zero(data, T::default())
     }
 }
 
 /// [`Debug`] on [`Bit`] prints the original values of the array, _not_ the values in the nodes of the tree.
 ///
 /// # Time complexity
-/// Printing takes `O(n log n)` time.
+/// Printing takes `O(n)` time.
 ///
 /// [`Debug`]: std::fmt::Debug
 impl<T> fmt::Debug for Bit<T>
 where
-    T: Add<Output = T> + Sub<Output = T> + Clone + Default + fmt::Debug,
+    T: SubAssign + Clone + fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let n = self.0.len();
-        f.debug_list()
-            .entries((0..n).map(|i| self.sum(i..=i)))
-            .finish()
+        f.debug_list().entries(self.iter()).finish()
     }
 }
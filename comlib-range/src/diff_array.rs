@@ -0,0 +1,78 @@
+use std::ops::{AddAssign, Range, RangeBounds, SubAssign};
+
+use crate::to_index_range;
+
+/// Difference array.
+///
+/// Collects additions to ranges of an array, after which the resulting array can be built at once. See [`DiffArray2d`]
+/// for the two-dimensional version.
+///
+/// [`DiffArray2d`]: crate::DiffArray2d
+///
+/// # Examples
+/// ```
+/// # use comlib_range::DiffArray;
+/// let mut diff = DiffArray::new(5);
+/// diff.add(1..4, 2);
+/// diff.add(3.., 1);
+/// diff.add(..=0, 7);
+/// assert_eq!(diff.build(), vec![7, 2, 2, 3, 1]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` time, unless otherwise stated.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiffArray<T> {
+    /// Differences of consecutive values, with an extra value at the end.
+    differences: Vec<T>,
+}
+
+impl<T> DiffArray<T>
+where
+    T: AddAssign + SubAssign + Clone + Default,
+{
+    /// Constructs an array of the given length with all values set to zero.
+    pub fn new(len: usize) -> Self {
+        Self {
+            differences: vec![T::default(); len + 1],
+        }
+    }
+
+    /// Returns the length of the array.
+    pub fn len(&self) -> usize {
+        self.differences.len() - 1
+    }
+
+    /// Checks whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the given value to every value on the given range.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    pub fn add<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let Range { start, end } = to_index_range(range, self.len());
+        if start >= end {
+            return;
+        }
+        self.differences[start] += value.clone();
+        self.differences[end] -= value;
+    }
+
+    /// Builds the resulting array.
+    ///
+    /// # Time complexity
+    /// Takes `O(n)` time.
+    pub fn build(&self) -> Vec<T> {
+        let mut sum = T::default();
+        self.differences[..self.len()]
+            .iter()
+            .map(|difference| {
+                sum += difference.clone();
+                sum.clone()
+            })
+            .collect()
+    }
+}
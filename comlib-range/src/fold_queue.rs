@@ -0,0 +1,133 @@
+/// First-in-first-out queue which keeps track of the fold of its values.
+///
+/// The values are folded with an associative operation, which need not be commutative nor have an identity or inverses.
+/// This allows for example maintaining the greatest common divisor or the product of matrices over a sliding window,
+/// for which [`MonotonicQueue`](crate::MonotonicQueue) does not apply. The queue is implemented with two stacks, each
+/// of which keeps track of the folds of its values.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::FoldQueue;
+/// let mut queue = FoldQueue::new(|a: &String, b: &String| format!("{}{}", a, b));
+/// queue.push("a".to_string());
+/// queue.push("b".to_string());
+/// queue.push("c".to_string());
+/// assert_eq!(queue.fold(), Some("abc".to_string()));
+/// assert_eq!(queue.pop(), Some("a".to_string()));
+/// queue.push("d".to_string());
+/// assert_eq!(queue.fold(), Some("bcd".to_string()));
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` amortized time, counting the applications of the operation as constant.
+#[derive(Debug, Clone)]
+pub struct FoldQueue<T, F> {
+    /// The values at the front of the queue with the folds from them to the end of the stack, front at the top.
+    front: Vec<(T, T)>,
+    /// The values at the back of the queue, back at the top.
+    back: Vec<T>,
+    /// The fold of the values in `back`.
+    back_fold: Option<T>,
+    op: F,
+}
+
+impl<T, F> FoldQueue<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    /// Constructs an empty queue folding its values with the given associative operation.
+    pub fn new(op: F) -> Self {
+        Self {
+            front: Vec::new(),
+            back: Vec::new(),
+            back_fold: None,
+            op,
+        }
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.front.is_empty() && self.back.is_empty()
+    }
+
+    /// Appends the value to the back of the queue.
+    pub fn push(&mut self, value: T) {
+        self.back_fold = Some(match &self.back_fold {
+            Some(fold) => (self.op)(fold, &value),
+            None => value.clone(),
+        });
+        self.back.push(value);
+    }
+
+    /// Removes the value at the front of the queue and returns it, or `None` if the queue is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.front.is_empty() {
+            // Move the values from the back to the front, computing the folds from the back of the queue
+            self.back_fold = None;
+            while let Some(value) = self.back.pop() {
+                let fold = match self.front.last() {
+                    Some((_, fold)) => (self.op)(&value, fold),
+                    None => value.clone(),
+                };
+                self.front.push((value, fold));
+            }
+        }
+        self.front.pop().map(|(value, _)| value)
+    }
+
+    /// Returns the fold of the values in the queue from front to back, or `None` if the queue is empty.
+    pub fn fold(&self) -> Option<T> {
+        match (self.front.last(), &self.back_fold) {
+            (Some((_, front)), Some(back)) => Some((self.op)(front, back)),
+            (Some((_, front)), None) => Some(front.clone()),
+            (None, back) => back.clone(),
+        }
+    }
+}
+
+/// Computes the fold of every window of `k` consecutive values with the given associative operation.
+///
+/// Returns an iterator over the folds of the windows starting at each index, of which there are `n - k + 1`.
+///
+/// # Panics
+/// Panics if `k` is zero.
+///
+/// # Time complexity
+/// Iterating over all of the windows takes `O(n)` time, counting the applications of the operation as constant.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::sliding_window_fold;
+/// let gcd = |&a: &u32, &b: &u32| (1..=a.min(b)).rev().find(|d| a % d == 0 && b % d == 0).unwrap();
+/// let gcds: Vec<_> = sliding_window_fold(&[4, 6, 9, 3, 8], 2, gcd).collect();
+/// assert_eq!(gcds, vec![2, 3, 3, 1]);
+/// ```
+pub fn sliding_window_fold<'a, T, F>(
+    values: &'a [T],
+    k: usize,
+    op: F,
+) -> impl Iterator<Item = T> + 'a
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T + 'a,
+{
+    assert!(k > 0, "the window must not be empty");
+    let mut queue = FoldQueue::new(op);
+    values.iter().enumerate().filter_map(move |(i, value)| {
+        queue.push(value.clone());
+        if i >= k {
+            queue.pop();
+        }
+        if i + 1 >= k {
+            queue.fold()
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,189 @@
+use std::{
+    collections::BTreeMap,
+    iter::FromIterator,
+    ops::{Add, Range, Sub},
+};
+
+/// Set of points stored as a union of disjoint intervals.
+///
+/// The intervals are half-open, and overlapping or adjacent intervals are merged on insertion, so that the stored
+/// intervals are always disjoint and separated by gaps.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::IntervalSet;
+/// let mut set = IntervalSet::new();
+/// set.insert(1..4);
+/// set.insert(6..8);
+/// set.insert(4..5);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..5, 6..8]);
+/// set.remove(2..7);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..2, 7..8]);
+/// assert!(set.contains(7));
+/// assert_eq!(set.covered_len(), 2);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` amortized time, where n is the number of intervals, unless otherwise stated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// The ends of the intervals keyed by their starts.
+    intervals: BTreeMap<T, T>,
+    /// The total length of the intervals.
+    covered: T,
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    /// Constructs an empty set.
+    pub fn new() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+            covered: T::default(),
+        }
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Checks whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the total length of the intervals.
+    ///
+    /// # Time complexity
+    /// Takes `O(1)` time.
+    pub fn covered_len(&self) -> T {
+        self.covered
+    }
+
+    /// Returns an iterator over the intervals in increasing order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + ExactSizeIterator + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..end)
+    }
+
+    /// Adds the points of the given range to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        let Range { mut start, mut end } = range;
+        if start >= end {
+            return;
+        }
+        // Merge with the interval starting before the range if they overlap or touch
+        if let Some((&before_start, &before_end)) = self.intervals.range(..start).next_back() {
+            if before_end >= start {
+                start = before_start;
+                end = end.max(before_end);
+                self.take(before_start);
+            }
+        }
+        // Merge with the intervals starting within the range or right after it
+        while let Some((&next_start, &next_end)) = self.intervals.range(start..=end).next() {
+            end = end.max(next_end);
+            self.take(next_start);
+        }
+        self.put(start, end);
+    }
+
+    /// Removes the points of the given range from the set.
+    pub fn remove(&mut self, range: Range<T>) {
+        let Range { start, end } = range;
+        if start >= end {
+            return;
+        }
+        // Cut the interval starting before the range
+        if let Some((&before_start, &before_end)) = self.intervals.range(..start).next_back() {
+            if before_end > start {
+                self.take(before_start);
+                self.put(before_start, start);
+                if before_end > end {
+                    self.put(end, before_end);
+                }
+            }
+        }
+        // Remove the intervals starting within the range, keeping the parts after it
+        while let Some((&next_start, &next_end)) = self.intervals.range(start..end).next() {
+            self.take(next_start);
+            if next_end > end {
+                self.put(end, next_end);
+            }
+        }
+    }
+
+    /// Checks whether the set contains the given point.
+    pub fn contains(&self, point: T) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// Returns the interval containing the given point, or `None` if the point is not in the set.
+    pub fn get(&self, point: T) -> Option<Range<T>> {
+        let (&start, &end) = self.intervals.range(..=point).next_back()?;
+        if point < end {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether any point of the given range is in the set.
+    pub fn overlaps(&self, range: Range<T>) -> bool {
+        let Range { start, end } = range;
+        if start >= end {
+            return false;
+        }
+        if let Some((_, &before_end)) = self.intervals.range(..=start).next_back() {
+            if before_end > start {
+                return true;
+            }
+        }
+        self.intervals.range(start..end).next().is_some()
+    }
+
+    /// Removes the interval starting at the given point.
+    fn take(&mut self, start: T) {
+        let end = self.intervals.remove(&start).unwrap();
+        self.covered = self.covered - (end - start);
+    }
+
+    /// Adds a non-empty interval which does not overlap with the other intervals.
+    fn put(&mut self, start: T, end: T) {
+        self.intervals.insert(start, end);
+        self.covered = self.covered + (end - start);
+    }
+}
+
+impl<T> Default for IntervalSet<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<Range<T>> for IntervalSet<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Ord + Copy + Add<Output = T> + Sub<Output = T> + Default,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
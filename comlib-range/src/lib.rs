@@ -3,11 +3,82 @@
 //!
 //! ## Content
 //! - [Binary Indexed Tree](Bit)
+//!   - [with range updates and point queries](RangeAddBit)
+//!   - [with range updates and range queries](RangeSumBit)
+//! - [Difference array](DiffArray)
+//! - [Two-dimensional prefix sums](Prefix2d)
+//! - [Two-dimensional difference array](DiffArray2d)
+//! - [Sparse table](SparseTable)
+//! - [Wavelet tree](WaveletTree)
+//! - [Convex hull trick](MonotoneCht) and [Li Chao tree](LiChaoTree)
+//! - [Set of disjoint intervals](IntervalSet)
+//!   - [with values assigned to ranges](RangeAssignMap)
+//! - [Square root decomposition](SqrtDecomposition)
+//! - [Queue with minimum](MonotonicQueue)
+//!   - [Sliding window minimum](sliding_window_min) and [maximum](sliding_window_max)
+//! - [Queue with fold](FoldQueue)
+//!   - [Sliding window fold](sliding_window_fold)
+//!
+//! The structures interpret the ranges of indices they are given with [`to_index_range`].
 //!
 //! ## Still missing
 //! - Segment tree
 
 #![warn(missing_docs)]
 
+use std::ops::{Bound, Range, RangeBounds};
+
 mod bit;
 pub use bit::Bit;
+mod diff_array;
+pub use diff_array::DiffArray;
+mod fold_queue;
+pub use fold_queue::{sliding_window_fold, FoldQueue};
+mod interval_set;
+pub use interval_set::IntervalSet;
+mod lines;
+pub use lines::{Extremum, LiChaoTree, MonotoneCht};
+mod monotonic;
+pub use monotonic::{sliding_window_max, sliding_window_min, MonotonicQueue};
+mod prefix2d;
+pub use prefix2d::{DiffArray2d, Prefix2d};
+mod range_assign;
+pub use range_assign::RangeAssignMap;
+mod range_bit;
+pub use range_bit::{RangeAddBit, RangeSumBit};
+mod sparse_table;
+pub use sparse_table::SparseTable;
+mod sqrt;
+pub use sqrt::{Block, SqrtDecomposition};
+mod wavelet;
+pub use wavelet::WaveletTree;
+
+/// Converts the range bounds into a half-open range of indices into an array of the given length.
+///
+/// Unbounded ends are replaced by `0` and `len`, respectively. All structures of this crate use this for interpreting
+/// the ranges they are given, so that they handle the ranges consistently.
+///
+/// # Panics
+/// Panics if the range does not fit within `0..len` or if its start is after its end.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::to_index_range;
+/// assert_eq!(to_index_range(.., 5), 0..5);
+/// assert_eq!(to_index_range(2..=3, 5), 2..4);
+/// assert_eq!(to_index_range(..1, 5), 0..1);
+/// ```
+pub fn to_index_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i + 1,
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range out of bounds");
+    start..end
+}
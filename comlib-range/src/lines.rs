@@ -0,0 +1,395 @@
+use std::{collections::VecDeque, convert::TryFrom, ops::RangeInclusive};
+
+/// Which extremum of the lines to query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Extremum {
+    /// Query the minimum value.
+    Minimum,
+    /// Query the maximum value.
+    Maximum,
+}
+
+/// Line `y = slope * x + intercept`, normalized so that the maximum is always queried.
+///
+/// The coefficients are stored in `i128` so that evaluating a line at any `i64` point cannot overflow.
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    slope: i128,
+    intercept: i128,
+}
+
+impl Line {
+    /// Constructs the line, negating it if the minimum is queried.
+    fn new(slope: i64, intercept: i64, extremum: Extremum) -> Self {
+        let (slope, intercept) = (slope as i128, intercept as i128);
+        match extremum {
+            Extremum::Maximum => Self { slope, intercept },
+            Extremum::Minimum => Self {
+                slope: -slope,
+                intercept: -intercept,
+            },
+        }
+    }
+
+    /// Evaluates the line at the given point.
+    fn eval(self, x: i64) -> i128 {
+        self.slope * x as i128 + self.intercept
+    }
+}
+
+/// Converts the value of a normalized line back into the value of the original line.
+fn denormalize(value: i128, extremum: Extremum) -> i64 {
+    let value = match extremum {
+        Extremum::Maximum => value,
+        Extremum::Minimum => -value,
+    };
+    i64::try_from(value).expect("the value to fit in i64")
+}
+
+/// Convex hull trick for lines inserted in order of their slopes.
+///
+/// Maintains the lower or upper envelope of a set of lines, answering the minimum or maximum value of the lines at a
+/// given point. The slopes of the lines must be monotone, but they can be either non-decreasing or non-increasing
+/// regardless of which extremum is queried. See [`LiChaoTree`] for inserting lines in an arbitrary order.
+///
+/// The computations are done in `i128`, so any lines and points representable in `i64` are supported as long as the
+/// answers fit in `i64`.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::{Extremum, MonotoneCht};
+/// let mut cht = MonotoneCht::new(Extremum::Minimum);
+/// cht.insert(2, 0);
+/// cht.insert(0, 3);
+/// cht.insert(-1, 8);
+/// assert_eq!(cht.query(1), Some(2));
+/// assert_eq!(cht.query(2), Some(3));
+/// assert_eq!(cht.query(10), Some(-2));
+/// ```
+///
+/// # Time complexity
+/// Insertion takes `O(1)` amortized time and querying takes `O(log n)` time.
+#[derive(Debug, Clone)]
+pub struct MonotoneCht {
+    /// The lines on the upper envelope of the normalized lines in increasing order of slopes.
+    lines: VecDeque<Line>,
+    extremum: Extremum,
+    /// Whether the normalized slopes are inserted in decreasing order, if known yet.
+    decreasing: Option<bool>,
+    /// The normalized slope of the previously inserted line.
+    last_slope: Option<i128>,
+}
+
+impl MonotoneCht {
+    /// Constructs an empty set of lines.
+    pub fn new(extremum: Extremum) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            extremum,
+            decreasing: None,
+            last_slope: None,
+        }
+    }
+
+    /// Returns the number of lines on the envelope.
+    ///
+    /// Lines which cannot be the answer to any query are discarded, so this can be less than the number of inserted
+    /// lines.
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Checks whether there are no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Inserts the line `y = slope * x + intercept`.
+    ///
+    /// # Panics
+    /// Panics if the slopes of the inserted lines are not monotone.
+    pub fn insert(&mut self, slope: i64, intercept: i64) {
+        let line = Line::new(slope, intercept, self.extremum);
+        if let Some(last) = self.last_slope {
+            if line.slope != last {
+                let decreasing = line.slope < last;
+                assert!(
+                    *self.decreasing.get_or_insert(decreasing) == decreasing,
+                    "the slopes must be monotone"
+                );
+            }
+        }
+        self.last_slope = Some(line.slope);
+        if self.decreasing == Some(true) {
+            self.insert_front(line);
+        } else {
+            self.insert_back(line);
+        }
+    }
+
+    /// Inserts a line whose slope is at least the slopes of the other lines.
+    fn insert_back(&mut self, line: Line) {
+        if let Some(&last) = self.lines.back() {
+            if last.slope == line.slope {
+                if last.intercept >= line.intercept {
+                    return;
+                }
+                self.lines.pop_back();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let (a, b) = (
+                self.lines[self.lines.len() - 2],
+                self.lines[self.lines.len() - 1],
+            );
+            if overtake(b, line) <= overtake(a, b) {
+                self.lines.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.lines.push_back(line);
+    }
+
+    /// Inserts a line whose slope is at most the slopes of the other lines.
+    fn insert_front(&mut self, line: Line) {
+        if let Some(&first) = self.lines.front() {
+            if first.slope == line.slope {
+                if first.intercept >= line.intercept {
+                    return;
+                }
+                self.lines.pop_front();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let (b, c) = (self.lines[0], self.lines[1]);
+            if overtake(b, c) <= overtake(line, b) {
+                self.lines.pop_front();
+            } else {
+                break;
+            }
+        }
+        self.lines.push_front(line);
+    }
+
+    /// Finds the minimum or maximum value of the lines at the given point.
+    ///
+    /// Returns `None` if there are no lines.
+    ///
+    /// # Panics
+    /// Panics if the value does not fit in `i64`.
+    pub fn query(&self, x: i64) -> Option<i64> {
+        if self.lines.is_empty() {
+            return None;
+        }
+        // The line at index `i` is the best from the point where it overtakes the previous line
+        let (mut low, mut high) = (0, self.lines.len() - 1);
+        while low < high {
+            let mid = (low + high).div_ceil(2);
+            if overtake(self.lines[mid - 1], self.lines[mid]) <= x as i128 {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        Some(denormalize(self.lines[low].eval(x), self.extremum))
+    }
+}
+
+/// Finds the smallest integer point where the line `b` is at least the line `a`.
+///
+/// The slope of `b` must be greater than the slope of `a`.
+fn overtake(a: Line, b: Line) -> i128 {
+    let numerator = a.intercept - b.intercept;
+    let denominator = b.slope - a.slope;
+    // Ceiling division with a positive denominator
+    -((-numerator).div_euclid(denominator))
+}
+
+/// Li Chao tree for querying the minimum or maximum of lines and line segments at a point.
+///
+/// Unlike [`MonotoneCht`], the lines can be inserted in any order, and they can be restricted to segments. The points
+/// are limited to a range of integers given at construction, and the nodes of the tree are created only as needed, so
+/// the range can be as large as all of `i64`.
+///
+/// The computations are done in `i128`, so any lines and points representable in `i64` are supported as long as the
+/// answers fit in `i64`.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::{Extremum, LiChaoTree};
+/// let mut tree = LiChaoTree::new(-100..=100, Extremum::Maximum);
+/// tree.insert(1, 0);
+/// tree.insert(-1, 0);
+/// tree.insert_segment(0, 50, -5..=5);
+/// assert_eq!(tree.query(-20), Some(20));
+/// assert_eq!(tree.query(3), Some(50));
+/// assert_eq!(tree.query(6), Some(6));
+/// ```
+///
+/// # Time complexity
+/// Inserting a line and querying take `O(log C)` time, where C is the size of the range of points. Inserting a segment
+/// takes `O(log² C)` time.
+#[derive(Debug, Clone)]
+pub struct LiChaoTree {
+    /// The nodes of the tree, with the root at index 0.
+    nodes: Vec<Node>,
+    /// The smallest point.
+    low: i64,
+    /// The largest point.
+    high: i64,
+    extremum: Extremum,
+}
+
+/// Node of [`LiChaoTree`].
+#[derive(Debug, Clone)]
+struct Node {
+    /// The line which is the best at the middle point of the node among the lines inserted to the node.
+    line: Option<Line>,
+    /// The indices of the children, or 0 if absent.
+    children: [usize; 2],
+}
+
+impl LiChaoTree {
+    /// Constructs an empty tree for the given range of points.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    pub fn new(points: RangeInclusive<i64>, extremum: Extremum) -> Self {
+        let (low, high) = points.into_inner();
+        assert!(low <= high, "the range of points must not be empty");
+        Self {
+            nodes: vec![Node {
+                line: None,
+                children: [0, 0],
+            }],
+            low,
+            high,
+            extremum,
+        }
+    }
+
+    /// Inserts the line `y = slope * x + intercept`.
+    pub fn insert(&mut self, slope: i64, intercept: i64) {
+        let line = Line::new(slope, intercept, self.extremum);
+        self.insert_line(0, self.low, self.high, line);
+    }
+
+    /// Inserts the line `y = slope * x + intercept` restricted to the given range of points.
+    ///
+    /// Points outside of the range of the tree are ignored.
+    pub fn insert_segment(&mut self, slope: i64, intercept: i64, points: RangeInclusive<i64>) {
+        let line = Line::new(slope, intercept, self.extremum);
+        let (start, end) = points.into_inner();
+        self.insert_segment_into(0, self.low, self.high, line, start, end);
+    }
+
+    /// Finds the minimum or maximum value of the lines at the given point.
+    ///
+    /// Returns `None` if no line or segment covers the point.
+    ///
+    /// # Panics
+    /// Panics if the point is out of range or the value does not fit in `i64`.
+    pub fn query(&self, x: i64) -> Option<i64> {
+        assert!(self.low <= x && x <= self.high, "point out of range");
+        let mut best: Option<i128> = None;
+        let (mut node, mut low, mut high) = (0, self.low, self.high);
+        loop {
+            if let Some(line) = self.nodes[node].line {
+                let value = line.eval(x);
+                best = Some(best.map_or(value, |best| best.max(value)));
+            }
+            let mid = midpoint(low, high);
+            let side = if x <= mid {
+                high = mid;
+                0
+            } else {
+                low = mid + 1;
+                1
+            };
+            node = self.nodes[node].children[side];
+            if node == 0 {
+                break;
+            }
+        }
+        best.map(|value| denormalize(value, self.extremum))
+    }
+
+    /// Returns the child of the node on the given side, creating it if needed.
+    fn child(&mut self, node: usize, side: usize) -> usize {
+        if self.nodes[node].children[side] == 0 {
+            self.nodes[node].children[side] = self.nodes.len();
+            self.nodes.push(Node {
+                line: None,
+                children: [0, 0],
+            });
+        }
+        self.nodes[node].children[side]
+    }
+
+    /// Inserts the line into the subtree of the node covering the points from `low` to `high`.
+    fn insert_line(&mut self, node: usize, low: i64, high: i64, line: Line) {
+        let (mut node, mut low, mut high, mut line) = (node, low, high, line);
+        loop {
+            let current = match self.nodes[node].line {
+                Some(current) => current,
+                None => {
+                    self.nodes[node].line = Some(line);
+                    return;
+                }
+            };
+            let mid = midpoint(low, high);
+            // Keep the better line at the middle in the node and push the other one to the side where it can still
+            // be better
+            let (keep, push) = if line.eval(mid) > current.eval(mid) {
+                (line, current)
+            } else {
+                (current, line)
+            };
+            self.nodes[node].line = Some(keep);
+            if low == high {
+                return;
+            }
+            if push.eval(low) > keep.eval(low) {
+                node = self.child(node, 0);
+                high = mid;
+            } else if push.eval(high) > keep.eval(high) {
+                node = self.child(node, 1);
+                low = mid + 1;
+            } else {
+                return;
+            }
+            line = push;
+        }
+    }
+
+    /// Inserts the line restricted to the points from `start` to `end` into the subtree of the node covering the
+    /// points from `low` to `high`.
+    fn insert_segment_into(
+        &mut self,
+        node: usize,
+        low: i64,
+        high: i64,
+        line: Line,
+        start: i64,
+        end: i64,
+    ) {
+        if end < low || high < start {
+            return;
+        }
+        if start <= low && high <= end {
+            self.insert_line(node, low, high, line);
+            return;
+        }
+        let mid = midpoint(low, high);
+        let left = self.child(node, 0);
+        self.insert_segment_into(left, low, mid, line, start, end);
+        let right = self.child(node, 1);
+        self.insert_segment_into(right, mid + 1, high, line, start, end);
+    }
+}
+
+/// Computes the midpoint of the given points, rounding down.
+fn midpoint(low: i64, high: i64) -> i64 {
+    ((low as i128 + high as i128).div_euclid(2)) as i64
+}
@@ -0,0 +1,156 @@
+use std::{cmp::Reverse, collections::VecDeque};
+
+/// First-in-first-out queue which keeps track of its minimum.
+///
+/// Only the values which can still become the minimum are stored, so the values themselves cannot be accessed. To track
+/// the maximum instead, wrap the values in [`Reverse`].
+///
+/// # Examples
+/// ```
+/// # use comlib_range::MonotonicQueue;
+/// let mut queue = MonotonicQueue::new();
+/// queue.push(3);
+/// queue.push(1);
+/// queue.push(2);
+/// assert_eq!(queue.min(), Some(&1));
+/// queue.pop();
+/// queue.pop();
+/// assert_eq!(queue.min(), Some(&2));
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` amortized time.
+#[derive(Debug, Clone)]
+pub struct MonotonicQueue<T> {
+    /// The candidates for the minimum with their positions in the queue, increasing in both.
+    candidates: VecDeque<(usize, T)>,
+    /// The number of values pushed.
+    pushed: usize,
+    /// The number of values popped.
+    popped: usize,
+}
+
+impl<T: Ord> MonotonicQueue<T> {
+    /// Constructs an empty queue.
+    pub fn new() -> Self {
+        Self {
+            candidates: VecDeque::new(),
+            pushed: 0,
+            popped: 0,
+        }
+    }
+
+    /// Returns the number of values in the queue.
+    pub fn len(&self) -> usize {
+        self.pushed - self.popped
+    }
+
+    /// Checks whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pushed == self.popped
+    }
+
+    /// Appends the value to the back of the queue.
+    pub fn push(&mut self, value: T) {
+        // Larger values before this one can no longer be the minimum
+        while self
+            .candidates
+            .back()
+            .is_some_and(|(_, candidate)| *candidate > value)
+        {
+            self.candidates.pop_back();
+        }
+        self.candidates.push_back((self.pushed, value));
+        self.pushed += 1;
+    }
+
+    /// Removes the value at the front of the queue.
+    ///
+    /// Returns `false` if the queue was empty.
+    pub fn pop(&mut self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        if self
+            .candidates
+            .front()
+            .is_some_and(|&(position, _)| position == self.popped)
+        {
+            self.candidates.pop_front();
+        }
+        self.popped += 1;
+        true
+    }
+
+    /// Returns the minimum value in the queue, or `None` if the queue is empty.
+    pub fn min(&self) -> Option<&T> {
+        self.candidates.front().map(|(_, value)| value)
+    }
+}
+
+impl<T: Ord> Default for MonotonicQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Computes the minimum of every window of `k` consecutive values.
+///
+/// Returns an iterator over the minimums of the windows starting at each index, of which there are `n - k + 1`.
+///
+/// # Panics
+/// Panics if `k` is zero.
+///
+/// # Time complexity
+/// Iterating over all of the windows takes `O(n)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::sliding_window_min;
+/// let minimums: Vec<_> = sliding_window_min(&[4, 2, 5, 3, 1], 3).copied().collect();
+/// assert_eq!(minimums, vec![2, 2, 1]);
+/// ```
+pub fn sliding_window_min<T: Ord>(values: &[T], k: usize) -> impl Iterator<Item = &T> + '_ {
+    sliding_window(values, k, |value| value)
+}
+
+/// Computes the maximum of every window of `k` consecutive values.
+///
+/// Returns an iterator over the maximums of the windows starting at each index, of which there are `n - k + 1`.
+///
+/// # Panics
+/// Panics if `k` is zero.
+///
+/// # Time complexity
+/// Iterating over all of the windows takes `O(n)` time.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::sliding_window_max;
+/// let maximums: Vec<_> = sliding_window_max(&[4, 2, 5, 3, 1], 3).copied().collect();
+/// assert_eq!(maximums, vec![5, 5, 5]);
+/// ```
+pub fn sliding_window_max<T: Ord>(values: &[T], k: usize) -> impl Iterator<Item = &T> + '_ {
+    sliding_window(values, k, Reverse).map(|value| value.0)
+}
+
+/// Computes the minimum of every window of `k` consecutive values after mapping them into keys.
+fn sliding_window<'a, T, K, F>(values: &'a [T], k: usize, key: F) -> impl Iterator<Item = K> + 'a
+where
+    K: Ord + Copy + 'a,
+    F: Fn(&'a T) -> K + 'a,
+{
+    assert!(k > 0, "the window must not be empty");
+    let mut queue = MonotonicQueue::new();
+    values.iter().enumerate().filter_map(move |(i, value)| {
+        queue.push(key(value));
+        if i >= k {
+            queue.pop();
+        }
+        if i + 1 >= k {
+            queue.min().copied()
+        } else {
+            None
+        }
+    })
+}
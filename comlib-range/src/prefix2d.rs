@@ -0,0 +1,222 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Range, RangeBounds, Sub, SubAssign},
+};
+
+use crate::to_index_range;
+
+/// Two-dimensional prefix sums of a matrix.
+///
+/// Answers the sum of values over any rectangle in constant time. The matrix cannot be modified after construction,
+/// see [`DiffArray2d`] for applying additions to rectangles instead.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::Prefix2d;
+/// let prefix = Prefix2d::from(vec![
+///     vec![1, 2, 3],
+///     vec![4, 5, 6],
+/// ]);
+/// assert_eq!(prefix.sum(.., ..), 21);
+/// assert_eq!(prefix.sum(1.., 1..), 11);
+/// assert_eq!(prefix.sum(..1, ..=1), 3);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` time, unless otherwise stated.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Prefix2d<T> {
+    /// Sums of the rectangles starting at the origin, stored row by row with an extra row and column of zeros.
+    sums: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Prefix2d<T> {
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Computes the sum of values on the rectangle spanned by the given ranges of rows and columns.
+    pub fn sum<R, C>(&self, rows: R, cols: C) -> T
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+        T: Add<Output = T> + Sub<Output = T> + Clone + Default,
+    {
+        let Range {
+            start: top,
+            end: bottom,
+        } = to_index_range(rows, self.rows);
+        let Range {
+            start: left,
+            end: right,
+        } = to_index_range(cols, self.cols);
+        if top >= bottom || left >= right {
+            return T::default();
+        }
+        self.corner(bottom, right) + self.corner(top, left)
+            - self.corner(top, right)
+            - self.corner(bottom, left)
+    }
+
+    /// Returns the sum of the rectangle covering the first `row` rows and `col` columns.
+    fn corner(&self, row: usize, col: usize) -> T
+    where
+        T: Clone,
+    {
+        self.sums[row * (self.cols + 1) + col].clone()
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for Prefix2d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Default,
+{
+    /// Computes the prefix sums of the given matrix.
+    ///
+    /// # Panics
+    /// Panics if the rows of the matrix have different lengths.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(nm)` time.
+    fn from(matrix: Vec<Vec<T>>) -> Self {
+        let rows = matrix.len();
+        let cols = matrix.first().map_or(0, Vec::len);
+        let width = cols + 1;
+        let mut sums = vec![T::default(); (rows + 1) * width];
+        for (i, row) in matrix.into_iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                cols,
+                "the rows of the matrix must have equal lengths"
+            );
+            for (j, value) in row.into_iter().enumerate() {
+                sums[(i + 1) * width + j + 1] =
+                    value + sums[i * width + j + 1].clone() + sums[(i + 1) * width + j].clone()
+                        - sums[i * width + j].clone();
+            }
+        }
+        Self { sums, rows, cols }
+    }
+}
+
+/// [`Debug`] on [`Prefix2d`] prints the original matrix, _not_ the prefix sums.
+///
+/// [`Debug`]: std::fmt::Debug
+impl<T> fmt::Debug for Prefix2d<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Default + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.rows).map(|i| {
+                (0..self.cols)
+                    .map(|j| self.sum(i..=i, j..=j))
+                    .collect::<Vec<_>>()
+            }))
+            .finish()
+    }
+}
+
+/// Two-dimensional difference array.
+///
+/// Collects additions to rectangles of a matrix, after which the resulting matrix can be built at once. This is the
+/// offline counterpart of [`Prefix2d`].
+///
+/// # Examples
+/// ```
+/// # use comlib_range::DiffArray2d;
+/// let mut diff = DiffArray2d::new(2, 3);
+/// diff.add(.., 1.., 1);
+/// diff.add(1..2, ..=1, 5);
+/// assert_eq!(diff.build(), vec![vec![0, 1, 1], vec![5, 6, 1]]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(1)` time, unless otherwise stated.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiffArray2d<T> {
+    /// Differences of the values, stored row by row with an extra row and column.
+    differences: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> DiffArray2d<T>
+where
+    T: AddAssign + SubAssign + Clone + Default,
+{
+    /// Constructs a matrix of the given size with all values set to zero.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            differences: vec![T::default(); (rows + 1) * (cols + 1)],
+            rows,
+            cols,
+        }
+    }
+
+    /// Returns the number of rows in the matrix.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns in the matrix.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Adds the given value to every value on the rectangle spanned by the given ranges of rows and columns.
+    pub fn add<R, C>(&mut self, rows: R, cols: C, value: T)
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let Range {
+            start: top,
+            end: bottom,
+        } = to_index_range(rows, self.rows);
+        let Range {
+            start: left,
+            end: right,
+        } = to_index_range(cols, self.cols);
+        if top >= bottom || left >= right {
+            return;
+        }
+        let width = self.cols + 1;
+        self.differences[top * width + left] += value.clone();
+        self.differences[top * width + right] -= value.clone();
+        self.differences[bottom * width + left] -= value.clone();
+        self.differences[bottom * width + right] += value;
+    }
+
+    /// Builds the resulting matrix.
+    ///
+    /// # Time complexity
+    /// Takes `O(nm)` time.
+    pub fn build(&self) -> Vec<Vec<T>> {
+        let width = self.cols + 1;
+        let mut matrix: Vec<Vec<T>> = Vec::with_capacity(self.rows);
+        for i in 0..self.rows {
+            let mut row = Vec::with_capacity(self.cols);
+            // Sum of the differences on the current row up to the current column
+            let mut sum = T::default();
+            for j in 0..self.cols {
+                sum += self.differences[i * width + j].clone();
+                let mut value = sum.clone();
+                if let Some(above) = matrix.last() {
+                    value += above[j].clone();
+                }
+                row.push(value);
+            }
+            matrix.push(row);
+        }
+        matrix
+    }
+}
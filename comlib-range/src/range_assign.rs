@@ -0,0 +1,184 @@
+use std::{
+    collections::BTreeMap,
+    ops::{Bound, Range, RangeBounds},
+};
+
+/// Map from a range of keys to values, supporting assigning a value to a range of keys.
+///
+/// The keys are stored as runs of consecutive keys with equal values, also known as Chtholly tree or ODT. Assigning a
+/// value to a range replaces all of the runs within it by a single run, so that the runs can be enumerated efficiently.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::RangeAssignMap;
+/// let mut map = RangeAssignMap::new(0..10, 0);
+/// map.assign(2..6, 1);
+/// map.assign(4..8, 2);
+/// assert_eq!(map.get(5), &2);
+/// assert_eq!(
+///     map.runs(3..9).collect::<Vec<_>>(),
+///     vec![(3..4, &1), (4..8, &2), (8..9, &0)],
+/// );
+/// for (_, value) in map.runs_mut(..5) {
+///     *value += 10;
+/// }
+/// assert_eq!(map.run_count(), 5);
+/// assert_eq!(map.get(4), &12);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` amortized time, where n is the number of runs, plus the time for visiting the runs.
+/// When the values are assigned to random ranges, the number of runs quickly shrinks to `O(log m)` for m keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeAssignMap<K, V> {
+    /// The values of the runs keyed by their starts.
+    runs: BTreeMap<K, V>,
+    /// The end of the range of keys.
+    end: K,
+}
+
+impl<K: Ord + Copy, V> RangeAssignMap<K, V> {
+    /// Constructs a map assigning the given value to all keys in the given range.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    pub fn new(range: Range<K>, value: V) -> Self {
+        assert!(
+            range.start < range.end,
+            "the range of keys must not be empty"
+        );
+        let mut runs = BTreeMap::new();
+        runs.insert(range.start, value);
+        Self {
+            runs,
+            end: range.end,
+        }
+    }
+
+    /// Returns the range of keys of the map.
+    pub fn keys(&self) -> Range<K> {
+        *self.runs.keys().next().unwrap()..self.end
+    }
+
+    /// Returns the number of runs.
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Returns the value of the given key.
+    ///
+    /// # Panics
+    /// Panics if the key is out of bounds.
+    pub fn get(&self, key: K) -> &V {
+        assert!(key < self.end, "key out of bounds");
+        self.runs
+            .range(..=key)
+            .next_back()
+            .expect("key out of bounds")
+            .1
+    }
+
+    /// Assigns the value to all keys in the given range.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds, or if its start is excluded or its end is included.
+    pub fn assign<R: RangeBounds<K>>(&mut self, range: R, value: V)
+    where
+        V: Clone,
+    {
+        let Range { start, end } = self.resolve(range);
+        if start >= end {
+            return;
+        }
+        self.split(start);
+        self.split(end);
+        while let Some((&key, _)) = self.runs.range(start..end).next() {
+            self.runs.remove(&key);
+        }
+        self.runs.insert(start, value);
+    }
+
+    /// Returns an iterator over the runs within the given range, clipped to the range.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds, or if its start is excluded or its end is included.
+    pub fn runs<R: RangeBounds<K>>(&self, range: R) -> impl Iterator<Item = (Range<K>, &V)> + '_ {
+        let Range { start, end } = self.resolve(range);
+        let first = match self.runs.range(..=start).next_back() {
+            Some((&key, _)) => key,
+            None => start,
+        };
+        let mut runs = self.runs.range(first..).peekable();
+        std::iter::from_fn(move || {
+            let (&key, value) = runs.next()?;
+            if key >= end || start >= end {
+                return None;
+            }
+            let next = runs.peek().map_or(self.end, |(&key, _)| key);
+            Some((key.max(start)..next.min(end), value))
+        })
+    }
+
+    /// Returns an iterator over the runs within the given range, allowing modifying their values.
+    ///
+    /// The runs crossing the ends of the range are split first, so that the values outside of the range are not
+    /// modified.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds, or if its start is excluded or its end is included.
+    pub fn runs_mut<R: RangeBounds<K>>(
+        &mut self,
+        range: R,
+    ) -> impl Iterator<Item = (Range<K>, &mut V)> + '_
+    where
+        V: Clone,
+    {
+        let Range { start, end } = self.resolve(range);
+        let end = end.max(start);
+        self.split(start);
+        self.split(end);
+        let mut runs = self.runs.range_mut(start..end).peekable();
+        std::iter::from_fn(move || {
+            let (&key, value) = runs.next()?;
+            let next = runs.peek().map_or(end, |(&key, _)| key);
+            Some((key..next, value))
+        })
+    }
+
+    /// Ensures that a run starts at the given key, unless the key is at either end of the map.
+    fn split(&mut self, key: K)
+    where
+        V: Clone,
+    {
+        if key >= self.end {
+            return;
+        }
+        let (&start, value) = self.runs.range(..=key).next_back().unwrap();
+        if start < key {
+            let value = value.clone();
+            self.runs.insert(key, value);
+        }
+    }
+
+    /// Converts the bounds into a range of keys, checking that it lies within the map.
+    ///
+    /// As keys have no notion of successor, the start must be included and the end excluded, if bounded.
+    fn resolve<R: RangeBounds<K>>(&self, range: R) -> Range<K> {
+        let keys = self.keys();
+        let start = match range.start_bound() {
+            Bound::Included(&key) => key,
+            Bound::Unbounded => keys.start,
+            Bound::Excluded(_) => panic!("the start of the range must be included"),
+        };
+        let end = match range.end_bound() {
+            Bound::Excluded(&key) => key,
+            Bound::Unbounded => keys.end,
+            Bound::Included(_) => panic!("the end of the range must be excluded"),
+        };
+        assert!(
+            keys.start <= start && end <= keys.end,
+            "range out of bounds"
+        );
+        start..end
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, AddAssign, Mul, Range, RangeBounds, Sub, SubAssign},
+};
+
+use crate::{to_index_range, Bit};
+
+/// Binary indexed tree supporting adding a value to a range and querying single values.
+///
+/// The tree stores the differences of adjacent values, so adding to a range only changes two of them.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::RangeAddBit;
+/// let mut bit = RangeAddBit::from(vec![1, 2, 3, 4, 5]);
+/// bit.add(1..4, 10);
+/// bit.add(3.., -1);
+/// assert_eq!((0..5).map(|i| bit.get(i)).collect::<Vec<_>>(), vec![1, 12, 13, 13, 4]);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` time, unless otherwise stated.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RangeAddBit<T> {
+    differences: Bit<T>,
+    len: usize,
+}
+
+impl<T> RangeAddBit<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + AddAssign + SubAssign + Clone + Default,
+{
+    /// Constructs a new tree of the given length with all values set to zero.
+    pub fn new(len: usize) -> Self {
+        Self::from(vec![T::default(); len])
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds the given value to every value on the given range.
+    pub fn add<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let Range { start, end } = to_index_range(range, self.len);
+        if start < end {
+            self.differences.add(start, value.clone());
+            if end < self.len {
+                self.differences.sub(end, value);
+            }
+        }
+    }
+
+    /// Gets the value at the given index.
+    pub fn get(&self, index: usize) -> T {
+        assert!(index < self.len, "index out of bounds");
+        self.differences.sum(..=index)
+    }
+}
+
+impl<T> From<Vec<T>> for RangeAddBit<T>
+where
+    T: Sub<Output = T> + AddAssign + Clone + Default,
+{
+    /// Constructs the tree from the given values.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n)` time.
+    fn from(values: Vec<T>) -> Self {
+        let len = values.len();
+        let mut previous = T::default();
+        let differences = values
+            .into_iter()
+            .map(|value| {
+                let difference = value.clone() - previous.clone();
+                previous = value;
+                difference
+            })
+            .collect::<Vec<_>>();
+        Self {
+            differences: Bit::from(differences),
+            len,
+        }
+    }
+}
+
+impl<T> fmt::Debug for RangeAddBit<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + AddAssign + SubAssign + Clone + Default + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.len).map(|i| self.get(i)))
+            .finish()
+    }
+}
+
+/// Binary indexed tree supporting adding a value to a range and querying the sum over a range.
+///
+/// Two trees are maintained such that the sum of the first `i` values is `i * a(i) - b(i)`, where `a(i)` and `b(i)`
+/// are prefix sums of the trees. Adding to a range changes the terms of both trees at the ends of the range.
+///
+/// The indices are converted into the value type for the multiplications, so the value type must be constructible from
+/// every index.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::RangeSumBit;
+/// let mut bit = RangeSumBit::from(vec![1, 2, 3, 4, 5]);
+/// bit.add(1..4, 10);
+/// assert_eq!(bit.sum(..), 45);
+/// assert_eq!(bit.sum(2..=3), 27);
+/// ```
+///
+/// # Time complexity
+/// All operations take `O(log n)` time, unless otherwise stated.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RangeSumBit<T> {
+    /// Differences of the values.
+    linear: Bit<T>,
+    /// Differences of the values multiplied by their indices.
+    constant: Bit<T>,
+    len: usize,
+}
+
+impl<T> RangeSumBit<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + TryFrom<usize>
+        + Clone
+        + Default,
+{
+    /// Constructs a new tree of the given length with all values set to zero.
+    pub fn new(len: usize) -> Self {
+        Self::from(vec![T::default(); len])
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds the given value to every value on the given range.
+    pub fn add<R: RangeBounds<usize>>(&mut self, range: R, value: T) {
+        let Range { start, end } = to_index_range(range, self.len);
+        if start < end {
+            self.add_difference(start, value.clone());
+            if end < self.len {
+                self.linear.sub(end, value.clone());
+                self.constant.sub(end, value * index(end));
+            }
+        }
+    }
+
+    /// Computes the sum of values on the given range.
+    pub fn sum<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let Range { start, end } = to_index_range(range, self.len);
+        if start < end {
+            self.prefix_sum(end) - self.prefix_sum(start)
+        } else {
+            T::default()
+        }
+    }
+
+    /// Computes the sum of the first `end` values.
+    fn prefix_sum(&self, end: usize) -> T {
+        if end == 0 {
+            return T::default();
+        }
+        self.linear.sum(..end) * index(end) - self.constant.sum(..end)
+    }
+
+    /// Adds the given value to the difference at the given index.
+    fn add_difference(&mut self, i: usize, value: T) {
+        self.linear.add(i, value.clone());
+        self.constant.add(i, value * index(i));
+    }
+}
+
+impl<T> From<Vec<T>> for RangeSumBit<T>
+where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + TryFrom<usize>
+        + Clone
+        + Default,
+{
+    /// Constructs the tree from the given values.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n)` time.
+    fn from(values: Vec<T>) -> Self {
+        let len = values.len();
+        let mut previous = T::default();
+        let differences: Vec<T> = values
+            .into_iter()
+            .map(|value| {
+                let difference = value.clone() - previous.clone();
+                previous = value;
+                difference
+            })
+            .collect();
+        let constant = differences
+            .iter()
+            .enumerate()
+            .map(|(i, difference)| difference.clone() * index(i))
+            .collect::<Vec<_>>();
+        Self {
+            linear: Bit::from(differences),
+            constant: Bit::from(constant),
+            len,
+        }
+    }
+}
+
+/// Converts the index into the value type.
+fn index<T: TryFrom<usize>>(i: usize) -> T {
+    T::try_from(i)
+        .ok()
+        .expect("index to be representable in the value type")
+}
@@ -0,0 +1,73 @@
+use std::ops::{Range, RangeBounds};
+
+use crate::to_index_range;
+
+/// Sparse table for folding ranges of an immutable array with an idempotent operation.
+///
+/// The operation must be associative and idempotent, that is `op(a, a) = a`, which holds for example for minimum,
+/// maximum and greatest common divisor. The table stores the folds of all ranges whose lengths are powers of two, so
+/// that any range is covered by two overlapping such ranges.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::SparseTable;
+/// let table = SparseTable::new(vec![5, 2, 8, 6, 1, 9], |a: &i32, b: &i32| *a.min(b));
+/// assert_eq!(table.query(..3), Some(2));
+/// assert_eq!(table.query(2..=3), Some(6));
+/// assert_eq!(table.query(3..), Some(1));
+/// assert_eq!(table.query(4..4), None);
+/// ```
+///
+/// # Time complexity
+/// Queries take `O(1)` time, counting the applications of the operation as constant.
+#[derive(Debug, Clone)]
+pub struct SparseTable<T, F> {
+    /// The folds of the ranges of length `2^k` starting at each index, for each `k`.
+    levels: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T, F> SparseTable<T, F>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> T,
+{
+    /// Constructs the table for the given values and operation.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n log n)` time.
+    pub fn new(values: Vec<T>, op: F) -> Self {
+        let mut levels = vec![values];
+        let mut width = 1;
+        while 2 * width <= levels[0].len() {
+            let previous = levels.last().unwrap();
+            let level = (0..previous.len() - width)
+                .map(|i| op(&previous[i], &previous[i + width]))
+                .collect();
+            levels.push(level);
+            width *= 2;
+        }
+        Self { levels, op }
+    }
+
+    /// Returns the length of the array.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Checks whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Folds the values on the given range, or returns `None` if the range is empty.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> Option<T> {
+        let Range { start, end } = to_index_range(range, self.len());
+        if start >= end {
+            return None;
+        }
+        let level = (usize::BITS - 1 - (end - start).leading_zeros()) as usize;
+        let values = &self.levels[level];
+        Some((self.op)(&values[start], &values[end - (1 << level)]))
+    }
+}
@@ -0,0 +1,212 @@
+use std::ops::{Range, RangeBounds};
+
+use crate::to_index_range;
+
+/// State of a single block of a [`SqrtDecomposition`].
+///
+/// The state typically consists of an aggregate of the values of the block, such as their sum, and of pending updates
+/// which apply to the whole block but have not yet been applied to the individual values.
+pub trait Block<T> {
+    /// Computes the state of a block containing the given values and no pending updates.
+    fn build(values: &[T]) -> Self;
+
+    /// Applies the pending updates of the block to its values.
+    ///
+    /// Called before modifying individual values of the block, after which the state is rebuilt with
+    /// [`build`](Self::build). The default implementation does nothing, which suits blocks without pending updates.
+    fn push(&mut self, values: &mut [T]) {
+        let _ = values;
+    }
+}
+
+/// Square root decomposition of an array.
+///
+/// Splits the array into blocks of about `√n` values, each of which keeps a user-defined state implementing
+/// [`Block`]. Range operations then apply to the whole blocks fully covered by the range and to the individual values
+/// on the partially covered blocks at the ends. This allows efficiently handling updates that cannot be composed, and
+/// which thus do not fit into a segment tree.
+///
+/// # Examples
+/// Range addition and range sum:
+/// ```
+/// # use comlib_range::{Block, SqrtDecomposition};
+/// struct Sum {
+///     sum: i64,
+///     add: i64,
+/// }
+///
+/// impl Block<i64> for Sum {
+///     fn build(values: &[i64]) -> Self {
+///         Sum { sum: values.iter().sum(), add: 0 }
+///     }
+///
+///     fn push(&mut self, values: &mut [i64]) {
+///         values.iter_mut().for_each(|value| *value += self.add);
+///     }
+/// }
+///
+/// let mut array = SqrtDecomposition::<_, Sum>::new(vec![1, 2, 3, 4, 5, 6]);
+/// array.update(1..5, |value| *value += 10, |block, values| {
+///     block.add += 10;
+///     block.sum += 10 * values.len() as i64;
+/// });
+/// let sum = array.fold(.., 0, |sum, block, value| sum + block.add + value, |sum, block, _| sum + block.sum);
+/// assert_eq!(sum, 61);
+/// assert_eq!(array.values(), &[1, 12, 13, 14, 15, 6]);
+/// ```
+///
+/// # Time complexity
+/// With `O(1)` callbacks, [`build`](Block::build) and [`push`](Block::push) taking linear time in the size of the block,
+/// all range operations take `O(√n)` time.
+#[derive(Debug, Clone)]
+pub struct SqrtDecomposition<T, B> {
+    values: Vec<T>,
+    blocks: Vec<B>,
+    block_size: usize,
+}
+
+impl<T, B: Block<T>> SqrtDecomposition<T, B> {
+    /// Constructs the decomposition of the given values using blocks of about `√n` values.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n)` time when [`build`](Block::build) takes linear time.
+    pub fn new(values: Vec<T>) -> Self {
+        let block_size = ((values.len() as f64).sqrt() as usize).max(1);
+        Self::with_block_size(values, block_size)
+    }
+
+    /// Constructs the decomposition of the given values using blocks of the given size.
+    ///
+    /// # Panics
+    /// Panics if the block size is zero.
+    pub fn with_block_size(values: Vec<T>, block_size: usize) -> Self {
+        assert!(block_size > 0, "the blocks must not be empty");
+        let blocks = values.chunks(block_size).map(B::build).collect();
+        Self {
+            values,
+            blocks,
+            block_size,
+        }
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks whether there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the number of values in each block, except possibly the last one.
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the states of the blocks.
+    pub fn blocks(&self) -> &[B] {
+        &self.blocks
+    }
+
+    /// Returns the values with all pending updates applied.
+    ///
+    /// # Time complexity
+    /// Takes `O(n)` time.
+    pub fn values(&mut self) -> &[T] {
+        for block in 0..self.blocks.len() {
+            self.rebuild(block, |_| {});
+        }
+        &self.values
+    }
+
+    /// Updates the values on the given range.
+    ///
+    /// Calls `full` with the state and the values of each block fully covered by the range, which must leave the state
+    /// consistent with the values. The values of the partially covered blocks are updated individually by `partial`,
+    /// after which the states of those blocks are rebuilt.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    pub fn update<R, P, F>(&mut self, range: R, partial: P, full: F)
+    where
+        R: RangeBounds<usize>,
+        P: FnMut(&mut T),
+        F: FnMut(&mut B, &mut [T]),
+    {
+        let Range { start, end } = to_index_range(range, self.len());
+        let mut partial = partial;
+        let mut full = full;
+        for (block, range) in self.split(start, end) {
+            let offset = block * self.block_size;
+            if range.len() == self.block_range(block).len() {
+                full(&mut self.blocks[block], &mut self.values[range]);
+            } else {
+                self.rebuild(block, |values| {
+                    values[range.start - offset..range.end - offset]
+                        .iter_mut()
+                        .for_each(&mut partial)
+                });
+            }
+        }
+    }
+
+    /// Folds the values on the given range into an accumulator, starting from `init`.
+    ///
+    /// Folds each block fully covered by the range with `full`, given its state and its values, and each value in the
+    /// partially covered blocks with `partial`, given the state of its block and the value. Note that the values do not
+    /// have the pending updates of their blocks applied.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    pub fn fold<A, R, P, F>(&self, range: R, init: A, partial: P, full: F) -> A
+    where
+        R: RangeBounds<usize>,
+        P: FnMut(A, &B, &T) -> A,
+        F: FnMut(A, &B, &[T]) -> A,
+    {
+        let Range { start, end } = to_index_range(range, self.len());
+        let mut partial = partial;
+        let mut full = full;
+        let mut accumulator = init;
+        for (block, range) in self.split(start, end) {
+            let state = &self.blocks[block];
+            if range.len() == self.block_range(block).len() {
+                accumulator = full(accumulator, state, &self.values[range]);
+            } else {
+                for value in &self.values[range] {
+                    accumulator = partial(accumulator, state, value);
+                }
+            }
+        }
+        accumulator
+    }
+
+    /// Returns the range of indices covered by the given block.
+    fn block_range(&self, block: usize) -> Range<usize> {
+        block * self.block_size..((block + 1) * self.block_size).min(self.len())
+    }
+
+    /// Splits the range of indices into the parts covered by each block, in increasing order.
+    fn split(&self, start: usize, end: usize) -> impl Iterator<Item = (usize, Range<usize>)> {
+        let block_size = self.block_size;
+        let blocks = if start < end {
+            start / block_size..(end - 1) / block_size + 1
+        } else {
+            0..0
+        };
+        blocks.map(move |block| {
+            let range = (block * block_size).max(start)..((block + 1) * block_size).min(end);
+            (block, range)
+        })
+    }
+
+    /// Applies the pending updates of the block, modifies its values and rebuilds its state.
+    fn rebuild<F: FnOnce(&mut [T])>(&mut self, block: usize, modify: F) {
+        let range = self.block_range(block);
+        let values = &mut self.values[range];
+        self.blocks[block].push(values);
+        modify(values);
+        self.blocks[block] = B::build(values);
+    }
+}
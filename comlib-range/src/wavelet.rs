@@ -0,0 +1,156 @@
+use std::ops::{Range, RangeBounds};
+
+use crate::to_index_range;
+
+/// Wavelet tree for order statistics on ranges.
+///
+/// Answers queries such as the `k`th smallest value on a range and the number of values less than a given value on a
+/// range. The array cannot be modified after construction.
+///
+/// The values are compressed to their ranks among the distinct values, after which the tree is stored level by level
+/// as in the [wavelet matrix](https://doi.org/10.1016/j.is.2014.06.002). Each level partitions the values by one bit of
+/// their ranks, starting from the most significant bit.
+///
+/// # Examples
+/// ```
+/// # use comlib_range::WaveletTree;
+/// let tree = WaveletTree::from(vec![5, 1, 4, 1, 3, 9, 2]);
+/// assert_eq!(tree.kth_smallest(1..5, 0), Some(&1));
+/// assert_eq!(tree.kth_smallest(1..5, 2), Some(&3));
+/// assert_eq!(tree.kth_smallest(1..5, 4), None);
+/// assert_eq!(tree.count_less_equal(.., &4), 5);
+/// assert_eq!(tree.count_less(2.., &4), 3);
+/// ```
+///
+/// # Time complexity
+/// All queries take `O(log σ)` time, where σ is the number of distinct values, unless otherwise stated.
+#[derive(Debug, Clone)]
+pub struct WaveletTree<T> {
+    /// The distinct values in increasing order.
+    values: Vec<T>,
+    /// For each level, the number of zero bits before each position.
+    zeros: Vec<Vec<u32>>,
+    len: usize,
+}
+
+impl<T: Ord> WaveletTree<T> {
+    /// Returns the length of the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the array is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Finds the `k`th smallest value, counting from zero, on the given range.
+    ///
+    /// Returns `None` if there are at most `k` values on the range.
+    pub fn kth_smallest<R: RangeBounds<usize>>(&self, range: R, k: usize) -> Option<&T> {
+        let Range { mut start, mut end } = to_index_range(range, self.len);
+        if start >= end || k >= end - start {
+            return None;
+        }
+        let mut k = k;
+        let mut rank = 0;
+        for zeros in &self.zeros {
+            let (zeros_start, zeros_end) = (zeros[start] as usize, zeros[end] as usize);
+            let left = zeros_end - zeros_start;
+            rank <<= 1;
+            if k < left {
+                start = zeros_start;
+                end = zeros_end;
+            } else {
+                let total = zeros[self.len] as usize;
+                k -= left;
+                rank |= 1;
+                start = total + start - zeros_start;
+                end = total + end - zeros_end;
+            }
+        }
+        Some(&self.values[rank])
+    }
+
+    /// Counts the values on the given range which are less than the given value.
+    pub fn count_less<R: RangeBounds<usize>>(&self, range: R, value: &T) -> usize {
+        let rank = self.values.partition_point(|v| v < value);
+        self.count_rank_less(range, rank)
+    }
+
+    /// Counts the values on the given range which are less than or equal to the given value.
+    pub fn count_less_equal<R: RangeBounds<usize>>(&self, range: R, value: &T) -> usize {
+        let rank = self.values.partition_point(|v| v <= value);
+        self.count_rank_less(range, rank)
+    }
+
+    /// Counts the values on the given range whose ranks are less than the given rank.
+    fn count_rank_less<R: RangeBounds<usize>>(&self, range: R, rank: usize) -> usize {
+        let Range { mut start, mut end } = to_index_range(range, self.len);
+        if start >= end {
+            return 0;
+        }
+        if rank >= self.values.len() {
+            return end - start;
+        }
+        let mut count = 0;
+        let levels = self.zeros.len();
+        for (level, zeros) in self.zeros.iter().enumerate() {
+            let (zeros_start, zeros_end) = (zeros[start] as usize, zeros[end] as usize);
+            if rank >> (levels - 1 - level) & 1 == 1 {
+                // All values with a zero bit are smaller
+                let total = zeros[self.len] as usize;
+                count += zeros_end - zeros_start;
+                start = total + start - zeros_start;
+                end = total + end - zeros_end;
+            } else {
+                start = zeros_start;
+                end = zeros_end;
+            }
+        }
+        count
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for WaveletTree<T> {
+    /// Constructs the wavelet tree over the given values.
+    ///
+    /// # Time complexity
+    /// Construction takes `O(n log n)` time.
+    fn from(data: Vec<T>) -> Self {
+        let len = data.len();
+        // Compress the values to their ranks among the distinct values
+        let mut order: Vec<usize> = (0..len).collect();
+        order.sort_by(|&a, &b| data[a].cmp(&data[b]));
+        let mut ranks = vec![0; len];
+        let mut distinct = 0;
+        for (i, &index) in order.iter().enumerate() {
+            if i > 0 && data[order[i - 1]] != data[index] {
+                distinct += 1;
+            }
+            ranks[index] = distinct;
+        }
+
+        let mut values = data;
+        values.sort();
+        values.dedup();
+
+        let levels = (usize::BITS - values.len().saturating_sub(1).leading_zeros()) as usize;
+        let mut zeros = Vec::with_capacity(levels);
+        for level in (0..levels).rev() {
+            let mut prefix = Vec::with_capacity(len + 1);
+            prefix.push(0);
+            for &rank in &ranks {
+                prefix.push(prefix.last().unwrap() + (rank >> level & 1 == 0) as u32);
+            }
+            zeros.push(prefix);
+            // Stable partition by the current bit for the next level
+            let (mut next, ones): (Vec<_>, Vec<_>) =
+                ranks.iter().partition(|&&rank| rank >> level & 1 == 0);
+            next.extend(ones);
+            ranks = next;
+        }
+
+        Self { values, zeros, len }
+    }
+}
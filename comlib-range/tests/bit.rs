@@ -1,4 +1,6 @@
+use comlib_math::{ModInt, RuntimePrimeModulus};
 use comlib_range::Bit;
+use comlib_testing::lcg;
 
 #[test]
 fn test_bit_sum() {
@@ -29,3 +31,92 @@ fn test_bit_add() {
     bit.sub(5, 2);
     assert_eq!(bit.sum(1..6), 18);
 }
+
+#[test]
+fn test_bit_push_pop() {
+    let values = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    let mut bit = Bit::from(Vec::new());
+    assert_eq!(bit.sum(..), 0);
+    for (i, &value) in values.iter().enumerate() {
+        bit.push(value);
+        assert_eq!(bit, Bit::from(values[..=i].to_vec()));
+    }
+    for i in (0..values.len()).rev() {
+        assert_eq!(bit.pop(), Some(values[i]));
+        assert_eq!(bit, Bit::from(values[..i].to_vec()));
+    }
+    assert_eq!(bit.pop(), None);
+}
+
+#[test]
+fn test_bit_lower_bound_kth() {
+    let mut random = lcg(1);
+
+    for n in 0..20 {
+        let values: Vec<u64> = (0..n).map(|_| random(4)).collect();
+        let bit = Bit::from(values.clone());
+        let total: u64 = values.iter().sum();
+        for target in 0..=total + 1 {
+            let expected = (0..n)
+                .find(|&i| values[..=i].iter().sum::<u64>() >= target)
+                .unwrap_or(n);
+            assert_eq!(bit.lower_bound(target), expected);
+
+            let expected = (0..n).find(|&i| values[..=i].iter().sum::<u64>() > target);
+            assert_eq!(bit.kth(target), expected);
+        }
+    }
+}
+
+#[test]
+fn test_bit_from_matches_adds() {
+    for n in 0..40 {
+        let values: Vec<i64> = (0..n).map(|i| i * i - 7 * i).collect();
+        let mut bit = Bit::from(vec![0; n as usize]);
+        for (i, &value) in values.iter().enumerate() {
+            bit.add(i, value);
+        }
+        assert_eq!(Bit::from(values), bit);
+    }
+}
+
+#[test]
+fn test_bit_access() {
+    let mut bit = Bit::from(vec![4, 8, 15, 16, 23, 42]);
+    assert_eq!(bit.len(), 6);
+    assert!(!bit.is_empty());
+    assert_eq!(bit.get(3), 16);
+    bit.add(3, 1);
+    assert_eq!(bit.get(3), 17);
+    assert_eq!(
+        bit.iter().rev().collect::<Vec<_>>(),
+        vec![42, 23, 17, 15, 8, 4]
+    );
+
+    bit.resize(3, 0);
+    assert_eq!(bit, Bit::from(vec![4, 8, 15]));
+    bit.resize(7, 2);
+    assert_eq!(bit, Bit::from(vec![4, 8, 15, 2, 2, 2, 2]));
+    assert_eq!(bit.sum(..), 35);
+    assert_eq!(format!("{:?}", bit), "[4, 8, 15, 2, 2, 2, 2]");
+
+    bit.resize(0, 0);
+    assert!(bit.is_empty());
+    assert_eq!(bit.iter().count(), 0);
+}
+
+#[test]
+fn test_bit_runtime_modulus() {
+    let p = RuntimePrimeModulus::from(11usize);
+    let zero = ModInt::from((0, p));
+    let mut bit = Bit::with_zero(Vec::new(), zero);
+    assert_eq!(bit.sum(..), zero);
+    for v in 0..20usize {
+        bit.push(ModInt::from((v, p)));
+    }
+    assert_eq!(*bit.sum(..), 190 % 11);
+    assert_eq!(*bit.sum(5..10), 35 % 11);
+    bit.sub(7, ModInt::from((3, p)));
+    assert_eq!(*bit.get(7), 4);
+    assert_eq!(bit.pop().map(ModInt::into_inner), Some(19 % 11));
+}
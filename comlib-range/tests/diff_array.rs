@@ -0,0 +1,41 @@
+use comlib_range::DiffArray;
+use comlib_testing::lcg;
+
+#[test]
+fn test_diff_array() {
+    let mut diff = DiffArray::new(4);
+    assert_eq!(diff.len(), 4);
+    diff.add(.., 1);
+    diff.add(2..2, 100);
+    diff.add(1..=2, -3);
+    assert_eq!(diff.build(), vec![1, -2, -2, 1]);
+
+    let empty = DiffArray::<i32>::new(0);
+    assert!(empty.is_empty());
+    assert!(empty.build().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_diff_array_out_of_bounds() {
+    let mut diff = DiffArray::new(3);
+    diff.add(1..4, 1);
+}
+
+#[test]
+fn test_diff_array_random() {
+    let mut random = lcg(1);
+
+    for n in 0..20 {
+        let mut array = vec![0i64; n];
+        let mut diff = DiffArray::new(n);
+        for _ in 0..50 {
+            let (a, b) = (random(n as u64 + 1) as usize, random(n as u64 + 1) as usize);
+            let (start, end) = (a.min(b), a.max(b));
+            let value = random(20) as i64 - 10;
+            array[start..end].iter_mut().for_each(|v| *v += value);
+            diff.add(start..end, value);
+        }
+        assert_eq!(diff.build(), array);
+    }
+}
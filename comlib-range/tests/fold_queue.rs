@@ -0,0 +1,54 @@
+use comlib_range::{sliding_window_fold, FoldQueue};
+use comlib_testing::lcg;
+
+#[test]
+fn test_fold_queue() {
+    // Composition of affine maps x -> a * x + b, which is not commutative
+    let compose = |&(a, b): &(i64, i64), &(c, d): &(i64, i64)| (a * c, b * c + d);
+    let mut queue = FoldQueue::new(compose);
+    assert_eq!(queue.fold(), None);
+    assert_eq!(queue.pop(), None);
+    queue.push((2, 1));
+    queue.push((3, 0));
+    assert_eq!(queue.fold(), Some((6, 3)));
+    assert_eq!(queue.pop(), Some((2, 1)));
+    queue.push((1, 5));
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.fold(), Some((3, 5)));
+    assert_eq!(queue.pop(), Some((3, 0)));
+    assert_eq!(queue.pop(), Some((1, 5)));
+    assert!(queue.is_empty());
+    assert_eq!(queue.fold(), None);
+}
+
+#[test]
+fn test_fold_queue_random() {
+    let mut random = lcg(1);
+
+    // Concatenation of strings
+    let concat = |a: &String, b: &String| format!("{}{}", a, b);
+    let mut queue = FoldQueue::new(concat);
+    let mut expected = std::collections::VecDeque::new();
+    for _ in 0..1000 {
+        if random(3) == 0 {
+            assert_eq!(queue.pop(), expected.pop_front());
+        } else {
+            let value = ((b'a' + random(26) as u8) as char).to_string();
+            queue.push(value.clone());
+            expected.push_back(value);
+        }
+        assert_eq!(queue.len(), expected.len());
+        let fold = expected.iter().cloned().reduce(|a, b| a + &b);
+        assert_eq!(queue.fold(), fold);
+    }
+}
+
+#[test]
+fn test_sliding_window_fold() {
+    let values = [3, 1, 4, 1, 5, 9, 2, 6];
+    for k in 1..=values.len() + 1 {
+        let sums: Vec<_> = sliding_window_fold(&values, k, |a, b| a + b).collect();
+        let expected: Vec<_> = values.windows(k).map(|w| w.iter().sum::<i32>()).collect();
+        assert_eq!(sums, expected);
+    }
+}
@@ -0,0 +1,29 @@
+use std::ops::Bound;
+
+use comlib_range::to_index_range;
+
+#[test]
+fn test_to_index_range() {
+    assert_eq!(to_index_range(.., 0), 0..0);
+    assert_eq!(to_index_range(.., 4), 0..4);
+    assert_eq!(to_index_range(1.., 4), 1..4);
+    assert_eq!(to_index_range(..=2, 4), 0..3);
+    assert_eq!(to_index_range(4..4, 4), 4..4);
+    assert_eq!(
+        to_index_range((Bound::Excluded(0), Bound::Included(3)), 4),
+        1..4
+    );
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_to_index_range_past_end() {
+    let _ = to_index_range(2..=4, 4);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_to_index_range_reversed() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = to_index_range(3..2, 4);
+}
@@ -0,0 +1,70 @@
+use comlib_range::IntervalSet;
+use comlib_testing::lcg;
+
+#[test]
+fn test_interval_set() {
+    let mut set: IntervalSet<i32> = vec![5..7, 1..3, 3..4, 10..10].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..4, 5..7]);
+    assert_eq!(set.covered_len(), 5);
+    assert_eq!(set.get(3), Some(1..4));
+    assert_eq!(set.get(4), None);
+    assert!(set.overlaps(3..5));
+    assert!(!set.overlaps(4..5));
+    assert!(set.overlaps(0..100));
+    assert!(!set.overlaps(6..6));
+
+    set.insert(0..6);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..7]);
+    set.remove(2..3);
+    set.remove(6..10);
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..2, 3..6]);
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_interval_set_random() {
+    let mut random = lcg(1);
+
+    const N: usize = 40;
+    let mut set = IntervalSet::new();
+    let mut covered = [false; N];
+    for _ in 0..1000 {
+        let (a, b) = (random(N as u64) as usize, random(N as u64) as usize);
+        let (start, end) = (a.min(b), a.max(b));
+        match random(3) {
+            0 => {
+                set.insert(start..end);
+                covered[start..end].iter_mut().for_each(|c| *c = true);
+            }
+            1 => {
+                set.remove(start..end);
+                covered[start..end].iter_mut().for_each(|c| *c = false);
+            }
+            _ => {
+                assert_eq!(
+                    set.overlaps(start..end),
+                    covered[start..end].contains(&true)
+                );
+            }
+        }
+
+        // The intervals must be exactly the maximal runs of covered points
+        let mut expected = Vec::new();
+        let mut i = 0;
+        while i < N {
+            if covered[i] {
+                let start = i;
+                while i < N && covered[i] {
+                    i += 1;
+                }
+                expected.push(start..i);
+            } else {
+                i += 1;
+            }
+        }
+        assert_eq!(set.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(set.covered_len(), covered.iter().filter(|&&c| c).count());
+        let point = random(N as u64) as usize;
+        assert_eq!(set.contains(point), covered[point]);
+    }
+}
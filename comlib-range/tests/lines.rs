@@ -0,0 +1,102 @@
+use comlib_range::{Extremum, LiChaoTree, MonotoneCht};
+use comlib_testing::Random;
+
+fn brute_force(lines: &[(i64, i64, i64, i64)], x: i64, extremum: Extremum) -> Option<i64> {
+    let values = lines
+        .iter()
+        .filter(|&&(_, _, start, end)| start <= x && x <= end)
+        .map(|&(k, m, _, _)| k * x + m);
+    match extremum {
+        Extremum::Minimum => values.min(),
+        Extremum::Maximum => values.max(),
+    }
+}
+
+#[test]
+fn test_monotone_cht_random() {
+    let mut random = Random::new(1);
+    for round in 0..200 {
+        let extremum = if round % 2 == 0 {
+            Extremum::Minimum
+        } else {
+            Extremum::Maximum
+        };
+        let n = random.range(1, 12) as usize;
+        let mut slopes: Vec<i64> = (0..n).map(|_| random.range(-10, 10)).collect();
+        slopes.sort_unstable();
+        if round % 4 < 2 {
+            slopes.reverse();
+        }
+        let mut cht = MonotoneCht::new(extremum);
+        let mut lines = Vec::new();
+        for slope in slopes {
+            let intercept = random.range(-50, 50);
+            cht.insert(slope, intercept);
+            lines.push((slope, intercept, i64::MIN, i64::MAX));
+            assert!(cht.len() <= lines.len());
+            for x in -30..=30 {
+                assert_eq!(cht.query(x), brute_force(&lines, x, extremum));
+            }
+        }
+    }
+    assert_eq!(MonotoneCht::new(Extremum::Minimum).query(0), None);
+}
+
+#[test]
+fn test_li_chao_tree_random() {
+    let mut random = Random::new(2);
+    for round in 0..100 {
+        let extremum = if round % 2 == 0 {
+            Extremum::Minimum
+        } else {
+            Extremum::Maximum
+        };
+        let (low, high) = (random.range(-20, 0), random.range(0, 20));
+        let mut tree = LiChaoTree::new(low..=high, extremum);
+        let mut lines = Vec::new();
+        for _ in 0..15 {
+            let (k, m) = (random.range(-10, 10), random.range(-50, 50));
+            if random.next(2) == 0 {
+                tree.insert(k, m);
+                lines.push((k, m, i64::MIN, i64::MAX));
+            } else {
+                let (a, b) = (random.range(-25, 25), random.range(-25, 25));
+                tree.insert_segment(k, m, a.min(b)..=a.max(b));
+                lines.push((k, m, a.min(b), a.max(b)));
+            }
+            for x in low..=high {
+                assert_eq!(tree.query(x), brute_force(&lines, x, extremum));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lines_extreme_values() {
+    let mut tree = LiChaoTree::new(i64::MIN..=i64::MAX, Extremum::Minimum);
+    tree.insert(1, 0);
+    tree.insert(-1, 0);
+    tree.insert(0, i64::MAX);
+    assert_eq!(tree.query(i64::MIN), Some(i64::MIN));
+    assert_eq!(tree.query(i64::MAX), Some(-i64::MAX));
+    assert_eq!(tree.query(0), Some(0));
+
+    let mut cht = MonotoneCht::new(Extremum::Maximum);
+    cht.insert(-i64::MAX, 0);
+    cht.insert(0, i64::MIN);
+    cht.insert(i64::MAX, 0);
+    assert_eq!(cht.query(0), Some(0));
+    assert_eq!(cht.query(1), Some(i64::MAX));
+    assert_eq!(cht.query(-1), Some(i64::MAX));
+    assert_eq!(cht.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "the slopes must be monotone")]
+fn test_monotone_cht_unsorted() {
+    let mut cht = MonotoneCht::new(Extremum::Minimum);
+    cht.insert(1, 0);
+    cht.insert(2, 0);
+    cht.insert(2, 1);
+    cht.insert(0, 0);
+}
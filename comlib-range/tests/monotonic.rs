@@ -0,0 +1,52 @@
+use std::cmp::Reverse;
+
+use comlib_range::{sliding_window_max, sliding_window_min, MonotonicQueue};
+use comlib_testing::lcg;
+
+#[test]
+fn test_monotonic_queue() {
+    let mut queue = MonotonicQueue::new();
+    assert_eq!(queue.min(), None);
+    assert!(!queue.pop());
+    for &value in &[5, 3, 3, 4, 1] {
+        queue.push(Reverse(value));
+    }
+    assert_eq!(queue.len(), 5);
+    assert_eq!(queue.min(), Some(&Reverse(5)));
+    queue.pop();
+    assert_eq!(queue.min(), Some(&Reverse(4)));
+    queue.pop();
+    queue.pop();
+    queue.pop();
+    assert_eq!(queue.min(), Some(&Reverse(1)));
+    queue.pop();
+    assert!(queue.is_empty());
+    assert_eq!(queue.min(), None);
+}
+
+#[test]
+fn test_sliding_window_random() {
+    let mut random = lcg(1);
+
+    for n in 0..30 {
+        let values: Vec<u64> = (0..n).map(|_| random(10)).collect();
+        for k in 1..=n + 1 {
+            let minimums: Vec<_> = sliding_window_min(&values, k).copied().collect();
+            let maximums: Vec<_> = sliding_window_max(&values, k).copied().collect();
+            let windows = values.windows(k);
+            assert_eq!(
+                minimums,
+                windows
+                    .clone()
+                    .map(|w| *w.iter().min().unwrap())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                maximums,
+                windows
+                    .map(|w| *w.iter().max().unwrap())
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use comlib_range::{DiffArray2d, Prefix2d};
+use comlib_testing::lcg;
+
+#[test]
+fn test_prefix2d_sum() {
+    let prefix = Prefix2d::from(vec![
+        vec![1, 2, 3, 4],
+        vec![5, 6, 7, 8],
+        vec![9, 10, 11, 12],
+    ]);
+    assert_eq!((prefix.rows(), prefix.cols()), (3, 4));
+    assert_eq!(prefix.sum(.., ..), 78);
+    assert_eq!(prefix.sum(1..=2, 1..3), 34);
+    assert_eq!(prefix.sum(2.., ..1), 9);
+    assert_eq!(prefix.sum(1..1, ..), 0);
+    assert_eq!(
+        format!("{:?}", prefix),
+        "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+    );
+
+    let empty = Prefix2d::<i32>::from(vec![]);
+    assert_eq!(empty.sum(.., ..), 0);
+}
+
+#[test]
+#[should_panic(expected = "equal lengths")]
+fn test_prefix2d_ragged() {
+    let _ = Prefix2d::from(vec![vec![1, 2], vec![3]]);
+}
+
+#[test]
+fn test_diff_array2d_random() {
+    let mut random = lcg(1);
+
+    for (rows, cols) in [(1, 1), (3, 5), (6, 2), (7, 7)] {
+        let mut matrix = vec![vec![0i64; cols]; rows];
+        let mut diff = DiffArray2d::new(rows, cols);
+        for _ in 0..50 {
+            let (a, b) = (
+                random(rows as u64 + 1) as usize,
+                random(rows as u64 + 1) as usize,
+            );
+            let (c, d) = (
+                random(cols as u64 + 1) as usize,
+                random(cols as u64 + 1) as usize,
+            );
+            let (top, bottom, left, right) = (a.min(b), a.max(b), c.min(d), c.max(d));
+            let value = random(20) as i64 - 10;
+            for row in &mut matrix[top..bottom] {
+                row[left..right].iter_mut().for_each(|v| *v += value);
+            }
+            diff.add(top..bottom, left..right, value);
+        }
+        assert_eq!(diff.build(), matrix);
+
+        let prefix = Prefix2d::from(matrix.clone());
+        for top in 0..=rows {
+            for bottom in top..=rows {
+                for left in 0..=cols {
+                    for right in left..=cols {
+                        let expected: i64 = matrix[top..bottom]
+                            .iter()
+                            .map(|row| row[left..right].iter().sum::<i64>())
+                            .sum();
+                        assert_eq!(prefix.sum(top..bottom, left..right), expected);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use comlib_range::RangeAssignMap;
+use comlib_testing::lcg;
+
+#[test]
+fn test_range_assign_map() {
+    let mut map = RangeAssignMap::new(-5..5, 'a');
+    assert_eq!(map.keys(), -5..5);
+    assert_eq!(map.run_count(), 1);
+    map.assign(-2..2, 'b');
+    map.assign(0.., 'c');
+    map.assign(3..3, 'd');
+    assert_eq!(
+        map.runs(..).collect::<Vec<_>>(),
+        vec![(-5..-2, &'a'), (-2..0, &'b'), (0..5, &'c')]
+    );
+    assert_eq!(map.runs(-1..-1).count(), 0);
+    assert_eq!(map.runs(1..2).collect::<Vec<_>>(), vec![(1..2, &'c')]);
+    assert_eq!(map.get(-5), &'a');
+    assert_eq!(map.get(4), &'c');
+
+    for (range, value) in map.runs_mut(-3..1) {
+        assert!(range.start >= -3 && range.end <= 1);
+        *value = 'e';
+    }
+    assert_eq!(
+        map.runs(..).collect::<Vec<_>>(),
+        vec![
+            (-5..-3, &'a'),
+            (-3..-2, &'e'),
+            (-2..0, &'e'),
+            (0..1, &'e'),
+            (1..5, &'c')
+        ]
+    );
+    map.assign(.., 'f');
+    assert_eq!(map.run_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_range_assign_map_out_of_bounds() {
+    let mut map = RangeAssignMap::new(0..10, 0);
+    map.assign(5..11, 1);
+}
+
+#[test]
+fn test_range_assign_map_random() {
+    let mut random = lcg(1);
+
+    const N: usize = 50;
+    let mut map = RangeAssignMap::new(0..N, 0);
+    let mut values = [0; N];
+    for _ in 0..1000 {
+        let (a, b) = (random(N as u64 + 1) as usize, random(N as u64 + 1) as usize);
+        let (start, end) = (a.min(b), a.max(b));
+        match random(3) {
+            0 => {
+                let value = random(5);
+                map.assign(start..end, value);
+                values[start..end].iter_mut().for_each(|v| *v = value);
+            }
+            1 => {
+                for (_, value) in map.runs_mut(start..end) {
+                    *value += 1;
+                }
+                values[start..end].iter_mut().for_each(|v| *v += 1);
+            }
+            _ => {
+                let mut expected = start;
+                for (range, &value) in map.runs(start..end) {
+                    assert_eq!(range.start, expected);
+                    assert!(range.start < range.end);
+                    assert!(values[range.clone()].iter().all(|&v| v == value));
+                    expected = range.end;
+                }
+                assert_eq!(expected, end);
+            }
+        }
+        let point = random(N as u64) as usize;
+        assert_eq!(map.get(point), &values[point]);
+    }
+}
@@ -0,0 +1,59 @@
+use comlib_range::{RangeAddBit, RangeSumBit};
+use comlib_testing::lcg;
+
+#[test]
+fn test_range_add_bit() {
+    let mut bit = RangeAddBit::from(vec![5, 1, 4, 2, 3]);
+    bit.add(1..=3, 2);
+    bit.add(..2, -1);
+    bit.add(4.., 10);
+    bit.add(2..2, 100);
+    assert_eq!(format!("{:?}", bit), "[4, 2, 6, 4, 13]");
+    assert_eq!(bit.len(), 5);
+
+    let mut bit = RangeAddBit::new(3);
+    bit.add(.., 7);
+    assert_eq!(bit.get(2), 7);
+}
+
+#[test]
+fn test_range_sum_bit() {
+    let mut bit = RangeSumBit::from(vec![1i64, 2, 3, 4, 5]);
+    assert_eq!(bit.sum(..), 15);
+    bit.add(1..3, 10);
+    assert_eq!(bit.sum(..), 35);
+    assert_eq!(bit.sum(0..1), 1);
+    assert_eq!(bit.sum(2..=4), 22);
+    assert_eq!(bit.sum(3..3), 0);
+
+    let bit = RangeSumBit::<i64>::new(0);
+    assert!(bit.is_empty());
+    assert_eq!(bit.sum(..), 0);
+}
+
+#[test]
+fn test_range_bits_random() {
+    let mut random = lcg(1);
+
+    for n in 1..20 {
+        let mut values: Vec<i64> = (0..n).map(|_| random(100) as i64 - 50).collect();
+        let mut add_bit = RangeAddBit::from(values.clone());
+        let mut sum_bit = RangeSumBit::from(values.clone());
+        for _ in 0..100 {
+            let a = random(n as u64 + 1) as usize;
+            let b = random(n as u64 + 1) as usize;
+            let (start, end) = (a.min(b), a.max(b));
+            if random(2) == 0 {
+                let value = random(100) as i64 - 50;
+                values[start..end].iter_mut().for_each(|v| *v += value);
+                add_bit.add(start..end, value);
+                sum_bit.add(start..end, value);
+            } else {
+                assert_eq!(sum_bit.sum(start..end), values[start..end].iter().sum());
+            }
+        }
+        for (i, &value) in values.iter().enumerate() {
+            assert_eq!(add_bit.get(i), value);
+        }
+    }
+}
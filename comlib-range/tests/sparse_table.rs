@@ -0,0 +1,31 @@
+use comlib_range::SparseTable;
+use comlib_testing::lcg;
+
+#[test]
+fn test_sparse_table() {
+    let empty = SparseTable::new(Vec::<u32>::new(), |a: &u32, b: &u32| *a.max(b));
+    assert!(empty.is_empty());
+    assert_eq!(empty.query(..), None);
+
+    let table = SparseTable::new(vec![3], |a: &u32, b: &u32| *a.max(b));
+    assert_eq!(table.len(), 1);
+    assert_eq!(table.query(..), Some(3));
+}
+
+#[test]
+fn test_sparse_table_random() {
+    let mut random = lcg(1);
+
+    for n in 0..40 {
+        let values: Vec<u64> = (0..n).map(|_| random(1000)).collect();
+        let minimum = SparseTable::new(values.clone(), |a: &u64, b: &u64| *a.min(b));
+        let maximum = SparseTable::new(values.clone(), |a: &u64, b: &u64| *a.max(b));
+        for start in 0..=n {
+            for end in start..=n {
+                let range = &values[start..end];
+                assert_eq!(minimum.query(start..end), range.iter().copied().min());
+                assert_eq!(maximum.query(start..end), range.iter().copied().max());
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use comlib_range::{Block, SqrtDecomposition};
+use comlib_testing::lcg;
+
+/// Sum and maximum of a block, supporting lazy addition.
+#[derive(Debug)]
+struct AddBlock {
+    sum: i64,
+    max: i64,
+    add: i64,
+}
+
+impl Block<i64> for AddBlock {
+    fn build(values: &[i64]) -> Self {
+        AddBlock {
+            sum: values.iter().sum(),
+            max: values.iter().copied().max().unwrap(),
+            add: 0,
+        }
+    }
+
+    fn push(&mut self, values: &mut [i64]) {
+        values.iter_mut().for_each(|value| *value += self.add);
+    }
+}
+
+/// Sum and maximum of a block, without pending updates.
+struct ModBlock {
+    sum: u64,
+    max: u64,
+}
+
+impl Block<u64> for ModBlock {
+    fn build(values: &[u64]) -> Self {
+        ModBlock {
+            sum: values.iter().sum(),
+            max: values.iter().copied().max().unwrap(),
+        }
+    }
+}
+
+#[test]
+fn test_sqrt_decomposition_add() {
+    let mut array = SqrtDecomposition::<_, AddBlock>::with_block_size(vec![3, 1, 4, 1, 5, 9, 2], 3);
+    assert_eq!(array.len(), 7);
+    assert_eq!(array.block_size(), 3);
+    assert_eq!(array.blocks().len(), 3);
+    array.update(
+        2..=6,
+        |value| *value -= 2,
+        |block, values| {
+            block.add -= 2;
+            block.sum -= 2 * values.len() as i64;
+            block.max -= 2;
+        },
+    );
+    assert_eq!(array.blocks()[1].add, -2);
+    assert_eq!(array.values(), &[3, 1, 2, -1, 3, 7, 0]);
+    assert_eq!(array.blocks()[1].add, 0);
+
+    let empty = SqrtDecomposition::<i64, AddBlock>::new(vec![]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.fold(.., 0, |_, _, _| panic!(), |_, _, _| panic!()), 0);
+}
+
+#[test]
+fn test_sqrt_decomposition_random() {
+    let mut random = lcg(1);
+
+    for n in 1..40 {
+        let mut expected: Vec<i64> = (0..n).map(|_| random(100) as i64).collect();
+        let mut array = SqrtDecomposition::<_, AddBlock>::new(expected.clone());
+        for _ in 0..50 {
+            let (a, b) = (random(n + 1) as usize, random(n + 1) as usize);
+            let (start, end) = (a.min(b), a.max(b));
+            if random(2) == 0 {
+                let add = random(21) as i64 - 10;
+                array.update(
+                    start..end,
+                    |value| *value += add,
+                    |block, values| {
+                        block.add += add;
+                        block.sum += add * values.len() as i64;
+                        block.max += add;
+                    },
+                );
+                expected[start..end].iter_mut().for_each(|v| *v += add);
+            } else {
+                let (sum, max) = array.fold(
+                    start..end,
+                    (0, i64::MIN),
+                    |(sum, max), block, value| {
+                        (sum + value + block.add, max.max(value + block.add))
+                    },
+                    |(sum, max), block, _| (sum + block.sum, max.max(block.max)),
+                );
+                assert_eq!(sum, expected[start..end].iter().sum::<i64>());
+                assert_eq!(
+                    max,
+                    expected[start..end]
+                        .iter()
+                        .copied()
+                        .max()
+                        .unwrap_or(i64::MIN)
+                );
+            }
+        }
+        assert_eq!(array.values(), &expected[..]);
+    }
+}
+
+#[test]
+fn test_sqrt_decomposition_modulo() {
+    let mut random = lcg(1);
+
+    // Taking values modulo a number does not compose, but touches each value only O(log value) times
+    let n = 100;
+    let mut expected: Vec<u64> = (0..n).map(|_| random(1000)).collect();
+    let mut array = SqrtDecomposition::<_, ModBlock>::new(expected.clone());
+    for _ in 0..200 {
+        let (a, b) = (random(n + 1) as usize, random(n + 1) as usize);
+        let (start, end) = (a.min(b), a.max(b));
+        let modulus = random(100) + 1;
+        array.update(
+            start..end,
+            |value| *value %= modulus,
+            |block, values| {
+                if block.max >= modulus {
+                    values.iter_mut().for_each(|value| *value %= modulus);
+                    *block = ModBlock::build(values);
+                }
+            },
+        );
+        expected[start..end].iter_mut().for_each(|v| *v %= modulus);
+
+        let sum = array.fold(
+            ..,
+            0,
+            |sum, _, value| sum + value,
+            |sum, block, _| sum + block.sum,
+        );
+        assert_eq!(sum, expected.iter().sum::<u64>());
+    }
+    assert_eq!(array.values(), &expected[..]);
+}
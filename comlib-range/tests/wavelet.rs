@@ -0,0 +1,52 @@
+use comlib_range::WaveletTree;
+use comlib_testing::lcg;
+
+#[test]
+fn test_wavelet_tree_small() {
+    let tree = WaveletTree::from(vec!["pear", "apple", "fig", "apple"]);
+    assert_eq!(tree.len(), 4);
+    assert_eq!(tree.kth_smallest(.., 0), Some(&"apple"));
+    assert_eq!(tree.kth_smallest(.., 1), Some(&"apple"));
+    assert_eq!(tree.kth_smallest(..3, 2), Some(&"pear"));
+    assert_eq!(tree.kth_smallest(2..2, 0), None);
+    assert_eq!(tree.count_less(.., &"fig"), 2);
+    assert_eq!(tree.count_less_equal(1..=2, &"fig"), 2);
+    assert_eq!(tree.count_less_equal(.., &"zebra"), 4);
+    assert_eq!(tree.count_less(.., &"a"), 0);
+
+    let single = WaveletTree::from(vec![7, 7, 7]);
+    assert_eq!(single.kth_smallest(1.., 1), Some(&7));
+    assert_eq!(single.count_less(.., &7), 0);
+    assert_eq!(single.count_less_equal(.., &7), 3);
+
+    let empty = WaveletTree::<i32>::from(vec![]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.kth_smallest(.., 0), None);
+    assert_eq!(empty.count_less_equal(.., &0), 0);
+}
+
+#[test]
+fn test_wavelet_tree_random() {
+    let mut random = lcg(1);
+
+    for n in 1..30 {
+        let range = random(20) + 1;
+        let values: Vec<i64> = (0..n).map(|_| random(range) as i64 - 5).collect();
+        let tree = WaveletTree::from(values.clone());
+        for start in 0..=n {
+            for end in start..=n {
+                let mut sorted = values[start..end].to_vec();
+                sorted.sort_unstable();
+                for k in 0..=sorted.len() {
+                    assert_eq!(tree.kth_smallest(start..end, k), sorted.get(k));
+                }
+                for x in -7..range as i64 - 3 {
+                    let less = sorted.iter().filter(|&&v| v < x).count();
+                    let less_equal = sorted.iter().filter(|&&v| v <= x).count();
+                    assert_eq!(tree.count_less(start..end, &x), less);
+                    assert_eq!(tree.count_less_equal(start..end, &x), less_equal);
+                }
+            }
+        }
+    }
+}
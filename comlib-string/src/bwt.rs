@@ -0,0 +1,71 @@
+use crate::SuffixArray;
+
+/// Computes the Burrows–Wheeler transform of the given sequence.
+///
+/// The transform is defined for the sequence with a unique sentinel smaller than every element appended to it. It
+/// consists of the last elements of the sorted rotations of that sequence. The sentinel is not included in the
+/// returned sequence, instead its position in the transform is returned alongside it.
+///
+/// # Time complexity
+/// Takes O(n log n) time, as the rotations are sorted using the [suffix array](SuffixArray).
+///
+/// # Examples
+/// ```
+/// # use comlib_string::{burrows_wheeler_transform, inverse_burrows_wheeler_transform};
+/// let (bwt, sentinel) = burrows_wheeler_transform(b"banana");
+/// assert_eq!((&bwt[..], sentinel), (&b"annbaa"[..], 4));
+/// assert_eq!(inverse_burrows_wheeler_transform(&bwt, sentinel), b"banana");
+/// ```
+pub fn burrows_wheeler_transform<T: Ord + Clone>(s: &[T]) -> (Vec<T>, usize) {
+    let sa = SuffixArray::new(s);
+    let mut bwt = Vec::with_capacity(s.len());
+    // The rotation starting with the sentinel comes first
+    bwt.extend(s.last().cloned());
+    let mut sentinel = 0;
+    for (rank, &start) in sa.suffixes().iter().enumerate() {
+        match start.checked_sub(1) {
+            Some(previous) => bwt.push(s[previous].clone()),
+            None => sentinel = rank + 1,
+        }
+    }
+    (bwt, sentinel)
+}
+
+/// Inverts the [Burrows–Wheeler transform](burrows_wheeler_transform).
+///
+/// The transform is given without the sentinel, together with the position of the sentinel in it.
+///
+/// # Panics
+/// Panics if the position of the sentinel is greater than the length of the transform.
+///
+/// # Time complexity
+/// Takes O(n log n) time.
+pub fn inverse_burrows_wheeler_transform<T: Ord + Clone>(bwt: &[T], sentinel: usize) -> Vec<T> {
+    assert!(sentinel <= bwt.len(), "sentinel out of bounds");
+    let n = bwt.len();
+    // The last column of the sorted rotations, with the sentinel as `None`
+    let last: Vec<Option<&T>> = bwt[..sentinel]
+        .iter()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(bwt[sentinel..].iter().map(Some))
+        .collect();
+    // Sorting the last column stably gives the first column. The `i`th occurrence of an element in both of them
+    // corresponds to the same position of the original sequence.
+    let mut first: Vec<usize> = (0..=n).collect();
+    first.sort_by_key(|&i| last[i]);
+    let mut previous_row = vec![0; n + 1];
+    for (row, &i) in first.iter().enumerate() {
+        previous_row[i] = row;
+    }
+
+    // Walk backwards from the rotation starting with the sentinel
+    let mut s = Vec::with_capacity(n);
+    let mut row = 0;
+    for _ in 0..n {
+        s.push(last[row].unwrap().clone());
+        row = previous_row[row];
+    }
+    s.reverse();
+    s
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Computes the edit distance between the given sequences.
+///
+/// The [edit distance], also known as Levenshtein distance, is the minimum number of insertions, deletions and
+/// substitutions of single elements needed to transform one sequence into the other.
+///
+/// # Time complexity
+/// Takes O(nm) time and O(m) memory, where m is the length of `a` and n is the length of `b`. See
+/// [`edit_distance_bitset`] for a faster variant.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::edit_distance;
+/// assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+/// ```
+///
+/// [edit distance]: https://en.wikipedia.org/wiki/Levenshtein_distance
+pub fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    // Distances from the prefixes of `a` to the current prefix of `b`
+    let mut row: Vec<usize> = (0..=a.len()).collect();
+    for (j, y) in b.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = j + 1;
+        for (i, x) in a.iter().enumerate() {
+            let substitute = diagonal + (x != y) as usize;
+            diagonal = row[i + 1];
+            row[i + 1] = substitute.min(row[i] + 1).min(row[i + 1] + 1);
+        }
+    }
+    row[a.len()]
+}
+
+/// Computes the edit distance between the given sequences using bit-parallelism.
+///
+/// Gives the same result as [`edit_distance`], but processes 64 elements of `a` at a time using [Myers' algorithm].
+///
+/// # Time complexity
+/// Takes O(n⌈m/64⌉ + m) time, where m is the length of `a` and n is the length of `b`.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::edit_distance_bitset;
+/// assert_eq!(edit_distance_bitset(b"kitten", b"sitting"), 3);
+/// ```
+///
+/// [Myers' algorithm]: https://doi.org/10.1145/316542.316550
+pub fn edit_distance_bitset<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let blocks = a.len().div_ceil(64);
+    if blocks == 0 {
+        return b.len();
+    }
+    let masks = match_masks(a);
+    let empty = vec![0; blocks];
+    // Bit `i` of the vertical vectors tells whether the distance increases or decreases from row `i` to `i + 1` of the
+    // current column
+    let mut positive = vec![!0u64; blocks];
+    let mut negative = vec![0u64; blocks];
+    let last_bit = (a.len() - 1) % 64;
+    let mut distance = a.len();
+    for y in b {
+        let eq = masks.get(y).unwrap_or(&empty);
+        // The distance in the first row increases by one in each column
+        let mut carry: i32 = 1;
+        for k in 0..blocks {
+            let (pv, mv) = (positive[k], negative[k]);
+            let carry_negative = (carry < 0) as u64;
+            let xv = eq[k] | mv;
+            let eq = eq[k] | carry_negative;
+            let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+            let mut ph = mv | !(xh | pv);
+            let mut mh = pv & xh;
+            if k + 1 == blocks {
+                distance += (ph >> last_bit & 1) as usize;
+                distance -= (mh >> last_bit & 1) as usize;
+            }
+            let carry_positive = (carry > 0) as u64;
+            carry = (ph >> 63) as i32 - (mh >> 63) as i32;
+            ph = ph << 1 | carry_positive;
+            mh = mh << 1 | carry_negative;
+            positive[k] = mh | !(xv | ph);
+            negative[k] = ph & xv;
+        }
+    }
+    distance
+}
+
+/// Finds a longest common subsequence of the given sequences.
+///
+/// Returns the pairs of indices of the matched elements in `a` and `b`, in increasing order. The length of the longest
+/// common subsequence is the number of pairs.
+///
+/// # Time complexity
+/// Takes O(nm) time and memory. See [`lcs_length_bitset`] for a faster way to compute only the length.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::longest_common_subsequence;
+/// let lcs = longest_common_subsequence(b"abcbdab", b"bdcaba");
+/// assert_eq!(lcs.len(), 4);
+/// assert!(lcs.iter().all(|&(i, j)| b"abcbdab"[i] == b"bdcaba"[j]));
+/// ```
+pub fn longest_common_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    // `table[i * (m + 1) + j]` is the length of the LCS of the suffixes `a[i..]` and `b[j..]`
+    let mut table = vec![0u32; (n + 1) * (m + 1)];
+    let index = |i: usize, j: usize| i * (m + 1) + j;
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[index(i, j)] = if a[i] == b[j] {
+                table[index(i + 1, j + 1)] + 1
+            } else {
+                table[index(i + 1, j)].max(table[index(i, j + 1)])
+            };
+        }
+    }
+
+    let mut pairs = Vec::with_capacity(table[0] as usize);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[index(i + 1, j)] >= table[index(i, j + 1)] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Computes the length of a longest common subsequence of the given sequences using bit-parallelism.
+///
+/// # Time complexity
+/// Takes O(n⌈m/64⌉ + m) time, where m is the length of `a` and n is the length of `b`.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::lcs_length_bitset;
+/// assert_eq!(lcs_length_bitset(b"abcbdab", b"bdcaba"), 4);
+/// ```
+pub fn lcs_length_bitset<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    let blocks = a.len().div_ceil(64);
+    let masks = match_masks(a);
+    // The zero bits of `row` mark the positions of `a` where the LCS with the current prefix of `b` increases
+    let mut row = vec![!0u64; blocks];
+    for y in b {
+        let eq = match masks.get(y) {
+            Some(eq) => eq,
+            None => continue,
+        };
+        // Compute `(row + (row & eq)) | (row & !eq)`
+        let mut carry = false;
+        for (v, &eq) in row.iter_mut().zip(eq) {
+            let u = *v & eq;
+            let (sum, overflow1) = v.overflowing_add(u);
+            let (sum, overflow2) = sum.overflowing_add(carry as u64);
+            carry = overflow1 || overflow2;
+            *v = sum | (*v & !eq);
+        }
+    }
+    // Ignore the unused bits at the end of the last block
+    row.iter()
+        .enumerate()
+        .map(|(k, v)| {
+            let valid = a.len() - 64 * k;
+            let mask = if valid >= 64 { !0 } else { (1 << valid) - 1 };
+            (!v & mask).count_ones() as usize
+        })
+        .sum()
+}
+
+/// Computes the bit masks of the positions of each element in the sequence.
+fn match_masks<T: Eq + Hash>(a: &[T]) -> HashMap<&T, Vec<u64>> {
+    let blocks = a.len().div_ceil(64);
+    let mut masks = HashMap::new();
+    for (i, x) in a.iter().enumerate() {
+        masks.entry(x).or_insert_with(|| vec![0; blocks])[i / 64] |= 1 << (i % 64);
+    }
+    masks
+}
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+
+use comlib_math::{InvertibleModulus, Mod1e9p7, ModInt};
+use rand::{thread_rng, RngCore};
+
+use crate::rolling_hash::hash_terms;
+
+/// Multiset of strings stored by their hashes.
+///
+/// Answers how many times a string has been inserted without storing the strings themselves. The hashes are the same
+/// as computed by [`RollingHash`](crate::RollingHash) and [`StaticHash`](crate::StaticHash) with the same `x`, so
+/// substrings can be looked up directly by their hashes using [`count_hash`](Self::count_hash).
+///
+/// As with the other hashes, distinct strings may collide. The hashes are keyed by the length of the string, and the
+/// probability of a collision can be further reduced by using a second, independent hash, at the cost of computing
+/// each hash twice.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::HashMultiset;
+/// # use comlib_math::Mod1e9p7;
+/// let mut dictionary: HashMultiset<Mod1e9p7> = HashMultiset::with_double_hash();
+/// for word in ["apple", "banana", "apple"] {
+///     dictionary.insert(word);
+/// }
+/// assert_eq!(dictionary.count("apple"), 2);
+/// assert!(!dictionary.contains("cherry"));
+/// assert_eq!(dictionary.len(), 3);
+/// assert_eq!(dictionary.distinct(), 2);
+/// ```
+#[derive(Clone)]
+pub struct HashMultiset<M = Mod1e9p7>
+where
+    M: InvertibleModulus + Copy,
+{
+    /// The group element used for hashing.
+    x: ModInt<M>,
+    /// The group element used for the second hash, if enabled.
+    second_x: Option<ModInt<M>>,
+    /// Multiplicities of the strings keyed by their lengths and hashes.
+    counts: BTreeMap<(usize, M::Base, M::Base), usize>,
+    /// The total number of strings.
+    len: usize,
+}
+
+impl<M> HashMultiset<M>
+where
+    M: InvertibleModulus + Copy + Default,
+    M::Base: From<u64>,
+{
+    /// Constructs an empty multiset using a single hash.
+    ///
+    /// The `x` is chosen randomly.
+    pub fn new() -> Self {
+        Self::with_x(ModInt::from(thread_rng().next_u64()), None)
+    }
+
+    /// Constructs an empty multiset using two independent hashes.
+    ///
+    /// Both values of `x` are chosen randomly.
+    pub fn with_double_hash() -> Self {
+        let mut rng = thread_rng();
+        Self::with_x(
+            ModInt::from(rng.next_u64()),
+            Some(ModInt::from(rng.next_u64())),
+        )
+    }
+
+    /// Constructs an empty multiset which uses the given `x`, and optionally another `x` for the second hash.
+    pub fn with_x(x: ModInt<M>, second_x: Option<ModInt<M>>) -> Self {
+        Self {
+            x,
+            second_x,
+            counts: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Inserts the string into the multiset.
+    ///
+    /// # Time complexity
+    /// Takes O(n + log k) time, where n is the length of the string and k is the number of distinct strings.
+    pub fn insert<S: AsRef<str>>(&mut self, s: S) {
+        let key = self.key(s.as_ref());
+        *self.counts.entry(key).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    /// Removes one copy of the string from the multiset.
+    ///
+    /// Returns whether the string was present.
+    pub fn remove<S: AsRef<str>>(&mut self, s: S) -> bool {
+        let key = self.key(s.as_ref());
+        match self.counts.get_mut(&key) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(&key);
+                }
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Counts the copies of the string in the multiset.
+    pub fn count<S: AsRef<str>>(&self, s: S) -> usize {
+        self.counts.get(&self.key(s.as_ref())).copied().unwrap_or(0)
+    }
+
+    /// Checks whether the string is in the multiset.
+    pub fn contains<S: AsRef<str>>(&self, s: S) -> bool {
+        self.count(s) > 0
+    }
+
+    /// Counts the copies of the string with the given length in characters and hashes.
+    ///
+    /// The second hash must be given if and only if the multiset uses two hashes.
+    ///
+    /// # Time complexity
+    /// Takes O(log k) time, where k is the number of distinct strings.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_string::{HashMultiset, StaticHash};
+    /// # use comlib_math::Mod1e9p7;
+    /// let mut dictionary: HashMultiset<Mod1e9p7> = HashMultiset::new();
+    /// dictionary.insert("ban");
+    /// dictionary.insert("nan");
+    /// let text = StaticHash::with_x("banana", dictionary.x());
+    /// let matches = (0..=3)
+    ///     .filter(|&i| dictionary.count_hash(3, text.get_hash(i..i + 3), None) > 0)
+    ///     .count();
+    /// assert_eq!(matches, 2);
+    /// ```
+    pub fn count_hash(&self, len: usize, hash: ModInt<M>, second_hash: Option<ModInt<M>>) -> usize {
+        assert_eq!(
+            second_hash.is_some(),
+            self.second_x.is_some(),
+            "the second hash must be given if and only if it is enabled"
+        );
+        let second = second_hash.map_or(M::Base::from(0), ModInt::into_inner);
+        self.counts
+            .get(&(len, hash.into_inner(), second))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the total number of strings in the multiset, counting copies.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the multiset is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of distinct strings in the multiset.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns the x used for hashing.
+    pub fn x(&self) -> ModInt<M> {
+        self.x
+    }
+
+    /// Returns the x used for the second hash, if enabled.
+    pub fn second_x(&self) -> Option<ModInt<M>> {
+        self.second_x
+    }
+
+    /// Computes the key of the string.
+    fn key(&self, s: &str) -> (usize, M::Base, M::Base) {
+        let hash = |x: ModInt<M>| {
+            let zero = ModInt::from((M::Base::from(0), x.modulus()));
+            hash_terms(s.chars(), x)
+                .fold(zero, |hash, term| hash + term)
+                .into_inner()
+        };
+        let second = self.second_x.map_or(M::Base::from(0), hash);
+        (s.chars().count(), hash(self.x), second)
+    }
+}
+
+impl<M> Default for HashMultiset<M>
+where
+    M: InvertibleModulus + Copy + Default,
+    M::Base: From<u64>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,233 @@
+/// Computes the prefix function of the given sequence.
+///
+/// The `i`th value of the prefix function is the length of the longest proper prefix of `s[..=i]` which is also its
+/// suffix.
+///
+/// # Time complexity
+/// Takes O(n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::prefix_function;
+/// assert_eq!(prefix_function(b"abacaba"), vec![0, 0, 1, 0, 1, 2, 3]);
+/// ```
+pub fn prefix_function<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// Computes the lengths of all borders of the given sequence in increasing order.
+///
+/// A border is a proper non-empty prefix of the sequence which is also its suffix.
+///
+/// # Time complexity
+/// Takes O(n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::all_borders;
+/// assert_eq!(all_borders(b"abacaba"), vec![1, 3]);
+/// assert_eq!(all_borders(b"abc"), vec![]);
+/// ```
+pub fn all_borders<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let pi = prefix_function(s);
+    // The borders of a border are also borders
+    let mut borders: Vec<_> = std::iter::successors(pi.last().copied(), |&len| {
+        Some(if len > 0 { pi[len - 1] } else { 0 })
+    })
+    .take_while(|&len| len > 0)
+    .collect();
+    borders.reverse();
+    borders
+}
+
+/// Computes the smallest period of the given sequence.
+///
+/// The period `p` is the smallest positive integer such that `s[i] == s[i + p]` for all valid `i`. The last repetition
+/// may be partial, so for example `abcab` has period 3. The period of an empty sequence is zero.
+///
+/// # Time complexity
+/// Takes O(n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::smallest_period;
+/// assert_eq!(smallest_period(b"abcab"), 3);
+/// assert_eq!(smallest_period(b"aaaa"), 1);
+/// assert_eq!(smallest_period(b"abcd"), 4);
+/// ```
+pub fn smallest_period<T: PartialEq>(s: &[T]) -> usize {
+    s.len() - prefix_function(s).last().copied().unwrap_or(0)
+}
+
+/// Computes the length of the shortest block whose repetition forms the given sequence.
+///
+/// Unlike with [`smallest_period`], the block must repeat a whole number of times. The length is the whole sequence
+/// if it is not a repetition of a shorter block.
+///
+/// # Time complexity
+/// Takes O(n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::smallest_repeating_unit;
+/// assert_eq!(smallest_repeating_unit(b"abcabc"), 3);
+/// assert_eq!(smallest_repeating_unit(b"abcab"), 5);
+/// ```
+pub fn smallest_repeating_unit<T: PartialEq>(s: &[T]) -> usize {
+    // Any period dividing the length is a multiple of the smallest period
+    let period = smallest_period(s);
+    if s.len().is_multiple_of(period) {
+        period
+    } else {
+        s.len()
+    }
+}
+
+/// Knuth–Morris–Pratt pattern matcher.
+///
+/// Preprocesses the pattern once, after which all of its occurrences in any text can be found in time linear in the
+/// length of the text.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::Kmp;
+/// let kmp = Kmp::new(b"aba");
+/// assert_eq!(kmp.find_all(b"abababa").collect::<Vec<_>>(), vec![0, 2, 4]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kmp<T> {
+    pattern: Vec<T>,
+    pi: Vec<usize>,
+}
+
+impl<T: PartialEq + Clone> Kmp<T> {
+    /// Constructs the matcher for the given pattern.
+    ///
+    /// # Time complexity
+    /// Takes O(m) time, where m is the length of the pattern.
+    pub fn new(pattern: &[T]) -> Self {
+        Self {
+            pattern: pattern.to_vec(),
+            pi: prefix_function(pattern),
+        }
+    }
+}
+
+impl<T: PartialEq> Kmp<T> {
+    /// Returns the pattern of the matcher.
+    pub fn pattern(&self) -> &[T] {
+        &self.pattern
+    }
+
+    /// Finds all occurrences of the pattern in the text.
+    ///
+    /// Returns an iterator over the starting positions of the occurrences in increasing order. Occurrences may overlap.
+    /// An empty pattern occurs at every position, including the end of the text.
+    ///
+    /// # Time complexity
+    /// Iterating over all of the occurrences takes O(n) time, where n is the length of the text.
+    pub fn find_all<'a>(&'a self, text: &'a [T]) -> Matches<'a, T> {
+        Matches {
+            kmp: self,
+            text,
+            position: 0,
+            matched: 0,
+            empty_done: false,
+        }
+    }
+}
+
+/// Finds all occurrences of the pattern in the text, including overlapping ones.
+///
+/// Returns the starting positions of the occurrences in increasing order. This is a shorthand for
+/// [`Kmp::find_all`].
+///
+/// # Time complexity
+/// Takes O(n + m) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::find_all;
+/// assert_eq!(find_all(b"aaaa", b"aa"), vec![0, 1, 2]);
+/// assert_eq!(find_all("bananas".as_bytes(), "ana".as_bytes()), vec![1, 3]);
+/// ```
+pub fn find_all<T: PartialEq + Clone>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    Kmp::new(pattern).find_all(text).collect()
+}
+
+/// Counts the occurrences of the pattern in the text, including overlapping ones.
+///
+/// # Time complexity
+/// Takes O(n + m) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::count_occurrences;
+/// assert_eq!(count_occurrences(b"abababa", b"aba"), 3);
+/// ```
+pub fn count_occurrences<T: PartialEq + Clone>(text: &[T], pattern: &[T]) -> usize {
+    Kmp::new(pattern).find_all(text).count()
+}
+
+/// Iterator over the occurrences of a pattern in a text.
+///
+/// Use [`Kmp::find_all`] to construct.
+#[derive(Debug, Clone)]
+pub struct Matches<'a, T> {
+    kmp: &'a Kmp<T>,
+    text: &'a [T],
+    /// Index of the next character of the text to process.
+    position: usize,
+    /// Length of the prefix of the pattern matching the processed text.
+    matched: usize,
+    /// Whether the empty pattern has been reported at the end of the text.
+    empty_done: bool,
+}
+
+impl<'a, T: PartialEq> Iterator for Matches<'a, T> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Kmp { pattern, pi } = self.kmp;
+        if pattern.is_empty() {
+            if self.position < self.text.len() {
+                self.position += 1;
+                return Some(self.position - 1);
+            }
+            if self.empty_done {
+                return None;
+            }
+            self.empty_done = true;
+            return Some(self.position);
+        }
+
+        while self.position < self.text.len() {
+            let c = &self.text[self.position];
+            self.position += 1;
+            if self.matched == pattern.len() {
+                self.matched = pi[self.matched - 1];
+            }
+            while self.matched > 0 && *c != pattern[self.matched] {
+                self.matched = pi[self.matched - 1];
+            }
+            if *c == pattern[self.matched] {
+                self.matched += 1;
+            }
+            if self.matched == pattern.len() {
+                return Some(self.position - pattern.len());
+            }
+        }
+        None
+    }
+}
@@ -2,14 +2,42 @@
 //! This library contains some commonly used string algorithms.
 //!
 //! ## Content
-//! - [Rolling hash](RollingHash)
+//! - [Rolling hash](RollingHash), also [immutable](StaticHash)
+//! - [Multiset of strings by their hashes](HashMultiset)
+//! - [Prefix function](prefix_function) and [KMP pattern matching](Kmp)
+//! - [Periods](smallest_period) and [borders](all_borders)
+//! - [Suffix array and LCP array](SuffixArray)
+//! - [Burrows–Wheeler transform](burrows_wheeler_transform)
+//! - [Edit distance](edit_distance) and [longest common subsequence](longest_common_subsequence)
 //!
 //! ## Still missing
 //! - Z algorithm
 //! - Automata
-//! - Pattern matching
 
 #![warn(missing_docs)]
 
 mod rolling_hash;
 pub use rolling_hash::RollingHash;
+
+mod static_hash;
+pub use static_hash::StaticHash;
+
+mod hash_multiset;
+pub use hash_multiset::HashMultiset;
+
+mod kmp;
+pub use kmp::{
+    all_borders, count_occurrences, find_all, prefix_function, smallest_period,
+    smallest_repeating_unit, Kmp, Matches,
+};
+
+mod suffix_array;
+pub use suffix_array::{distinct_substring_count, SuffixArray};
+
+mod bwt;
+pub use bwt::{burrows_wheeler_transform, inverse_burrows_wheeler_transform};
+
+mod distance;
+pub use distance::{
+    edit_distance, edit_distance_bitset, lcs_length_bitset, longest_common_subsequence,
+};
@@ -1,7 +1,8 @@
-use std::ops::{Bound, RangeBounds};
+use std::cmp::Ordering;
+use std::ops::{Range, RangeBounds};
 
-use comlib_math::{InvertibleModulus, Mod1e9p7, ModInt};
-use comlib_range::Bit;
+use comlib_math::{InvertibleModulus, Mod1e9p7, ModInt, Modulus};
+use comlib_range::{to_index_range, Bit};
 use rand::{thread_rng, RngCore};
 
 /// Rolling hash for strings
@@ -18,7 +19,8 @@ use rand::{thread_rng, RngCore};
 /// <code>h<sub>l...r</sub> = (c<sub>l</sub>x<sup>l</sup> + c<sub>l+1</sub>x<sup>l+1</sup> + ... + c<sub>r</sub>x<sup>r</sup>) / x<sup>l</sup></code>.
 /// The sum can be computed efficiently by storing the terms in a [Binary indexed tree](comlib_range::Bit) which allow
 /// querying the sum over a range in `O(log n)` time. The Binary indexed tree also allows updating the terms of the sum
-/// in `O(log n)` time meaning that we can modify the string one character at a time.
+/// in `O(log n)` time meaning that we can modify the string one character at a time. Characters can also be appended
+/// to and removed from the end of the string.
 ///
 /// <sup>1</sup>: The change of a collision attack is tried to be mitigated by randomly choosing the value of `x` for
 /// each run.
@@ -53,20 +55,7 @@ where
     pub fn with_x<S: AsRef<str>>(input: S, x: ModInt<M>) -> Self {
         let input = input.as_ref();
         let chars: Vec<char> = input.chars().collect();
-        let hashes = Bit::from(
-            chars
-                .iter()
-                .copied()
-                // Construct the terms of the hash iteratively.
-                .scan(ModInt::from((M::Base::from(1), x.modulus())), |s, c| {
-                    // Value for the current term
-                    let hash = *s * ModInt::from((M::Base::from(c as u64), x.modulus()));
-                    // Iteratively increase the power of x
-                    *s *= x;
-                    Some(hash)
-                })
-                .collect::<Vec<_>>(),
-        );
+        let hashes = Bit::from(hash_terms(chars.iter().copied(), x).collect::<Vec<_>>());
 
         Self { hashes, chars, x }
     }
@@ -75,12 +64,8 @@ where
     ///
     /// Note that the range is given in characters, not in bytes like with [`str`].
     pub fn get_hash<R: RangeBounds<usize>>(&self, range: R) -> ModInt<M> {
-        let x = match range.start_bound() {
-            Bound::Included(&i) => i,
-            Bound::Excluded(&i) => i + 1,
-            Bound::Unbounded => 0,
-        };
-        let x_pow = self.x.pow(x);
+        let range = to_index_range(range, self.len());
+        let x_pow = self.x.pow(range.start);
 
         self.hashes.sum(range) / x_pow
     }
@@ -99,8 +84,110 @@ where
         self.hashes.add(index, change);
     }
 
+    /// Appends the given character to the end of the string.
+    pub fn push_char(&mut self, c: char) {
+        let x_pow = self.x.pow(self.chars.len());
+        self.hashes
+            .push(x_pow * ModInt::from((M::Base::from(c as u64), x_pow.modulus())));
+        self.chars.push(c);
+    }
+
+    /// Removes the last character of the string and returns it, or `None` if the string is empty.
+    pub fn pop_char(&mut self) -> Option<char> {
+        self.hashes.pop();
+        self.chars.pop()
+    }
+
+    /// Returns the length of the string in characters.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Checks whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Computes the length of the longest common prefix of the substrings over the given ranges.
+    ///
+    /// The result is correct with high probability, as it relies on comparing hashes.
+    ///
+    /// # Time complexity
+    /// Takes O(log² n) time.
+    pub fn lcp(&self, a: Range<usize>, b: Range<usize>) -> usize {
+        common_prefix_len(&a, &b, |len| {
+            self.get_hash(a.start..a.start + len) == self.get_hash(b.start..b.start + len)
+        })
+    }
+
+    /// Compares the substrings over the given ranges lexicographically.
+    ///
+    /// The result is correct with high probability, as it relies on comparing hashes.
+    ///
+    /// # Time complexity
+    /// Takes O(log² n) time.
+    pub fn compare(&self, a: Range<usize>, b: Range<usize>) -> Ordering {
+        let lcp = self.lcp(a.clone(), b.clone());
+        compare_after_prefix(&self.chars, a, b, lcp)
+    }
+
     /// Returns the x used for hashing.
     pub fn x(&self) -> ModInt<M> {
         self.x
     }
 }
+
+/// Computes the terms <code>c<sub>i</sub>x<sup>i</sup></code> of the hash of the given characters.
+pub(crate) fn hash_terms<M, I>(chars: I, x: ModInt<M>) -> impl Iterator<Item = ModInt<M>>
+where
+    M: Modulus,
+    M::Base: From<u64>,
+    I: IntoIterator<Item = char>,
+{
+    chars
+        .into_iter()
+        // Construct the terms of the hash iteratively.
+        .scan(
+            ModInt::from((M::Base::from(1), x.modulus())),
+            move |power, c| {
+                // Value for the current term
+                let term = *power * ModInt::from((M::Base::from(c as u64), x.modulus()));
+                // Iteratively increase the power of x
+                *power *= x;
+                Some(term)
+            },
+        )
+}
+
+/// Finds the length of the longest common prefix of two ranges by binary searching with the given predicate.
+///
+/// The predicate tells whether the prefixes of the given length are equal.
+pub(crate) fn common_prefix_len<F>(a: &Range<usize>, b: &Range<usize>, equal: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    let (mut low, mut high) = (0, a.len().min(b.len()));
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        if equal(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    low
+}
+
+/// Compares two substrings given the length of their longest common prefix.
+pub(crate) fn compare_after_prefix(
+    chars: &[char],
+    a: Range<usize>,
+    b: Range<usize>,
+    lcp: usize,
+) -> Ordering {
+    if lcp == a.len().min(b.len()) {
+        a.len().cmp(&b.len())
+    } else {
+        chars[a.start + lcp].cmp(&chars[b.start + lcp])
+    }
+}
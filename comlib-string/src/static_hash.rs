@@ -0,0 +1,137 @@
+use std::cmp::Ordering;
+use std::ops::{Range, RangeBounds};
+
+use comlib_math::{InvertibleModulus, Mod1e9p7, ModInt};
+use comlib_range::to_index_range;
+use rand::{thread_rng, RngCore};
+
+use crate::rolling_hash::{common_prefix_len, compare_after_prefix, hash_terms};
+
+/// Immutable rolling hash for strings.
+///
+/// Computes the same hashes as [`RollingHash`](crate::RollingHash) with the same `x`, but doesn't allow modifying the
+/// string. In exchange the hash of any substring can be computed in `O(1)` time. This is achieved by storing the prefix
+/// sums <code>c<sub>0</sub> + c<sub>1</sub>x + ... + c<sub>i-1</sub>x<sup>i-1</sup></code> together with the powers
+/// <code>x<sup>-i</sup></code>, so that the hash of a substring is the difference of two prefix sums multiplied by a
+/// single power.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::StaticHash;
+/// # use comlib_math::Mod1e9p7;
+/// let hash: StaticHash<Mod1e9p7> = StaticHash::new("abcxyzabc");
+/// assert_eq!(hash.get_hash(0..3), hash.get_hash(6..));
+/// assert_ne!(hash.get_hash(3..6), hash.get_hash(6..));
+/// ```
+#[derive(Clone)]
+pub struct StaticHash<M = Mod1e9p7>
+where
+    M: InvertibleModulus + Copy,
+{
+    /// Prefix sums of the terms of the hash.
+    prefixes: Vec<ModInt<M>>,
+    /// Powers of the inverse of `x`.
+    inverse_powers: Vec<ModInt<M>>,
+    /// Original characters for comparing substrings.
+    chars: Vec<char>,
+    /// The group element used for hashing.
+    x: ModInt<M>,
+}
+
+impl<M> StaticHash<M>
+where
+    M: InvertibleModulus + Copy + Default,
+    M::Base: From<u64>,
+{
+    /// Constructs new `StaticHash`.
+    ///
+    /// The `x` is chosen randomly.
+    pub fn new<S: AsRef<str>>(input: S) -> Self {
+        // Choose random `x`
+        let x = ModInt::from(thread_rng().next_u64());
+        Self::with_x(input, x)
+    }
+
+    /// Constructs new `StaticHash` which uses the given `x`.
+    ///
+    /// # Time complexity
+    /// Takes O(n) time.
+    pub fn with_x<S: AsRef<str>>(input: S, x: ModInt<M>) -> Self {
+        let x_inv = x.inv();
+        let mut prefixes = vec![ModInt::from((M::Base::from(0), x.modulus()))];
+        let mut inverse_powers = vec![ModInt::from((M::Base::from(1), x.modulus()))];
+        let chars: Vec<char> = input.as_ref().chars().collect();
+        for term in hash_terms(chars.iter().copied(), x) {
+            prefixes.push(*prefixes.last().unwrap() + term);
+            inverse_powers.push(*inverse_powers.last().unwrap() * x_inv);
+        }
+
+        Self {
+            prefixes,
+            inverse_powers,
+            chars,
+            x,
+        }
+    }
+
+    /// Gets the hash of the substring over the given range.
+    ///
+    /// Note that the range is given in characters, not in bytes like with [`str`].
+    ///
+    /// # Time complexity
+    /// Takes O(1) time.
+    pub fn get_hash<R: RangeBounds<usize>>(&self, range: R) -> ModInt<M> {
+        let Range { start, end } = to_index_range(range, self.len());
+
+        (self.prefixes[end] - self.prefixes[start]) * self.inverse_powers[start]
+    }
+
+    /// Computes the length of the longest common prefix of the substrings over the given ranges.
+    ///
+    /// The result is correct with high probability, as it relies on comparing hashes.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    pub fn lcp(&self, a: Range<usize>, b: Range<usize>) -> usize {
+        common_prefix_len(&a, &b, |len| {
+            self.get_hash(a.start..a.start + len) == self.get_hash(b.start..b.start + len)
+        })
+    }
+
+    /// Compares the substrings over the given ranges lexicographically.
+    ///
+    /// Together with [`lcp`](Self::lcp) this allows sorting substrings, for example all suffixes in O(n log² n) time.
+    /// The result is correct with high probability, as it relies on comparing hashes.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_string::StaticHash;
+    /// # use comlib_math::Mod1e9p7;
+    /// let hash: StaticHash<Mod1e9p7> = StaticHash::new("banana");
+    /// let mut suffixes: Vec<usize> = (0..6).collect();
+    /// suffixes.sort_by(|&a, &b| hash.compare(a..6, b..6));
+    /// assert_eq!(suffixes, vec![5, 3, 1, 0, 4, 2]);
+    /// ```
+    pub fn compare(&self, a: Range<usize>, b: Range<usize>) -> Ordering {
+        let lcp = self.lcp(a.clone(), b.clone());
+        compare_after_prefix(&self.chars, a, b, lcp)
+    }
+
+    /// Returns the length of the string in characters.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Checks whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the x used for hashing.
+    pub fn x(&self) -> ModInt<M> {
+        self.x
+    }
+}
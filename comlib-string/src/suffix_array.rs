@@ -0,0 +1,246 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use comlib_range::SparseTable;
+
+/// Suffix array of a sequence together with its LCP array.
+///
+/// The suffix array lists the starting positions of the suffixes of the sequence in lexicographic order. The LCP array
+/// contains the lengths of the longest common prefixes of adjacent suffixes in that order. Together they allow
+/// comparing any two substrings in constant time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::SuffixArray;
+/// let sa = SuffixArray::new(b"banana");
+/// assert_eq!(sa.suffixes(), &[5, 3, 1, 0, 4, 2]);
+/// assert_eq!(sa.lcp(), &[1, 3, 0, 0, 2]);
+/// assert_eq!(sa.distinct_substrings(), 15);
+/// assert!(sa.compare(1..3, 3..6).is_lt());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixArray {
+    suffixes: Vec<usize>,
+    ranks: Vec<usize>,
+    lcp: Vec<usize>,
+    /// Sparse table of minimums over the LCP array.
+    sparse: SparseTable<usize, fn(&usize, &usize) -> usize>,
+    /// Numbers of distinct substrings which are prefixes of the first `i` suffixes in lexicographic order.
+    distinct: Vec<usize>,
+}
+
+impl SuffixArray {
+    /// Constructs the suffix array of the given sequence.
+    ///
+    /// # Time complexity
+    /// Takes O(n log n) time. This uses prefix doubling with counting sort, and [Kasai's algorithm] for the LCP array.
+    ///
+    /// [Kasai's algorithm]: https://doi.org/10.1007/3-540-48194-X_17
+    pub fn new<T: Ord>(s: &[T]) -> Self {
+        let n = s.len();
+        let mut suffixes: Vec<usize> = (0..n).collect();
+        suffixes.sort_by(|&a, &b| s[a].cmp(&s[b]));
+        let mut ranks = vec![0; n];
+        for i in 1..n {
+            let (a, b) = (suffixes[i - 1], suffixes[i]);
+            ranks[b] = ranks[a] + (s[a] != s[b]) as usize;
+        }
+
+        // After each round the suffixes are sorted by their prefixes of length `2k`
+        let mut k = 1;
+        let mut order = Vec::with_capacity(n);
+        let mut count = vec![0; n + 1];
+        let mut new_ranks = vec![0; n];
+        while k < n && ranks[suffixes[n - 1]] < n - 1 {
+            // Sort by the rank of the second half. Suffixes without a second half come first.
+            order.clear();
+            order.extend(n - k..n);
+            order.extend(suffixes.iter().filter(|&&i| i >= k).map(|&i| i - k));
+
+            // Stable counting sort by the rank of the first half
+            count.iter_mut().for_each(|c| *c = 0);
+            for &i in &order {
+                count[ranks[i] + 1] += 1;
+            }
+            for r in 1..=n {
+                count[r] += count[r - 1];
+            }
+            for &i in &order {
+                suffixes[count[ranks[i]]] = i;
+                count[ranks[i]] += 1;
+            }
+
+            let key = |i: usize| (ranks[i], ranks.get(i + k).map_or(0, |&r| r + 1));
+            new_ranks[suffixes[0]] = 0;
+            for i in 1..n {
+                let (a, b) = (suffixes[i - 1], suffixes[i]);
+                new_ranks[b] = new_ranks[a] + (key(a) != key(b)) as usize;
+            }
+            std::mem::swap(&mut ranks, &mut new_ranks);
+            k *= 2;
+        }
+
+        let lcp = kasai(s, &suffixes, &ranks);
+        let min: fn(&usize, &usize) -> usize = |a, b| *a.min(b);
+        let sparse = SparseTable::new(lcp.clone(), min);
+
+        // Each suffix adds the prefixes which are longer than the common prefix with the previous suffix
+        let mut distinct = vec![0; n + 1];
+        for i in 0..n {
+            let shared = if i > 0 { lcp[i - 1] } else { 0 };
+            distinct[i + 1] = distinct[i] + (n - suffixes[i]) - shared;
+        }
+
+        Self {
+            suffixes,
+            ranks,
+            lcp,
+            sparse,
+            distinct,
+        }
+    }
+
+    /// Returns the number of suffixes, i.e. the length of the sequence.
+    pub fn len(&self) -> usize {
+        self.suffixes.len()
+    }
+
+    /// Checks whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
+    /// Returns the starting positions of the suffixes in lexicographic order.
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    /// Returns the position of each suffix in the suffix array, i.e. the inverse of [`suffixes`](Self::suffixes).
+    pub fn ranks(&self) -> &[usize] {
+        &self.ranks
+    }
+
+    /// Returns the LCP array.
+    ///
+    /// The `i`th value is the length of the longest common prefix of the `i`th and `(i + 1)`th suffix in lexicographic
+    /// order.
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Computes the length of the longest common prefix of the suffixes starting at the given positions.
+    ///
+    /// # Panics
+    /// Panics if either of the positions is out of bounds.
+    ///
+    /// # Time complexity
+    /// Takes O(1) time.
+    pub fn longest_common_prefix(&self, a: usize, b: usize) -> usize {
+        if a == b {
+            return self.len() - a;
+        }
+        let (low, high) = if self.ranks[a] < self.ranks[b] {
+            (self.ranks[a], self.ranks[b])
+        } else {
+            (self.ranks[b], self.ranks[a])
+        };
+        self.sparse.query(low..high).unwrap()
+    }
+
+    /// Compares the substrings over the given ranges lexicographically.
+    ///
+    /// # Panics
+    /// Panics if either of the ranges is out of bounds.
+    ///
+    /// # Time complexity
+    /// Takes O(1) time.
+    pub fn compare(&self, a: Range<usize>, b: Range<usize>) -> Ordering {
+        assert!(
+            a.start <= a.end && a.end <= self.len(),
+            "range out of bounds"
+        );
+        assert!(
+            b.start <= b.end && b.end <= self.len(),
+            "range out of bounds"
+        );
+        let (len_a, len_b) = (a.end - a.start, b.end - b.start);
+        let min_len = len_a.min(len_b);
+        if min_len == 0 || self.longest_common_prefix(a.start, b.start) >= min_len {
+            len_a.cmp(&len_b)
+        } else {
+            self.ranks[a.start].cmp(&self.ranks[b.start])
+        }
+    }
+
+    /// Counts the distinct non-empty substrings of the sequence.
+    ///
+    /// Each suffix contributes the prefixes which are not shared with the previous suffix in lexicographic order.
+    pub fn distinct_substrings(&self) -> usize {
+        self.distinct[self.len()]
+    }
+
+    /// Finds the `k`th distinct non-empty substring in lexicographic order, counting from zero.
+    ///
+    /// Returns the range of some occurrence of the substring, or `None` if there are at most `k` distinct substrings.
+    ///
+    /// # Time complexity
+    /// Takes O(log n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use comlib_string::SuffixArray;
+    /// let s = b"aba";
+    /// let sa = SuffixArray::new(s);
+    /// let substrings: Vec<_> = (0..sa.distinct_substrings())
+    ///     .map(|k| &s[sa.kth_substring(k).unwrap()])
+    ///     .collect();
+    /// assert_eq!(substrings, [&b"a"[..], b"ab", b"aba", b"b", b"ba"]);
+    /// assert_eq!(sa.kth_substring(5), None);
+    /// ```
+    pub fn kth_substring(&self, k: usize) -> Option<Range<usize>> {
+        if k >= self.distinct_substrings() {
+            return None;
+        }
+        // The substrings are listed by the suffix they first occur in, and then by their length
+        let rank = self.distinct.partition_point(|&count| count <= k) - 1;
+        let shared = if rank > 0 { self.lcp[rank - 1] } else { 0 };
+        let start = self.suffixes[rank];
+        Some(start..start + shared + 1 + (k - self.distinct[rank]))
+    }
+}
+
+/// Counts the distinct non-empty substrings of the string.
+///
+/// # Time complexity
+/// Takes O(n log n) time.
+///
+/// # Examples
+/// ```
+/// # use comlib_string::distinct_substring_count;
+/// assert_eq!(distinct_substring_count("abab"), 7);
+/// ```
+pub fn distinct_substring_count(s: &str) -> usize {
+    let chars: Vec<char> = s.chars().collect();
+    SuffixArray::new(&chars).distinct_substrings()
+}
+
+/// Computes the LCP array using Kasai's algorithm.
+fn kasai<T: Eq>(s: &[T], suffixes: &[usize], ranks: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    let mut lcp = vec![0; n.saturating_sub(1)];
+    // The common prefix with the next suffix shrinks by at most one when moving to the next starting position
+    let mut common = 0;
+    for i in 0..n {
+        if ranks[i] + 1 == n {
+            common = 0;
+            continue;
+        }
+        let j = suffixes[ranks[i] + 1];
+        while i + common < n && j + common < n && s[i + common] == s[j + common] {
+            common += 1;
+        }
+        lcp[ranks[i]] = common;
+        common = common.saturating_sub(1);
+    }
+    lcp
+}
@@ -0,0 +1,41 @@
+use comlib_string::{burrows_wheeler_transform, inverse_burrows_wheeler_transform};
+use comlib_testing::lcg;
+
+/// Computes the transform by sorting the rotations explicitly, using `None` as the sentinel.
+fn brute_force(s: &[u8]) -> Vec<Option<u8>> {
+    let with_sentinel: Vec<Option<u8>> = s.iter().copied().map(Some).chain([None]).collect();
+    let n = with_sentinel.len();
+    let mut rotations: Vec<Vec<Option<u8>>> = (0..n)
+        .map(|i| {
+            with_sentinel[i..]
+                .iter()
+                .chain(&with_sentinel[..i])
+                .copied()
+                .collect()
+        })
+        .collect();
+    rotations.sort();
+    rotations.iter().map(|r| r[n - 1]).collect()
+}
+
+#[test]
+fn test_empty() {
+    let (bwt, sentinel) = burrows_wheeler_transform::<u8>(&[]);
+    assert_eq!((bwt, sentinel), (vec![], 0));
+    assert_eq!(inverse_burrows_wheeler_transform::<u8>(&[], 0), vec![]);
+}
+
+#[test]
+fn test_random() {
+    let mut next = lcg(23);
+    for _ in 0..300 {
+        let alphabet = 1 + next(4);
+        let s: Vec<u8> = (0..next(30)).map(|_| b'a' + next(alphabet) as u8).collect();
+        let (bwt, sentinel) = burrows_wheeler_transform(&s);
+
+        let mut expected = brute_force(&s);
+        assert_eq!(expected.remove(sentinel), None);
+        assert_eq!(bwt.iter().copied().map(Some).collect::<Vec<_>>(), expected);
+        assert_eq!(inverse_burrows_wheeler_transform(&bwt, sentinel), s);
+    }
+}
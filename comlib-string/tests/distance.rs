@@ -0,0 +1,73 @@
+use comlib_string::{
+    edit_distance, edit_distance_bitset, lcs_length_bitset, longest_common_subsequence,
+};
+use comlib_testing::lcg;
+
+fn lcs_length(a: &[u8], b: &[u8]) -> usize {
+    let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            table[i + 1][j + 1] = if a[i] == b[j] {
+                table[i][j] + 1
+            } else {
+                table[i][j + 1].max(table[i + 1][j])
+            };
+        }
+    }
+    table[a.len()][b.len()]
+}
+
+#[test]
+fn test_edit_distance() {
+    assert_eq!(edit_distance::<u8>(b"", b""), 0);
+    assert_eq!(edit_distance(b"", b"abc"), 3);
+    assert_eq!(edit_distance(b"abc", b""), 3);
+    assert_eq!(edit_distance(b"flaw", b"lawn"), 2);
+    assert_eq!(edit_distance(b"intention", b"execution"), 5);
+    assert_eq!(edit_distance_bitset::<u8>(b"", b""), 0);
+    assert_eq!(edit_distance_bitset(b"", b"abc"), 3);
+    assert_eq!(edit_distance_bitset(b"abc", b""), 3);
+    assert_eq!(edit_distance_bitset(b"intention", b"execution"), 5);
+}
+
+#[test]
+fn test_longest_common_subsequence() {
+    assert_eq!(longest_common_subsequence::<u8>(b"", b"abc"), vec![]);
+    assert_eq!(
+        longest_common_subsequence(b"axbyc", b"abc"),
+        vec![(0, 0), (2, 1), (4, 2)]
+    );
+    assert_eq!(lcs_length_bitset::<u8>(b"", b"abc"), 0);
+    assert_eq!(lcs_length_bitset(b"axbyc", b"abc"), 3);
+}
+
+#[test]
+fn test_random() {
+    // Lengths around multiples of the word size exercise the carries between blocks
+    const WORD_LENGTHS: [usize; 7] = [0, 63, 64, 65, 127, 128, 129];
+    let mut next = lcg(17);
+    for round in 0..300 {
+        let alphabet = 1 + next(4);
+        let (n, m) = if round % 2 == 0 {
+            (
+                WORD_LENGTHS[next(7) as usize],
+                WORD_LENGTHS[next(7) as usize],
+            )
+        } else {
+            (next(200) as usize, next(200) as usize)
+        };
+        let a: Vec<u8> = (0..n).map(|_| b'a' + next(alphabet) as u8).collect();
+        let b: Vec<u8> = (0..m).map(|_| b'a' + next(alphabet) as u8).collect();
+
+        let distance = edit_distance(&a, &b);
+        assert_eq!(edit_distance_bitset(&a, &b), distance);
+        assert_eq!(edit_distance(&b, &a), distance);
+
+        let length = lcs_length(&a, &b);
+        assert_eq!(lcs_length_bitset(&a, &b), length);
+        let pairs = longest_common_subsequence(&a, &b);
+        assert_eq!(pairs.len(), length);
+        assert!(pairs.iter().all(|&(i, j)| a[i] == b[j]));
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+    }
+}
@@ -0,0 +1,51 @@
+use comlib_math::{Mod1e9p7, ModInt};
+use comlib_string::{HashMultiset, RollingHash};
+
+#[test]
+fn test_insert_remove() {
+    let mut set: HashMultiset<Mod1e9p7> = HashMultiset::new();
+    assert!(set.is_empty());
+    set.insert("a");
+    set.insert("ab");
+    set.insert("a");
+    set.insert("");
+    assert_eq!((set.len(), set.distinct()), (4, 3));
+    assert_eq!(set.count("a"), 2);
+    assert_eq!(set.count(""), 1);
+    assert_eq!(set.count("b"), 0);
+    assert!(set.remove("a"));
+    assert!(set.remove("a"));
+    assert!(!set.remove("a"));
+    assert!(!set.contains("a"));
+    assert_eq!((set.len(), set.distinct()), (2, 2));
+}
+
+#[test]
+fn test_count_hash() {
+    let x = ModInt::from(1_234_567u64);
+    let second_x = ModInt::from(7_654_321u64);
+    let mut set: HashMultiset<Mod1e9p7> = HashMultiset::with_x(x, Some(second_x));
+    let words = ["ab", "ba", "aba", "b"];
+    for word in &words {
+        set.insert(word);
+    }
+    assert_eq!(set.second_x(), Some(second_x));
+
+    let text = "abababa";
+    let first: RollingHash<Mod1e9p7> = RollingHash::with_x(text, x);
+    let second: RollingHash<Mod1e9p7> = RollingHash::with_x(text, second_x);
+    for l in 0..=text.len() {
+        for r in l..=text.len() {
+            let expected = words.iter().filter(|&&w| w == &text[l..r]).count();
+            let count = set.count_hash(r - l, first.get_hash(l..r), Some(second.get_hash(l..r)));
+            assert_eq!(count, expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "second hash")]
+fn test_count_hash_requires_second_hash() {
+    let set: HashMultiset<Mod1e9p7> = HashMultiset::with_double_hash();
+    set.count_hash(0, ModInt::from(0u64), None);
+}
@@ -0,0 +1,74 @@
+use comlib_string::{
+    all_borders, count_occurrences, find_all, prefix_function, smallest_period,
+    smallest_repeating_unit, Kmp,
+};
+use comlib_testing::lcg;
+
+fn brute_force(pattern: &[u8], text: &[u8]) -> Vec<usize> {
+    (0..=text.len())
+        .filter(|&i| text[i..].starts_with(pattern))
+        .collect()
+}
+
+#[test]
+fn test_prefix_function() {
+    assert_eq!(prefix_function::<u8>(&[]), Vec::<usize>::new());
+    assert_eq!(prefix_function(b"aaaa"), vec![0, 1, 2, 3]);
+    assert_eq!(prefix_function(b"abcabcd"), vec![0, 0, 0, 1, 2, 3, 0]);
+    assert_eq!(prefix_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+}
+
+#[test]
+fn test_empty() {
+    let kmp = Kmp::new(b"");
+    assert_eq!(kmp.find_all(b"ab").collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(kmp.find_all(b"").collect::<Vec<_>>(), vec![0]);
+    let kmp = Kmp::new(b"a");
+    assert_eq!(kmp.find_all(b"").count(), 0);
+}
+
+#[test]
+fn test_chars() {
+    let pattern: Vec<char> = "äö".chars().collect();
+    let text: Vec<char> = "äöäääöö".chars().collect();
+    let kmp = Kmp::new(&pattern);
+    assert_eq!(kmp.find_all(&text).collect::<Vec<_>>(), vec![0, 4]);
+}
+
+#[test]
+fn test_random() {
+    let mut next = lcg(1);
+    for _ in 0..500 {
+        let alphabet = 1 + next(3);
+        let pattern: Vec<u8> = (0..next(5)).map(|_| b'a' + next(alphabet) as u8).collect();
+        let text: Vec<u8> = (0..next(30)).map(|_| b'a' + next(alphabet) as u8).collect();
+        let kmp = Kmp::new(&pattern);
+        let expected = brute_force(&pattern, &text);
+        assert_eq!(kmp.find_all(&text).collect::<Vec<_>>(), expected);
+        assert_eq!(find_all(&text, &pattern), expected);
+        assert_eq!(count_occurrences(&text, &pattern), expected.len());
+    }
+}
+
+#[test]
+fn test_periodicity() {
+    let mut next = lcg(2);
+    assert_eq!(smallest_period::<u8>(&[]), 0);
+    assert_eq!(smallest_repeating_unit::<u8>(&[]), 0);
+    assert_eq!(all_borders::<u8>(&[]), vec![]);
+    for _ in 0..500 {
+        let alphabet = 1 + next(3);
+        let s: Vec<u8> = (0..1 + next(20))
+            .map(|_| b'a' + next(alphabet) as u8)
+            .collect();
+        let n = s.len();
+        let borders: Vec<usize> = (1..n).filter(|&len| s[..len] == s[n - len..]).collect();
+        let period = (1..=n).find(|&p| (p..n).all(|i| s[i] == s[i - p])).unwrap();
+        let unit = (1..=n)
+            .find(|&p| n.is_multiple_of(p) && (p..n).all(|i| s[i] == s[i - p]))
+            .unwrap();
+        assert_eq!(all_borders(&s), borders);
+        assert_eq!(smallest_period(&s), period);
+        assert_eq!(smallest_repeating_unit(&s), unit);
+    }
+}
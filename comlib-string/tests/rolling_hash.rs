@@ -1,4 +1,4 @@
-use comlib_math::Mod1e9p7;
+use comlib_math::{Mod1e9p7, ModInt};
 use comlib_string::RollingHash;
 
 #[test]
@@ -17,3 +17,30 @@ fn test_rolling_hash() {
     assert_ne!(hash.get_hash(3..=5), hash.get_hash(6..));
     assert_eq!(hash.get_hash(0..=1), hash.get_hash(6..8));
 }
+
+#[test]
+fn test_push_pop() {
+    let x = ModInt::from(987_654_321u64);
+    let mut hash: RollingHash<Mod1e9p7> = RollingHash::with_x("", x);
+    for c in "abcab".chars() {
+        hash.push_char(c);
+    }
+    assert_eq!(hash.len(), 5);
+    assert_eq!(
+        hash.get_hash(..),
+        RollingHash::with_x("abcab", x).get_hash(..)
+    );
+    assert_eq!(hash.get_hash(0..2), hash.get_hash(3..));
+    assert_eq!(hash.pop_char(), Some('b'));
+    assert_eq!(hash.pop_char(), Some('a'));
+    assert_eq!(
+        hash.get_hash(..),
+        RollingHash::with_x("abc", x).get_hash(..)
+    );
+    hash.push_char('d');
+    hash.set_char(0, 'd');
+    assert_eq!(hash.get_hash(0..1), hash.get_hash(3..));
+    while hash.pop_char().is_some() {}
+    assert!(hash.is_empty());
+    assert_eq!(hash.get_hash(..), RollingHash::with_x("", x).get_hash(..));
+}
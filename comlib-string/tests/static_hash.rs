@@ -0,0 +1,55 @@
+use comlib_math::{Mod1e9p7, ModInt};
+use comlib_string::{RollingHash, StaticHash};
+
+#[test]
+fn test_static_hash() {
+    let hash: StaticHash<Mod1e9p7> = StaticHash::new("abcxyzabc");
+    assert_eq!(hash.len(), 9);
+    assert_eq!(hash.get_hash(0..=2), hash.get_hash(6..));
+    assert_ne!(hash.get_hash(3..=5), hash.get_hash(6..));
+    assert_eq!(hash.get_hash(2..2), hash.get_hash(..0));
+    assert!(StaticHash::<Mod1e9p7>::new("").is_empty());
+}
+
+#[test]
+fn test_matches_rolling_hash() {
+    let s = "håshing strings, håshing substrings";
+    let x = ModInt::from(123_456_789u64);
+    let rolling: RollingHash<Mod1e9p7> = RollingHash::with_x(s, x);
+    let fixed: StaticHash<Mod1e9p7> = StaticHash::with_x(s, x);
+    let n = s.chars().count();
+    for l in 0..=n {
+        for r in l..=n {
+            assert_eq!(fixed.get_hash(l..r), rolling.get_hash(l..r));
+        }
+    }
+}
+
+#[test]
+fn test_compare_and_lcp() {
+    let s = "abaababaabaababaab";
+    let chars: Vec<char> = s.chars().collect();
+    let x = ModInt::from(31_415_926u64);
+    let rolling: RollingHash<Mod1e9p7> = RollingHash::with_x(s, x);
+    let fixed: StaticHash<Mod1e9p7> = StaticHash::with_x(s, x);
+    let n = chars.len();
+    let ranges: Vec<_> = (0..=n)
+        .flat_map(|l| (l..=n).map(move |r| l..r))
+        .step_by(7)
+        .collect();
+    for a in &ranges {
+        for b in &ranges {
+            let (a, b) = (a.clone(), b.clone());
+            let lcp = chars[a.clone()]
+                .iter()
+                .zip(&chars[b.clone()])
+                .take_while(|(x, y)| x == y)
+                .count();
+            let ordering = chars[a.clone()].cmp(&chars[b.clone()]);
+            assert_eq!(fixed.lcp(a.clone(), b.clone()), lcp);
+            assert_eq!(rolling.lcp(a.clone(), b.clone()), lcp);
+            assert_eq!(fixed.compare(a.clone(), b.clone()), ordering);
+            assert_eq!(rolling.compare(a, b), ordering);
+        }
+    }
+}
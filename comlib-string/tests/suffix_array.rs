@@ -0,0 +1,88 @@
+use comlib_string::{distinct_substring_count, SuffixArray};
+use comlib_testing::lcg;
+use std::collections::{BTreeSet, HashSet};
+
+fn common_prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+#[test]
+fn test_empty() {
+    let sa = SuffixArray::new::<u8>(&[]);
+    assert!(sa.is_empty());
+    assert_eq!(sa.lcp(), &[] as &[usize]);
+    assert_eq!(sa.distinct_substrings(), 0);
+    assert!(sa.compare(0..0, 0..0).is_eq());
+}
+
+#[test]
+fn test_single() {
+    let sa = SuffixArray::new(b"x");
+    assert_eq!(sa.suffixes(), &[0]);
+    assert_eq!(sa.ranks(), &[0]);
+    assert_eq!(sa.longest_common_prefix(0, 0), 1);
+    assert_eq!(sa.distinct_substrings(), 1);
+}
+
+#[test]
+fn test_random() {
+    let mut next = lcg(3);
+    for _ in 0..300 {
+        let alphabet = 1 + next(4);
+        let s: Vec<u8> = (0..next(40)).map(|_| b'a' + next(alphabet) as u8).collect();
+        let n = s.len();
+        let sa = SuffixArray::new(&s);
+
+        let mut expected: Vec<usize> = (0..n).collect();
+        expected.sort_by_key(|&i| &s[i..]);
+        assert_eq!(sa.suffixes(), &expected[..]);
+        for i in 0..n {
+            assert_eq!(sa.suffixes()[sa.ranks()[i]], i);
+        }
+        for i in 1..n {
+            assert_eq!(
+                sa.lcp()[i - 1],
+                common_prefix(&s[expected[i - 1]..], &s[expected[i]..])
+            );
+        }
+
+        let distinct: BTreeSet<&[u8]> = (0..n)
+            .flat_map(|i| (i + 1..=n).map(move |j| (i, j)))
+            .map(|(i, j)| &s[i..j])
+            .collect();
+        assert_eq!(sa.distinct_substrings(), distinct.len());
+        for (k, &substring) in distinct.iter().enumerate() {
+            assert_eq!(&s[sa.kth_substring(k).unwrap()], substring);
+        }
+        assert_eq!(sa.kth_substring(distinct.len()), None);
+
+        for _ in 0..30 {
+            if n == 0 {
+                break;
+            }
+            let (a, b) = (next(n as u64) as usize, next(n as u64) as usize);
+            assert_eq!(
+                sa.longest_common_prefix(a, b),
+                common_prefix(&s[a..], &s[b..])
+            );
+            let a_end = a + next((n - a) as u64 + 1) as usize;
+            let b_end = b + next((n - b) as u64 + 1) as usize;
+            assert_eq!(
+                sa.compare(a..a_end, b..b_end),
+                s[a..a_end].cmp(&s[b..b_end])
+            );
+        }
+    }
+}
+
+#[test]
+fn test_distinct_substring_count() {
+    assert_eq!(distinct_substring_count(""), 0);
+    assert_eq!(distinct_substring_count("aaaa"), 4);
+    assert_eq!(distinct_substring_count("äöä"), 5);
+    let s = "mississippi";
+    let distinct: HashSet<&str> = (0..s.len())
+        .flat_map(|i| (i + 1..=s.len()).map(move |j| &s[i..j]))
+        .collect();
+    assert_eq!(distinct_substring_count(s), distinct.len());
+}
@@ -0,0 +1,52 @@
+//! # Comlib Testing Utilities
+//! This library contains helpers shared by the tests of the other Comlib libraries. It is only used as a
+//! dev-dependency.
+//!
+//! ## Content
+//! - [Deterministic pseudorandom numbers](Random) for randomized tests, also [as a closure](lcg)
+
+#![warn(missing_docs)]
+
+/// Deterministic pseudorandom number generator for randomized tests.
+///
+/// Uses a [linear congruential generator](https://en.wikipedia.org/wiki/Linear_congruential_generator), so the same
+/// seed always produces the same numbers and failing tests can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Random(u64);
+
+impl Random {
+    /// Constructs a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Generates a number in `0..m`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    pub fn next(&mut self, m: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % m
+    }
+
+    /// Generates a number in `low..=high`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.next((high - low + 1) as u64) as i64
+    }
+}
+
+/// Constructs a closure generating numbers in `0..m` for the given `m`, as [`Random::next`] does.
+///
+/// # Examples
+/// ```
+/// # use comlib_testing::lcg;
+/// let mut next = lcg(1);
+/// assert!((0..100).all(|_| next(6) < 6));
+/// ```
+pub fn lcg(seed: u64) -> impl FnMut(u64) -> u64 {
+    let mut random = Random::new(seed);
+    move |m| random.next(m)
+}